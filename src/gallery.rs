@@ -0,0 +1,152 @@
+//! Classic test matrices.
+//!
+//! Those matrices have known properties, e.g., a bad conditioning or close eigenvalues, which
+//! makes them standard inputs for numerical tests and benchmarks.
+
+use std::num;
+use std::num::{Zero, NumCast};
+use std::cmp;
+use std::rand;
+use std::rand::Rand;
+use structs::DMat;
+
+/// The `n x n` Hilbert matrix, with components `1 / (i + j + 1)`.
+///
+/// It is symmetric positive-definite and notoriously ill-conditioned: its condition number grows
+/// like `e^(3.5 n)`.
+pub fn hilbert<N: Float>(n: uint) -> DMat<N> {
+    DMat::from_fn(n, n, |i, j| {
+        let d: N = num::cast(i + j + 1).unwrap();
+
+        d.recip()
+    })
+}
+
+/// The `n x n` Lehmer matrix, with components `(min(i, j) + 1) / (max(i, j) + 1)`.
+///
+/// It is symmetric positive-definite, and its inverse is tridiagonal.
+pub fn lehmer<N: Float>(n: uint) -> DMat<N> {
+    DMat::from_fn(n, n, |i, j| {
+        let a: N = num::cast(cmp::min(i, j) + 1).unwrap();
+        let b: N = num::cast(cmp::max(i, j) + 1).unwrap();
+
+        a / b
+    })
+}
+
+/// The `n x n` Wilkinson matrix `W+`.
+///
+/// It is symmetric tridiagonal with ones on its off-diagonals and `|(n - 1) / 2 - i|` on its
+/// diagonal. Its largest eigenvalues come in pairs that are very close to each other.
+pub fn wilkinson<N: Float>(n: uint) -> DMat<N> {
+    let _2: N   = num::cast(2u).unwrap();
+    let half: N = num::cast::<uint, N>(n - 1).unwrap() / _2;
+
+    DMat::from_fn(n, n, |i, j| {
+        if i == j {
+            let fi: N = num::cast(i).unwrap();
+
+            (half - fi).abs()
+        }
+        else if i == j + 1 || j == i + 1 {
+            num::one()
+        }
+        else {
+            Zero::zero()
+        }
+    })
+}
+
+/// A random `n x n` band matrix with `lower` subdiagonals and `upper` superdiagonals.
+///
+/// The components inside of the band are random, the other ones are zero.
+pub fn random_banded<N: Rand + Zero>(n: uint, lower: uint, upper: uint) -> DMat<N> {
+    DMat::from_fn(n, n, |i, j| {
+        if i <= j + lower && j <= i + upper {
+            rand::random()
+        }
+        else {
+            Zero::zero()
+        }
+    })
+}
+
+/// The `n x n` magic square.
+///
+/// It contains every integer from `1` to `n * n`, and the sums of each row, each column, and both
+/// diagonals are all equal to `n * (n * n + 1) / 2`. Fails if `n == 2` since there is no such
+/// square.
+pub fn magic<N: NumCast>(n: uint) -> DMat<N> {
+    assert!(n != 2, "There is no magic square of size 2.");
+
+    let m = magic_square(n);
+
+    DMat::from_fn(n, n, |i, j| num::cast(m[i * n + j]).unwrap())
+}
+
+// Row-major magic square of size `n`.
+fn magic_square(n: uint) -> Vec<uint> {
+    let mut res = Vec::from_elem(n * n, 0u);
+
+    if n % 2 == 1 {
+        // siamese method.
+        let (mut i, mut j) = (0u, n / 2);
+
+        for k in range(1u, n * n + 1) {
+            *res.get_mut(i * n + j) = k;
+
+            let (ni, nj) = ((i + n - 1) % n, (j + 1) % n);
+
+            if res[ni * n + nj] == 0 {
+                i = ni;
+                j = nj;
+            }
+            else {
+                i = (i + 1) % n;
+            }
+        }
+    }
+    else if n % 4 == 0 {
+        // complement the components on the diagonals of each 4x4 block.
+        for i in range(0u, n) {
+            for j in range(0u, n) {
+                let k = i * n + j + 1;
+
+                *res.get_mut(i * n + j) = if i % 4 == j % 4 || (i % 4) + (j % 4) == 3 {
+                    n * n + 1 - k
+                }
+                else {
+                    k
+                };
+            }
+        }
+    }
+    else {
+        // LUX method: four odd magic squares, with some columns swapped.
+        let half    = n / 2;
+        let sub     = magic_square(half);
+        let factors = [0u, 2, 3, 1];
+
+        for i in range(0u, n) {
+            for j in range(0u, n) {
+                let quadrant = (i / half) * 2 + j / half;
+
+                *res.get_mut(i * n + j) = sub[(i % half) * half + j % half] +
+                                          factors[quadrant] * half * half;
+            }
+        }
+
+        let left  = half / 2;
+        let right = left - 1;
+
+        for i in range(0u, half) {
+            for j in range(0u, n) {
+                if (j < left || j >= n - right || (j == left && i == left)) && !(j == 0 && i == left) {
+                    res.as_mut_slice().swap(i * n + j, (i + half) * n + j);
+                }
+            }
+        }
+    }
+
+    res
+}
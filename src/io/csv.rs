@@ -0,0 +1,141 @@
+use std::io::{IoResult, IoError, InvalidInput};
+use std::from_str::FromStr;
+use std::fmt::Show;
+use structs::{DMat, DVec};
+
+// Error returned when the input is not a valid CSV file.
+fn invalid<T>(detail: String) -> IoResult<T> {
+    Err(IoError {
+        kind:   InvalidInput,
+        desc:   "invalid CSV file",
+        detail: Some(detail)
+    })
+}
+
+// Reads the rows of a CSV file, skipping empty lines and, if `has_header` is `true`, the first
+// line.
+fn read_rows<N: FromStr, R: Buffer>(r: &mut R, delimiter: char, has_header: bool) -> IoResult<Vec<Vec<N>>> {
+    let mut rows = Vec::new();
+    let mut skip = has_header;
+
+    for (l, line) in r.lines().enumerate() {
+        let line = try!(line);
+        let line = line.as_slice().trim();
+
+        if skip {
+            skip = false;
+            continue;
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut row = Vec::new();
+
+        for field in line.split(delimiter) {
+            match from_str(field.trim()) {
+                Some(v) => row.push(v),
+                None    => return invalid(format!("line {}: invalid value `{}`.", l + 1, field.trim()))
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+// Writes a header line if `header` is not `None`.
+fn write_header<W: Writer>(w: &mut W, delimiter: char, header: Option<&[&str]>) -> IoResult<()> {
+    match header {
+        Some(names) => {
+            for (i, name) in names.iter().enumerate() {
+                if i != 0 {
+                    try!(w.write_char(delimiter));
+                }
+
+                try!(w.write_str(*name));
+            }
+
+            w.write_char('\n')
+        },
+        None => Ok(())
+    }
+}
+
+impl<N: FromStr + Clone> DMat<N> {
+    /// Reads a matrix from a CSV file.
+    ///
+    /// Each line of the file is a row of the matrix, with its components separated by
+    /// `delimiter`. All the rows must have the same number of components. Empty lines are
+    /// ignored, and so is the first line if `has_header` is `true`.
+    pub fn from_csv<R: Buffer>(r: &mut R, delimiter: char, has_header: bool) -> IoResult<DMat<N>> {
+        let rows: Vec<Vec<N>> = try!(read_rows(r, delimiter, has_header));
+        let nrows = rows.len();
+        let ncols = if nrows == 0 { 0 } else { rows[0].len() };
+
+        if rows.iter().any(|row| row.len() != ncols) {
+            return invalid(format!("all the rows must have {} components.", ncols))
+        }
+
+        let data: Vec<N> = rows.into_iter().flat_map(|row| row.into_iter()).collect();
+
+        Ok(DMat::from_row_vec(nrows, ncols, data.as_slice()))
+    }
+}
+
+impl<N: Show> DMat<N> {
+    /// Writes this matrix to a CSV file.
+    ///
+    /// Each row of the matrix is written on its own line, with its components separated by
+    /// `delimiter`. If `header` is not `None`, its column names are written on the first line.
+    pub fn to_csv<W: Writer>(&self, w: &mut W, delimiter: char, header: Option<&[&str]>) -> IoResult<()> {
+        try!(write_header(w, delimiter, header));
+
+        for i in range(0u, self.nrows()) {
+            for j in range(0u, self.ncols()) {
+                if j != 0 {
+                    try!(w.write_char(delimiter));
+                }
+
+                try!(write!(w, "{}", self.as_vec()[i + j * self.nrows()]));
+            }
+
+            try!(w.write_char('\n'));
+        }
+
+        Ok(())
+    }
+}
+
+impl<N: FromStr + Clone> DVec<N> {
+    /// Reads a vector from a CSV file.
+    ///
+    /// The components may be listed on a single line, one per line, or any combination of both:
+    /// they are read in order, left to right and top to bottom. Empty lines are ignored, and so
+    /// is the first line if `has_header` is `true`.
+    pub fn from_csv<R: Buffer>(r: &mut R, delimiter: char, has_header: bool) -> IoResult<DVec<N>> {
+        let rows: Vec<Vec<N>> = try!(read_rows(r, delimiter, has_header));
+
+        Ok(DVec { at: rows.into_iter().flat_map(|row| row.into_iter()).collect() })
+    }
+}
+
+impl<N: Show> DVec<N> {
+    /// Writes this vector to a CSV file, with one component per line.
+    ///
+    /// If `header` is not `None`, it is written on the first line.
+    pub fn to_csv<W: Writer>(&self, w: &mut W, header: Option<&str>) -> IoResult<()> {
+        match header {
+            Some(name) => try!(w.write_line(name)),
+            None       => { }
+        }
+
+        for e in self.at.iter() {
+            try!(write!(w, "{}\n", *e));
+        }
+
+        Ok(())
+    }
+}
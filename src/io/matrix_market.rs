@@ -0,0 +1,198 @@
+use std::io::{IoResult, IoError, InvalidInput};
+use std::num::{Zero, One};
+use std::from_str::FromStr;
+use std::fmt::Show;
+use traits::structure::Indexable;
+use structs::DMat;
+
+// Error returned when the input is not a valid Matrix Market file.
+fn invalid<T>(detail: String) -> IoResult<T> {
+    Err(IoError {
+        kind:   InvalidInput,
+        desc:   "invalid Matrix Market file",
+        detail: Some(detail)
+    })
+}
+
+#[deriving(PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric
+}
+
+/// Reads a matrix from a Matrix Market (`.mtx`) file.
+///
+/// Both the `array` (dense) and the `coordinate` (sparse) formats are supported, with `real`,
+/// `integer` or `pattern` fields. For the `pattern` field, every listed entry is set to one.
+/// Matrices with `symmetric` or `skew-symmetric` storage, for which only the lower triangle is
+/// listed, are expanded to their full form.
+///
+/// Complex and hermitian matrices are not supported.
+pub fn read_matrix_market<N: FromStr + Zero + One + Neg<N> + Clone, R: Buffer>(r: &mut R)
+                                                                            -> IoResult<DMat<N>> {
+    let header = try!(r.read_line());
+    let header: Vec<String> = header.as_slice().words().map(|w| w.to_ascii_lower()).collect();
+
+    if header.len() != 5 || header[0].as_slice() != "%%matrixmarket" || header[1].as_slice() != "matrix" {
+        return invalid(format!("malformed header."))
+    }
+
+    let coordinate = match header[2].as_slice() {
+        "coordinate" => true,
+        "array"      => false,
+        other        => return invalid(format!("unknown format: {}.", other))
+    };
+
+    let pattern = match header[3].as_slice() {
+        "real" | "double" | "integer" => false,
+        "pattern" if coordinate       => true,
+        other                         => return invalid(format!("unsupported field: {}.", other))
+    };
+
+    let symmetry = match header[4].as_slice() {
+        "general"        => General,
+        "symmetric"      => Symmetric,
+        "skew-symmetric" => SkewSymmetric,
+        other            => return invalid(format!("unsupported symmetry: {}.", other))
+    };
+
+    // every remaining non-comment line is a list of whitespace-separated values.
+    let mut tokens = Vec::new();
+
+    for line in r.lines() {
+        let line = try!(line);
+        let line = line.as_slice().trim();
+
+        if !line.is_empty() && !line.starts_with("%") {
+            tokens.push(line.words().map(|w| w.to_string()).collect::<Vec<String>>());
+        }
+    }
+
+    if tokens.is_empty() {
+        return invalid(format!("missing matrix size."))
+    }
+
+    let size: Vec<uint> = tokens[0].iter().filter_map(|w| from_str(w.as_slice())).collect();
+
+    if size.len() != tokens[0].len() || size.len() != if coordinate { 3 } else { 2 } {
+        return invalid(format!("malformed matrix size."))
+    }
+
+    let (nrows, ncols) = (size[0], size[1]);
+
+    if symmetry != General && nrows != ncols {
+        return invalid(format!("a symmetric matrix must be square."))
+    }
+
+    let mut res: DMat<N> = DMat::new_zeros(nrows, ncols);
+
+    // sets an entry and its symmetric counterpart.
+    let set = |res: &mut DMat<N>, i: uint, j: uint, val: N| -> IoResult<()> {
+        if i >= nrows || j >= ncols {
+            return invalid(format!("entry ({}, {}) out of bounds.", i + 1, j + 1))
+        }
+
+        if symmetry != General && i < j {
+            return invalid(format!("entry ({}, {}) not in the lower triangle.", i + 1, j + 1))
+        }
+
+        if i != j {
+            match symmetry {
+                General       => { },
+                Symmetric     => res.set((j, i), val.clone()),
+                SkewSymmetric => res.set((j, i), -val)
+            }
+        }
+
+        res.set((i, j), val);
+
+        Ok(())
+    };
+
+    let entries = tokens.slice_from(1);
+
+    if coordinate {
+        let nnz = size[2];
+
+        if entries.len() != nnz {
+            return invalid(format!("expected {} entries, found {}.", nnz, entries.len()))
+        }
+
+        for entry in entries.iter() {
+            if entry.len() != if pattern { 2 } else { 3 } {
+                return invalid(format!("malformed entry: {}.", entry))
+            }
+
+            let i: Option<uint> = from_str(entry[0].as_slice());
+            let j: Option<uint> = from_str(entry[1].as_slice());
+            let v: Option<N>    = if pattern { Some(One::one()) } else { from_str(entry[2].as_slice()) };
+
+            match (i, j, v) {
+                (Some(i), Some(j), Some(v)) if i > 0 && j > 0 => try!(set(&mut res, i - 1, j - 1, v)),
+                _ => return invalid(format!("malformed entry: {}.", entry))
+            }
+        }
+    }
+    else {
+        // the values are listed in column-major order, only for the lower triangle of symmetric
+        // matrices and only for the strict lower triangle of skew-symmetric ones.
+        let mut idx = Vec::new();
+
+        for j in range(0u, ncols) {
+            let start = match symmetry {
+                General       => 0,
+                Symmetric     => j,
+                SkewSymmetric => j + 1
+            };
+
+            for i in range(start, nrows) {
+                idx.push((i, j));
+            }
+        }
+
+        if entries.len() != idx.len() {
+            return invalid(format!("expected {} entries, found {}.", idx.len(), entries.len()))
+        }
+
+        for (entry, &(i, j)) in entries.iter().zip(idx.iter()) {
+            let v: Option<N> = if entry.len() == 1 { from_str(entry[0].as_slice()) } else { None };
+
+            match v {
+                Some(v) => try!(set(&mut res, i, j, v)),
+                None    => return invalid(format!("malformed entry: {}.", entry))
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Writes a matrix to a Matrix Market (`.mtx`) file.
+///
+/// The matrix is written with the `array` format and the `general` symmetry if `symmetric` is
+/// `false`. Otherwise, the `symmetric` storage is used: only the lower triangle of `m` is
+/// written, and `m` must be square.
+pub fn write_matrix_market<N: Show, W: Writer>(w: &mut W, m: &DMat<N>, symmetric: bool) -> IoResult<()> {
+    let (nrows, ncols) = (m.nrows(), m.ncols());
+
+    if symmetric {
+        assert!(nrows == ncols, "A symmetric matrix must be square.");
+        try!(w.write_line("%%MatrixMarket matrix array real symmetric"));
+    }
+    else {
+        try!(w.write_line("%%MatrixMarket matrix array real general"));
+    }
+
+    try!(w.write_line(format!("{} {}", nrows, ncols).as_slice()));
+
+    for j in range(0u, ncols) {
+        let start = if symmetric { j } else { 0 };
+
+        for i in range(start, nrows) {
+            try!(w.write_line(format!("{}", m.as_vec()[i + j * nrows]).as_slice()));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+//! Reading and writing matrices from and to standard file formats.
+//!
+//! CSV files are read and written with `DMat::from_csv`, `DMat::to_csv`, `DVec::from_csv` and
+//! `DVec::to_csv`. NumPy `.npy` files are read and written with `DMat::from_npy`, `DMat::to_npy`,
+//! `DVec::from_npy` and `DVec::to_npy`.
+
+pub use self::matrix_market::{read_matrix_market, write_matrix_market};
+pub use self::npy::NpyScalar;
+
+mod matrix_market;
+mod csv;
+mod npy;
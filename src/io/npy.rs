@@ -0,0 +1,211 @@
+use std::io::{IoResult, IoError, InvalidInput};
+use traits::structure::Bytes;
+use structs::{DMat, DVec};
+
+static MAGIC: &'static [u8] = b"\x93NUMPY";
+
+/// Trait of scalars that can be stored in a NumPy `.npy` file.
+pub trait NpyScalar: Bytes + Clone {
+    /// The type code of this scalar in the `descr` field of the `.npy` header, without its byte
+    /// order prefix.
+    fn type_code(Option<Self>) -> &'static str;
+}
+
+impl NpyScalar for f32 {
+    #[inline]
+    fn type_code(_: Option<f32>) -> &'static str {
+        "f4"
+    }
+}
+
+impl NpyScalar for f64 {
+    #[inline]
+    fn type_code(_: Option<f64>) -> &'static str {
+        "f8"
+    }
+}
+
+// Error returned when the input is not a valid `.npy` file.
+fn invalid<T>(detail: String) -> IoResult<T> {
+    Err(IoError {
+        kind:   InvalidInput,
+        desc:   "invalid npy file",
+        detail: Some(detail)
+    })
+}
+
+// Extracts the textual value associated to `key` in the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("'{}':", key);
+
+    header.find_str(pattern.as_slice()).map(|start| {
+        let value = header.slice_from(start + pattern.len()).trim_left();
+        let end   = if value.starts_with("(") {
+            value.find(')').map(|i| i + 1)
+        }
+        else {
+            value.find(',').or(value.find('}'))
+        };
+
+        value.slice_to(end.unwrap_or(value.len())).trim()
+    })
+}
+
+// Reads the header of a `.npy` file and the components of the array it contains.
+//
+// Returns the shape of the array, and its components in column-major order.
+fn read_npy<N: NpyScalar, R: Reader>(r: &mut R) -> IoResult<(Vec<uint>, Vec<N>)> {
+    let magic = try!(r.read_exact(MAGIC.len()));
+
+    if magic.as_slice() != MAGIC {
+        return invalid(format!("bad magic string."))
+    }
+
+    let major   = try!(r.read_u8());
+    let _minor  = try!(r.read_u8());
+    let hlen    = match major {
+        1     => try!(r.read_le_u16()) as uint,
+        2 | 3 => try!(r.read_le_u32()) as uint,
+        _     => return invalid(format!("unsupported version: {}.", major))
+    };
+    let header = match String::from_utf8(try!(r.read_exact(hlen))) {
+        Ok(header) => header,
+        Err(_)     => return invalid(format!("the header is not valid text."))
+    };
+    let header = header.as_slice();
+
+    let descr = match header_value(header, "descr") {
+        Some(descr) => descr.trim_chars('\'').trim_chars('"'),
+        None        => return invalid(format!("missing descr."))
+    };
+
+    let type_code = NpyScalar::type_code(None::<N>);
+
+    if descr.len() != type_code.len() + 1 || descr.slice_from(1) != type_code {
+        return invalid(format!("expected elements of type {}, found {}.", type_code, descr))
+    }
+
+    let big_endian = match descr.char_at(0) {
+        '<' => false,
+        '>' => true,
+        '=' => cfg!(target_endian = "big"),
+        c   => return invalid(format!("unknown byte order: {}.", c))
+    };
+
+    let fortran_order = match header_value(header, "fortran_order") {
+        Some("True")  => true,
+        Some("False") => false,
+        _             => return invalid(format!("missing fortran_order."))
+    };
+
+    let shape: Vec<uint> = match header_value(header, "shape") {
+        Some(s) if s.starts_with("(") && s.ends_with(")") => {
+            let dims: Vec<&str> = s.slice(1, s.len() - 1)
+                                   .split(',')
+                                   .map(|d| d.trim())
+                                   .filter(|d| !d.is_empty())
+                                   .collect();
+            let shape: Vec<uint> = dims.iter().filter_map(|d| from_str(*d)).collect();
+
+            if shape.len() != dims.len() {
+                return invalid(format!("malformed shape: {}.", s))
+            }
+
+            shape
+        },
+        _ => return invalid(format!("missing shape."))
+    };
+
+    let len = shape.iter().fold(1u, |a, b| a * *b);
+    let mut data = Vec::with_capacity(len);
+
+    for _ in range(0u, len) {
+        data.push(if big_endian { try!(Bytes::read_be(r)) } else { try!(Bytes::read_le(r)) });
+    }
+
+    // reorder 2D arrays to column-major order.
+    if shape.len() == 2 && !fortran_order {
+        let (nrows, ncols) = (shape[0], shape[1]);
+        let mut cm = Vec::with_capacity(len);
+
+        for j in range(0u, ncols) {
+            for i in range(0u, nrows) {
+                cm.push(data[j + i * ncols].clone());
+            }
+        }
+
+        data = cm;
+    }
+
+    Ok((shape, data))
+}
+
+// Writes a `.npy` file containing an array in fortran order, with little-endian components.
+fn write_npy<N: NpyScalar, W: Writer>(w: &mut W, shape: &str, data: &[N]) -> IoResult<()> {
+    let mut header = format!("{{'descr': '<{}', 'fortran_order': True, 'shape': {}, }}",
+                             NpyScalar::type_code(None::<N>), shape);
+
+    // the header is padded with spaces and a final newline so that the data are 64-bytes aligned.
+    let unpadded = MAGIC.len() + 2 + 2 + header.len() + 1;
+
+    for _ in range(0u, (64 - unpadded % 64) % 64) {
+        header.push(' ');
+    }
+
+    header.push('\n');
+
+    try!(w.write(MAGIC));
+    try!(w.write_u8(1));
+    try!(w.write_u8(0));
+    try!(w.write_le_u16(header.len() as u16));
+    try!(w.write_str(header.as_slice()));
+
+    for e in data.iter() {
+        try!(e.write_le(w));
+    }
+
+    Ok(())
+}
+
+impl<N: NpyScalar> DMat<N> {
+    /// Reads a matrix from a NumPy `.npy` file.
+    ///
+    /// The file must contain a two-dimensional array of `f32` or `f64`, stored in either C or
+    /// Fortran order, with any byte order.
+    pub fn from_npy<R: Reader>(r: &mut R) -> IoResult<DMat<N>> {
+        let (shape, data) = try!(read_npy(r));
+
+        if shape.len() != 2 {
+            return invalid(format!("expected a two-dimensional array, found shape {}.", shape))
+        }
+
+        Ok(DMat::from_col_vec(shape[0], shape[1], data.as_slice()))
+    }
+
+    /// Writes this matrix to a NumPy `.npy` file.
+    ///
+    /// The components are written in Fortran order since this is the storage order of `DMat`.
+    pub fn to_npy<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+        write_npy(w, format!("({}, {})", self.nrows(), self.ncols()).as_slice(), self.as_vec())
+    }
+}
+
+impl<N: NpyScalar> DVec<N> {
+    /// Reads a vector from a NumPy `.npy` file.
+    ///
+    /// The file must contain a one-dimensional array of `f32` or `f64`, with any byte order.
+    pub fn from_npy<R: Reader>(r: &mut R) -> IoResult<DVec<N>> {
+        let (shape, data) = try!(read_npy(r));
+
+        if shape.len() != 1 {
+            return invalid(format!("expected a one-dimensional array, found shape {}.", shape))
+        }
+
+        Ok(DVec { at: data })
+    }
+
+    /// Writes this vector to a NumPy `.npy` file.
+    pub fn to_npy<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+        write_npy(w, format!("({},)", self.at.len()).as_slice(), self.at.as_slice())
+    }
+}
@@ -46,11 +46,66 @@ an optimized set of tools for computer graphics and physics. Those features incl
 * Square matrices with static sizes: `Mat1`, `Mat2`, `Mat3`, `Mat4`, `Mat5`, `Mat6 `.
 * Rotation matrices: `Rot2`, `Rot3`, `Rot4`.
 * Quaternions: `Quat`, `UnitQuat`.
+* Dual quaternions: `DualQuat`.
+* Unit complex numbers for 2D rotations: `UnitComplex`.
 * Isometries: `Iso2`, `Iso3`, `Iso4`.
+* Lie algebra elements with their Jacobians: `So3`, `Se3`.
+* Similarities: `Sim2`, `Sim3`.
+* Non-uniform scalings: `Scale2`, `Scale3`.
+* Reflections with respect to a hyperplane: `Reflection`.
+* General projective transformations: `Proj2`, `Proj3`.
 * 3D projections for computer graphics: `Persp3`, `PerspMat3`, `Ortho3`, `OrthoMat3`.
 * Dynamically sized vector: `DVec`.
 * Dynamically sized (square or rectangular) matrix: `DMat`.
 * A few methods for data analysis: `Cov`, `Mean`.
+* Barycenters and affine combinations of points: `barycenter`, `affine_combination`, `Barycenter`.
+* Total lexicographic order over vectors and points, e.g., to use them as map keys: `Ordered`.
+* Conversions from and to (nested) arrays: `from_array`, `to_array`, `as_array`.
+* Fast approximate normalization of `f32` vectors and quaternions: `normalize_fast`.
+* Column-major access for graphics APIs: `as_ptr`, `as_col_major_array`, `to_col_major_array`.
+* Parsing from bracketed text, e.g., `"[[1, 2], [3, 4]]"`: `FromStr`, `parse`.
+* Binary (de)serialization with explicit endianness: `to_bytes`, `from_bytes`, `Bytes`.
+* Matrix Market file input/output: `io::read_matrix_market`, `io::write_matrix_market`.
+* CSV input/output for `DMat` and `DVec`: `from_csv`, `to_csv`.
+* NumPy `.npy` input/output for `DMat` and `DVec`: `from_npy`, `to_npy`.
+* In-place arithmetic without allocation: `AddInPlace`, `SubInPlace`, `ScaleInPlace`, `MulInPlace`.
+* Strassen multiplication of large square `DMat`: `mul_strassen`.
+* Closed-form eigendecomposition of 2x2 and 3x3 matrices: `eigen_mat2`, `eigen_mat3`, `eigen_sym3`.
+* Decompositions reusing their temporary buffers: `qr_with_workspace`, `eigen_qr_with_workspace`,
+  `DecompositionWorkspace`.
+* Optional BLAS backend for large `DMat` products and `DVec` axpy: the `blas` cargo feature.
+* Optional LAPACK backend for the `qr` and `eigen_qr` decompositions of `DMat`: the `lapack` cargo feature.
+* Random generation with custom distributions: `new_random_with`, `new_uniform`, `new_gaussian`,
+  `new_random_unit`.
+* Optional SIMD-backed 4D vector and matrix of `f32`: `SimdVec4`, `SimdMat4` with the `simd` cargo
+  feature. They are separate types with a reduced API, convertible to and from `Vec4` and `Mat4`.
+* Optional quickcheck `Arbitrary` implementations and invertible matrix generators: the `arbitrary`
+  cargo feature.
+* Optional dual-number scalar for forward-mode automatic differentiation of the generic products,
+  norms, and rotations: `Dual` with the `dual` cargo feature.
+* Optional `Encodable` and `Decodable` implementations for `DVec` and `DMat`: the `serialize` cargo
+  feature.
+* Type-level dimensions for compile-time dimension checks: `StaticDim`, `D1`, `D2`, `D3`, etc.
+* Exact determinants of integer matrices: `det_bareiss`.
+* Assertion macros printing the operands and their difference: `assert_approx_eq!`,
+  `assert_approx_eq_eps!`.
+* Polynomials: `roots`, `DMat::vandermonde`.
+* Convenience constructors: `zeros`, `ones`, `DVec::linspace`.
+* Convolution and correlation of signals: `DVec::convolve`, `DVec::correlate`,
+  `DVec::circular_convolve`.
+* Classic test matrices: the `gallery` module.
+* Toeplitz and circulant matrices: `DMat::toeplitz`, `DMat::circulant`.
+* Random orthogonal and symmetric positive-definite matrices: `DMat::new_random_orthogonal`,
+  `DMat::new_random_spd`.
+* Cholesky decomposition and multivariate normal sampling: `cholesky`, `MultivariateNormal`.
+* Streaming mean and covariance of observations: `RunningStats`.
+* Weighted mean and covariance: `weighted_mean`, `weighted_cov`.
+* Approximate structural predicates: `is_symmetric`, `is_orthogonal`, `is_upper_triangular`,
+  `is_diagonal`, `is_identity`, `is_zero`.
+* Off-diagonals and triangular parts of matrices: `diag_k`, `triu`, `tril`.
+* Rectangular matrices with static sizes: `Mat2x3`, `Mat3x2`, `Mat3x4`, `Mat4x3`.
+* Least-squares alignment of point sets: `best_fit_rotation`, `best_fit_isometry`,
+  `best_fit_similarity`.
 * Almost one trait per functionality: useful for generic programming.
 * Operator overloading using the double trait dispatch
   [trick](http://smallcultfollowing.com/babysteps/blog/2012/10/04/refining-traits-slash-impls/).
@@ -68,9 +123,13 @@ fn main() {
     let _ = v * m;      // vector-matrix multiplication.
     let _ = m * m;      // matrix-matrix multiplication.
     let _ = v * 2.0f64; // vector-scalar multiplication.
+    let _ = 2.0f64 * v; // scalar-vector multiplication.
 }
 ```
 
+  Note that the scalar can be on the left-hand side of the operator only if it is a `f32` or a
+  `f64`.
+
 ## Compilation
 You will need the last nightly build of the [rust compiler](http://www.rust-lang.org)
 and the official package manager: [cargo](https://github.com/rust-lang/cargo).
@@ -105,24 +164,34 @@ Feel free to add your project to this list if you happen to use **nalgebra**!
 
 extern crate serialize;
 
+#[cfg(feature = "arbitrary")]
+extern crate quickcheck;
+
 #[cfg(test)]
 extern crate test;
 
 use std::num::{Zero, One, FloatMath};
 use std::cmp;
 pub use traits::{PartialLess, PartialEqual, PartialGreater, NotComparable};
+pub use traits::{PopulationCov, SampleCov};
 pub use traits::{
     Absolute,
     AbsoluteRotate,
+    AddInPlace, SubInPlace, ScaleInPlace, MulInPlace,
     AnyVec,
     AnyPnt,
     ApproxEq,
     Axpy,
+    Band,
     Basis,
+    Bytes,
     Cast,
     Col,
+    ConjTranspose,
+    Conjugate,
     ColSlice, RowSlice,
     Cov,
+    CovNormalization,
     Cross,
     CrossMatrix,
     Det,
@@ -135,6 +204,8 @@ pub use traits::{
     FloatVec,
     FloatVecExt,
     FromHomogeneous,
+    Gemm,
+    Gemv,
     Indexable,
     Inv,
     Iterable,
@@ -149,44 +220,88 @@ pub use traits::{
     PartialOrdering,
     PntAsVec,
     PntExt,
+    PntVecTransform,
     RMul,
     Rotate, Rotation, RotationMatrix, RotationWithTranslation,
     Row,
     ScalarAdd, ScalarSub,
     ScalarMul, ScalarDiv,
+    StaticDim,
     ToHomogeneous,
-    Transform, Transformation,
+    Transform, TransformAll, Transformation,
     Translate, Translation,
     Transpose,
+    TypeDim,
     UniformSphereSample,
     VecAsPnt,
-    VecExt
+    VecExt,
+    WeightedCov,
+    WeightedMean
 };
 
 pub use structs::{
     Identity,
     DMat,
     DVec, DVec1, DVec2,  DVec3,  DVec4,  DVec5,  DVec6,
+    ConvolutionMode, FullConvolution, SameConvolution, ValidConvolution,
+    StridedSlice, StridedSliceMut, StridedItems,
     Iso2, Iso3, Iso4,
+    Sim2, Sim3,
+    Scale2, Scale3,
+    Reflection,
+    Proj2, Proj3,
     Mat1, Mat2, Mat3, Mat4,
     Mat5, Mat6,
+    Mat2x3, Mat3x2, Mat3x4, Mat4x3,
     Rot2, Rot3, Rot4,
+    EulerConvention, EulerXYZ, EulerZYX, EulerZXZ,
     Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6,
     Pnt0, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6,
     Persp3, PerspMat3,
     Ortho3, OrthoMat3,
-    Quat, UnitQuat
+    Quat, UnitQuat, DualQuat, UnitComplex,
+    Barycenter,
+    RunningStats,
+    MultivariateNormal,
+    So3, Se3,
+    Ordered,
+    D0, D1, D2, D3, D4, D5, D6
 };
 
+#[cfg(feature = "arbitrary")]
+pub use structs::Invertible;
+
+#[cfg(feature = "simd")]
+pub use structs::{SimdVec4, SimdMat4};
+
+#[cfg(feature = "dual")]
+pub use structs::Dual;
+
 pub use linalg::{
     qr,
+    qr_with_workspace,
+    cholesky,
     eigen_qr,
-    householder_matrix
+    eigen_qr_with_workspace,
+    eigen_mat2,
+    eigen_mat3,
+    eigen_sym3,
+    DecompositionWorkspace,
+    householder_matrix,
+    decompose_trs,
+    decompose_trs_shear,
+    best_fit_rotation,
+    best_fit_isometry,
+    best_fit_similarity,
+    roots
 };
 
+mod macros;
 mod structs;
 mod traits;
 mod linalg;
+pub mod io;
+pub mod gallery;
 
 // mod lower_triangular;
 // mod chol;
@@ -223,7 +338,10 @@ pub mod overload {
                       Mat1MulRhs, Mat2MulRhs, Mat3MulRhs, Mat4MulRhs, Mat5MulRhs, Mat6MulRhs,
                       Mat1DivRhs, Mat2DivRhs, Mat3DivRhs, Mat4DivRhs, Mat5DivRhs, Mat6DivRhs,
                       Mat1AddRhs, Mat2AddRhs, Mat3AddRhs, Mat4AddRhs, Mat5AddRhs, Mat6AddRhs,
-                      Mat1SubRhs, Mat2SubRhs, Mat3SubRhs, Mat4SubRhs, Mat5SubRhs, Mat6SubRhs};
+                      Mat1SubRhs, Mat2SubRhs, Mat3SubRhs, Mat4SubRhs, Mat5SubRhs, Mat6SubRhs,
+                      Mat2x3MulRhs, Mat3x2MulRhs, Mat3x4MulRhs, Mat4x3MulRhs,
+                      Mat2x3AddRhs, Mat3x2AddRhs, Mat3x4AddRhs, Mat4x3AddRhs,
+                      Mat2x3SubRhs, Mat3x2SubRhs, Mat3x4SubRhs, Mat4x3SubRhs};
 }
 
 /// Change the input value to ensure it is on the range `[min, max]`.
@@ -345,6 +463,23 @@ pub fn one<T: One>() -> T {
     One::one()
 }
 
+/// Tests if `m` is approximately the identity, using a custom epsilon.
+///
+/// This works with any matrix or transformation having an identity, e.g., `Mat3`, `Rot3`, `Iso3`,
+/// or `UnitQuat`.
+#[inline(always)]
+pub fn is_identity<M: One + ApproxEq<N>, N>(m: &M, eps: &N) -> bool {
+    ApproxEq::approx_eq_eps(m, &One::one(), eps)
+}
+
+/// Tests if `v` is approximately zero, using a custom epsilon.
+///
+/// Unlike `Zero::is_zero`, this does not fail on rounding errors.
+#[inline(always)]
+pub fn is_zero<V: Zero + ApproxEq<N>, N>(v: &V, eps: &N) -> bool {
+    ApproxEq::approx_eq_eps(v, &Zero::zero(), eps)
+}
+
 //
 //
 // Geometry
@@ -364,6 +499,37 @@ pub fn center<N: Float, P: FloatPnt<N, V>, V>(a: &P, b: &P) -> P {
     (*a + *b.as_vec()) / _2
 }
 
+/// Returns the barycenter of a set of points.
+///
+/// Fails if `pts` is empty.
+#[inline]
+pub fn barycenter<N: Float, P: Clone + AnyPnt<N, V>, V: Clone + AnyVec<N>>(pts: &[P]) -> P {
+    let mut acc = Barycenter::new();
+
+    for p in pts.iter() {
+        acc.add(p)
+    }
+
+    acc.barycenter().expect("Cannot compute the barycenter of an empty set of points.")
+}
+
+/// Returns the affine combination `sum(weights[i] * pts[i])` of a set of points.
+///
+/// The weights are expected to sum to one. Fails if `pts` is empty or if `pts` and `weights` do
+/// not have the same length.
+#[inline]
+pub fn affine_combination<N: Float, P: Clone + AnyPnt<N, V>, V: Clone + AnyVec<N>>(pts: &[P], weights: &[N]) -> P {
+    assert!(pts.len() == weights.len(), "There must be exactly one weight per point.");
+
+    let mut acc = Barycenter::new();
+
+    for (p, w) in pts.iter().zip(weights.iter()) {
+        acc.add_weighted(p, w)
+    }
+
+    acc.affine_combination().expect("Cannot compute the affine combination of an empty set of points.")
+}
+
 /*
  * FloatPnt
  */
@@ -699,6 +865,50 @@ pub fn inv_transform<V, M: Transform<V>>(m: &M, v: &V) -> V {
     m.inv_transform(v)
 }
 
+/*
+ * TransformAll<V>
+ */
+
+/// Applies a transformation to each element of a slice, in-place.
+#[inline(always)]
+pub fn transform_all<V, M: TransformAll<V>>(m: &M, vs: &mut [V]) {
+    m.transform_all(vs)
+}
+
+/// Applies an inverse transformation to each element of a slice, in-place.
+#[inline(always)]
+pub fn inv_transform_all<V, M: TransformAll<V>>(m: &M, vs: &mut [V]) {
+    m.inv_transform_all(vs)
+}
+
+/*
+ * PntVecTransform<P, V>
+ */
+
+/// Applies a transformation to a point.
+#[inline(always)]
+pub fn transform_pnt<P, V, M: PntVecTransform<P, V>>(m: &M, p: &P) -> P {
+    m.transform_pnt(p)
+}
+
+/// Applies a transformation to a vector.
+#[inline(always)]
+pub fn transform_vec<P, V, M: PntVecTransform<P, V>>(m: &M, v: &V) -> V {
+    m.transform_vec(v)
+}
+
+/// Applies an inverse transformation to a point.
+#[inline(always)]
+pub fn inv_transform_pnt<P, V, M: PntVecTransform<P, V>>(m: &M, p: &P) -> P {
+    m.inv_transform_pnt(p)
+}
+
+/// Applies an inverse transformation to a vector.
+#[inline(always)]
+pub fn inv_transform_vec<P, V, M: PntVecTransform<P, V>>(m: &M, v: &V) -> V {
+    m.inv_transform_vec(v)
+}
+
 /*
  * Dot<N>
  */
@@ -816,6 +1026,50 @@ pub fn approx_eq_eps<T: ApproxEq<N>, N>(a: &T, b: &T, eps: &N) -> bool {
     ApproxEq::approx_eq_eps(a, b, eps)
 }
 
+/// Tests approximate equality using both an absolute and a relative epsilon.
+#[inline(always)]
+pub fn approx_eq_eps_rel<T: ApproxEq<N>, N>(a: &T, b: &T, abs_eps: &N, rel_eps: &N) -> bool {
+    ApproxEq::approx_eq_eps_rel(a, b, abs_eps, rel_eps)
+}
+
+/// Tests approximate equality using a custom number of units in the last place (ULPs).
+#[inline(always)]
+pub fn approx_eq_ulps<T: ApproxEq<N>, N>(a: &T, b: &T, ulps: u32) -> bool {
+    ApproxEq::approx_eq_ulps(a, b, ulps)
+}
+
+/// Tests approximate equality of two matrices relative to the Frobenius norm of `a`.
+///
+/// This is true if `‖a - b‖ <= eps * ‖a‖`, or if `‖a - b‖ <= eps` when `a` is zero. Unlike the
+/// componentwise epsilon of `approx_eq_eps`, this is insensitive to the scale of the matrices,
+/// which makes it suitable to check, e.g., that the factors of a decomposition multiply back to
+/// the original matrix.
+pub fn relative_eq_norm<N: Float, M: Indexable<(uint, uint), N>>(a: &M, b: &M, eps: &N) -> bool {
+    let shape = a.shape();
+    assert!(shape == b.shape(), "Cannot compare matrices with different shapes.");
+
+    let (nrows, ncols) = shape;
+    let mut sqnorm_a: N    = Zero::zero();
+    let mut sqnorm_diff: N = Zero::zero();
+
+    for j in range(0u, ncols) {
+        for i in range(0u, nrows) {
+            let ea = a.at((i, j));
+            let ed = ea - b.at((i, j));
+
+            sqnorm_a    = sqnorm_a + ea * ea;
+            sqnorm_diff = sqnorm_diff + ed * ed;
+        }
+    }
+
+    if sqnorm_a.is_zero() {
+        sqnorm_diff.sqrt() <= *eps
+    }
+    else {
+        sqnorm_diff.sqrt() <= *eps * sqnorm_a.sqrt()
+    }
+}
+
 
 /*
  * Absolute<A>
@@ -847,6 +1101,12 @@ pub fn transpose<M: Transpose>(m: &M) -> M {
     Transpose::transpose_cpy(m)
 }
 
+/// Gets a conjugate-transposed copy of a matrix.
+#[inline(always)]
+pub fn conj_transpose<M: ConjTranspose>(m: &M) -> M {
+    ConjTranspose::conj_transpose_cpy(m)
+}
+
 /*
  * Outer<M>
  */
@@ -877,6 +1137,19 @@ pub fn mean<N, M: Mean<N>>(observations: &M) -> N {
     Mean::mean(observations)
 }
 
+/// Computes the weighted mean of a set of observations.
+#[inline(always)]
+pub fn weighted_mean<N, W, M: WeightedMean<N, W>>(observations: &M, weights: &W) -> N {
+    WeightedMean::weighted_mean(observations, weights)
+}
+
+/// Computes the weighted covariance of a set of observations.
+#[inline(always)]
+pub fn weighted_cov<M: WeightedCov<Res, W>, Res, W>(observations: &M, weights: &W,
+                                                    normalization: CovNormalization) -> Res {
+    WeightedCov::weighted_cov(observations, weights, normalization)
+}
+
 //
 //
 // Structure
@@ -958,6 +1231,15 @@ pub fn cast<T, U: Cast<T>>(t: T) -> U {
     Cast::from(t)
 }
 
+/// Converts an object from one type to another without loss of information.
+///
+/// Contrary to `cast`, this returns `None` if any component of `t` overflows, changes sign, or
+/// loses precision during the conversion.
+#[inline(always)]
+pub fn try_cast<T, U: Cast<T>>(t: T) -> Option<U> {
+    Cast::try_from(t)
+}
+
 /*
  * Indexable
  */
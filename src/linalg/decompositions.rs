@@ -1,8 +1,11 @@
-use std::num::{Zero, Float};
-use traits::operations::{Transpose, ApproxEq};
-use traits::structure::{ColSlice, Eye, Indexable, Diag};
-use traits::geometry::Norm;
+use std::num::{Zero, One, Float};
+use traits::operations::ApproxEq;
+use traits::structure::{Eye, Indexable, Diag, ColSlice};
+use traits::geometry::{Norm, Dot, Cross};
+use structs::{Vec3, Mat3, Mat4, Rot3};
 use std::cmp::min;
+#[cfg(feature = "lapack")]
+use linalg::lapack;
 
 /// Get the householder matrix corresponding to a reflexion to the hyperplane
 /// defined by `vec`. It can be a reflexion contained in a subspace.
@@ -34,55 +37,317 @@ pub fn householder_matrix<N: Float,
     qk
 }
 
+/// Buffers reused by the decompositions to avoid allocations.
+///
+/// A workspace can be reused by any number of successive decompositions, of any dimension. Its
+/// buffers grow to the size required by the largest decomposition performed so far.
+pub struct DecompositionWorkspace<N> {
+    v: Vec<N>,
+    w: Vec<N>
+}
+
+impl<N> DecompositionWorkspace<N> {
+    /// Creates a new empty workspace.
+    #[inline]
+    pub fn new() -> DecompositionWorkspace<N> {
+        DecompositionWorkspace {
+            v: Vec::new(),
+            w: Vec::new()
+        }
+    }
+}
+
+// Applies the householder reflection `I - 2 * v * v^T`, which operates on the dimensions
+// `start .. start + v.len()`, to the left of `r` and to the right of `q`.
+fn apply_householder<N: Float, M: Indexable<(uint, uint), N>>(start: uint, v: &[N], w: &mut Vec<N>,
+                                                              r: &mut M, q: &mut M) {
+    let (rrows, rcols) = r.shape();
+    let (qrows, _)     = q.shape();
+    let _2: N          = One::one() + One::one();
+
+    assert!(start + v.len() <= rrows);
+
+    unsafe {
+        // r = r - 2 * v * (v^T * r)
+        w.truncate(0);
+
+        for j in range(0u, rcols) {
+            let mut s: N = Zero::zero();
+
+            for (k, vk) in v.iter().enumerate() {
+                s = s + *vk * r.unsafe_at((start + k, j));
+            }
+
+            w.push(s * _2);
+        }
+
+        for j in range(0u, rcols) {
+            for (k, vk) in v.iter().enumerate() {
+                let rkj = r.unsafe_at((start + k, j));
+                r.unsafe_set((start + k, j), rkj - *vk * w[j]);
+            }
+        }
+
+        // q = q - 2 * (q * v) * v^T
+        w.truncate(0);
+
+        for i in range(0u, qrows) {
+            let mut s: N = Zero::zero();
+
+            for (k, vk) in v.iter().enumerate() {
+                s = s + q.unsafe_at((i, start + k)) * *vk;
+            }
+
+            w.push(s * _2);
+        }
+
+        for i in range(0u, qrows) {
+            for (k, vk) in v.iter().enumerate() {
+                let qik = q.unsafe_at((i, start + k));
+                q.unsafe_set((i, start + k), qik - w[i] * *vk);
+            }
+        }
+    }
+}
+
 /// QR decomposition using Householder reflections.
 ///
+/// The column type `V` is not used by the decomposition anymore. It is kept so that the type
+/// parameters of this function are unchanged; use `qr_with_workspace` to avoid the `ColSlice`
+/// bound.
+///
 /// # Arguments
 /// * `m` - matrix to decompose
+#[cfg(not(feature = "lapack"))]
 pub fn qr<N: Float,
           V: Indexable<uint, N> + Norm<N>,
-          M: Clone + Eye + ColSlice<V> + Transpose
-              + Indexable<(uint, uint), N> + Mul<M, M>>
-          (m: &M) 
+          M: Clone + Eye + ColSlice<V> + Indexable<(uint, uint), N>>
+          (m: &M)
           -> (M, M) {
+    householder_qr(m, &mut DecompositionWorkspace::new())
+}
+
+/// QR decomposition using Householder reflections, computed by LAPACK for `DMat<f32>` and
+/// `DMat<f64>`.
+///
+/// The column type `V` is not used by the decomposition anymore. It is kept so that the type
+/// parameters of this function are unchanged; use `qr_with_workspace` to avoid the `ColSlice`
+/// bound.
+///
+/// # Arguments
+/// * `m` - matrix to decompose
+#[cfg(feature = "lapack")]
+pub fn qr<N: Float,
+          V: Indexable<uint, N> + Norm<N>,
+          M: Clone + Eye + ColSlice<V> + Indexable<(uint, uint), N> + 'static>
+          (m: &M)
+          -> (M, M) {
+    qr_with_workspace(m, &mut DecompositionWorkspace::new())
+}
+
+/// QR decomposition using Householder reflections, using `workspace` for its temporary buffers.
+///
+/// The Householder reflections are applied in-place: no allocation occurs besides the creation
+/// of the results, once `workspace` is large enough.
+///
+/// # Arguments
+/// * `m` - matrix to decompose
+/// * `workspace` - buffers that can be reused across decompositions
+#[cfg(not(feature = "lapack"))]
+pub fn qr_with_workspace<N: Float, M: Clone + Eye + Indexable<(uint, uint), N>>
+                        (m: &M, workspace: &mut DecompositionWorkspace<N>)
+                        -> (M, M) {
+    householder_qr(m, workspace)
+}
+
+/// QR decomposition using Householder reflections, using `workspace` for its temporary buffers.
+///
+/// `DMat<f32>` and `DMat<f64>` are decomposed by LAPACK, which does not use `workspace`.
+///
+/// # Arguments
+/// * `m` - matrix to decompose
+/// * `workspace` - buffers that can be reused across decompositions
+#[cfg(feature = "lapack")]
+pub fn qr_with_workspace<N: Float, M: Clone + Eye + Indexable<(uint, uint), N> + 'static>
+                        (m: &M, workspace: &mut DecompositionWorkspace<N>)
+                        -> (M, M) {
+    match lapack::qr(m) {
+        Some(qr) => qr,
+        None     => householder_qr(m, workspace)
+    }
+}
+
+// Pure-Rust QR decomposition.
+fn householder_qr<N: Float, M: Clone + Eye + Indexable<(uint, uint), N>>
+                 (m: &M, workspace: &mut DecompositionWorkspace<N>)
+                 -> (M, M) {
     let (rows, cols) = m.shape();
     assert!(rows >= cols);
+
     let mut q : M = Eye::new_identity(rows);
     let mut r = m.clone();
 
     let iterations = min(rows - 1, cols);
 
     for ite in range(0u, iterations) {
-        let mut v = r.col_slice(ite, ite, rows);
-        let alpha =
-            if unsafe { v.unsafe_at(ite) } >= Zero::zero() {
-                -Norm::norm(&v)
-            }
-            else {
-                Norm::norm(&v)
-            };
-        unsafe {
-            let x = v.unsafe_at(0);
-            v.unsafe_set(0, x - alpha);
+        let v = &mut workspace.v;
+
+        v.truncate(0);
+
+        for i in range(ite, rows) {
+            v.push(unsafe { r.unsafe_at((i, ite)) });
         }
-        if !v.normalize().is_zero() {
-            let qk: M = householder_matrix(rows, ite, v);
-            r = qk * r;
-            q = q * Transpose::transpose_cpy(&qk);
+
+        let norm  = v.iter().fold(Zero::zero(), |acc: N, e| acc + *e * *e).sqrt();
+        let alpha = if (*v)[0] >= Zero::zero() { -norm } else { norm };
+
+        *v.get_mut(0) = (*v)[0] - alpha;
+
+        let vnorm = v.iter().fold(Zero::zero(), |acc: N, e| acc + *e * *e).sqrt();
+
+        if !vnorm.is_zero() {
+            for e in v.iter_mut() {
+                *e = *e / vnorm;
+            }
+
+            apply_householder(ite, v.as_slice(), &mut workspace.w, &mut r, &mut q);
         }
     }
 
     (q, r)
 }
 
+/// Cholesky decomposition of a symmetric positive-definite matrix.
+///
+/// Returns the lower-triangular matrix `l` such that `m = l * l^T`. Only the lower triangle of `m`
+/// is read. Returns `None` if `m` is not square or not positive-definite.
+///
+/// # Arguments
+/// * `m` - matrix to decompose
+pub fn cholesky<N: Float, M: Clone + Indexable<(uint, uint), N>>(m: &M) -> Option<M> {
+    let (rows, cols) = m.shape();
+
+    if rows != cols {
+        return None
+    }
+
+    let mut l = m.clone();
+
+    for j in range(0u, cols) {
+        let mut d = l.at((j, j));
+
+        for k in range(0u, j) {
+            let ljk = l.at((j, k));
+            d = d - ljk * ljk;
+        }
+
+        if d <= Zero::zero() {
+            return None
+        }
+
+        let ljj = d.sqrt();
+        l.set((j, j), ljj);
+
+        for i in range(j + 1, rows) {
+            let mut s = l.at((i, j));
+
+            for k in range(0u, j) {
+                s = s - l.at((i, k)) * l.at((j, k));
+            }
+
+            l.set((i, j), s / ljj);
+            l.set((j, i), Zero::zero());
+        }
+    }
+
+    Some(l)
+}
+
 /// Eigendecomposition of a square matrix using the qr algorithm.
+///
+/// The eigenvalues are sorted in ascending order, and the `i`-th column of the first returned
+/// matrix is an eigenvector of the `i`-th eigenvalue.
+///
+/// The column type `V` is not used by the decomposition anymore. It is kept so that the type
+/// parameters of this function are unchanged; use `eigen_qr_with_workspace` to avoid the
+/// `ColSlice` bound.
+#[cfg(not(feature = "lapack"))]
 pub fn eigen_qr<N:  Float,
                 V:  Indexable<uint, N> + Norm<N>,
                 V2: Zero,
-                M:  Clone + Eye + ColSlice<V> + Transpose
-                    + Indexable<(uint, uint), N> + Mul<M, M>
+                M:  Clone + Eye + ColSlice<V> + Indexable<(uint, uint), N> + Mul<M, M>
                     + Diag<V2> + ApproxEq<N> + Add<M, M>
                     + Sub<M, M>>
                 (m: &M, eps: &N, niter: uint) -> (M, V2) {
+    qr_eigen(m, eps, niter, &mut DecompositionWorkspace::new())
+}
+
+/// Eigendecomposition of a square matrix using the qr algorithm, computed by LAPACK for
+/// symmetric `DMat<f32>` and `DMat<f64>`.
+///
+/// The eigenvalues are sorted in ascending order, and the `i`-th column of the first returned
+/// matrix is an eigenvector of the `i`-th eigenvalue.
+///
+/// The column type `V` is not used by the decomposition anymore. It is kept so that the type
+/// parameters of this function are unchanged; use `eigen_qr_with_workspace` to avoid the
+/// `ColSlice` bound.
+#[cfg(feature = "lapack")]
+pub fn eigen_qr<N:  Float,
+                V:  Indexable<uint, N> + Norm<N>,
+                V2: Zero + 'static,
+                M:  Clone + Eye + ColSlice<V> + Indexable<(uint, uint), N> + Mul<M, M>
+                    + Diag<V2> + ApproxEq<N> + Add<M, M>
+                    + Sub<M, M> + 'static>
+                (m: &M, eps: &N, niter: uint) -> (M, V2) {
+    eigen_qr_with_workspace(m, eps, niter, &mut DecompositionWorkspace::new())
+}
+
+/// Eigendecomposition of a square matrix using the qr algorithm, using `workspace` for the
+/// temporary buffers of its QR decompositions.
+///
+/// The eigenvalues are sorted in ascending order.
+#[cfg(not(feature = "lapack"))]
+pub fn eigen_qr_with_workspace<N:  Float,
+                               V2: Zero,
+                               M:  Clone + Eye + Indexable<(uint, uint), N> + Mul<M, M>
+                                   + Diag<V2> + ApproxEq<N> + Add<M, M>
+                                   + Sub<M, M>>
+                               (m: &M, eps: &N, niter: uint, workspace: &mut DecompositionWorkspace<N>)
+                               -> (M, V2) {
+    qr_eigen(m, eps, niter, workspace)
+}
+
+/// Eigendecomposition of a square matrix using the qr algorithm, using `workspace` for the
+/// temporary buffers of its QR decompositions.
+///
+/// The eigenvalues are sorted in ascending order. Symmetric `DMat<f32>` and `DMat<f64>` are
+/// diagonalized by LAPACK, which does not use `workspace`.
+#[cfg(feature = "lapack")]
+pub fn eigen_qr_with_workspace<N:  Float,
+                               V2: Zero + 'static,
+                               M:  Clone + Eye + Indexable<(uint, uint), N> + Mul<M, M>
+                                   + Diag<V2> + ApproxEq<N> + Add<M, M>
+                                   + Sub<M, M> + 'static>
+                               (m: &M, eps: &N, niter: uint, workspace: &mut DecompositionWorkspace<N>)
+                               -> (M, V2) {
+    let (rows, cols) = m.shape();
+
+    assert!(rows == cols, "The matrix being decomposed must be square.");
+
+    match lapack::eigen_sym(m) {
+        Some(eigen) => eigen,
+        None        => qr_eigen(m, eps, niter, workspace)
+    }
+}
+
+// Pure-Rust eigendecomposition with the qr algorithm.
+fn qr_eigen<N:  Float,
+            V2: Zero,
+            M:  Clone + Eye + Indexable<(uint, uint), N> + Mul<M, M>
+                + Diag<V2> + ApproxEq<N> + Add<M, M>
+                + Sub<M, M>>
+            (m: &M, eps: &N, niter: uint, workspace: &mut DecompositionWorkspace<N>)
+            -> (M, V2) {
     let (rows, cols) = m.shape();
 
     assert!(rows == cols, "The matrix being decomposed must be square.");
@@ -123,11 +388,121 @@ pub fn eigen_qr<N:  Float,
             unsafe { shifter.unsafe_set((i, i), shift.clone()) }
         }
 
-        let (q, r) = qr(&eigenvalues);//  - shifter));
+        let (q, r) = householder_qr(&eigenvalues, workspace);//  - shifter));
 
         eigenvalues = r * q /*+ shifter*/;
         eigenvectors = eigenvectors * q;
     }
 
+    // sort the eigenvalues in ascending order, like LAPACK does.
+    for i in range(0u, rows) {
+        let mut imin = i;
+
+        for k in range(i + 1, rows) {
+            if unsafe { eigenvalues.unsafe_at((k, k)) < eigenvalues.unsafe_at((imin, imin)) } {
+                imin = k;
+            }
+        }
+
+        if imin != i {
+            eigenvalues.swap((i, i), (imin, imin));
+
+            for k in range(0u, rows) {
+                eigenvectors.swap((k, i), (k, imin));
+            }
+        }
+    }
+
     (eigenvectors, eigenvalues.diag())
 }
+
+/// Decomposition of a 4x4 affine transformation matrix into a translation, a rotation, and a
+/// non-uniform scaling.
+///
+/// Returns `(t, r, s)` such that `m` is the composition of the scaling `s`, followed by the
+/// rotation `r`, followed by the translation `t`. A reflection is reported as a negative scale
+/// factor along the `x` axis.
+///
+/// Returns `None` if `m` is not affine (its last row is not `[ 0, 0, 0, 1 ]`), if it is
+/// degenerate (one of its scale factors is zero), or if it contains a shear. Use
+/// `decompose_trs_shear` to decompose matrices with a shear.
+pub fn decompose_trs<N: Float + Clone + ApproxEq<N>>(m: &Mat4<N>) -> Option<(Vec3<N>, Rot3<N>, Vec3<N>)> {
+    match decompose_trs_shear(m) {
+        Some((translation, rotation, shear, scale)) => {
+            // the shear factors are ratios of lengths: they do not depend on the scale of `m`.
+            if ApproxEq::approx_eq(&shear, &Zero::zero()) {
+                Some((translation, rotation, scale))
+            }
+            else {
+                None
+            }
+        },
+        None => None
+    }
+}
+
+/// Decomposition of a 4x4 affine transformation matrix into a translation, a rotation, a shear,
+/// and a non-uniform scaling.
+///
+/// Returns `(t, r, h, s)` such that `m` is the composition of the scaling `s`, followed by the
+/// shear `h`, followed by the rotation `r`, followed by the translation `t`. The shear is
+/// returned as its `(xy, xz, yz)` factors, i.e., its matrix is
+/// `Mat4::new_shear(h.x, h.y, 0, h.z, 0, 0)`. A reflection is reported as a negative scale
+/// factor along the `x` axis.
+///
+/// Returns `None` if `m` is not affine (its last row is not `[ 0, 0, 0, 1 ]`) or if it is
+/// degenerate (one of its scale factors is zero).
+pub fn decompose_trs_shear<N: Float + Clone + ApproxEq<N>>(m: &Mat4<N>)
+                                                          -> Option<(Vec3<N>, Rot3<N>, Vec3<N>, Vec3<N>)> {
+    let _0: N = Zero::zero();
+    let _1: N = One::one();
+
+    // the last row does not depend on the scale of the transformation.
+    if !ApproxEq::approx_eq(&m.m41, &_0) || !ApproxEq::approx_eq(&m.m42, &_0) ||
+       !ApproxEq::approx_eq(&m.m43, &_0) || !ApproxEq::approx_eq(&m.m44, &_1) {
+        return None
+    }
+
+    let translation = Vec3::new(m.m14.clone(), m.m24.clone(), m.m34.clone());
+
+    let mut x = Vec3::new(m.m11.clone(), m.m21.clone(), m.m31.clone());
+    let mut y = Vec3::new(m.m12.clone(), m.m22.clone(), m.m32.clone());
+    let mut z = Vec3::new(m.m13.clone(), m.m23.clone(), m.m33.clone());
+
+    // the degeneracy threshold is relative to the largest column, so that small-scale
+    // transformations are still decomposed.
+    let eps: N = ApproxEq::approx_epsilon(None::<N>);
+    let tol    = eps * Norm::norm(&x).max(Norm::norm(&y)).max(Norm::norm(&z));
+
+    // Gram-Schmidt orthonormalization of the columns.
+    let mut sx = x.normalize();
+
+    let mut xy = Dot::dot(&x, &y);
+    y          = Vec3::new(y.x - x.x * xy, y.y - x.y * xy, y.z - x.z * xy);
+    let sy     = y.normalize();
+
+    let mut xz = Dot::dot(&x, &z);
+    let yz     = Dot::dot(&y, &z);
+    z          = Vec3::new(z.x - x.x * xz - y.x * yz, z.y - x.y * xz - y.y * yz, z.z - x.z * xz - y.z * yz);
+    let sz     = z.normalize();
+
+    if tol == _0 || sx <= tol || sy <= tol || sz <= tol {
+        return None
+    }
+
+    if Dot::dot(&Cross::cross(&x, &y), &z) < _0 {
+        x  = -x;
+        sx = -sx;
+        xy = -xy;
+        xz = -xz;
+    }
+
+    let rotation = unsafe {
+        Rot3::new_with_mat(Mat3::new(
+            x.x, y.x, z.x,
+            x.y, y.y, z.y,
+            x.z, y.z, z.z))
+    };
+
+    Some((translation, rotation, Vec3::new(xy / sy, xz / sz, yz / sz), Vec3::new(sx, sy, sz)))
+}
@@ -0,0 +1,301 @@
+//! Closed-form eigendecompositions of small matrices.
+
+use std::num;
+use std::num::{Zero, One, Float};
+use traits::operations::Det;
+use traits::structure::{Row, Iterable, Indexable};
+use traits::geometry::{Norm, Cross, Dot};
+use structs::{Vec2, Vec3, Mat2, Mat3};
+
+/// Eigendecomposition of a 2x2 matrix, computed in closed form from its characteristic
+/// polynomial.
+///
+/// Returns the eigenvectors as the columns of a matrix, and the eigenvalues sorted in ascending
+/// order. The eigenvectors have a unit norm but are orthogonal only if `m` is symmetric. If `m`
+/// is defective, i.e., has a repeated eigenvalue but not enough independent eigenvectors, the
+/// same eigenvector is returned twice.
+///
+/// Returns `None` if the eigenvalues of `m` are complex.
+pub fn eigen_mat2<N: FloatMath + Clone>(m: &Mat2<N>) -> Option<(Mat2<N>, Vec2<N>)> {
+    let _2: N = One::one() + One::one();
+
+    let half_tr   = (m.m11 + m.m22) / _2;
+    let half_diff = (m.m11 - m.m22) / _2;
+    let disc      = half_diff * half_diff + m.m12 * m.m21;
+
+    if disc < Zero::zero() {
+        return None
+    }
+
+    let sqrt_disc = disc.sqrt();
+    let vals      = Vec2::new(half_tr - sqrt_disc, half_tr + sqrt_disc);
+    let scale     = max_abs(m.iter());
+    let tol       = tolerance(&scale);
+
+    let v1 = eigenvector2(m, &vals.x, &tol, 0);
+    let v2 = eigenvector2(m, &vals.y, &tol, if (vals.y - vals.x) <= tol { 1 } else { 0 });
+
+    Some((Mat2::new(v1.x, v2.x, v1.y, v2.y), vals))
+}
+
+/// Eigendecomposition of a 3x3 matrix, computed in closed form from its characteristic
+/// polynomial.
+///
+/// Returns the eigenvectors as the columns of a matrix, and the eigenvalues sorted in ascending
+/// order. The eigenvectors have a unit norm but are orthogonal only if `m` is symmetric. If `m`
+/// is defective, i.e., has a repeated eigenvalue but not enough independent eigenvectors, some
+/// eigenvectors are returned several times. Use `eigen_sym3` for symmetric matrices.
+///
+/// Returns `None` if some eigenvalues of `m` are complex.
+pub fn eigen_mat3<N: FloatMath + Clone>(m: &Mat3<N>) -> Option<(Mat3<N>, Vec3<N>)> {
+    let vals = match eigenvalues3(m, false) {
+        Some(vals) => vals,
+        None       => return None
+    };
+
+    let scale = max_abs(m.iter());
+    let tol   = tolerance(&scale);
+
+    let v1 = eigenvector3(m, &vals.x, &scale, 0);
+    let v2 = eigenvector3(m, &vals.y, &scale, if (vals.y - vals.x) <= tol { 1 } else { 0 });
+    let v3 = eigenvector3(m, &vals.z, &scale,
+                          if (vals.z - vals.x) <= tol { 2 } else if (vals.z - vals.y) <= tol { 1 } else { 0 });
+
+    Some((Mat3::new(v1.x, v2.x, v3.x,
+                    v1.y, v2.y, v3.y,
+                    v1.z, v2.z, v3.z),
+          vals))
+}
+
+/// Eigendecomposition of a symmetric 3x3 matrix, computed in closed form.
+///
+/// Returns an orthonormal basis of eigenvectors as the columns of a rotation or reflection
+/// matrix, and the eigenvalues sorted in ascending order. This is suited for inertia tensors,
+/// covariance matrices, or curvature tensors. Only the lower triangle of `m` is read.
+///
+/// The eigenvector of the eigenvalue the most separated from the others is computed first. The
+/// two others are then found by diagonalizing the restriction of `m` to the plane orthogonal to
+/// it, so that the basis remains orthonormal even if some eigenvalues are repeated.
+pub fn eigen_sym3<N: FloatMath + Clone>(m: &Mat3<N>) -> (Mat3<N>, Vec3<N>) {
+    let m = Mat3::new(m.m11.clone(), m.m21.clone(), m.m31.clone(),
+                      m.m21.clone(), m.m22.clone(), m.m32.clone(),
+                      m.m31.clone(), m.m32.clone(), m.m33.clone());
+
+    let vals = eigenvalues3(&m, true).unwrap();
+    let tol  = tolerance(&max_abs(m.iter()));
+
+    if vals.z - vals.x <= tol {
+        // triple eigenvalue: `m` is a multiple of the identity.
+        return (One::one(), vals)
+    }
+
+    let first_is_min = vals.y - vals.x >= vals.z - vals.y;
+    let first_val    = if first_is_min { vals.x.clone() } else { vals.z.clone() };
+
+    let mut b = m.clone();
+
+    b.m11 = b.m11 - first_val;
+    b.m22 = b.m22 - first_val;
+    b.m33 = b.m33 - first_val;
+
+    let (r1, r2, r3) = (b.row(0), b.row(1), b.row(2));
+    let cross        = largest(Cross::cross(&r1, &r2), Cross::cross(&r1, &r3), Cross::cross(&r2, &r3));
+
+    if Norm::sqnorm(&cross).is_zero() {
+        return (One::one(), vals)
+    }
+
+    let first = Norm::normalize_cpy(&cross);
+
+    // the two other eigenvectors are the eigenvectors of the restriction of `m` to the plane
+    // orthogonal to `first`, i.e., of the symmetric matrix `[ a, c ; c, d ]` in the basis `(u, w)`.
+    let u = any_orthogonal(&first);
+    let w = Cross::cross(&first, &u);
+
+    let (mu, mw) = (m * u, m * w);
+    let a        = Dot::dot(&u, &mu);
+    let c        = Dot::dot(&u, &mw);
+    let d        = Dot::dot(&w, &mw);
+
+    // the rotation of angle `theta` diagonalizes the restriction, with `big` the eigenvector of its
+    // greatest eigenvalue.
+    let _2: N      = One::one() + One::one();
+    let theta      = (_2 * c).atan2(a - d) / _2;
+    let (sin, cos) = theta.sin_cos();
+    let big        = lin_comb(&cos, &u, &sin, &w);
+    let small      = lin_comb(&cos, &w, &-sin, &u);
+
+    let (v1, v2, v3) = if first_is_min { (first, small, big) } else { (small, big, first) };
+
+    // the rayleigh quotients are more accurate than the roots of the characteristic polynomial.
+    let mut vals = Vec3::new(Dot::dot(&v1, &(m * v1)), Dot::dot(&v2, &(m * v2)), Dot::dot(&v3, &(m * v3)));
+    let mut vecs = [ v1, v2, v3 ];
+
+    // rounding errors can swap close eigenvalues.
+    for i in range(1u, 3) {
+        for j in range(0u, 3 - i) {
+            if vals.at(j) > vals.at(j + 1) {
+                let vj = vals.at(j);
+                vals.set(j, vals.at(j + 1));
+                vals.set(j + 1, vj);
+                vecs.swap(j, j + 1);
+            }
+        }
+    }
+
+    (Mat3::new(vecs[0].x.clone(), vecs[1].x.clone(), vecs[2].x.clone(),
+               vecs[0].y.clone(), vecs[1].y.clone(), vecs[2].y.clone(),
+               vecs[0].z.clone(), vecs[1].z.clone(), vecs[2].z.clone()),
+     vals)
+}
+
+// Greatest absolute value of the elements of `it`.
+fn max_abs<'a, N: 'a + FloatMath, I: Iterator<&'a N>>(mut it: I) -> N {
+    it.fold(Zero::zero(), |acc: N, e| acc.max(e.abs()))
+}
+
+// Threshold under which a quantity homogeneous to the components of a matrix with the greatest
+// absolute component `scale` is considered zero. Repeated eigenvalues are computed with an error
+// of the order of the square root of the machine epsilon.
+#[inline]
+fn tolerance<N: FloatMath>(scale: &N) -> N {
+    let eps: N = Float::epsilon();
+
+    eps.sqrt() * *scale
+}
+
+// Real eigenvalues of `m`, sorted in ascending order, computed with the trigonometric solution of
+// its characteristic polynomial.
+//
+// If `real` is `true`, the eigenvalues are known to be real, e.g., because `m` is symmetric, and
+// rounding errors are ignored. Otherwise, returns `None` if some eigenvalues are complex.
+fn eigenvalues3<N: FloatMath + Clone>(m: &Mat3<N>, real: bool) -> Option<Vec3<N>> {
+    let _2: N = One::one() + One::one();
+    let _3: N = _2 + One::one();
+
+    // the characteristic polynomial of the traceless matrix `b` is `t^3 + p * t + q`.
+    let shift = (m.m11 + m.m22 + m.m33) / _3;
+    let b     = Mat3::new(m.m11 - shift, m.m12.clone(),  m.m13.clone(),
+                          m.m21.clone(),  m.m22 - shift, m.m23.clone(),
+                          m.m31.clone(),  m.m32.clone(),  m.m33 - shift);
+
+    let p = (b.m11 * b.m22 - b.m12 * b.m21) +
+            (b.m11 * b.m33 - b.m13 * b.m31) +
+            (b.m22 * b.m33 - b.m23 * b.m32);
+    let q = -Det::det(&b);
+
+    let p3   = p / _3;
+    let q2   = q / _2;
+    let disc = q2 * q2 + p3 * p3 * p3;
+
+    if !real {
+        let eps: N   = Float::epsilon();
+        let _1000: N = num::cast(1000.0f64).unwrap();
+        let tol      = eps * _1000 * (q2 * q2 + (p3 * p3 * p3).abs());
+
+        if disc > tol {
+            return None
+        }
+    }
+
+    if p3 >= Zero::zero() {
+        // triple eigenvalue.
+        return Some(Vec3::new(shift.clone(), shift.clone(), shift))
+    }
+
+    let two_pi: N = Float::two_pi();
+    let r         = (-p3).sqrt();
+    let cos_arg   = (-q2 / (r * r * r)).max(-One::one()).min(One::one());
+    let phi       = cos_arg.acos() / _3;
+    let third     = two_pi / _3;
+
+    // with `phi` in `[0, pi / 3]`, those are in ascending order.
+    Some(Vec3::new(shift + _2 * r * (phi + third).cos(),
+                   shift + _2 * r * (phi - third).cos(),
+                   shift + _2 * r * phi.cos()))
+}
+
+// Unit eigenvector of the 2x2 matrix `m` associated with the eigenvalue `val`. If `m - val * I` is
+// zero, the `nth` canonical basis vector is returned.
+fn eigenvector2<N: FloatMath + Clone>(m: &Mat2<N>, val: &N, tol: &N, nth: uint) -> Vec2<N> {
+    // the eigenvector is orthogonal to every row of `m - val * I`.
+    let c1 = Vec2::new(-m.m12, m.m11 - *val);
+    let c2 = Vec2::new(*val - m.m22, m.m21.clone());
+
+    let best = if Norm::sqnorm(&c1) >= Norm::sqnorm(&c2) { c1 } else { c2 };
+
+    if Norm::norm(&best) <= *tol {
+        if nth == 0 { Vec2::x() } else { Vec2::y() }
+    }
+    else {
+        Norm::normalize_cpy(&best)
+    }
+}
+
+// Unit eigenvector of the 3x3 matrix `m`, with the greatest absolute component `scale`,
+// associated with the eigenvalue `val`.
+//
+// If `m - val * I` has a rank of one, its null space is a plane, and `nth` selects one of two
+// orthogonal vectors of this plane. If it is zero, the `nth` canonical basis vector is returned.
+fn eigenvector3<N: FloatMath + Clone>(m: &Mat3<N>, val: &N, scale: &N, nth: uint) -> Vec3<N> {
+    let mut b = m.clone();
+
+    b.m11 = b.m11 - *val;
+    b.m22 = b.m22 - *val;
+    b.m33 = b.m33 - *val;
+
+    let (r1, r2, r3) = (b.row(0), b.row(1), b.row(2));
+
+    // the eigenvector is orthogonal to every row of `b`.
+    let best_cross = largest(Cross::cross(&r1, &r2), Cross::cross(&r1, &r3), Cross::cross(&r2, &r3));
+    let tol        = tolerance(scale);
+
+    // the cross products are homogeneous to the square of the components.
+    if Norm::norm(&best_cross) > tol * *scale {
+        return Norm::normalize_cpy(&best_cross)
+    }
+
+    let best_row = largest(r1, r2, r3);
+
+    if Norm::norm(&best_row) <= tol {
+        return match nth {
+            0 => Vec3::x(),
+            1 => Vec3::y(),
+            _ => Vec3::z()
+        }
+    }
+
+    let u = any_orthogonal(&best_row);
+
+    if nth == 0 {
+        u
+    }
+    else {
+        Norm::normalize_cpy(&Cross::cross(&best_row, &u))
+    }
+}
+
+// Computes `a * x + b * y`.
+#[inline]
+fn lin_comb<N: Mul<N, N> + Add<N, N>>(a: &N, x: &Vec3<N>, b: &N, y: &Vec3<N>) -> Vec3<N> {
+    Vec3::new(*a * x.x + *b * y.x, *a * x.y + *b * y.y, *a * x.z + *b * y.z)
+}
+
+// The vector with the greatest norm.
+fn largest<N: FloatMath + Clone>(a: Vec3<N>, b: Vec3<N>, c: Vec3<N>) -> Vec3<N> {
+    let (na, nb, nc) = (Norm::sqnorm(&a), Norm::sqnorm(&b), Norm::sqnorm(&c));
+
+    if na >= nb && na >= nc { a } else if nb >= nc { b } else { c }
+}
+
+// A unit vector orthogonal to the non-zero vector `v`.
+fn any_orthogonal<N: FloatMath + Clone>(v: &Vec3<N>) -> Vec3<N> {
+    let res = if v.x.abs() > v.y.abs() {
+        Vec3::new(-v.z, Zero::zero(), v.x.clone())
+    }
+    else {
+        Vec3::new(Zero::zero(), v.z.clone(), -v.y)
+    };
+
+    Norm::normalize_cpy(&res)
+}
@@ -0,0 +1,177 @@
+//! Dispatch of decompositions to an external LAPACK implementation.
+//!
+//! This module only exists when the `lapack` feature is enabled. The decompositions of
+//! `DMat<f32>` and `DMat<f64>` are then computed by the `lapack` library, and the generic
+//! pure-Rust implementations are used for the other types.
+
+use std::intrinsics::TypeId;
+use std::num::Zero;
+use std::cmp::{min, max};
+use std::mem;
+use std::ptr;
+use structs::{DMat, DVec};
+
+// Number of workspace elements allocated per row for the blocked LAPACK routines.
+static BLOCK_SIZE: uint = 64;
+
+#[link(name = "lapack")]
+extern {
+    fn sgeqrf_(m: *const i32, n: *const i32, a: *mut f32, lda: *const i32, tau: *mut f32,
+               work: *mut f32, lwork: *const i32, info: *mut i32);
+    fn dgeqrf_(m: *const i32, n: *const i32, a: *mut f64, lda: *const i32, tau: *mut f64,
+               work: *mut f64, lwork: *const i32, info: *mut i32);
+    fn sorgqr_(m: *const i32, n: *const i32, k: *const i32, a: *mut f32, lda: *const i32,
+               tau: *const f32, work: *mut f32, lwork: *const i32, info: *mut i32);
+    fn dorgqr_(m: *const i32, n: *const i32, k: *const i32, a: *mut f64, lda: *const i32,
+               tau: *const f64, work: *mut f64, lwork: *const i32, info: *mut i32);
+    fn ssyev_(jobz: *const u8, uplo: *const u8, n: *const i32, a: *mut f32, lda: *const i32,
+              w: *mut f32, work: *mut f32, lwork: *const i32, info: *mut i32);
+    fn dsyev_(jobz: *const u8, uplo: *const u8, n: *const i32, a: *mut f64, lda: *const i32,
+              w: *mut f64, work: *mut f64, lwork: *const i32, info: *mut i32);
+}
+
+// Scalar types supported by LAPACK. Each method returns the `info` output of the routine.
+trait LapackScalar: Clone + Zero + PartialEq {
+    unsafe fn geqrf(m: uint, n: uint, a: &mut [Self], tau: &mut [Self], work: &mut [Self]) -> i32;
+    unsafe fn orgqr(m: uint, n: uint, k: uint, a: &mut [Self], tau: &[Self], work: &mut [Self]) -> i32;
+    unsafe fn syev(n: uint, a: &mut [Self], w: &mut [Self], work: &mut [Self]) -> i32;
+}
+
+macro_rules! lapack_scalar_impl(
+    ($n: ident, $geqrf: ident, $orgqr: ident, $syev: ident) => (
+        impl LapackScalar for $n {
+            unsafe fn geqrf(m: uint, n: uint, a: &mut [$n], tau: &mut [$n], work: &mut [$n]) -> i32 {
+                let mut info = 0i32;
+
+                $geqrf(&(m as i32), &(n as i32), a.as_mut_ptr(), &(m as i32), tau.as_mut_ptr(),
+                       work.as_mut_ptr(), &(work.len() as i32), &mut info);
+
+                info
+            }
+
+            unsafe fn orgqr(m: uint, n: uint, k: uint, a: &mut [$n], tau: &[$n], work: &mut [$n]) -> i32 {
+                let mut info = 0i32;
+
+                $orgqr(&(m as i32), &(n as i32), &(k as i32), a.as_mut_ptr(), &(m as i32), tau.as_ptr(),
+                       work.as_mut_ptr(), &(work.len() as i32), &mut info);
+
+                info
+            }
+
+            unsafe fn syev(n: uint, a: &mut [$n], w: &mut [$n], work: &mut [$n]) -> i32 {
+                let mut info = 0i32;
+
+                // compute the eigenvectors too, using the lower triangle of `a`.
+                $syev(&b'V', &b'L', &(n as i32), a.as_mut_ptr(), &(n as i32), w.as_mut_ptr(),
+                      work.as_mut_ptr(), &(work.len() as i32), &mut info);
+
+                info
+            }
+        }
+    )
+)
+
+lapack_scalar_impl!(f32, sgeqrf_, sorgqr_, ssyev_)
+lapack_scalar_impl!(f64, dgeqrf_, dorgqr_, dsyev_)
+
+#[inline]
+fn is<A: 'static, B: 'static>() -> bool {
+    TypeId::of::<A>() == TypeId::of::<B>()
+}
+
+// Reinterprets `a` as a value of type `B`, which must be the same type as `A`.
+unsafe fn reinterpret<A, B>(a: A) -> B {
+    let res = ptr::read(&a as *const A as *const B);
+    mem::forget(a);
+
+    res
+}
+
+fn dmat_qr<N: LapackScalar>(m: &DMat<N>) -> Option<(DMat<N>, DMat<N>)> {
+    let (rows, cols) = (m.nrows(), m.ncols());
+    let k            = min(rows, cols);
+
+    // `a` has room for the `rows` columns of `q`, which overwrite the reflectors.
+    let mut a: Vec<N> = m.as_vec().to_vec();
+    a.grow(rows * rows - rows * cols, Zero::zero());
+
+    let mut tau  = Vec::from_elem(max(k, 1), Zero::zero());
+    let mut work = Vec::from_elem(max(rows, 1) * BLOCK_SIZE, Zero::zero());
+
+    unsafe {
+        if LapackScalar::geqrf(rows, cols, a.as_mut_slice(), tau.as_mut_slice(), work.as_mut_slice()) != 0 {
+            return None
+        }
+
+        let r = DMat::from_fn(rows, cols, |i, j| {
+            if i <= j { a[i + j * rows].clone() } else { Zero::zero() }
+        });
+
+        if LapackScalar::orgqr(rows, rows, k, a.as_mut_slice(), tau.as_slice(), work.as_mut_slice()) != 0 {
+            return None
+        }
+
+        Some((DMat::from_col_vec(rows, rows, a.as_slice()), r))
+    }
+}
+
+fn dmat_eigen_sym<N: LapackScalar>(m: &DMat<N>) -> Option<(DMat<N>, DVec<N>)> {
+    let n  = m.nrows();
+    let mv = m.as_vec();
+
+    for i in range(0u, n) {
+        for j in range(0u, i) {
+            if mv[i + j * n] != mv[j + i * n] {
+                return None
+            }
+        }
+    }
+
+    let mut a    = mv.to_vec();
+    let mut w    = Vec::from_elem(n, Zero::zero());
+    let mut work = Vec::from_elem(max(3 * n, 1) * BLOCK_SIZE, Zero::zero());
+
+    unsafe {
+        if LapackScalar::syev(n, a.as_mut_slice(), w.as_mut_slice(), work.as_mut_slice()) != 0 {
+            return None
+        }
+    }
+
+    Some((DMat::from_col_vec(n, n, a.as_slice()), DVec { at: w }))
+}
+
+/// QR decomposition computed by LAPACK.
+///
+/// Returns `None` if `M` is neither `DMat<f32>` nor `DMat<f64>`, or if LAPACK failed.
+pub fn qr<M: 'static>(m: &M) -> Option<(M, M)> {
+    unsafe {
+        if is::<M, DMat<f64>>() {
+            reinterpret(dmat_qr(&*(m as *const M as *const DMat<f64>)))
+        }
+        else if is::<M, DMat<f32>>() {
+            reinterpret(dmat_qr(&*(m as *const M as *const DMat<f32>)))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Eigendecomposition of a symmetric matrix computed by LAPACK.
+///
+/// The eigenvalues are sorted in ascending order. Returns `None` if `M` and `V` are neither
+/// `DMat<f32>` and `DVec<f32>` nor `DMat<f64>` and `DVec<f64>`, if `m` is not symmetric, or if
+/// LAPACK failed.
+pub fn eigen_sym<M: 'static, V: 'static>(m: &M) -> Option<(M, V)> {
+    unsafe {
+        if is::<M, DMat<f64>>() && is::<V, DVec<f64>>() {
+            reinterpret(dmat_eigen_sym(&*(m as *const M as *const DMat<f64>)))
+        }
+        else if is::<M, DMat<f32>>() && is::<V, DVec<f32>>() {
+            reinterpret(dmat_eigen_sym(&*(m as *const M as *const DMat<f32>)))
+        }
+        else {
+            None
+        }
+    }
+}
@@ -1,4 +1,13 @@
-
-pub use self::decompositions::{qr, eigen_qr, householder_matrix};
+pub use self::decompositions::{qr, cholesky, eigen_qr, householder_matrix, decompose_trs, decompose_trs_shear};
+pub use self::decompositions::{qr_with_workspace, eigen_qr_with_workspace, DecompositionWorkspace};
+pub use self::eigen::{eigen_mat2, eigen_mat3, eigen_sym3};
+pub use self::roots::roots;
+pub use self::procrustes::{best_fit_rotation, best_fit_isometry, best_fit_similarity};
+pub use self::procrustes::dominant_eigenvector;
 
 mod decompositions;
+mod eigen;
+#[cfg(feature = "lapack")]
+mod lapack;
+mod procrustes;
+mod roots;
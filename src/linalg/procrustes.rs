@@ -0,0 +1,192 @@
+use std::num;
+use std::num::{Zero, One};
+use traits::structure::{Indexable, Col};
+use traits::geometry::{Norm, Dot};
+use structs::{Vec3, Vec4, Pnt3, Mat4, Quat, UnitQuat, Rot3, Iso3, Sim3};
+
+/// Computes the rotation that best aligns the points `a` onto the points `b`.
+///
+/// The result is the rotation `r` minimizing the sum of the squared distances between `r * a[i]`
+/// and `b[i]`. The rotation is about the origin: use `best_fit_isometry` to find the optimal
+/// translation as well.
+///
+/// The rotation is found with Horn's closed-form method: it is the unit quaternion that is the
+/// dominant eigenvector of a 4x4 symmetric matrix built from the cross-covariance of both point
+/// sets. Unlike methods based on the singular value decomposition, it never returns a
+/// reflection.
+///
+/// Returns `None` if the point sets are empty. Fails if they do not have the same length.
+pub fn best_fit_rotation<N: FloatMath + Clone>(a: &[Pnt3<N>], b: &[Pnt3<N>]) -> Option<Rot3<N>> {
+    assert!(a.len() == b.len(), "The point sets to align must have the same length.");
+
+    if a.is_empty() {
+        return None
+    }
+
+    let mut sxx: N = Zero::zero();
+    let mut sxy: N = Zero::zero();
+    let mut sxz: N = Zero::zero();
+    let mut syx: N = Zero::zero();
+    let mut syy: N = Zero::zero();
+    let mut syz: N = Zero::zero();
+    let mut szx: N = Zero::zero();
+    let mut szy: N = Zero::zero();
+    let mut szz: N = Zero::zero();
+
+    for (pa, pb) in a.iter().zip(b.iter()) {
+        sxx = sxx + pa.x * pb.x;
+        sxy = sxy + pa.x * pb.y;
+        sxz = sxz + pa.x * pb.z;
+        syx = syx + pa.y * pb.x;
+        syy = syy + pa.y * pb.y;
+        syz = syz + pa.y * pb.z;
+        szx = szx + pa.z * pb.x;
+        szy = szy + pa.z * pb.y;
+        szz = szz + pa.z * pb.z;
+    }
+
+    let m = Mat4::new(
+        sxx + syy + szz, syz - szy,        szx - sxz,        sxy - syx,
+        syz - szy,       sxx - syy - szz,  sxy + syx,        szx + sxz,
+        szx - sxz,       sxy + syx,        -sxx + syy - szz, syz + szy,
+        sxy - syx,       szx + sxz,        syz + szy,        -sxx - syy + szz
+    );
+
+    let v = dominant_eigenvector(m);
+
+    Some(UnitQuat::new_with_quat(Quat::new(v.x, v.y, v.z, v.w)).to_rot())
+}
+
+/// Computes the isometry that best aligns the points `a` onto the points `b`.
+///
+/// This uses Horn's quaternion method: the result is the isometry `m` minimizing the sum of the
+/// squared distances between `m * a[i]` and `b[i]`.
+///
+/// Returns `None` if the point sets are empty. Fails if they do not have the same length.
+pub fn best_fit_isometry<N: FloatMath + Clone>(a: &[Pnt3<N>], b: &[Pnt3<N>]) -> Option<Iso3<N>> {
+    assert!(a.len() == b.len(), "The point sets to align must have the same length.");
+
+    if a.is_empty() {
+        return None
+    }
+
+    let (ca, a) = centered(a);
+    let (cb, b) = centered(b);
+    let rot     = best_fit_rotation(a.as_slice(), b.as_slice()).unwrap();
+
+    Some(Iso3::new_with_rotmat(cb - rot * ca, rot))
+}
+
+/// Computes the similarity that best aligns the points `a` onto the points `b`.
+///
+/// This is Umeyama's method: the result is the similarity `m` minimizing the sum of the squared
+/// distances between `m * a[i]` and `b[i]`.
+///
+/// Returns `None` if the point sets are empty or if all the points of `a` or of `b` coincide.
+/// Fails if they do not have the same length.
+pub fn best_fit_similarity<N: FloatMath + Clone>(a: &[Pnt3<N>], b: &[Pnt3<N>]) -> Option<Sim3<N>> {
+    assert!(a.len() == b.len(), "The point sets to align must have the same length.");
+
+    if a.is_empty() {
+        return None
+    }
+
+    let (ca, a) = centered(a);
+    let (cb, b) = centered(b);
+    let rot     = best_fit_rotation(a.as_slice(), b.as_slice()).unwrap();
+
+    let mut sab: N = Zero::zero();
+    let mut saa: N = Zero::zero();
+
+    for (pa, pb) in a.iter().zip(b.iter()) {
+        sab = sab + Dot::dot(pb.as_vec(), (rot * *pa).as_vec());
+        saa = saa + Norm::sqnorm(pa.as_vec());
+    }
+
+    if saa.is_zero() || sab <= Zero::zero() {
+        return None
+    }
+
+    let scale = sab / saa;
+    let rca   = rot * ca;
+
+    Some(Sim3::new_with_rotmat(cb - Vec3::new(rca.x * scale, rca.y * scale, rca.z * scale), rot, scale))
+}
+
+// Returns the centroid of `pts` and the points translated so that their centroid is the origin.
+fn centered<N: FloatMath + Clone>(pts: &[Pnt3<N>]) -> (Vec3<N>, Vec<Pnt3<N>>) {
+    let mut c: Vec3<N> = Zero::zero();
+
+    for p in pts.iter() {
+        c = c + *p.as_vec();
+    }
+
+    let n: N = num::cast(pts.len()).unwrap();
+    c = Vec3::new(c.x / n, c.y / n, c.z / n);
+
+    (c.clone(), pts.iter().map(|p| *p - c).collect())
+}
+
+// Unit eigenvector associated with the greatest eigenvalue of the symmetric matrix `m`.
+//
+// The matrix is shifted to make it positive semi-definite, and then repeatedly squared: each
+// squaring doubles the power of the power iteration.
+pub fn dominant_eigenvector<N: FloatMath + Clone>(mut m: Mat4<N>) -> Vec4<N> {
+    // the frobenius norm is an upper bound of the spectral radius.
+    let mut shift: N = Zero::zero();
+
+    for i in range(0u, 4) {
+        for j in range(0u, 4) {
+            shift = shift + m.at((i, j)) * m.at((i, j));
+        }
+    }
+
+    shift = shift.sqrt();
+
+    if shift.is_zero() {
+        return Vec4::new(One::one(), Zero::zero(), Zero::zero(), Zero::zero())
+    }
+
+    for i in range(0u, 4) {
+        let mii = m.at((i, i));
+        m.set((i, i), (mii + shift) / shift);
+    }
+
+    for i in range(0u, 4) {
+        for j in range(0u, 4) {
+            if i != j {
+                let mij = m.at((i, j));
+                m.set((i, j), mij / shift);
+            }
+        }
+    }
+
+    for _ in range(0u, 64u) {
+        m = m * m;
+
+        let mut norm: N = Zero::zero();
+
+        for i in range(0u, 4) {
+            norm = norm + m.at((i, i));
+        }
+
+        // the trace of a positive semi-definite matrix is zero only if the matrix is zero.
+        for i in range(0u, 4) {
+            for j in range(0u, 4) {
+                let mij = m.at((i, j));
+                m.set((i, j), mij / norm);
+            }
+        }
+    }
+
+    // every non-zero column of the converged matrix is colinear with the eigenvector.
+    let mut imax = 0u;
+
+    for i in range(1u, 4) {
+        if m.at((i, i)) > m.at((imax, imax)) {
+            imax = i;
+        }
+    }
+
+    Norm::normalize_cpy(&m.col(imax))
+}
@@ -0,0 +1,239 @@
+use std::num;
+use std::num::{Zero, One};
+use std::cmp;
+use traits::structure::Indexable;
+use structs::DMat;
+
+/// Computes the complex roots of a polynomial with real coefficients.
+///
+/// The coefficient of `x^i` is `coeffs[i]`. Each root is returned as a pair `(re, im)`, with
+/// multiple roots repeated, and complex roots coming in conjugate pairs. The roots are the
+/// eigenvalues of the companion matrix of the polynomial, computed with the Francis double-shift
+/// QR algorithm.
+///
+/// Returns an empty vector if the polynomial is constant. The result is wrapped into an `Option`
+/// instead of being a plain `Vec<(N, N)>` because the QR iterations are not guaranteed to
+/// converge: `None` is returned if they did not.
+pub fn roots<N: Float>(coeffs: &[N]) -> Option<Vec<(N, N)>> {
+    // ignore the zero coefficients of the highest degrees.
+    let mut deg = coeffs.len();
+
+    while deg != 0 && coeffs[deg - 1].is_zero() {
+        deg = deg - 1;
+    }
+
+    if deg <= 1 {
+        return Some(Vec::new())
+    }
+
+    let n    = deg - 1;
+    let lead = coeffs[n];
+
+    // the companion matrix is upper Hessenberg.
+    let mut companion: DMat<N> = DMat::new_zeros(n, n);
+
+    for j in range(0u, n) {
+        companion.set((0, j), -coeffs[n - 1 - j] / lead);
+    }
+
+    for i in range(1u, n) {
+        companion.set((i, i - 1), One::one());
+    }
+
+    hessenberg_eigenvalues(companion)
+}
+
+// Eigenvalues of the upper Hessenberg matrix `h`.
+//
+// Francis double-shift steps are applied to the trailing unreduced block of `h` until its last
+// one or two eigenvalues are isolated by a negligible subdiagonal element. Only the active block
+// is updated since the Schur vectors are not needed.
+fn hessenberg_eigenvalues<N: Float>(mut h: DMat<N>) -> Option<Vec<(N, N)>> {
+    let n          = h.nrows();
+    let eps: N     = Float::epsilon();
+    let mut res    = Vec::with_capacity(n);
+    let mut hi     = n;
+    let mut niter  = 0u;
+
+    // used instead of the neighbouring diagonal elements when they are both zero.
+    let mut scale: N = Zero::zero();
+
+    for i in range(0u, n) {
+        for j in range(0u, n) {
+            scale = scale + h.at((i, j)).abs();
+        }
+    }
+
+    while hi != 0 {
+        // find the first row of the unreduced block ending at `hi - 1`.
+        let mut lo = hi - 1;
+
+        while lo != 0 {
+            let mut s = h.at((lo - 1, lo - 1)).abs() + h.at((lo, lo)).abs();
+
+            if s.is_zero() {
+                s = scale;
+            }
+
+            if h.at((lo, lo - 1)).abs() <= eps * s {
+                h.set((lo, lo - 1), Zero::zero());
+                break;
+            }
+
+            lo = lo - 1;
+        }
+
+        if lo == hi - 1 {
+            res.push((h.at((lo, lo)), Zero::zero()));
+            hi    = hi - 1;
+            niter = 0;
+        }
+        else if lo == hi - 2 {
+            let (e1, e2) = eigenvalues_2x2(h.at((lo, lo)), h.at((lo, lo + 1)),
+                                           h.at((lo + 1, lo)), h.at((lo + 1, lo + 1)));
+            res.push(e1);
+            res.push(e2);
+            hi    = hi - 2;
+            niter = 0;
+        }
+        else {
+            if niter == 100 {
+                return None
+            }
+
+            niter = niter + 1;
+            francis_step(&mut h, lo, hi, niter % 10 == 0);
+        }
+    }
+
+    Some(res)
+}
+
+// Eigenvalues of the 2x2 matrix `[ a b ; c d ]`.
+fn eigenvalues_2x2<N: Float>(a: N, b: N, c: N, d: N) -> ((N, N), (N, N)) {
+    let _0: N   = Zero::zero();
+    let _2: N   = num::cast(2.0f64).unwrap();
+    let mean    = (a + d) / _2;
+    let half    = (a - d) / _2;
+    let disc    = half * half + b * c;
+
+    if disc >= _0 {
+        // the eigenvalue of largest magnitude is computed first to avoid a cancellation, and the
+        // other one is deduced from the determinant.
+        let sq = disc.sqrt();
+        let e1 = if mean >= _0 { mean + sq } else { mean - sq };
+        let e2 = if e1.is_zero() { _0 } else { (a * d - b * c) / e1 };
+
+        ((e1, _0), (e2, _0))
+    }
+    else {
+        let sq = (-disc).sqrt();
+
+        ((mean, sq), (mean, -sq))
+    }
+}
+
+// Performs one implicit double-shift QR step on the unreduced block `lo .. hi` of `h`, which has
+// at least three rows.
+//
+// The shifts are the eigenvalues of the trailing 2x2 block of `h`, unless `exceptional` is set:
+// arbitrary shifts are then used to break the cycles the iterations may fall into.
+fn francis_step<N: Float>(h: &mut DMat<N>, lo: uint, hi: uint, exceptional: bool) {
+    let m = hi - 1;
+
+    // sum and product of the shifts.
+    let (s, t) =
+        if exceptional {
+            let w: N  = h.at((m, m - 1)).abs() + h.at((m - 1, m - 2)).abs();
+            let _15: N = num::cast(1.5f64).unwrap();
+
+            (w * _15, w * w)
+        }
+        else {
+            let (a, b, c, d) = (h.at((m - 1, m - 1)), h.at((m - 1, m)), h.at((m, m - 1)), h.at((m, m)));
+
+            (a + d, a * d - b * c)
+        };
+
+    // first column of `(h - s1 * I) * (h - s2 * I)`, which has only three non-zero components.
+    let (h00, h01, h10, h11, h21) = (h.at((lo, lo)), h.at((lo, lo + 1)), h.at((lo + 1, lo)),
+                                     h.at((lo + 1, lo + 1)), h.at((lo + 2, lo + 1)));
+    let mut x = h00 * h00 + h01 * h10 - s * h00 + t;
+    let mut y = h10 * (h00 + h11 - s);
+    let mut z = h10 * h21;
+
+    // the reflection introduces a bulge below the subdiagonal, which is chased down to the bottom
+    // of the block by the following reflections.
+    for k in range(lo, hi - 2) {
+        reflect(h, lo, hi, k, &mut [x, y, z]);
+
+        if k != lo {
+            h.set((k + 1, k - 1), Zero::zero());
+            h.set((k + 2, k - 1), Zero::zero());
+        }
+
+        x = h.at((k + 1, k));
+        y = h.at((k + 2, k));
+
+        if k + 3 < hi {
+            z = h.at((k + 3, k));
+        }
+    }
+
+    reflect(h, lo, hi, hi - 2, &mut [x, y]);
+    h.set((hi - 1, hi - 3), Zero::zero());
+}
+
+// Applies, to both sides of the block `lo .. hi` of `h`, the householder reflection operating on
+// the dimensions `k .. k + u.len()` which maps `u` to a multiple of the first canonical vector.
+//
+// `u` is overwritten by the reflection vector.
+fn reflect<N: Float>(h: &mut DMat<N>, lo: uint, hi: uint, k: uint, u: &mut [N]) {
+    let _0: N = Zero::zero();
+    let _2: N = num::cast(2.0f64).unwrap();
+    let norm  = u.iter().fold(_0, |acc, e| acc + *e * *e).sqrt();
+
+    if norm.is_zero() {
+        return
+    }
+
+    // `v = u - alpha * e1` where `alpha` has the opposite sign of `u[0]`, to avoid cancellation.
+    u[0] = if u[0] >= _0 { u[0] + norm } else { u[0] - norm };
+
+    let sqnorm = u.iter().fold(_0, |acc, e| acc + *e * *e);
+    let beta   = _2 / sqnorm;
+    let len    = u.len();
+
+    // rows: `h = (I - beta * v * v^T) * h`, the columns before `k - 1` being zero on those rows.
+    for j in range(if k > lo { k - 1 } else { lo }, hi) {
+        let mut p = _0;
+
+        for i in range(0u, len) {
+            p = p + u[i] * h.at((k + i, j));
+        }
+
+        p = p * beta;
+
+        for i in range(0u, len) {
+            let hij = h.at((k + i, j));
+            h.set((k + i, j), hij - p * u[i]);
+        }
+    }
+
+    // columns: `h = h * (I - beta * v * v^T)`, the rows after `k + len` being zero on those
+    // columns.
+    for i in range(lo, cmp::min(k + len + 1, hi)) {
+        let mut p = _0;
+
+        for j in range(0u, len) {
+            p = p + h.at((i, k + j)) * u[j];
+        }
+
+        p = p * beta;
+
+        for j in range(0u, len) {
+            let hij = h.at((i, k + j));
+            h.set((i, k + j), hij - p * u[j]);
+        }
+    }
+}
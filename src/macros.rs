@@ -0,0 +1,45 @@
+//! Assertion macros for numerical tests.
+//!
+//! To use them, **nalgebra** must be imported as `na` with the `plugin` phase:
+//!
+//! ```.ignore
+//! #![feature(phase)]
+//!
+//! #[phase(plugin, link)]
+//! extern crate "nalgebra" as na;
+//! ```
+
+#![macro_escape]
+
+/// Asserts that two values are approximately equal using the default epsilon.
+///
+/// On failure, both operands and their difference are printed. Their type must implement
+/// `ApproxEq`, `Sub` and `Show`.
+#[macro_export]
+macro_rules! assert_approx_eq(
+    ($given: expr, $expected: expr) => ({
+        let (given_val, expected_val) = (&($given), &($expected));
+
+        if !::na::approx_eq(given_val, expected_val) {
+            fail!("assertion failed: `left ≈ right` (left: `{}`, right: `{}`, difference: `{}`)",
+                  *given_val, *expected_val, *given_val - *expected_val)
+        }
+    })
+)
+
+/// Asserts that two values are approximately equal using a custom epsilon.
+///
+/// On failure, both operands, their difference and the epsilon are printed. Their type must
+/// implement `ApproxEq`, `Sub` and `Show`.
+#[macro_export]
+macro_rules! assert_approx_eq_eps(
+    ($given: expr, $expected: expr, $eps: expr) => ({
+        let (given_val, expected_val, eps_val) = (&($given), &($expected), &($eps));
+
+        if !::na::approx_eq_eps(given_val, expected_val, eps_val) {
+            fail!("assertion failed: `left ≈ right` (left: `{}`, right: `{}`, difference: `{}`, \
+                   epsilon: `{}`)",
+                  *given_val, *expected_val, *given_val - *expected_val, *eps_val)
+        }
+    })
+)
@@ -0,0 +1,164 @@
+//! Implementations of quickcheck's `Arbitrary` trait, to property-test code using nalgebra types.
+
+use std::num::{Zero, One};
+use std::rand::Rand;
+use quickcheck::{Arbitrary, Gen};
+use traits::structure::Indexable;
+use structs::{DVec, DMat, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6,
+              Mat1, Mat2, Mat3, Mat4, Mat5, Mat6, Quat, UnitQuat, Rot2, Rot3, Iso2, Iso3};
+
+/// A square matrix generated by `Arbitrary` to be invertible.
+///
+/// Its off-diagonal components are arbitrary, while its diagonal is set to make it strictly
+/// diagonally dominant, hence invertible.
+#[deriving(Clone, Show)]
+pub struct Invertible<M> {
+    /// The invertible matrix.
+    pub mat: M
+}
+
+macro_rules! arbitrary_impl(
+    ($t: ident, $($compN: ident),+) => (
+        impl<N: Arbitrary> Arbitrary for $t<N> {
+            #[inline]
+            fn arbitrary<G: Gen>(g: &mut G) -> $t<N> {
+                $t { $($compN: Arbitrary::arbitrary(g)),+ }
+            }
+        }
+    )
+)
+
+macro_rules! invertible_impl(
+    ($t: ident) => (
+        impl<N: Arbitrary + Signed + PartialOrd> Arbitrary for Invertible<$t<N>> {
+            #[inline]
+            fn arbitrary<G: Gen>(g: &mut G) -> Invertible<$t<N>> {
+                let mut m: $t<N> = Arbitrary::arbitrary(g);
+                make_diagonally_dominant(&mut m);
+
+                Invertible { mat: m }
+            }
+        }
+    )
+)
+
+// Replaces the diagonal of `m` so that each diagonal component is greater than the sum of the
+// absolute values of the other components of its row.
+fn make_diagonally_dominant<N: Signed + PartialOrd, M: Indexable<(uint, uint), N>>(m: &mut M) {
+    let (nrows, ncols) = m.shape();
+    let _1: N = One::one();
+
+    for i in range(0u, nrows) {
+        let mut sum: N = Zero::zero();
+
+        for j in range(0u, ncols) {
+            if i != j {
+                sum = sum + m.at((i, j)).abs();
+            }
+        }
+
+        // keep the sign of the original diagonal to cover both positive and negative pivots.
+        if m.at((i, i)) < Zero::zero() {
+            m.set((i, i), -(sum + _1));
+        }
+        else {
+            m.set((i, i), sum + _1);
+        }
+    }
+}
+
+arbitrary_impl!(Vec1, x)
+arbitrary_impl!(Vec2, x, y)
+arbitrary_impl!(Vec3, x, y, z)
+arbitrary_impl!(Vec4, x, y, z, w)
+arbitrary_impl!(Vec5, x, y, z, w, a)
+arbitrary_impl!(Vec6, x, y, z, w, a, b)
+
+arbitrary_impl!(Pnt1, x)
+arbitrary_impl!(Pnt2, x, y)
+arbitrary_impl!(Pnt3, x, y, z)
+arbitrary_impl!(Pnt4, x, y, z, w)
+arbitrary_impl!(Pnt5, x, y, z, w, a)
+arbitrary_impl!(Pnt6, x, y, z, w, a, b)
+
+arbitrary_impl!(Mat1, m11)
+arbitrary_impl!(Mat2, m11, m21, m12, m22)
+arbitrary_impl!(Mat3, m11, m21, m31, m12, m22, m32, m13, m23, m33)
+arbitrary_impl!(Mat4, m11, m21, m31, m41, m12, m22, m32, m42, m13, m23, m33, m43, m14, m24, m34, m44)
+arbitrary_impl!(Mat5, m11, m21, m31, m41, m51, m12, m22, m32, m42, m52, m13, m23, m33, m43, m53, m14, m24, m34, m44, m54, m15, m25, m35, m45, m55)
+arbitrary_impl!(Mat6, m11, m21, m31, m41, m51, m61, m12, m22, m32, m42, m52, m62, m13, m23, m33, m43, m53, m63, m14, m24, m34, m44, m54, m64, m15, m25, m35, m45, m55, m65, m16, m26, m36, m46, m56, m66)
+arbitrary_impl!(Quat, w, i, j, k)
+
+invertible_impl!(Mat1)
+invertible_impl!(Mat2)
+invertible_impl!(Mat3)
+invertible_impl!(Mat4)
+invertible_impl!(Mat5)
+invertible_impl!(Mat6)
+
+impl<N: Arbitrary> Arbitrary for DVec<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> DVec<N> {
+        DVec { at: Arbitrary::arbitrary(g) }
+    }
+}
+
+impl<N: Arbitrary> Arbitrary for DMat<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> DMat<N> {
+        let size  = g.size();
+        let nrows = g.gen_range(0, size + 1);
+        let ncols = g.gen_range(0, size + 1);
+
+        DMat::from_fn(nrows, ncols, |_, _| Arbitrary::arbitrary(g))
+    }
+}
+
+impl<N: Arbitrary + Signed + PartialOrd> Arbitrary for Invertible<DMat<N>> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Invertible<DMat<N>> {
+        let size = g.size();
+        let dim  = g.gen_range(1, size + 2);
+        let mut m: DMat<N> = DMat::from_fn(dim, dim, |_, _| Arbitrary::arbitrary(g));
+        make_diagonally_dominant(&mut m);
+
+        Invertible { mat: m }
+    }
+}
+
+// Rotations are sampled uniformly with their `Rand` implementation so that they are always
+// unit rotations, whatever the size of the generator.
+impl<N: Clone + Rand + FloatMath + Send> Arbitrary for UnitQuat<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> UnitQuat<N> {
+        g.gen()
+    }
+}
+
+impl<N: Clone + Rand + FloatMath + Send> Arbitrary for Rot2<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Rot2<N> {
+        g.gen()
+    }
+}
+
+impl<N: Clone + Rand + FloatMath + Send> Arbitrary for Rot3<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Rot3<N> {
+        g.gen()
+    }
+}
+
+impl<N: Clone + Rand + FloatMath + Send + Arbitrary> Arbitrary for Iso2<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Iso2<N> {
+        Iso2::new_with_rotmat(Arbitrary::arbitrary(g), g.gen())
+    }
+}
+
+impl<N: Clone + Rand + FloatMath + Send + Arbitrary> Arbitrary for Iso3<N> {
+    #[inline]
+    fn arbitrary<G: Gen>(g: &mut G) -> Iso3<N> {
+        Iso3::new_with_rotmat(Arbitrary::arbitrary(g), g.gen())
+    }
+}
@@ -0,0 +1,59 @@
+//! Off-diagonals and triangular parts of matrices.
+
+use std::cmp;
+use std::num::Zero;
+use structs::dvec::DVec;
+use traits::structure::Indexable;
+
+/// The `k`-th diagonal of `m`.
+///
+/// The diagonal is above the main diagonal if `k > 0`, and below it if `k < 0`. It is empty if
+/// it lies outside of the matrix.
+pub fn diag_k<N: Clone, M: Indexable<(uint, uint), N>>(m: &M, k: int) -> DVec<N> {
+    let (nrows, ncols) = m.shape();
+
+    if k >= 0 {
+        let k = k as uint;
+        let n = if k < ncols { cmp::min(nrows, ncols - k) } else { 0 };
+
+        DVec::from_fn(n, |i| m.at((i, i + k)))
+    }
+    else {
+        let k = (-k) as uint;
+        let n = if k < nrows { cmp::min(nrows - k, ncols) } else { 0 };
+
+        DVec::from_fn(n, |i| m.at((i + k, i)))
+    }
+}
+
+/// Copy of `m` with every component below its `k`-th diagonal set to zero.
+pub fn triu<N: Zero, M: Clone + Indexable<(uint, uint), N>>(m: &M, k: int) -> M {
+    let mut res = m.clone();
+    let (nrows, ncols) = res.shape();
+
+    for j in range(0u, ncols) {
+        for i in range(0u, nrows) {
+            if (j as int) - (i as int) < k {
+                res.set((i, j), Zero::zero())
+            }
+        }
+    }
+
+    res
+}
+
+/// Copy of `m` with every component above its `k`-th diagonal set to zero.
+pub fn tril<N: Zero, M: Clone + Indexable<(uint, uint), N>>(m: &M, k: int) -> M {
+    let mut res = m.clone();
+    let (nrows, ncols) = res.shape();
+
+    for j in range(0u, ncols) {
+        for i in range(0u, nrows) {
+            if (j as int) - (i as int) > k {
+                res.set((i, j), Zero::zero())
+            }
+        }
+    }
+
+    res
+}
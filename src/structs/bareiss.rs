@@ -0,0 +1,106 @@
+//! Fraction-free determinant computation.
+
+use std::num::{One, Zero};
+
+/// Determinant of the `dim x dim` matrix stored in `m` computed with the Bareiss algorithm.
+///
+/// `m` is overwritten. Every division performed is exact, and every intermediate value is a
+/// minor of the original matrix, so this is exact for integer scalars as long as those minors do
+/// not overflow.
+pub fn det<N: Clone + Num>(m: &mut [N], dim: uint) -> N {
+    let mut negate  = false;
+    let mut prev: N = One::one();
+
+    for k in range(0u, dim) {
+        if m[k + k * dim].is_zero() {
+            // swap with a row having a non-zero component on the k-th column.
+            let mut piv = k + 1;
+
+            while piv != dim && m[piv + k * dim].is_zero() {
+                piv = piv + 1;
+            }
+
+            if piv == dim {
+                return Zero::zero()
+            }
+
+            for j in range(k, dim) {
+                m.swap(piv + j * dim, k + j * dim);
+            }
+
+            negate = !negate;
+        }
+
+        let pivot = m[k + k * dim].clone();
+
+        for i in range(k + 1, dim) {
+            for j in range(k + 1, dim) {
+                let mij = (pivot * m[i + j * dim] - m[i + k * dim] * m[k + j * dim]) / prev;
+                m[i + j * dim] = mij;
+            }
+        }
+
+        prev = pivot;
+    }
+
+    if negate { -prev } else { prev }
+}
+
+/// Solves in-place the `dim x dim` system `m * x = b`, where `b` is initially stored in `x`,
+/// using the Bareiss fraction-free elimination followed by a back substitution.
+///
+/// `m` is overwritten. Returns `false` if `m` is singular. The result is exact for rational
+/// scalars, and for integer scalars whenever the solution has integer components.
+pub fn solve<N: Clone + Num>(m: &mut [N], x: &mut [N], dim: uint) -> bool {
+    let mut prev: N = One::one();
+
+    for k in range(0u, dim) {
+        if m[k + k * dim].is_zero() {
+            // swap with a row having a non-zero component on the k-th column.
+            let mut piv = k + 1;
+
+            while piv != dim && m[piv + k * dim].is_zero() {
+                piv = piv + 1;
+            }
+
+            if piv == dim {
+                return false
+            }
+
+            for j in range(k, dim) {
+                m.swap(piv + j * dim, k + j * dim);
+            }
+
+            x.swap(piv, k);
+        }
+
+        let pivot = m[k + k * dim].clone();
+
+        for i in range(k + 1, dim) {
+            let mik = m[i + k * dim].clone();
+
+            for j in range(k + 1, dim) {
+                let mij = (pivot * m[i + j * dim] - mik * m[k + j * dim]) / prev;
+                m[i + j * dim] = mij;
+            }
+
+            let xi = (pivot * x[i] - mik * x[k]) / prev;
+            x[i] = xi;
+        }
+
+        prev = pivot;
+    }
+
+    // back substitution on the resulting upper-triangular system.
+    for k in range(0u, dim).rev() {
+        let mut acc = x[k].clone();
+
+        for j in range(k + 1, dim) {
+            acc = acc - m[k + j * dim] * x[j];
+        }
+
+        x[k] = acc / m[k + k * dim];
+    }
+
+    true
+}
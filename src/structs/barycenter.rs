@@ -0,0 +1,75 @@
+//! Streaming computation of weighted barycenters of points.
+
+use std::num::{Zero, One};
+use traits::structure::{AnyVec, AnyPnt};
+
+/// Accumulates points one at a time to compute their (weighted) barycenter.
+///
+/// Points cannot be added to each other. Thus, the accumulated quantity is the weighted sum of
+/// the vectors joining the first point added to every other point. This also avoids the loss of
+/// precision that would occur when summing points far from the origin.
+#[deriving(Clone, Show)]
+pub struct Barycenter<N, P, V> {
+    orig:   Option<P>,
+    sum:    V,
+    weight: N
+}
+
+impl<N: Clone + Zero + Add<N, N>, P: Clone + AnyPnt<N, V>, V: Clone + AnyVec<N>> Barycenter<N, P, V> {
+    /// Creates a new accumulator without any point.
+    #[inline]
+    pub fn new() -> Barycenter<N, P, V> {
+        Barycenter {
+            orig:   None,
+            sum:    Zero::zero(),
+            weight: Zero::zero()
+        }
+    }
+
+    /// The sum of the weights of all the points accumulated so far.
+    #[inline]
+    pub fn total_weight(&self) -> N {
+        self.weight.clone()
+    }
+
+    /// Accumulates a point with a weight.
+    #[inline]
+    pub fn add_weighted(&mut self, p: &P, w: &N) {
+        match self.orig {
+            Some(ref o) => self.sum = self.sum + (*p - *o) * *w,
+            None        => self.orig = Some(p.clone())
+        }
+
+        self.weight = self.weight + *w;
+    }
+
+    /// The weighted barycenter of all the points accumulated so far.
+    ///
+    /// Returns `None` if no point has been accumulated or if the total weight is zero.
+    #[inline]
+    pub fn barycenter(&self) -> Option<P> {
+        match self.orig {
+            Some(ref o) if !self.weight.is_zero() => Some(*o + self.sum / self.weight),
+            _                                     => None
+        }
+    }
+
+    /// The affine combination of all the points accumulated so far.
+    ///
+    /// Unlike `barycenter`, the result is not divided by the total weight. The weights are thus
+    /// expected to sum to one. Returns `None` if no point has been accumulated.
+    #[inline]
+    pub fn affine_combination(&self) -> Option<P> {
+        self.orig.as_ref().map(|o| *o + self.sum)
+    }
+}
+
+impl<N: Clone + Zero + One + Add<N, N>, P: Clone + AnyPnt<N, V>, V: Clone + AnyVec<N>> Barycenter<N, P, V> {
+    /// Accumulates a point with a unit weight.
+    #[inline]
+    pub fn add(&mut self, p: &P) {
+        let _1: N = One::one();
+
+        self.add_weighted(p, &_1)
+    }
+}
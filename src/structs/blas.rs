@@ -0,0 +1,158 @@
+//! Dispatch of dense products to an external BLAS implementation.
+//!
+//! When the `blas` feature is enabled, the products of `DMat<f32>` and `DMat<f64>` large enough
+//! for the call overhead to be negligible are computed by the `cblas` library. Otherwise, every
+//! function of this module returns without doing anything and the pure-Rust path is used.
+
+#[cfg(feature = "blas")]
+use std::intrinsics::TypeId;
+
+// Minimum number of multiply-adds of an operation for it to be dispatched to BLAS.
+#[cfg(feature = "blas")]
+static BLAS_THRESHOLD: uint = 32 * 32 * 32;
+
+#[cfg(feature = "blas")]
+static COL_MAJOR: i32 = 102;
+#[cfg(feature = "blas")]
+static NO_TRANS:  i32 = 111;
+#[cfg(feature = "blas")]
+static TRANS:     i32 = 112;
+
+#[cfg(feature = "blas")]
+#[link(name = "cblas")]
+extern {
+    fn cblas_sgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32,
+                   alpha: f32, a: *const f32, lda: i32, b: *const f32, ldb: i32,
+                   beta: f32, c: *mut f32, ldc: i32);
+    fn cblas_dgemm(order: i32, transa: i32, transb: i32, m: i32, n: i32, k: i32,
+                   alpha: f64, a: *const f64, lda: i32, b: *const f64, ldb: i32,
+                   beta: f64, c: *mut f64, ldc: i32);
+    fn cblas_sgemv(order: i32, trans: i32, m: i32, n: i32,
+                   alpha: f32, a: *const f32, lda: i32, x: *const f32, incx: i32,
+                   beta: f32, y: *mut f32, incy: i32);
+    fn cblas_dgemv(order: i32, trans: i32, m: i32, n: i32,
+                   alpha: f64, a: *const f64, lda: i32, x: *const f64, incx: i32,
+                   beta: f64, y: *mut f64, incy: i32);
+    fn cblas_saxpy(n: i32, alpha: f32, x: *const f32, incx: i32, y: *mut f32, incy: i32);
+    fn cblas_daxpy(n: i32, alpha: f64, x: *const f64, incx: i32, y: *mut f64, incy: i32);
+}
+
+#[cfg(feature = "blas")]
+#[inline]
+fn is<N: 'static, T: 'static>() -> bool {
+    TypeId::of::<N>() == TypeId::of::<T>()
+}
+
+/// Computes the product of the `m x k` matrix `a` with the `k x n` matrix `b`, both stored in
+/// column-major order.
+///
+/// Returns `None` if BLAS is not used for this product.
+#[cfg(feature = "blas")]
+pub fn gemm<N: 'static>(m: uint, n: uint, k: uint, a: &[N], b: &[N]) -> Option<Vec<N>> {
+    if m * n * k < BLAS_THRESHOLD || !(is::<N, f32>() || is::<N, f64>()) {
+        return None
+    }
+
+    let mut res: Vec<N> = Vec::with_capacity(m * n);
+
+    unsafe {
+        // every component is overwritten since `beta` is zero.
+        res.set_len(m * n);
+
+        if is::<N, f64>() {
+            cblas_dgemm(COL_MAJOR, NO_TRANS, NO_TRANS, m as i32, n as i32, k as i32,
+                        1.0, a.as_ptr() as *const f64, m as i32, b.as_ptr() as *const f64, k as i32,
+                        0.0, res.as_mut_ptr() as *mut f64, m as i32)
+        }
+        else {
+            cblas_sgemm(COL_MAJOR, NO_TRANS, NO_TRANS, m as i32, n as i32, k as i32,
+                        1.0, a.as_ptr() as *const f32, m as i32, b.as_ptr() as *const f32, k as i32,
+                        0.0, res.as_mut_ptr() as *mut f32, m as i32)
+        }
+    }
+
+    Some(res)
+}
+
+/// Computes the product of the `m x n` matrix `a`, stored in column-major order, or of its
+/// transpose if `transpose` is `true`, with the vector `x`.
+///
+/// Returns `None` if BLAS is not used for this product.
+#[cfg(feature = "blas")]
+pub fn gemv<N: 'static>(m: uint, n: uint, a: &[N], transpose: bool, x: &[N]) -> Option<Vec<N>> {
+    if m * n < BLAS_THRESHOLD || !(is::<N, f32>() || is::<N, f64>()) {
+        return None
+    }
+
+    let len   = if transpose { n } else { m };
+    let trans = if transpose { TRANS } else { NO_TRANS };
+    let mut res: Vec<N> = Vec::with_capacity(len);
+
+    unsafe {
+        // every component is overwritten since `beta` is zero.
+        res.set_len(len);
+
+        if is::<N, f64>() {
+            cblas_dgemv(COL_MAJOR, trans, m as i32, n as i32,
+                        1.0, a.as_ptr() as *const f64, m as i32, x.as_ptr() as *const f64, 1,
+                        0.0, res.as_mut_ptr() as *mut f64, 1)
+        }
+        else {
+            cblas_sgemv(COL_MAJOR, trans, m as i32, n as i32,
+                        1.0, a.as_ptr() as *const f32, m as i32, x.as_ptr() as *const f32, 1,
+                        0.0, res.as_mut_ptr() as *mut f32, 1)
+        }
+    }
+
+    Some(res)
+}
+
+/// Computes `y = y + a * x`.
+///
+/// Returns `false` if BLAS is not used for this operation, in which case `y` is left unchanged.
+#[cfg(feature = "blas")]
+pub fn axpy<N: 'static>(a: &N, x: &[N], y: &mut [N]) -> bool {
+    if x.len() < BLAS_THRESHOLD || !(is::<N, f32>() || is::<N, f64>()) {
+        return false
+    }
+
+    unsafe {
+        if is::<N, f64>() {
+            cblas_daxpy(x.len() as i32, *(a as *const N as *const f64),
+                        x.as_ptr() as *const f64, 1, y.as_mut_ptr() as *mut f64, 1)
+        }
+        else {
+            cblas_saxpy(x.len() as i32, *(a as *const N as *const f32),
+                        x.as_ptr() as *const f32, 1, y.as_mut_ptr() as *mut f32, 1)
+        }
+    }
+
+    true
+}
+
+/// Computes the product of the `m x k` matrix `a` with the `k x n` matrix `b`.
+///
+/// Always returns `None` since the `blas` feature is disabled.
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub fn gemm<N>(_: uint, _: uint, _: uint, _: &[N], _: &[N]) -> Option<Vec<N>> {
+    None
+}
+
+/// Computes the product of the `m x n` matrix `a`, or of its transpose, with the vector `x`.
+///
+/// Always returns `None` since the `blas` feature is disabled.
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub fn gemv<N>(_: uint, _: uint, _: &[N], _: bool, _: &[N]) -> Option<Vec<N>> {
+    None
+}
+
+/// Computes `y = y + a * x`.
+///
+/// Always returns `false` since the `blas` feature is disabled.
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub fn axpy<N>(_: &N, _: &[N], _: &mut [N]) -> bool {
+    false
+}
@@ -0,0 +1,50 @@
+//! Type-level dimensions.
+
+use traits::structure::TypeDim;
+
+/// Type-level dimension 0.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D0;
+
+/// Type-level dimension 1.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D1;
+
+/// Type-level dimension 2.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D2;
+
+/// Type-level dimension 3.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D3;
+
+/// Type-level dimension 4.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D4;
+
+/// Type-level dimension 5.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D5;
+
+/// Type-level dimension 6.
+#[deriving(Eq, PartialEq, Clone, Show)]
+pub struct D6;
+
+macro_rules! type_dim_impl(
+    ($t: ident, $dim: expr) => (
+        impl TypeDim for $t {
+            #[inline]
+            fn value(_: Option<$t>) -> uint {
+                $dim
+            }
+        }
+    )
+)
+
+type_dim_impl!(D0, 0)
+type_dim_impl!(D1, 1)
+type_dim_impl!(D2, 2)
+type_dim_impl!(D3, 3)
+type_dim_impl!(D4, 4)
+type_dim_impl!(D5, 5)
+type_dim_impl!(D6, 6)
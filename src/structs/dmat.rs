@@ -3,18 +3,37 @@
 #![allow(missing_doc)] // we hide doc to not have to document the $trhs double dispatch trait.
 
 use std::cmp;
-use std::rand::Rand;
+use std::os;
+use std::sync::Arc;
+use std::rand::{Rand, Rng};
 use std::rand;
+use std::rand::distributions::{IndependentSample, Range, Normal};
+use std::rand::distributions::range::SampleRange;
 use std::num::{One, Zero};
 use traits::operations::ApproxEq;
 use std::mem;
 use structs::dvec::{DVec, DVecMulRhs};
-use traits::operations::{Inv, Transpose, Mean, Cov};
-use traits::structure::{Cast, ColSlice, RowSlice, Diag, Eye, Indexable};
-use std::fmt::{Show, Formatter, Result};
+use structs::strided::{StridedSlice, StridedSliceMut};
+use structs::{pretty, parse, blas, bareiss, band, predicates};
+use linalg::qr;
+use std::from_str::FromStr;
+use std::result;
+use traits::operations::{Inv, Transpose, Mean, Cov, AddInPlace, SubInPlace, ScaleInPlace, MulInPlace,
+                         Gemm, Gemv, Conjugate, ConjTranspose, WeightedMean, WeightedCov,
+                         CovNormalization, PopulationCov, SampleCov};
+use traits::structure::{Cast, ColSlice, RowSlice, Diag, Band, Eye, Indexable};
+use std::fmt::{Show, LowerExp, Formatter, Result};
+#[cfg(feature = "serialize")]
+use std::num::CheckedMul;
+#[cfg(feature = "serialize")]
+use serialize::{Encodable, Encoder, Decodable, Decoder};
 
 
 /// Matrix with dimensions unknown at compile-time.
+///
+/// Its components are stored contiguously in column-major order: the component at row `i` and
+/// column `j` is the element `i + j * nrows` of the underlying `Vec`. This layout is guaranteed
+/// by `from_vec`, `into_vec`, and `as_vec`.
 #[deriving(Eq, PartialEq, Clone)]
 pub struct DMat<N> {
     nrows: uint,
@@ -47,17 +66,51 @@ impl<N> DMat<N> {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl<E, S: Encoder<E>, N: Encodable<S, E>> Encodable<S, E> for DMat<N> {
+    fn encode(&self, s: &mut S) -> result::Result<(), E> {
+        s.emit_struct("DMat", 3, |s| {
+            try!(s.emit_struct_field("nrows", 0, |s| self.nrows.encode(s)));
+            try!(s.emit_struct_field("ncols", 1, |s| self.ncols.encode(s)));
+            s.emit_struct_field("mij", 2, |s| self.mij.encode(s))
+        })
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<E, D: Decoder<E>, N: Decodable<D, E>> Decodable<D, E> for DMat<N> {
+    /// Decodes a matrix, failing unless it has exactly `nrows * ncols` components.
+    fn decode(d: &mut D) -> result::Result<DMat<N>, E> {
+        d.read_struct("DMat", 3, |d| {
+            let nrows: uint  = try!(d.read_struct_field("nrows", 0, |d| Decodable::decode(d)));
+            let ncols: uint  = try!(d.read_struct_field("ncols", 1, |d| Decodable::decode(d)));
+            let mij: Vec<N>  = try!(d.read_struct_field("mij", 2, |d| Decodable::decode(d)));
+
+            match nrows.checked_mul(&ncols) {
+                Some(len) if len == mij.len() => Ok(DMat { nrows: nrows, ncols: ncols, mij: mij }),
+                _ => Err(d.error("the number of components of the matrix must be `nrows * ncols`."))
+            }
+        })
+    }
+}
+
 impl<N: Zero + Clone> DMat<N> {
     /// Builds a matrix filled with zeros.
     ///
     /// # Arguments
-    ///   * `dim` - The dimension of the matrix. A `dim`-dimensional matrix contains `dim * dim`
-    ///   components.
+    ///   * `nrows` - The number of rows of the matrix.
+    ///   * `ncols` - The number of columns of the matrix.
     #[inline]
     pub fn new_zeros(nrows: uint, ncols: uint) -> DMat<N> {
         DMat::from_elem(nrows, ncols, Zero::zero())
     }
 
+    /// Builds a matrix filled with zeros. This is the same as `new_zeros`.
+    #[inline]
+    pub fn zeros(nrows: uint, ncols: uint) -> DMat<N> {
+        DMat::new_zeros(nrows, ncols)
+    }
+
     /// Tests if all components of the matrix are zeroes.
     #[inline]
     pub fn is_zero(&self) -> bool {
@@ -80,12 +133,146 @@ impl<N: Rand> DMat<N> {
     }
 }
 
+impl<N> DMat<N> {
+    /// Builds a matrix with components sampled independently from the distribution `dist`.
+    #[inline]
+    pub fn new_random_with<R: Rng, D: IndependentSample<N>>(nrows: uint, ncols: uint, rng: &mut R, dist: &D)
+                                                            -> DMat<N> {
+        DMat::from_fn(nrows, ncols, |_, _| dist.ind_sample(rng))
+    }
+}
+
+impl<N: SampleRange + PartialOrd> DMat<N> {
+    /// Builds a matrix with components uniformly distributed in `[low, high[`.
+    ///
+    /// Fails if `low >= high`.
+    #[inline]
+    pub fn new_uniform<R: Rng>(nrows: uint, ncols: uint, rng: &mut R, low: N, high: N) -> DMat<N> {
+        DMat::new_random_with(nrows, ncols, rng, &Range::new(low, high))
+    }
+}
+
+impl<N: Cast<f64>> DMat<N> {
+    /// Builds a matrix with components following the normal distribution with mean `mean` and
+    /// standard deviation `std_dev`.
+    #[inline]
+    pub fn new_gaussian<R: Rng>(nrows: uint, ncols: uint, rng: &mut R, mean: f64, std_dev: f64) -> DMat<N> {
+        let normal = Normal::new(mean, std_dev);
+
+        DMat::from_fn(nrows, ncols, |_, _| Cast::from(normal.ind_sample(rng)))
+    }
+}
+
+impl<N: Float + Cast<f64> + 'static> DMat<N> {
+    /// Builds a random `n x n` orthogonal matrix, uniformly distributed with respect to the Haar
+    /// measure.
+    ///
+    /// This is the `q` factor of the QR decomposition of a Gaussian matrix, with the sign of each
+    /// column fixed so that `r` has a positive diagonal. Without this correction, the result would
+    /// not be uniformly distributed.
+    pub fn new_random_orthogonal(n: uint) -> DMat<N> {
+        let g      = DMat::new_gaussian(n, n, &mut rand::task_rng(), 0.0, 1.0);
+        let (q, r) = qr(&g);
+        let mut q  = q;
+
+        for j in range(0u, n) {
+            if r.at((j, j)) < Zero::zero() {
+                for i in range(0u, n) {
+                    let qij = -q.at((i, j));
+                    q.set((i, j), qij);
+                }
+            }
+        }
+
+        q
+    }
+
+    /// Builds a random `n x n` symmetric positive-definite matrix with the condition number `cond`.
+    ///
+    /// The eigenvalues are spaced geometrically from `1` to `cond` and the eigenvectors are given
+    /// by a random orthogonal matrix. Fails if `cond < 1`.
+    pub fn new_random_spd(n: uint, cond: f64) -> DMat<N> {
+        assert!(cond >= 1.0, "The condition number must be greater than or equal to one.");
+
+        let q: DMat<N>   = DMat::new_random_orthogonal(n);
+        let denom        = if n > 1 { (n - 1) as f64 } else { 1.0 };
+        let eigs: Vec<N> = range(0u, n).map(|k| Cast::from(cond.powf(k as f64 / denom))).collect();
+        let mut res      = DMat::new_zeros(n, n);
+
+        for j in range(0u, n) {
+            for i in range(j, n) {
+                let mut mij: N = Zero::zero();
+
+                for k in range(0u, n) {
+                    mij = mij + q.at((i, k)) * eigs[k] * q.at((j, k));
+                }
+
+                res.set((i, j), mij);
+                res.set((j, i), mij);
+            }
+        }
+
+        res
+    }
+}
+
 impl<N: One + Clone> DMat<N> {
-    /// Builds a matrix filled with a given constant.
+    /// Builds a matrix filled with ones.
     #[inline]
     pub fn new_ones(nrows: uint, ncols: uint) -> DMat<N> {
         DMat::from_elem(nrows, ncols, One::one())
     }
+
+    /// Builds a matrix filled with ones. This is the same as `new_ones`.
+    #[inline]
+    pub fn ones(nrows: uint, ncols: uint) -> DMat<N> {
+        DMat::new_ones(nrows, ncols)
+    }
+}
+
+impl<N: Clone> DMat<N> {
+    /// Builds the Toeplitz matrix with the first column `col` and the first row `row`.
+    ///
+    /// Every diagonal of the result is constant. The first components of `col` and `row` must
+    /// be the same since they are both the top-left component. Only `col[0]` is actually used.
+    pub fn toeplitz(col: &[N], row: &[N]) -> DMat<N> {
+        assert!(!col.is_empty() && !row.is_empty(), "The first column and row must not be empty.");
+
+        DMat::from_fn(col.len(), row.len(), |i, j| {
+            if i >= j { col[i - j].clone() } else { row[j - i].clone() }
+        })
+    }
+
+    /// Builds the circulant matrix with the first column `col`.
+    ///
+    /// Each column is the previous one rotated down by one component. Use
+    /// `DVec::circular_convolve` to multiply a vector by this matrix without building it.
+    pub fn circulant(col: &[N]) -> DMat<N> {
+        let n = col.len();
+
+        DMat::from_fn(n, n, |i, j| col[(i + n - j) % n].clone())
+    }
+}
+
+impl<N: One + Clone + Mul<N, N>> DMat<N> {
+    /// Builds the Vandermonde matrix of the points `xs` up to the power `degree`.
+    ///
+    /// The result has one row per point and `degree + 1` columns: the component at row `i` and
+    /// column `j` is `xs[i]^j`. Multiplying it by the coefficients of a polynomial, in increasing
+    /// degree order, evaluates the polynomial at every point.
+    pub fn vandermonde(xs: &[N], degree: uint) -> DMat<N> {
+        let nrows   = xs.len();
+        let mut res = DMat::new_ones(nrows, degree + 1);
+
+        for j in range(1u, degree + 1) {
+            for i in range(0u, nrows) {
+                let xij = res.at((i, j - 1)) * xs[i];
+                res.set((i, j), xij);
+            }
+        }
+
+        res
+    }
 }
 
 impl<N: Clone> DMat<N> {
@@ -99,6 +286,24 @@ impl<N: Clone> DMat<N> {
         }
     }
 
+    /// Builds a matrix filled with a given constant, reusing the allocation of `buf`.
+    ///
+    /// The content of `buf` is discarded. No allocation occurs if `buf` has a capacity of at least
+    /// `nrows * ncols` elements, so a preallocated buffer can be recycled with `to_vec`.
+    #[inline]
+    pub fn from_buffer(nrows: uint, ncols: uint, buf: Vec<N>, val: N) -> DMat<N> {
+        let mut buf = buf;
+
+        buf.truncate(0);
+        buf.grow(nrows * ncols, val);
+
+        DMat {
+            nrows: nrows,
+            ncols: ncols,
+            mij:   buf
+        }
+    }
+
     /// Builds a matrix filled with the components provided by a vector.
     /// The vector contains the matrix data in row-major order.
     /// Note that `from_col_vec` is a lot faster than `from_row_vec` since a `DMat` stores its data
@@ -131,6 +336,35 @@ impl<N: Clone> DMat<N> {
             mij:   vec.to_vec()
         }
     }
+
+    /// Builds a matrix filled with the components yielded by an iterator in row-major order.
+    ///
+    /// Fails if the iterator does not yield exactly `nrows * ncols` elements.
+    #[inline]
+    pub fn from_row_iter<I: Iterator<N>>(nrows: uint, ncols: uint, iter: I) -> DMat<N> {
+        let mut res = DMat::from_col_iter(ncols, nrows, iter);
+
+        // we transpose because the iterator is row_major
+        res.transpose();
+
+        res
+    }
+
+    /// Builds a matrix filled with the components yielded by an iterator in column-major order.
+    ///
+    /// Fails if the iterator does not yield exactly `nrows * ncols` elements.
+    #[inline]
+    pub fn from_col_iter<I: Iterator<N>>(nrows: uint, ncols: uint, iter: I) -> DMat<N> {
+        let mij: Vec<N> = iter.collect();
+
+        assert!(nrows * ncols == mij.len(), "The iterator does not yield nrows * ncols elements.");
+
+        DMat {
+            nrows: nrows,
+            ncols: ncols,
+            mij:   mij
+        }
+    }
 }
 
 impl<N> DMat<N> {
@@ -156,10 +390,33 @@ impl<N> DMat<N> {
         self.ncols
     }
 
+    /// Builds a matrix from a vector containing its components in column-major order. This takes
+    /// ownership of the vector and is O(1).
+    ///
+    /// The vector must have exactly `nrows * ncols` elements.
+    #[inline]
+    pub fn from_vec(nrows: uint, ncols: uint, vec: Vec<N>) -> DMat<N> {
+        assert!(nrows * ncols == vec.len(), "The vector length must be `nrows * ncols`.");
+
+        DMat {
+            nrows: nrows,
+            ncols: ncols,
+            mij:   vec
+        }
+    }
+
     /// Transforms this matrix into an array. This consumes the matrix and is O(1).
     /// The returned vector contains the matrix data in column-major order.
     #[inline]
+    #[deprecated = "use `into_vec` instead."]
     pub fn to_vec(self) -> Vec<N> {
+        self.into_vec()
+    }
+
+    /// Transforms this matrix into a vector. This consumes the matrix and is O(1).
+    /// The returned vector contains the matrix data in column-major order.
+    #[inline]
+    pub fn into_vec(self) -> Vec<N> {
         self.mij
     }
 
@@ -176,6 +433,81 @@ impl<N> DMat<N> {
     pub fn as_mut_vec<'r>(&'r mut self) -> &'r mut [N] {
          self.mij.as_mut_slice()
     }
+
+    /// Gets a read-only strided view on the `i`-th row of this matrix, without copy.
+    #[inline]
+    pub fn row_strided<'r>(&'r self, i: uint) -> StridedSlice<'r, N> {
+        assert!(i < self.nrows, "Row index out of bounds.");
+        StridedSlice::new(self.mij.as_slice(), i, self.nrows, self.ncols)
+    }
+
+    /// Gets a mutable view on the `i`-th row of this matrix, without copy.
+    ///
+    /// This allows the row to be scaled or axpy-ed into in-place, e.g., during Gaussian
+    /// elimination, instead of reading and writing it back with `set_row`.
+    #[inline]
+    pub fn row_mut<'r>(&'r mut self, i: uint) -> StridedSliceMut<'r, N> {
+        assert!(i < self.nrows, "Row index out of bounds.");
+        let (nrows, ncols) = (self.nrows, self.ncols);
+        StridedSliceMut::new(self.mij.as_mut_slice(), i, nrows, ncols)
+    }
+
+    /// Gets a read-write strided view on the `i`-th row of this matrix, without copy.
+    ///
+    /// This is an alias of `row_mut`.
+    #[inline]
+    pub fn row_strided_mut<'r>(&'r mut self, i: uint) -> StridedSliceMut<'r, N> {
+        self.row_mut(i)
+    }
+
+    /// Gets a read-only strided view on the `j`-th column of this matrix, without copy.
+    #[inline]
+    pub fn col_strided<'r>(&'r self, j: uint) -> StridedSlice<'r, N> {
+        assert!(j < self.ncols, "Column index out of bounds.");
+        StridedSlice::new(self.mij.as_slice(), j * self.nrows, 1, self.nrows)
+    }
+
+    /// Gets a mutable view on the `j`-th column of this matrix, without copy.
+    ///
+    /// This allows the column to be scaled or axpy-ed into in-place instead of reading and
+    /// writing it back with `set_col`.
+    #[inline]
+    pub fn col_mut<'r>(&'r mut self, j: uint) -> StridedSliceMut<'r, N> {
+        assert!(j < self.ncols, "Column index out of bounds.");
+        let nrows = self.nrows;
+        StridedSliceMut::new(self.mij.as_mut_slice(), j * nrows, 1, nrows)
+    }
+
+    /// Gets a read-write strided view on the `j`-th column of this matrix, without copy.
+    ///
+    /// This is an alias of `col_mut`.
+    #[inline]
+    pub fn col_strided_mut<'r>(&'r mut self, j: uint) -> StridedSliceMut<'r, N> {
+        self.col_mut(j)
+    }
+
+    /// Gets a read-only view on the rows `row_start` to `row_end` (excluded) of the `col_id`-th
+    /// column of this matrix, without copy.
+    ///
+    /// This is the borrowing counterpart of `ColSlice::col_slice`.
+    #[inline]
+    pub fn col_slice_ref<'r>(&'r self, col_id: uint, row_start: uint, row_end: uint) -> StridedSlice<'r, N> {
+        assert!(col_id < self.ncols, "Column index out of bounds.");
+        assert!(row_start <= row_end && row_end <= self.nrows, "Row range out of bounds.");
+        StridedSlice::new(self.mij.as_slice(), row_start + col_id * self.nrows, 1, row_end - row_start)
+    }
+
+    /// Gets a read-only view on the columns `col_start` to `col_end` (excluded) of the `row_id`-th
+    /// row of this matrix, without copy.
+    ///
+    /// This is the borrowing counterpart of `RowSlice::row_slice`.
+    #[inline]
+    pub fn row_slice_ref<'r>(&'r self, row_id: uint, col_start: uint, col_end: uint) -> StridedSlice<'r, N> {
+        assert!(row_id < self.nrows, "Row index out of bounds.");
+        assert!(col_start <= col_end && col_end <= self.ncols, "Column range out of bounds.");
+        StridedSlice::new(self.mij.as_slice(), row_id + col_start * self.nrows, self.nrows,
+                          col_end - col_start)
+    }
 }
 
 // FIXME: add a function to modify the dimension (to avoid useless allocations)?
@@ -278,10 +610,114 @@ impl<N> IndexMut<uint, [N]> for DMat<N> {
 }
 */
 
-impl<N: Clone + Mul<N, N> + Add<N, N> + Zero> DMatMulRhs<N, DMat<N>> for DMat<N> {
+// Computes the rows `start .. end` of `left * right`, in row-major order.
+fn mul_rows<N: Clone + Mul<N, N> + Add<N, N> + Zero>(left: &DMat<N>, right: &DMat<N>, start: uint, end: uint)
+                                                    -> Vec<N> {
+    let mut res = Vec::with_capacity((end - start) * right.ncols);
+
+    for i in range(start, end) {
+        for j in range(0u, right.ncols) {
+            let mut acc: N = Zero::zero();
+
+            unsafe {
+                for k in range(0u, left.ncols) {
+                    acc = acc + left.unsafe_at((i, k)) * right.unsafe_at((k, j));
+                }
+            }
+
+            res.push(acc);
+        }
+    }
+
+    res
+}
+
+impl<N: Clone + Mul<N, N> + Add<N, N> + Zero + Send + Sync> DMat<N> {
+    /// Multiplies two matrices using one task per CPU.
+    ///
+    /// The rows of the result are split into one contiguous block per CPU, each computed by its
+    /// own task. The operands are copied once and shared by the tasks. This is worth it only for
+    /// large products, e.g., with more than `128^3` multiply-adds. The `*` operator never spawns
+    /// any task.
+    pub fn par_mul(&self, other: &DMat<N>) -> DMat<N> {
+        assert!(self.ncols == other.nrows);
+
+        let (nrows, ncols) = (self.nrows, other.ncols);
+        let ntasks         = cmp::max(cmp::min(os::num_cpus(), nrows), 1);
+        let (tx, rx)       = channel();
+
+        // the operands are copied once, and shared by all the tasks.
+        let left  = Arc::new(self.clone());
+        let right = Arc::new(other.clone());
+
+        for t in range(0u, ntasks) {
+            let start = t * nrows / ntasks;
+            let end   = (t + 1) * nrows / ntasks;
+            let tx    = tx.clone();
+            let left  = left.clone();
+            let right = right.clone();
+
+            spawn(proc() {
+                tx.send((start, mul_rows(&*left, &*right, start, end)));
+            });
+        }
+
+        // `recv` fails only once every task has dropped its sender.
+        drop(tx);
+
+        let mut res = DMat::new_zeros(nrows, ncols);
+
+        for _ in range(0u, ntasks) {
+            let (start, rows) = rx.recv();
+
+            for (k, e) in rows.into_iter().enumerate() {
+                unsafe {
+                    res.unsafe_set((start + k / ncols, k % ncols), e);
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: Add<N, N>> DMatAddRhs<N, DMat<N>> for DMat<N> {
+    #[inline]
+    fn binop(left: &DMat<N>, right: &DMat<N>) -> DMat<N> {
+        assert!(left.nrows == right.nrows && left.ncols == right.ncols,
+                "Addition of matrices with different dimensions.");
+
+        DMat {
+            nrows: left.nrows,
+            ncols: left.ncols,
+            mij:   left.mij.iter().zip(right.mij.iter()).map(|(a, b)| *a + *b).collect()
+        }
+    }
+}
+
+impl<N: Sub<N, N>> DMatSubRhs<N, DMat<N>> for DMat<N> {
+    #[inline]
+    fn binop(left: &DMat<N>, right: &DMat<N>) -> DMat<N> {
+        assert!(left.nrows == right.nrows && left.ncols == right.ncols,
+                "Subtraction of matrices with different dimensions.");
+
+        DMat {
+            nrows: left.nrows,
+            ncols: left.ncols,
+            mij:   left.mij.iter().zip(right.mij.iter()).map(|(a, b)| *a - *b).collect()
+        }
+    }
+}
+
+impl<N: Clone + Mul<N, N> + Add<N, N> + Zero + 'static> DMatMulRhs<N, DMat<N>> for DMat<N> {
     fn binop(left: &DMat<N>, right: &DMat<N>) -> DMat<N> {
         assert!(left.ncols == right.nrows);
 
+        match blas::gemm(left.nrows, right.ncols, left.ncols, left.mij.as_slice(), right.mij.as_slice()) {
+            Some(mij) => return DMat { nrows: left.nrows, ncols: right.ncols, mij: mij },
+            None      => { }
+        }
+
         let mut res = unsafe { DMat::new_uninitialized(left.nrows, right.ncols) };
 
         for i in range(0u, left.nrows) {
@@ -303,11 +739,16 @@ impl<N: Clone + Mul<N, N> + Add<N, N> + Zero> DMatMulRhs<N, DMat<N>> for DMat<N>
     }
 }
 
-impl<N: Clone + Add<N, N> + Mul<N, N> + Zero>
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero + 'static>
 DMatMulRhs<N, DVec<N>> for DVec<N> {
     fn binop(left: &DMat<N>, right: &DVec<N>) -> DVec<N> {
         assert!(left.ncols == right.at.len());
 
+        match blas::gemv(left.nrows, left.ncols, left.mij.as_slice(), false, right.at.as_slice()) {
+            Some(at) => return DVec { at: at },
+            None     => { }
+        }
+
         let mut res : DVec<N> = unsafe { DVec::new_uninitialized(left.nrows) };
 
         for i in range(0u, left.nrows) {
@@ -327,11 +768,16 @@ DMatMulRhs<N, DVec<N>> for DVec<N> {
 }
 
 
-impl<N: Clone + Add<N, N> + Mul<N, N> + Zero>
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero + 'static>
 DVecMulRhs<N, DVec<N>> for DMat<N> {
     fn binop(left: &DVec<N>, right: &DMat<N>) -> DVec<N> {
         assert!(right.nrows == left.at.len());
 
+        match blas::gemv(right.nrows, right.ncols, right.mij.as_slice(), true, left.at.as_slice()) {
+            Some(at) => return DVec { at: at },
+            None     => { }
+        }
+
         let mut res : DVec<N> = unsafe { DVec::new_uninitialized(right.ncols) };
 
         for i in range(0u, right.ncols) {
@@ -350,6 +796,369 @@ DVecMulRhs<N, DVec<N>> for DMat<N> {
     }
 }
 
+// Dimension below which `mul_strassen` switches to the usual product.
+static STRASSEN_THRESHOLD: uint = 64;
+
+// Componentwise sum of two matrices with the same dimensions.
+fn add_mat<N: Add<N, N>>(a: &DMat<N>, b: &DMat<N>) -> DMat<N> {
+    DMat {
+        nrows: a.nrows,
+        ncols: a.ncols,
+        mij:   a.mij.iter().zip(b.mij.iter()).map(|(a, b)| *a + *b).collect()
+    }
+}
+
+// Componentwise difference of two matrices with the same dimensions.
+fn sub_mat<N: Sub<N, N>>(a: &DMat<N>, b: &DMat<N>) -> DMat<N> {
+    DMat {
+        nrows: a.nrows,
+        ncols: a.ncols,
+        mij:   a.mij.iter().zip(b.mij.iter()).map(|(a, b)| *a - *b).collect()
+    }
+}
+
+// The `dim x dim` block of `m` starting at `(i0, j0)`, padded with zeros beyond the borders of `m`.
+fn square_block<N: Clone + Zero>(m: &DMat<N>, i0: uint, j0: uint, dim: uint) -> DMat<N> {
+    DMat::from_fn(dim, dim, |i, j| {
+        if i0 + i < m.nrows && j0 + j < m.ncols {
+            m.mij[(i0 + i) + (j0 + j) * m.nrows].clone()
+        }
+        else {
+            Zero::zero()
+        }
+    })
+}
+
+impl<N: Clone + Num + 'static> DMat<N> {
+    /// Multiplies two square matrices with the Strassen algorithm.
+    ///
+    /// This performs about `n^2.81` scalar multiplications instead of `n^3`, which makes it
+    /// faster than the usual product for very large matrices. The recursion switches to the usual
+    /// product once the blocks have less than 64 rows. Note that the Strassen algorithm is less
+    /// numerically stable than the usual product.
+    pub fn mul_strassen(&self, other: &DMat<N>) -> DMat<N> {
+        assert!(self.nrows == self.ncols && other.nrows == other.ncols && self.ncols == other.nrows,
+                "Strassen multiplication requires square matrices with the same dimensions.");
+
+        let n = self.nrows;
+
+        if n <= STRASSEN_THRESHOLD {
+            return *self * *other
+        }
+
+        // odd dimensions are handled by padding the blocks with zeros.
+        let h = (n + 1) / 2;
+
+        let a11 = square_block(self, 0, 0, h);
+        let a12 = square_block(self, 0, h, h);
+        let a21 = square_block(self, h, 0, h);
+        let a22 = square_block(self, h, h, h);
+        let b11 = square_block(other, 0, 0, h);
+        let b12 = square_block(other, 0, h, h);
+        let b21 = square_block(other, h, 0, h);
+        let b22 = square_block(other, h, h, h);
+
+        let m1 = add_mat(&a11, &a22).mul_strassen(&add_mat(&b11, &b22));
+        let m2 = add_mat(&a21, &a22).mul_strassen(&b11);
+        let m3 = a11.mul_strassen(&sub_mat(&b12, &b22));
+        let m4 = a22.mul_strassen(&sub_mat(&b21, &b11));
+        let m5 = add_mat(&a11, &a12).mul_strassen(&b22);
+        let m6 = sub_mat(&a21, &a11).mul_strassen(&add_mat(&b11, &b12));
+        let m7 = sub_mat(&a12, &a22).mul_strassen(&add_mat(&b21, &b22));
+
+        let c11 = add_mat(&sub_mat(&add_mat(&m1, &m4), &m5), &m7);
+        let c12 = add_mat(&m3, &m5);
+        let c21 = add_mat(&m2, &m4);
+        let c22 = add_mat(&add_mat(&sub_mat(&m1, &m2), &m3), &m6);
+        let c   = [ [ &c11, &c12 ], [ &c21, &c22 ] ];
+
+        DMat::from_fn(n, n, |i, j| c[i / h][j / h].mij[(i % h) + (j % h) * h].clone())
+    }
+}
+
+impl<N: Add<N, N>> DMat<N> {
+    /// Computes `a + b + c` in a single pass, without allocating the intermediate sum.
+    pub fn add3(a: &DMat<N>, b: &DMat<N>, c: &DMat<N>) -> DMat<N> {
+        assert!(a.nrows == b.nrows && a.ncols == b.ncols && a.nrows == c.nrows && a.ncols == c.ncols,
+                "Addition of matrices with different dimensions.");
+
+        DMat {
+            nrows: a.nrows,
+            ncols: a.ncols,
+            mij:   a.mij.iter().zip(b.mij.iter()).zip(c.mij.iter()).map(|((a, b), c)| *a + *b + *c).collect()
+        }
+    }
+}
+
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero + One> DMat<N> {
+    /// Computes `self * b + c` in a single pass, without allocating the intermediate product.
+    pub fn mul_add(&self, b: &DMat<N>, c: &DMat<N>) -> DMat<N> {
+        let mut res = c.clone();
+
+        res.gemm(&One::one(), self, b, &One::one());
+
+        res
+    }
+
+    /// Computes `self * x + y` in a single pass, without allocating the intermediate product.
+    pub fn mul_vec_add(&self, x: &DVec<N>, y: &DVec<N>) -> DVec<N> {
+        let mut res = y.clone();
+
+        res.gemv(&One::one(), self, x, &One::one());
+
+        res
+    }
+
+    /// Computes `self * b` and stores the result into `out`, which must already have the right
+    /// dimensions.
+    pub fn mul_to(&self, b: &DMat<N>, out: &mut DMat<N>) {
+        // `out` is reset first since its components might not be finite.
+        for e in out.mij.iter_mut() {
+            *e = Zero::zero();
+        }
+
+        out.gemm(&One::one(), self, b, &Zero::zero());
+    }
+
+    /// Computes `self * x` and stores the result into `out`, which must already have the right
+    /// dimension.
+    pub fn mul_vec_to(&self, x: &DVec<N>, out: &mut DVec<N>) {
+        for e in out.at.iter_mut() {
+            *e = Zero::zero();
+        }
+
+        out.gemv(&One::one(), self, x, &Zero::zero());
+    }
+}
+
+impl<N: Clone> DMat<N> {
+    /// Stores the transpose of `self` into `out`, which must already have the right dimensions.
+    pub fn transpose_to(&self, out: &mut DMat<N>) {
+        assert!(out.nrows == self.ncols && out.ncols == self.nrows, "Incompatible matrix dimensions.");
+
+        for j in range(0u, self.ncols) {
+            for i in range(0u, self.nrows) {
+                *out.mij.get_mut(j + i * out.nrows) = self.mij[i + j * self.nrows].clone();
+            }
+        }
+    }
+}
+
+impl<N: Add<N, N>> DMat<N> {
+    /// Computes `self + b` and stores the result into `out`, which must already have the right
+    /// dimensions.
+    pub fn add_to(&self, b: &DMat<N>, out: &mut DMat<N>) {
+        assert!(self.nrows == b.nrows && self.ncols == b.ncols &&
+                out.nrows == self.nrows && out.ncols == self.ncols,
+                "Addition of matrices with different dimensions.");
+
+        for ((o, a), b) in out.mij.iter_mut().zip(self.mij.iter()).zip(b.mij.iter()) {
+            *o = *a + *b;
+        }
+    }
+}
+
+impl<N: Add<N, N>> AddInPlace<DMat<N>> for DMat<N> {
+    #[inline]
+    fn add_inplace(&mut self, rhs: &DMat<N>) {
+        assert!(self.nrows == rhs.nrows && self.ncols == rhs.ncols,
+                "Addition of matrices with different dimensions.");
+
+        for (e, r) in self.mij.iter_mut().zip(rhs.mij.iter()) {
+            *e = *e + *r;
+        }
+    }
+}
+
+impl<N: Sub<N, N>> SubInPlace<DMat<N>> for DMat<N> {
+    #[inline]
+    fn sub_inplace(&mut self, rhs: &DMat<N>) {
+        assert!(self.nrows == rhs.nrows && self.ncols == rhs.ncols,
+                "Subtraction of matrices with different dimensions.");
+
+        for (e, r) in self.mij.iter_mut().zip(rhs.mij.iter()) {
+            *e = *e - *r;
+        }
+    }
+}
+
+impl<N: Mul<N, N>> ScaleInPlace<N> for DMat<N> {
+    #[inline]
+    fn scale_inplace(&mut self, s: &N) {
+        for e in self.mij.iter_mut() {
+            *e = *e * *s;
+        }
+    }
+}
+
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> MulInPlace<DMat<N>> for DMat<N> {
+    /// Sets `self` to `self * rhs`, where `rhs` is a square matrix.
+    ///
+    /// Only one row of `self` is buffered at a time.
+    fn mul_inplace(&mut self, rhs: &DMat<N>) {
+        assert!(rhs.nrows == rhs.ncols && self.ncols == rhs.nrows,
+                "In-place multiplication requires a square right-hand side.");
+
+        let mut row: Vec<N> = Vec::with_capacity(self.ncols);
+
+        for i in range(0u, self.nrows) {
+            row.truncate(0);
+
+            for j in range(0u, self.ncols) {
+                let mut acc: N = Zero::zero();
+
+                for k in range(0u, self.ncols) {
+                    acc = acc + self.mij[i + k * self.nrows] * rhs.mij[k + j * rhs.nrows];
+                }
+
+                row.push(acc);
+            }
+
+            for (j, e) in row.iter().enumerate() {
+                *self.mij.get_mut(i + j * self.nrows) = e.clone();
+            }
+        }
+    }
+}
+
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> Gemm<N> for DMat<N> {
+    fn gemm(&mut self, alpha: &N, a: &DMat<N>, b: &DMat<N>, beta: &N) {
+        assert!(a.ncols == b.nrows && self.nrows == a.nrows && self.ncols == b.ncols,
+                "Incompatible matrix dimensions.");
+
+        for j in range(0u, b.ncols) {
+            for i in range(0u, a.nrows) {
+                let mut acc: N = Zero::zero();
+
+                for k in range(0u, a.ncols) {
+                    acc = acc + a.mij[i + k * a.nrows] * b.mij[k + j * b.nrows];
+                }
+
+                let e = self.mij.get_mut(i + j * self.nrows);
+                *e = acc * *alpha + *e * *beta;
+            }
+        }
+    }
+}
+
+impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> Gemv<N, DMat<N>> for DVec<N> {
+    fn gemv(&mut self, alpha: &N, a: &DMat<N>, x: &DVec<N>, beta: &N) {
+        assert!(a.ncols == x.at.len() && self.at.len() == a.nrows, "Incompatible dimensions.");
+
+        for i in range(0u, a.nrows) {
+            let mut acc: N = Zero::zero();
+
+            for j in range(0u, a.ncols) {
+                acc = acc + a.mij[i + j * a.nrows] * x.at[j];
+            }
+
+            let e = self.at.get_mut(i);
+            *e = acc * *alpha + *e * *beta;
+        }
+    }
+}
+
+impl<N: Clone + Num + Signed + PartialOrd> DMat<N> {
+    /// Solves the linear system `self * x = b` using Gaussian elimination with partial pivoting.
+    ///
+    /// This is more accurate than `solve` for floating-point scalars, but the result is not exact
+    /// for integer scalars. Returns `None` if `self` is singular. Fails if `self` is not square or
+    /// if `b` does not have as many components as `self` has rows.
+    pub fn solve_partial_pivot(&self, b: &DVec<N>) -> Option<DVec<N>> {
+        let n = self.nrows;
+
+        assert!(self.ncols == n && b.at.len() == n, "Incompatible dimensions.");
+
+        let mut a = self.clone();
+        let mut x = b.clone();
+
+        for k in range(0u, n) {
+            // the pivot is the greatest component of the k-th column below the diagonal.
+            let mut piv = k;
+
+            for i in range(k + 1, n) {
+                if a.mij[i + k * n].abs() > a.mij[piv + k * n].abs() {
+                    piv = i;
+                }
+            }
+
+            if a.mij[piv + k * n].is_zero() {
+                return None
+            }
+
+            if piv != k {
+                for j in range(k, n) {
+                    a.mij.as_mut_slice().swap(piv + j * n, k + j * n);
+                }
+
+                x.at.as_mut_slice().swap(piv, k);
+            }
+
+            for i in range(k + 1, n) {
+                let factor = a.mij[i + k * n] / a.mij[k + k * n];
+
+                for j in range(k + 1, n) {
+                    let aij = a.mij[i + j * n] - factor * a.mij[k + j * n];
+                    *a.mij.get_mut(i + j * n) = aij;
+                }
+
+                let xi = x.at[i] - factor * x.at[k];
+                *x.at.get_mut(i) = xi;
+            }
+        }
+
+        // back substitution.
+        for k in range(0u, n).rev() {
+            let mut acc = x.at[k].clone();
+
+            for j in range(k + 1, n) {
+                acc = acc - a.mij[k + j * n] * x.at[j];
+            }
+
+            *x.at.get_mut(k) = acc / a.mij[k + k * n];
+        }
+
+        Some(x)
+    }
+}
+
+impl<N: Clone + Num> DMat<N> {
+    /// Solves the linear system `self * x = b` using the fraction-free Bareiss elimination.
+    ///
+    /// The pivot of each column is its first non-zero component, and every division performed
+    /// during the elimination is exact. Thus, the solution is exact for rational scalars, and for
+    /// integer scalars whenever it has integer components. Use `solve_partial_pivot` for
+    /// floating-point scalars.
+    ///
+    /// Returns `None` if `self` is singular. Fails if `self` is not square or if `b` does not
+    /// have as many components as `self` has rows.
+    pub fn solve(&self, b: &DVec<N>) -> Option<DVec<N>> {
+        assert!(self.ncols == self.nrows && b.at.len() == self.nrows, "Incompatible dimensions.");
+
+        let mut m = self.mij.clone();
+        let mut x = b.clone();
+
+        if bareiss::solve(m.as_mut_slice(), x.at.as_mut_slice(), self.nrows) {
+            Some(x)
+        }
+        else {
+            None
+        }
+    }
+
+    /// The determinant of this matrix computed with the fraction-free Bareiss algorithm.
+    ///
+    /// All the divisions performed are exact, so the result is exact for integer matrices as long
+    /// as the minors of `self` do not overflow. Fails if `self` is not square.
+    pub fn det_bareiss(&self) -> N {
+        assert!(self.nrows == self.ncols, "The determinant of a non-square matrix is not defined.");
+
+        let mut m = self.mij.clone();
+
+        bareiss::det(m.as_mut_slice(), self.nrows)
+    }
+}
+
 impl<N: Clone + Num>
 Inv for DMat<N> {
     #[inline]
@@ -484,6 +1293,28 @@ impl<N: Clone> Transpose for DMat<N> {
     }
 }
 
+impl<N: Clone + Conjugate> ConjTranspose for DMat<N> {
+    #[inline]
+    fn conj_transpose_cpy(m: &DMat<N>) -> DMat<N> {
+        let mut res: DMat<N> = Transpose::transpose_cpy(m);
+
+        for e in res.mij.iter_mut() {
+            *e = e.conjugate();
+        }
+
+        res
+    }
+
+    #[inline]
+    fn conj_transpose(&mut self) {
+        self.transpose();
+
+        for e in self.mij.iter_mut() {
+            *e = e.conjugate();
+        }
+    }
+}
+
 impl<N: Num + Cast<f32> + Clone> Mean<DVec<N>> for DMat<N> {
     fn mean(m: &DMat<N>) -> DVec<N> {
         let mut res: DVec<N> = DVec::new_zeros(m.ncols);
@@ -502,7 +1333,7 @@ impl<N: Num + Cast<f32> + Clone> Mean<DVec<N>> for DMat<N> {
     }
 }
 
-impl<N: Clone + Num + Cast<f32> + DMatDivRhs<N, DMat<N>>> Cov<DMat<N>> for DMat<N> {
+impl<N: Clone + Num + Cast<f32> + DMatDivRhs<N, DMat<N>> + 'static> Cov<DMat<N>> for DMat<N> {
     // FIXME: this could be heavily optimized, removing all temporaries by merging loops.
     fn cov(m: &DMat<N>) -> DMat<N> {
         assert!(m.nrows > 1);
@@ -527,6 +1358,81 @@ impl<N: Clone + Num + Cast<f32> + DMatDivRhs<N, DMat<N>>> Cov<DMat<N>> for DMat<
     }
 }
 
+impl<N: Clone + Num> WeightedMean<DVec<N>, DVec<N>> for DMat<N> {
+    fn weighted_mean(m: &DMat<N>, weights: &DVec<N>) -> DVec<N> {
+        assert!(weights.len() == m.nrows, "There must be one weight per observation.");
+
+        let mut res: DVec<N> = DVec::new_zeros(m.ncols);
+        let mut wsum: N      = Zero::zero();
+
+        for i in range(0u, m.nrows) {
+            let w = weights.at(i);
+
+            for j in range(0u, m.ncols) {
+                unsafe {
+                    let acc = res.unsafe_at(j) + m.unsafe_at((i, j)) * w;
+                    res.unsafe_set(j, acc);
+                }
+            }
+
+            wsum = wsum + w;
+        }
+
+        for j in range(0u, m.ncols) {
+            unsafe {
+                let e = res.unsafe_at(j) / wsum;
+                res.unsafe_set(j, e);
+            }
+        }
+
+        res
+    }
+}
+
+impl<N: Clone + Num> WeightedCov<DMat<N>, DVec<N>> for DMat<N> {
+    fn weighted_cov(m: &DMat<N>, weights: &DVec<N>, normalization: CovNormalization) -> DMat<N> {
+        let mean: DVec<N> = WeightedMean::weighted_mean(m, weights);
+        let mut res       = DMat::new_zeros(m.ncols, m.ncols);
+        let mut v1: N     = Zero::zero();
+        let mut v2: N     = Zero::zero();
+
+        for i in range(0u, m.nrows) {
+            let w = weights.at(i);
+
+            for j in range(0u, m.ncols) {
+                for k in range(0u, j + 1) {
+                    unsafe {
+                        let dj  = m.unsafe_at((i, j)) - mean.unsafe_at(j);
+                        let dk  = m.unsafe_at((i, k)) - mean.unsafe_at(k);
+                        let acc = res.unsafe_at((j, k)) + w * dj * dk;
+                        res.unsafe_set((j, k), acc);
+                    }
+                }
+            }
+
+            v1 = v1 + w;
+            v2 = v2 + w * w;
+        }
+
+        let normalizer = match normalization {
+            PopulationCov => v1,
+            SampleCov     => v1 - v2 / v1
+        };
+
+        for j in range(0u, m.ncols) {
+            for k in range(0u, j + 1) {
+                unsafe {
+                    let c = res.unsafe_at((j, k)) / normalizer;
+                    res.unsafe_set((j, k), c.clone());
+                    res.unsafe_set((k, j), c);
+                }
+            }
+        }
+
+        res
+    }
+}
+
 impl<N: Clone> ColSlice<DVec<N>> for DMat<N> {
     fn col_slice(&self, col_id :uint, row_start: uint, row_end: uint) -> DVec<N> {
         assert!(col_id < self.ncols);
@@ -547,7 +1453,7 @@ impl<N: Clone> RowSlice<DVec<N>> for DMat<N> {
         assert!(col_start < col_end);
         assert!(col_end <= self.ncols);
         let mut slice : DVec<N> = unsafe {
-            DVec::new_uninitialized(self.nrows)
+            DVec::new_uninitialized(col_end - col_start)
         };
         let mut slice_idx = 0u;
         for col_id in range(col_start, col_end) {
@@ -595,12 +1501,67 @@ impl<N: Clone + Zero>  Diag<DVec<N>> for DMat<N> {
     }
 }
 
+impl<N: Clone + Zero> Band<DVec<N>> for DMat<N> {
+    #[inline]
+    fn diag_k(&self, k: int) -> DVec<N> {
+        band::diag_k(self, k)
+    }
+
+    #[inline]
+    fn triu(&self, k: int) -> DMat<N> {
+        band::triu(self, k)
+    }
+
+    #[inline]
+    fn tril(&self, k: int) -> DMat<N> {
+        band::tril(self, k)
+    }
+}
+
+impl<N: Clone + Zero + One + Add<N, N> + Mul<N, N> + ApproxEq<N>> DMat<N> {
+    /// Tests if this matrix is square and equal to its transpose, up to `eps`.
+    #[inline]
+    pub fn is_symmetric(&self, eps: &N) -> bool {
+        predicates::is_symmetric(self, eps)
+    }
+
+    /// Tests if this matrix is square and its transpose times itself is the identity, up to
+    /// `eps`.
+    #[inline]
+    pub fn is_orthogonal(&self, eps: &N) -> bool {
+        predicates::is_orthogonal(self, eps)
+    }
+
+    /// Tests if every component below the diagonal of this matrix is zero, up to `eps`.
+    #[inline]
+    pub fn is_upper_triangular(&self, eps: &N) -> bool {
+        predicates::is_upper_triangular(self, eps)
+    }
+
+    /// Tests if every off-diagonal component of this matrix is zero, up to `eps`.
+    #[inline]
+    pub fn is_diagonal(&self, eps: &N) -> bool {
+        predicates::is_diagonal(self, eps)
+    }
+
+    /// Tests if this matrix is square and is the identity, up to `eps`.
+    #[inline]
+    pub fn is_identity(&self, eps: &N) -> bool {
+        predicates::is_identity(self, eps)
+    }
+}
+
 impl<N: ApproxEq<N>> ApproxEq<N> for DMat<N> {
     #[inline]
     fn approx_epsilon(_: Option<DMat<N>>) -> N {
         ApproxEq::approx_epsilon(None::<N>)
     }
 
+    #[inline]
+    fn approx_ulps(_: Option<DMat<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
     #[inline]
     fn approx_eq(a: &DMat<N>, b: &DMat<N>) -> bool {
         let mut zip = a.mij.iter().zip(b.mij.iter());
@@ -614,17 +1575,57 @@ impl<N: ApproxEq<N>> ApproxEq<N> for DMat<N> {
 
         zip.all(|(a, b)| ApproxEq::approx_eq_eps(a, b, epsilon))
     }
+
+    #[inline]
+    fn approx_eq_ulps(a: &DMat<N>, b: &DMat<N>, ulps: u32) -> bool {
+        let mut zip = a.mij.iter().zip(b.mij.iter());
+
+        zip.all(|(a, b)| ApproxEq::approx_eq_ulps(a, b, ulps))
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &DMat<N>, b: &DMat<N>, abs_eps: &N, rel_eps: &N) -> bool {
+        let mut zip = a.mij.iter().zip(b.mij.iter());
+
+        zip.all(|(a, b)| ApproxEq::approx_eq_eps_rel(a, b, abs_eps, rel_eps))
+    }
 }
 
-impl<N: Show + Clone> Show for DMat<N> {
-    fn fmt(&self, form:&mut Formatter) -> Result {
-        for i in range(0u, self.nrows()) {
-            for j in range(0u, self.ncols()) {
-                let _ = write!(form, "{} ", self.at((i, j)));
+impl<N: Show> Show for DMat<N> {
+    fn fmt(&self, form: &mut Formatter) -> Result {
+        let strs = pretty::show_strs(form, self.mij.as_slice());
+
+        pretty::fmt_mat(form, self.nrows, self.ncols, strs.as_slice())
+    }
+}
+
+impl<N: FromStr> DMat<N> {
+    /// Parses the bracketed list of rows of a matrix, e.g., `"[[1, 2, 3], [4, 5, 6]]"`.
+    ///
+    /// Returns a message describing the error if the input is malformed.
+    pub fn parse(s: &str) -> result::Result<DMat<N>, String> {
+        parse::parse_mat(s).map(|(nrows, ncols, mij)| {
+            DMat {
+                nrows: nrows,
+                ncols: ncols,
+                mij:   mij
             }
-            let _ = write!(form, "\n");
-        }
-        write!(form, "\n")
+        })
+    }
+}
+
+impl<N: FromStr> FromStr for DMat<N> {
+    #[inline]
+    fn from_str(s: &str) -> Option<DMat<N>> {
+        DMat::parse(s).ok()
+    }
+}
+
+impl<N: LowerExp> LowerExp for DMat<N> {
+    fn fmt(&self, form: &mut Formatter) -> Result {
+        let strs = pretty::exp_strs(form, self.mij.as_slice());
+
+        pretty::fmt_mat(form, self.nrows, self.ncols, strs.as_slice())
     }
 }
 
@@ -700,6 +1701,8 @@ scalar_mul_impl!(i16)
 scalar_mul_impl!(i8)
 scalar_mul_impl!(uint)
 scalar_mul_impl!(int)
+left_scalar_mul_impl!(DMat, f64)
+left_scalar_mul_impl!(DMat, f32)
 
 scalar_div_impl!(f64)
 scalar_div_impl!(f32)
@@ -0,0 +1,695 @@
+//! Dual numbers for forward-mode automatic differentiation.
+
+use std::num;
+use std::num::{Zero, One, Bounded, FPCategory};
+use traits::operations::{ApproxEq, Conjugate};
+
+/// Dual number `re + eps * ε`, where `ε` is a non-zero number such that `ε * ε = 0`.
+///
+/// Evaluating a function `f` at `Dual::new_variable(x)` gives `f(x)` as its real part and the
+/// derivative `f'(x)` as its `eps` part. Since `Dual` implements `Float` and `FloatMath`, the
+/// generic products, norms, and rotations can be differentiated this way.
+///
+/// The rounding functions, e.g., `floor`, have a zero derivative, and the predicates, e.g.,
+/// `is_nan`, only consider the real part.
+///
+/// Dual numbers are ordered lexicographically: by their real parts first, and then by their
+/// infinitesimal parts.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+pub struct Dual<N> {
+    /// The real part.
+    pub re:  N,
+    /// The infinitesimal part, i.e., the derivative.
+    pub eps: N
+}
+
+impl<N> Dual<N> {
+    /// Creates a new dual number.
+    #[inline]
+    pub fn new(re: N, eps: N) -> Dual<N> {
+        Dual {
+            re:  re,
+            eps: eps
+        }
+    }
+}
+
+impl<N: Zero + One> Dual<N> {
+    /// Creates the dual number of the variable the derivatives are computed with respect to.
+    #[inline]
+    pub fn new_variable(re: N) -> Dual<N> {
+        Dual::new(re, One::one())
+    }
+
+    /// Creates the dual number of a constant, i.e., with a zero derivative.
+    #[inline]
+    pub fn new_constant(re: N) -> Dual<N> {
+        Dual::new(re, Zero::zero())
+    }
+}
+
+impl<N: Add<N, N>> Add<Dual<N>, Dual<N>> for Dual<N> {
+    #[inline]
+    fn add(&self, other: &Dual<N>) -> Dual<N> {
+        Dual::new(self.re + other.re, self.eps + other.eps)
+    }
+}
+
+impl<N: Sub<N, N>> Sub<Dual<N>, Dual<N>> for Dual<N> {
+    #[inline]
+    fn sub(&self, other: &Dual<N>) -> Dual<N> {
+        Dual::new(self.re - other.re, self.eps - other.eps)
+    }
+}
+
+impl<N: Mul<N, N> + Add<N, N>> Mul<Dual<N>, Dual<N>> for Dual<N> {
+    #[inline]
+    fn mul(&self, other: &Dual<N>) -> Dual<N> {
+        Dual::new(self.re * other.re, self.re * other.eps + self.eps * other.re)
+    }
+}
+
+impl<N: Mul<N, N> + Sub<N, N> + Div<N, N>> Div<Dual<N>, Dual<N>> for Dual<N> {
+    #[inline]
+    fn div(&self, other: &Dual<N>) -> Dual<N> {
+        Dual::new(self.re / other.re,
+                  (self.eps * other.re - self.re * other.eps) / (other.re * other.re))
+    }
+}
+
+impl<N: Num> Rem<Dual<N>, Dual<N>> for Dual<N> {
+    #[inline]
+    fn rem(&self, other: &Dual<N>) -> Dual<N> {
+        // `a % b = a - q * b` where the integral quotient `q` is locally constant.
+        let rem = self.re % other.re;
+        let q   = (self.re - rem) / other.re;
+
+        Dual::new(rem, self.eps - q * other.eps)
+    }
+}
+
+impl<N: Neg<N>> Neg<Dual<N>> for Dual<N> {
+    #[inline]
+    fn neg(&self) -> Dual<N> {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<N: Zero> Zero for Dual<N> {
+    #[inline]
+    fn zero() -> Dual<N> {
+        Dual::new(Zero::zero(), Zero::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<N: Num> One for Dual<N> {
+    #[inline]
+    fn one() -> Dual<N> {
+        Dual::new(One::one(), Zero::zero())
+    }
+}
+
+impl<N: Num> Num for Dual<N> { }
+
+// The lexicographic order is consistent with `PartialEq`, which compares both parts.
+impl<N: PartialOrd> PartialOrd for Dual<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Dual<N>) -> Option<Ordering> {
+        match self.re.partial_cmp(&other.re) {
+            Some(Equal) => self.eps.partial_cmp(&other.eps),
+            ord         => ord
+        }
+    }
+}
+
+impl<N: Signed + Clone> Signed for Dual<N> {
+    #[inline]
+    fn abs(&self) -> Dual<N> {
+        if self.re.is_negative() { -*self } else { self.clone() }
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Dual<N>) -> Dual<N> {
+        if self.re <= other.re { Zero::zero() } else { *self - *other }
+    }
+
+    #[inline]
+    fn signum(&self) -> Dual<N> {
+        Dual::new(self.re.signum(), Zero::zero())
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.re.is_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.re.is_negative()
+    }
+}
+
+impl<N: Clone> Conjugate for Dual<N> {
+    #[inline]
+    fn conjugate(&self) -> Dual<N> {
+        self.clone()
+    }
+}
+
+// The real parts and the derivatives are compared with the corresponding parts of the epsilon.
+impl<N: ApproxEq<N>> ApproxEq<Dual<N>> for Dual<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<Dual<N>>) -> Dual<N> {
+        Dual::new(ApproxEq::approx_epsilon(None::<N>), ApproxEq::approx_epsilon(None::<N>))
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<Dual<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &Dual<N>, b: &Dual<N>, eps: &Dual<N>) -> bool {
+        ApproxEq::approx_eq_eps(&a.re, &b.re, &eps.re) && ApproxEq::approx_eq_eps(&a.eps, &b.eps, &eps.eps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &Dual<N>, b: &Dual<N>, abs_eps: &Dual<N>, rel_eps: &Dual<N>) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.re, &b.re, &abs_eps.re, &rel_eps.re) &&
+            ApproxEq::approx_eq_eps_rel(&a.eps, &b.eps, &abs_eps.eps, &rel_eps.eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &Dual<N>, b: &Dual<N>, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.re, &b.re, ulps) && ApproxEq::approx_eq_ulps(&a.eps, &b.eps, ulps)
+    }
+}
+
+impl<N: ToPrimitive> ToPrimitive for Dual<N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        self.re.to_f32()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<N: NumCast + Zero> NumCast for Dual<N> {
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Dual<N>> {
+        NumCast::from(n).map(|re| Dual::new(re, Zero::zero()))
+    }
+}
+
+impl<N: Bounded + Zero> Bounded for Dual<N> {
+    #[inline]
+    fn min_value() -> Dual<N> {
+        Dual::new(Bounded::min_value(), Zero::zero())
+    }
+
+    #[inline]
+    fn max_value() -> Dual<N> {
+        Dual::new(Bounded::max_value(), Zero::zero())
+    }
+}
+
+impl<N: Float> Primitive for Dual<N> { }
+
+impl<N: Float> Float for Dual<N> {
+    #[inline]
+    fn nan() -> Dual<N> {
+        Dual::new(Float::nan(), Zero::zero())
+    }
+
+    #[inline]
+    fn infinity() -> Dual<N> {
+        Dual::new(Float::infinity(), Zero::zero())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Dual<N> {
+        Dual::new(Float::neg_infinity(), Zero::zero())
+    }
+
+    #[inline]
+    fn neg_zero() -> Dual<N> {
+        Dual::new(Float::neg_zero(), Zero::zero())
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.re.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.re.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.re.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> FPCategory {
+        self.re.classify()
+    }
+
+    #[inline]
+    fn mantissa_digits(_: Option<Dual<N>>) -> uint {
+        Float::mantissa_digits(None::<N>)
+    }
+
+    #[inline]
+    fn digits(_: Option<Dual<N>>) -> uint {
+        Float::digits(None::<N>)
+    }
+
+    #[inline]
+    fn epsilon() -> Dual<N> {
+        Dual::new(Float::epsilon(), Zero::zero())
+    }
+
+    #[inline]
+    fn min_exp(_: Option<Dual<N>>) -> int {
+        Float::min_exp(None::<N>)
+    }
+
+    #[inline]
+    fn max_exp(_: Option<Dual<N>>) -> int {
+        Float::max_exp(None::<N>)
+    }
+
+    #[inline]
+    fn min_10_exp(_: Option<Dual<N>>) -> int {
+        Float::min_10_exp(None::<N>)
+    }
+
+    #[inline]
+    fn max_10_exp(_: Option<Dual<N>>) -> int {
+        Float::max_10_exp(None::<N>)
+    }
+
+    #[inline]
+    fn min_pos_value(_: Option<Dual<N>>) -> Dual<N> {
+        Dual::new(Float::min_pos_value(None::<N>), Zero::zero())
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.re.integer_decode()
+    }
+
+    #[inline]
+    fn floor(self) -> Dual<N> {
+        Dual::new(self.re.floor(), Zero::zero())
+    }
+
+    #[inline]
+    fn ceil(self) -> Dual<N> {
+        Dual::new(self.re.ceil(), Zero::zero())
+    }
+
+    #[inline]
+    fn round(self) -> Dual<N> {
+        Dual::new(self.re.round(), Zero::zero())
+    }
+
+    #[inline]
+    fn trunc(self) -> Dual<N> {
+        Dual::new(self.re.trunc(), Zero::zero())
+    }
+
+    #[inline]
+    fn fract(self) -> Dual<N> {
+        Dual::new(self.re.fract(), self.eps)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Dual<N>, b: Dual<N>) -> Dual<N> {
+        self * a + b
+    }
+
+    #[inline]
+    fn recip(self) -> Dual<N> {
+        Dual::new(self.re.recip(), -self.eps / (self.re * self.re))
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Dual<N> {
+        if n == 0 {
+            // `re^(n - 1)` might be infinite.
+            return One::one()
+        }
+
+        let n_1 = self.re.powi(n - 1);
+        let nf: N = num::cast(n).unwrap();
+
+        Dual::new(n_1 * self.re, self.eps * nf * n_1)
+    }
+
+    #[inline]
+    fn powf(self, n: Dual<N>) -> Dual<N> {
+        let pow = self.re.powf(n.re);
+        let _1: N = One::one();
+
+        // the `ln` term is skipped for constant exponents, so that negative bases are supported.
+        let deps =
+            if n.eps.is_zero() {
+                self.eps * n.re * self.re.powf(n.re - _1)
+            }
+            else {
+                self.eps * n.re * self.re.powf(n.re - _1) + n.eps * pow * self.re.ln()
+            };
+
+        Dual::new(pow, deps)
+    }
+
+    #[inline]
+    fn sqrt2() -> Dual<N> {
+        Dual::new(Float::sqrt2(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_1_sqrt2() -> Dual<N> {
+        Dual::new(Float::frac_1_sqrt2(), Zero::zero())
+    }
+
+    #[inline]
+    fn sqrt(self) -> Dual<N> {
+        let _2: N = One::one() + One::one();
+        let sqrt  = self.re.sqrt();
+
+        Dual::new(sqrt, self.eps / (_2 * sqrt))
+    }
+
+    #[inline]
+    fn rsqrt(self) -> Dual<N> {
+        self.sqrt().recip()
+    }
+
+    #[inline]
+    fn pi() -> Dual<N> {
+        Dual::new(Float::pi(), Zero::zero())
+    }
+
+    #[inline]
+    fn two_pi() -> Dual<N> {
+        Dual::new(Float::two_pi(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_pi_2() -> Dual<N> {
+        Dual::new(Float::frac_pi_2(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_pi_3() -> Dual<N> {
+        Dual::new(Float::frac_pi_3(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_pi_4() -> Dual<N> {
+        Dual::new(Float::frac_pi_4(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_pi_6() -> Dual<N> {
+        Dual::new(Float::frac_pi_6(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_pi_8() -> Dual<N> {
+        Dual::new(Float::frac_pi_8(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_1_pi() -> Dual<N> {
+        Dual::new(Float::frac_1_pi(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_2_pi() -> Dual<N> {
+        Dual::new(Float::frac_2_pi(), Zero::zero())
+    }
+
+    #[inline]
+    fn frac_2_sqrtpi() -> Dual<N> {
+        Dual::new(Float::frac_2_sqrtpi(), Zero::zero())
+    }
+
+    #[inline]
+    fn e() -> Dual<N> {
+        Dual::new(Float::e(), Zero::zero())
+    }
+
+    #[inline]
+    fn log2_e() -> Dual<N> {
+        Dual::new(Float::log2_e(), Zero::zero())
+    }
+
+    #[inline]
+    fn log10_e() -> Dual<N> {
+        Dual::new(Float::log10_e(), Zero::zero())
+    }
+
+    #[inline]
+    fn ln_2() -> Dual<N> {
+        Dual::new(Float::ln_2(), Zero::zero())
+    }
+
+    #[inline]
+    fn ln_10() -> Dual<N> {
+        Dual::new(Float::ln_10(), Zero::zero())
+    }
+
+    #[inline]
+    fn exp(self) -> Dual<N> {
+        let exp = self.re.exp();
+
+        Dual::new(exp, self.eps * exp)
+    }
+
+    #[inline]
+    fn exp2(self) -> Dual<N> {
+        let exp2 = self.re.exp2();
+        let ln_2: N = Float::ln_2();
+
+        Dual::new(exp2, self.eps * exp2 * ln_2)
+    }
+
+    #[inline]
+    fn ln(self) -> Dual<N> {
+        Dual::new(self.re.ln(), self.eps / self.re)
+    }
+
+    #[inline]
+    fn log(self, base: Dual<N>) -> Dual<N> {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Dual<N> {
+        let ln_2: N = Float::ln_2();
+
+        Dual::new(self.re.log2(), self.eps / (self.re * ln_2))
+    }
+
+    #[inline]
+    fn log10(self) -> Dual<N> {
+        let ln_10: N = Float::ln_10();
+
+        Dual::new(self.re.log10(), self.eps / (self.re * ln_10))
+    }
+
+    #[inline]
+    fn to_degrees(self) -> Dual<N> {
+        Dual::new(self.re.to_degrees(), self.eps.to_degrees())
+    }
+
+    #[inline]
+    fn to_radians(self) -> Dual<N> {
+        Dual::new(self.re.to_radians(), self.eps.to_radians())
+    }
+}
+
+impl<N: FloatMath> FloatMath for Dual<N> {
+    #[inline]
+    fn ldexp(x: Dual<N>, exp: int) -> Dual<N> {
+        Dual::new(FloatMath::ldexp(x.re, exp), FloatMath::ldexp(x.eps, exp))
+    }
+
+    #[inline]
+    fn frexp(self) -> (Dual<N>, int) {
+        let (mantissa, exp) = self.re.frexp();
+
+        (Dual::new(mantissa, FloatMath::ldexp(self.eps, -exp)), exp)
+    }
+
+    #[inline]
+    fn next_after(self, other: Dual<N>) -> Dual<N> {
+        Dual::new(self.re.next_after(other.re), self.eps)
+    }
+
+    #[inline]
+    fn max(self, other: Dual<N>) -> Dual<N> {
+        if other.re.is_nan() || self.re >= other.re { self } else { other }
+    }
+
+    #[inline]
+    fn min(self, other: Dual<N>) -> Dual<N> {
+        if other.re.is_nan() || self.re <= other.re { self } else { other }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Dual<N>) -> Dual<N> {
+        if self.re <= other.re { Zero::zero() } else { self - other }
+    }
+
+    #[inline]
+    fn cbrt(self) -> Dual<N> {
+        let _3: N = num::cast(3.0f64).unwrap();
+        let cbrt  = self.re.cbrt();
+
+        Dual::new(cbrt, self.eps / (_3 * cbrt * cbrt))
+    }
+
+    #[inline]
+    fn hypot(self, other: Dual<N>) -> Dual<N> {
+        let hypot = self.re.hypot(other.re);
+
+        Dual::new(hypot, (self.re * self.eps + other.re * other.eps) / hypot)
+    }
+
+    #[inline]
+    fn sin(self) -> Dual<N> {
+        let (sin, cos) = self.re.sin_cos();
+
+        Dual::new(sin, self.eps * cos)
+    }
+
+    #[inline]
+    fn cos(self) -> Dual<N> {
+        let (sin, cos) = self.re.sin_cos();
+
+        Dual::new(cos, -self.eps * sin)
+    }
+
+    #[inline]
+    fn tan(self) -> Dual<N> {
+        let tan   = self.re.tan();
+        let _1: N = One::one();
+
+        Dual::new(tan, self.eps * (_1 + tan * tan))
+    }
+
+    #[inline]
+    fn asin(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.asin(), self.eps / (_1 - self.re * self.re).sqrt())
+    }
+
+    #[inline]
+    fn acos(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.acos(), -self.eps / (_1 - self.re * self.re).sqrt())
+    }
+
+    #[inline]
+    fn atan(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.atan(), self.eps / (_1 + self.re * self.re))
+    }
+
+    #[inline]
+    fn atan2(self, other: Dual<N>) -> Dual<N> {
+        let sqnorm = self.re * self.re + other.re * other.re;
+
+        Dual::new(self.re.atan2(other.re), (other.re * self.eps - self.re * other.eps) / sqnorm)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Dual<N>, Dual<N>) {
+        let (sin, cos) = self.re.sin_cos();
+
+        (Dual::new(sin, self.eps * cos), Dual::new(cos, -self.eps * sin))
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Dual<N> {
+        Dual::new(self.re.exp_m1(), self.eps * self.re.exp())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.ln_1p(), self.eps / (_1 + self.re))
+    }
+
+    #[inline]
+    fn sinh(self) -> Dual<N> {
+        Dual::new(self.re.sinh(), self.eps * self.re.cosh())
+    }
+
+    #[inline]
+    fn cosh(self) -> Dual<N> {
+        Dual::new(self.re.cosh(), self.eps * self.re.sinh())
+    }
+
+    #[inline]
+    fn tanh(self) -> Dual<N> {
+        let tanh  = self.re.tanh();
+        let _1: N = One::one();
+
+        Dual::new(tanh, self.eps * (_1 - tanh * tanh))
+    }
+
+    #[inline]
+    fn asinh(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.asinh(), self.eps / (self.re * self.re + _1).sqrt())
+    }
+
+    #[inline]
+    fn acosh(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.acosh(), self.eps / (self.re * self.re - _1).sqrt())
+    }
+
+    #[inline]
+    fn atanh(self) -> Dual<N> {
+        let _1: N = One::one();
+
+        Dual::new(self.re.atanh(), self.eps / (_1 - self.re * self.re))
+    }
+}
@@ -0,0 +1,330 @@
+//! Dual quaternion definition.
+
+#![allow(missing_doc)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::num::{Zero, One};
+use std::num;
+use std::rand::{Rand, Rng};
+use structs::{Quat, UnitQuat, Vec3, Pnt3, Iso3, Vec3MulRhs, Pnt3MulRhs};
+use traits::operations::{ApproxEq, Inv};
+use traits::structure::Cast;
+use traits::geometry::{Norm, Transform};
+
+/// A unit dual quaternion that can represent a 3D rigid-body motion.
+///
+/// The motion is the composition of a rotation followed by a translation. With `q` the unit
+/// quaternion of the rotation and `t` the pure quaternion `(0, tx, ty, tz)` of the translation,
+/// the real part of the dual quaternion is `q` and its dual part is `0.5 * t * q`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Show)]
+pub struct DualQuat<N> {
+    real: Quat<N>,
+    dual: Quat<N>
+}
+
+impl<N: FloatMath + Clone> DualQuat<N> {
+    /// Creates a new unit dual quaternion from a rotation and a translation.
+    ///
+    /// The rotation is applied first.
+    #[inline]
+    pub fn new(rotation: UnitQuat<N>, translation: Vec3<N>) -> DualQuat<N> {
+        let _0_5: N = num::cast(0.5f64).unwrap();
+        let real    = rotation.quat().clone();
+        let t       = Quat::new(Zero::zero(), translation.x, translation.y, translation.z);
+
+        DualQuat {
+            dual: scale(&(t * real), _0_5),
+            real: real
+        }
+    }
+
+    /// Creates a new unit dual quaternion from its real and dual parts.
+    ///
+    /// The input will be normalized.
+    #[inline]
+    pub fn new_with_quats(real: Quat<N>, dual: Quat<N>) -> DualQuat<N> {
+        let mut res = DualQuat {
+            real: real,
+            dual: dual
+        };
+
+        res.normalize();
+
+        res
+    }
+
+    /// Normalizes this dual quaternion.
+    ///
+    /// The real part is made unit and the dual part orthogonal to it. This is useful to remove
+    /// the numerical drift due to successive compositions.
+    #[inline]
+    pub fn normalize(&mut self) {
+        let _1: N = One::one();
+        let inv_n = _1 / Norm::norm(&self.real);
+
+        self.real = scale(&self.real, inv_n);
+        self.dual = scale(&self.dual, inv_n);
+
+        let proj  = dot(&self.real, &self.dual);
+        self.dual = self.dual - scale(&self.real, proj);
+    }
+
+    /// The rotation part of this rigid-body motion.
+    #[inline]
+    pub fn rotation(&self) -> UnitQuat<N> {
+        unsafe {
+            UnitQuat::new_with_unit_quat(self.real.clone())
+        }
+    }
+
+    /// The translation part of this rigid-body motion.
+    #[inline]
+    pub fn translation(&self) -> Vec3<N> {
+        let _2: N    = num::cast(2.0f64).unwrap();
+        let mut conj = self.real.clone();
+        conj.conjugate();
+
+        let t = self.dual * conj;
+
+        Vec3::new(t.i * _2, t.j * _2, t.k * _2)
+    }
+
+    /// Converts this dual quaternion to an isometry.
+    #[inline]
+    pub fn to_iso(&self) -> Iso3<N> {
+        Iso3::new_with_rotmat(self.translation(), self.rotation().to_rot())
+    }
+
+    /// Screw linear interpolation between two rigid-body motions.
+    ///
+    /// The interpolated motion moves along the screw axis joining `self` to `other`, with
+    /// constant rotational and translational velocities, and following the shortest path.
+    /// Returns `self` if `t == 0` and a dual quaternion representing the same motion as `other`
+    /// if `t == 1`.
+    #[inline]
+    pub fn sclerp(&self, other: &DualQuat<N>, t: N) -> DualQuat<N> {
+        let mut diff = Inv::inv_cpy(self).unwrap() * *other;
+
+        if diff.real.w < Zero::zero() {
+            diff.real = -diff.real;
+            diff.dual = -diff.dual;
+        }
+
+        *self * diff.screw_pow(t)
+    }
+
+    // Raises this dual quaternion to the power `t`, i.e., scales its screw angle and pitch by `t`.
+    fn screw_pow(&self, t: N) -> DualQuat<N> {
+        let _2: N    = num::cast(2.0f64).unwrap();
+        let half_sin = Norm::norm(self.real.vector());
+
+        if half_sin.is_zero() {
+            // pure translation: no screw axis, the translation is interpolated linearly.
+            return DualQuat {
+                real: self.real.clone(),
+                dual: scale(&self.dual, t)
+            }
+        }
+
+        let half_ang = half_sin.atan2(self.real.w);
+        let axis     = Vec3::new(self.real.i / half_sin, self.real.j / half_sin, self.real.k / half_sin);
+
+        // translation along the screw axis.
+        let pitch    = -_2 * self.dual.w / half_sin;
+        // moment of the screw axis.
+        let half_pc  = pitch / _2 * self.real.w;
+        let moment   = Vec3::new(
+            (self.dual.i - axis.x * half_pc) / half_sin,
+            (self.dual.j - axis.y * half_pc) / half_sin,
+            (self.dual.k - axis.z * half_pc) / half_sin);
+
+        let half_ang = half_ang * t;
+        let half_pit = pitch * t / _2;
+        let (s, c)   = half_ang.sin_cos();
+
+        DualQuat {
+            real: Quat::new(c, axis.x * s, axis.y * s, axis.z * s),
+            dual: Quat::new(
+                -half_pit * s,
+                moment.x * s + axis.x * half_pit * c,
+                moment.y * s + axis.y * half_pit * c,
+                moment.z * s + axis.z * half_pit * c)
+        }
+    }
+}
+
+impl<N: FloatMath + Clone + Cast<f32>> DualQuat<N> {
+    /// Creates a new unit dual quaternion from an isometry.
+    #[inline]
+    pub fn new_with_iso(iso: &Iso3<N>) -> DualQuat<N> {
+        DualQuat::new(UnitQuat::new_with_rot(&iso.rotation), iso.translation.clone())
+    }
+}
+
+impl<N> DualQuat<N> {
+    /// Creates a new dual quaternion from its real and dual parts.
+    ///
+    /// This is unsafe because the input will not be normalized.
+    #[inline]
+    pub unsafe fn new_with_unit_dual_quat(real: Quat<N>, dual: Quat<N>) -> DualQuat<N> {
+        DualQuat {
+            real: real,
+            dual: dual
+        }
+    }
+
+    /// The real part of this dual quaternion.
+    #[inline]
+    pub fn real<'a>(&'a self) -> &'a Quat<N> {
+        &self.real
+    }
+
+    /// The dual part of this dual quaternion.
+    #[inline]
+    pub fn dual<'a>(&'a self) -> &'a Quat<N> {
+        &self.dual
+    }
+}
+
+impl<N: Num + Clone> One for DualQuat<N> {
+    #[inline]
+    fn one() -> DualQuat<N> {
+        unsafe {
+            DualQuat::new_with_unit_dual_quat(
+                Quat::new(One::one(), Zero::zero(), Zero::zero(), Zero::zero()),
+                Zero::zero())
+        }
+    }
+}
+
+impl<N: Clone + Neg<N>> Inv for DualQuat<N> {
+    #[inline]
+    fn inv_cpy(m: &DualQuat<N>) -> Option<DualQuat<N>> {
+        let mut cpy = m.clone();
+
+        cpy.inv();
+        Some(cpy)
+    }
+
+    #[inline]
+    fn inv(&mut self) -> bool {
+        // the inverse of a unit dual quaternion is its quaternion conjugate.
+        self.real.conjugate();
+        self.dual.conjugate();
+
+        true
+    }
+}
+
+impl<N: Clone + Rand + FloatMath> Rand for DualQuat<N> {
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> DualQuat<N> {
+        DualQuat::new(rng.gen(), rng.gen())
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for DualQuat<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<DualQuat<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<DualQuat<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &DualQuat<N>, b: &DualQuat<N>) -> bool {
+        ApproxEq::approx_eq(&a.real, &b.real) && ApproxEq::approx_eq(&a.dual, &b.dual)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &DualQuat<N>, b: &DualQuat<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.real, &b.real, eps) && ApproxEq::approx_eq_eps(&a.dual, &b.dual, eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &DualQuat<N>, b: &DualQuat<N>, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.real, &b.real, ulps) && ApproxEq::approx_eq_ulps(&a.dual, &b.dual, ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &DualQuat<N>, b: &DualQuat<N>, abs_eps: &N, rel_eps: &N) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.real, &b.real, abs_eps, rel_eps) && ApproxEq::approx_eq_eps_rel(&a.dual, &b.dual, abs_eps, rel_eps)
+    }
+}
+
+impl<N: Num + Clone> DualQuatMulRhs<N, DualQuat<N>> for DualQuat<N> {
+    #[inline]
+    fn binop(left: &DualQuat<N>, right: &DualQuat<N>) -> DualQuat<N> {
+        DualQuat {
+            real: left.real * right.real,
+            dual: left.real * right.dual + left.dual * right.real
+        }
+    }
+}
+
+impl<N: FloatMath + Clone> DualQuatMulRhs<N, Vec3<N>> for Vec3<N> {
+    #[inline]
+    fn binop(left: &DualQuat<N>, right: &Vec3<N>) -> Vec3<N> {
+        left.rotation() * *right
+    }
+}
+
+impl<N: FloatMath + Clone> DualQuatMulRhs<N, Pnt3<N>> for Pnt3<N> {
+    #[inline]
+    fn binop(left: &DualQuat<N>, right: &Pnt3<N>) -> Pnt3<N> {
+        left.rotation() * *right + left.translation()
+    }
+}
+
+impl<N: FloatMath + Clone> Vec3MulRhs<N, Vec3<N>> for DualQuat<N> {
+    #[inline]
+    fn binop(left: &Vec3<N>, right: &DualQuat<N>) -> Vec3<N> {
+        *left * right.rotation()
+    }
+}
+
+impl<N: FloatMath + Clone> Pnt3MulRhs<N, Pnt3<N>> for DualQuat<N> {
+    #[inline]
+    fn binop(left: &Pnt3<N>, right: &DualQuat<N>) -> Pnt3<N> {
+        (*left - right.translation()) * right.rotation()
+    }
+}
+
+impl<N: FloatMath + Clone> Transform<Vec3<N>> for DualQuat<N> {
+    #[inline]
+    fn transform(&self, v: &Vec3<N>) -> Vec3<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_transform(&self, v: &Vec3<N>) -> Vec3<N> {
+        *v * *self
+    }
+}
+
+impl<N: FloatMath + Clone> Transform<Pnt3<N>> for DualQuat<N> {
+    #[inline]
+    fn transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_transform(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        *p * *self
+    }
+}
+
+#[inline]
+fn scale<N: Mul<N, N>>(q: &Quat<N>, s: N) -> Quat<N> {
+    Quat::new(q.w * s, q.i * s, q.j * s, q.k * s)
+}
+
+#[inline]
+fn dot<N: Mul<N, N> + Add<N, N>>(a: &Quat<N>, b: &Quat<N>) -> N {
+    a.w * b.w + a.i * b.i + a.j * b.j + a.k * b.k
+}
+
+double_dispatch_binop_decl_trait!(DualQuat, DualQuatMulRhs)
+mul_redispatch_impl!(DualQuat, DualQuatMulRhs)
@@ -2,17 +2,41 @@
 
 #![allow(missing_doc)] // we hide doc to not have to document the $trhs double dispatch trait.
 
-use std::num::{Zero, One, Float};
-use std::rand::Rand;
+use std::cmp;
+use std::num;
+use std::num::{Zero, One, Float, FloatMath};
+use std::rand::{Rand, Rng};
 use std::rand;
+use std::rand::distributions::{IndependentSample, Range, Normal};
+use std::rand::distributions::range::SampleRange;
 use std::slice::{Items, MutItems};
-use traits::operations::ApproxEq;
+use traits::operations::{ApproxEq, Axpy, AddInPlace, SubInPlace, ScaleInPlace};
 use std::iter::FromIterator;
 use traits::geometry::{Dot, Norm};
-use traits::structure::{Iterable, IterableMut, Indexable};
+use traits::structure::{Iterable, IterableMut, Indexable, Cast};
+use structs::strided::{StridedSlice, StridedSliceMut};
+use structs::{pretty, parse, blas, fft};
+use std::from_str::FromStr;
+use std::result;
+use std::fmt::{Show, LowerExp, Formatter, Result};
+#[cfg(feature = "serialize")]
+use serialize::{Encodable, Encoder, Decodable, Decoder};
+
+/// Size of the result of a convolution or a correlation of a signal with `n` components by a
+/// kernel with `m` components.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub enum ConvolutionMode {
+    /// Every output where the signal and the kernel overlap: `n + m - 1` components.
+    FullConvolution,
+    /// The `n` outputs centered with respect to `FullConvolution`.
+    SameConvolution,
+    /// The outputs where the shortest operand overlaps the other one completely:
+    /// `max(n, m) - min(n, m) + 1` components.
+    ValidConvolution
+}
 
 /// Heap allocated, dynamically sized vector.
-#[deriving(Eq, PartialEq, Show, Clone)]
+#[deriving(Eq, PartialEq, Clone)]
 pub struct DVec<N> {
     /// Components of the vector. Contains as much elements as the vector dimension.
     pub at: Vec<N>
@@ -31,6 +55,24 @@ impl<N> DVec<N> {
     }
 }
 
+#[cfg(feature = "serialize")]
+impl<E, S: Encoder<E>, N: Encodable<S, E>> Encodable<S, E> for DVec<N> {
+    fn encode(&self, s: &mut S) -> result::Result<(), E> {
+        s.emit_struct("DVec", 1, |s| s.emit_struct_field("at", 0, |s| self.at.encode(s)))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<E, D: Decoder<E>, N: Decodable<D, E>> Decodable<D, E> for DVec<N> {
+    fn decode(d: &mut D) -> result::Result<DVec<N>, E> {
+        d.read_struct("DVec", 1, |d| {
+            let at = try!(d.read_struct_field("at", 0, |d| Decodable::decode(d)));
+
+            Ok(DVec { at: at })
+        })
+    }
+}
+
 impl<N: Clone> DVec<N> {
     /// Builds a vector filled with a constant.
     #[inline]
@@ -38,6 +80,20 @@ impl<N: Clone> DVec<N> {
         DVec { at: Vec::from_elem(dim, elem) }
     }
 
+    /// Builds a vector filled with a constant, reusing the allocation of `buf`.
+    ///
+    /// The content of `buf` is discarded. No allocation occurs if `buf` has a capacity of at least
+    /// `dim` elements.
+    #[inline]
+    pub fn from_buffer(dim: uint, buf: Vec<N>, elem: N) -> DVec<N> {
+        let mut buf = buf;
+
+        buf.truncate(0);
+        buf.grow(dim, elem);
+
+        DVec { at: buf }
+    }
+
     /// Builds a vector filled with the components provided by a vector.
     ///
     /// The vector must have at least `dim` elements.
@@ -52,11 +108,69 @@ impl<N: Clone> DVec<N> {
 }
 
 impl<N> DVec<N> {
+    /// Builds a vector from its components. This takes ownership of `vec` and is O(1).
+    #[inline]
+    pub fn from_vec(vec: Vec<N>) -> DVec<N> {
+        DVec { at: vec }
+    }
+
+    /// Transforms this vector into a `Vec` of its components. This consumes the vector and is O(1).
+    #[inline]
+    pub fn into_vec(self) -> Vec<N> {
+        self.at
+    }
+
+    /// Gets a reference to the components of this vector.
+    #[inline]
+    pub fn as_vec<'r>(&'r self) -> &'r [N] {
+        self.at.as_slice()
+    }
+
     /// Builds a vector filled with the result of a function.
     #[inline(always)]
     pub fn from_fn(dim: uint, f: |uint| -> N) -> DVec<N> {
         DVec { at: Vec::from_fn(dim, |i| f(i)) }
     }
+
+    /// Appends a component at the end of this vector, increasing its dimension by one.
+    #[inline]
+    pub fn push(&mut self, elem: N) {
+        self.at.push(elem)
+    }
+
+    /// Removes the last component of this vector and returns it, or `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<N> {
+        self.at.pop()
+    }
+
+    /// Appends all the elements yielded by an iterator at the end of this vector.
+    #[inline]
+    pub fn extend<I: Iterator<N>>(&mut self, iter: I) {
+        self.at.extend(iter)
+    }
+
+    /// Shortens this vector to `dim` components.
+    ///
+    /// This does nothing if the vector dimension is already smaller than `dim`.
+    #[inline]
+    pub fn truncate(&mut self, dim: uint) {
+        self.at.truncate(dim)
+    }
+
+    /// Gets a read-only view on `len` components of this vector, starting at `offset` and
+    /// separated by `stride`.
+    #[inline]
+    pub fn strided<'a>(&'a self, offset: uint, stride: uint, len: uint) -> StridedSlice<'a, N> {
+        StridedSlice::new(self.at.as_slice(), offset, stride, len)
+    }
+
+    /// Gets a read-write view on `len` components of this vector, starting at `offset` and
+    /// separated by `stride`.
+    #[inline]
+    pub fn strided_mut<'a>(&'a mut self, offset: uint, stride: uint, len: uint) -> StridedSliceMut<'a, N> {
+        StridedSliceMut::new(self.at.as_mut_slice(), offset, stride, len)
+    }
 }
 
 impl<N> FromIterator<N> for DVec<N> {
@@ -82,6 +196,165 @@ impl<N> Collection for DVec<N> {
 
 dvec_impl!(DVec, DVecMulRhs, DVecDivRhs, DVecAddRhs, DVecSubRhs)
 
+impl<N: Add<N, N> + Mul<N, N> + 'static> Axpy<N> for DVec<N> {
+    #[inline]
+    fn axpy(&mut self, a: &N, x: &DVec<N>) {
+        assert!(self.at.len() == x.at.len(), "Axpy: vector dimensions mismatch.");
+
+        if !blas::axpy(a, x.at.as_slice(), self.at.as_mut_slice()) {
+            for (s, x) in self.at.iter_mut().zip(x.at.iter()) {
+                *s = *s + *x * *a;
+            }
+        }
+    }
+}
+
+impl<N: Add<N, N>> DVec<N> {
+    /// Computes `a + b + c` in a single pass, without allocating the intermediate sum.
+    pub fn add3(a: &DVec<N>, b: &DVec<N>, c: &DVec<N>) -> DVec<N> {
+        assert!(a.at.len() == b.at.len() && a.at.len() == c.at.len(),
+                "Addition of vectors with different dimensions.");
+
+        DVec {
+            at: a.at.iter().zip(b.at.iter()).zip(c.at.iter()).map(|((a, b), c)| *a + *b + *c).collect()
+        }
+    }
+}
+
+impl<N: Add<N, N>> DVec<N> {
+    /// Computes `self + b` and stores the result into `out`, which must already have the right
+    /// dimension.
+    pub fn add_to(&self, b: &DVec<N>, out: &mut DVec<N>) {
+        assert!(self.at.len() == b.at.len() && self.at.len() == out.at.len(),
+                "Addition of vectors with different dimensions.");
+
+        for ((o, a), b) in out.at.iter_mut().zip(self.at.iter()).zip(b.at.iter()) {
+            *o = *a + *b;
+        }
+    }
+}
+
+impl<N: Add<N, N> + Mul<N, N>> DVec<N> {
+    /// Computes `a * x + b * y` in a single pass, without allocating intermediate vectors.
+    pub fn lin_comb(a: &N, x: &DVec<N>, b: &N, y: &DVec<N>) -> DVec<N> {
+        assert!(x.at.len() == y.at.len(), "Linear combination of vectors with different dimensions.");
+
+        DVec {
+            at: x.at.iter().zip(y.at.iter()).map(|(x, y)| *x * *a + *y * *b).collect()
+        }
+    }
+}
+
+impl<N: Clone + Zero + Add<N, N> + Mul<N, N>> DVec<N> {
+    /// The discrete convolution of this signal by `kernel`.
+    ///
+    /// The result is empty if one of the operands is empty.
+    pub fn convolve(&self, kernel: &DVec<N>, mode: ConvolutionMode) -> DVec<N> {
+        let (n, m) = (self.at.len(), kernel.at.len());
+
+        if n == 0 || m == 0 {
+            return DVec::from_vec(Vec::new())
+        }
+
+        let (start, len) = match mode {
+            FullConvolution  => (0, n + m - 1),
+            SameConvolution  => ((m - 1) / 2, n),
+            ValidConvolution => (cmp::min(n, m) - 1, cmp::max(n, m) - cmp::min(n, m) + 1)
+        };
+
+        DVec::from_fn(len, |k| {
+            // component `k + start` of the full convolution.
+            let k          = k + start;
+            let mut acc: N = Zero::zero();
+
+            for i in range(if k >= m { k - m + 1 } else { 0 }, cmp::min(k + 1, n)) {
+                acc = acc + self.at[i] * kernel.at[k - i];
+            }
+
+            acc
+        })
+    }
+
+    /// The discrete cross-correlation of this signal with `kernel`.
+    ///
+    /// This is the convolution by `kernel` reversed. The result is empty if one of the operands
+    /// is empty.
+    pub fn correlate(&self, kernel: &DVec<N>, mode: ConvolutionMode) -> DVec<N> {
+        let reversed = DVec::from_vec(kernel.at.iter().rev().map(|e| e.clone()).collect());
+
+        self.convolve(&reversed, mode)
+    }
+}
+
+impl<N: Float> DVec<N> {
+    /// Builds a vector of `n` evenly spaced values from `a` to `b`, both included.
+    ///
+    /// The result is `[a]` if `n == 1`, and empty if `n == 0`.
+    pub fn linspace(a: N, b: N, n: uint) -> DVec<N> {
+        if n <= 1 {
+            return DVec::from_fn(n, |_| a)
+        }
+
+        let step = (b - a) / num::cast(n - 1).unwrap();
+
+        DVec::from_fn(n, |i| {
+            if i == n - 1 { b } else { a + step * num::cast(i).unwrap() }
+        })
+    }
+}
+
+impl<N: FloatMath> DVec<N> {
+    /// The circular convolution of this signal by `kernel`, which must have the same dimension.
+    ///
+    /// This is the product of the circulant matrix with first column `kernel` by `self`. It is
+    /// computed in `O(n log(n))` using the fast Fourier transform.
+    pub fn circular_convolve(&self, kernel: &DVec<N>) -> DVec<N> {
+        let n = self.at.len();
+        assert!(kernel.at.len() == n, "Circular convolution of vectors with different dimensions.");
+
+        if n == 0 {
+            return DVec::from_vec(Vec::new())
+        }
+
+        // fold the linear convolution, which has `2 * n - 1` components.
+        let full = fft::convolve(self.at.as_slice(), kernel.at.as_slice());
+
+        DVec::from_fn(n, |k| if k + n < full.len() { full[k] + full[k + n] } else { full[k] })
+    }
+}
+
+impl<N: Show> Show for DVec<N> {
+    fn fmt(&self, form: &mut Formatter) -> Result {
+        let strs = pretty::show_strs(form, self.at.as_slice());
+
+        pretty::fmt_vec(form, strs.as_slice())
+    }
+}
+
+impl<N: FromStr> DVec<N> {
+    /// Parses the bracketed list of components of a vector, e.g., `"[1, 2, 3]"`.
+    ///
+    /// Returns a message describing the error if the input is malformed.
+    pub fn parse(s: &str) -> result::Result<DVec<N>, String> {
+        parse::parse_list(s).map(|at| DVec { at: at })
+    }
+}
+
+impl<N: FromStr> FromStr for DVec<N> {
+    #[inline]
+    fn from_str(s: &str) -> Option<DVec<N>> {
+        DVec::parse(s).ok()
+    }
+}
+
+impl<N: LowerExp> LowerExp for DVec<N> {
+    fn fmt(&self, form: &mut Formatter) -> Result {
+        let strs = pretty::exp_strs(form, self.at.as_slice());
+
+        pretty::fmt_vec(form, strs.as_slice())
+    }
+}
+
 /// Stack-allocated, dynamically sized vector with a maximum size of 1.
 pub struct DVec1<N> {
     at:  [N, ..1],
@@ -22,6 +22,12 @@ macro_rules! dvec_impl(
                 $dvec::from_elem(dim, Zero::zero())
             }
 
+            /// Builds a vector filled with zeros. This is the same as `new_zeros`.
+            #[inline]
+            pub fn zeros(dim: uint) -> $dvec<N> {
+                $dvec::new_zeros(dim)
+            }
+
             /// Tests if all components of the vector are zeroes.
             #[inline]
             pub fn is_zero(&self) -> bool {
@@ -106,6 +112,12 @@ macro_rules! dvec_impl(
             pub fn new_ones(dim: uint) -> $dvec<N> {
                 $dvec::from_elem(dim, One::one())
             }
+
+            /// Builds a vector filled with ones. This is the same as `new_ones`.
+            #[inline]
+            pub fn ones(dim: uint) -> $dvec<N> {
+                $dvec::new_ones(dim)
+            }
         }
 
         impl<N: Rand + Zero> $dvec<N> {
@@ -116,6 +128,88 @@ macro_rules! dvec_impl(
             }
         }
 
+        impl<N> $dvec<N> {
+            /// Builds a vector with components sampled independently from the distribution `dist`.
+            #[inline]
+            pub fn new_random_with<R: Rng, D: IndependentSample<N>>(dim: uint, rng: &mut R, dist: &D) -> $dvec<N> {
+                $dvec::from_fn(dim, |_| dist.ind_sample(rng))
+            }
+        }
+
+        impl<N: SampleRange + PartialOrd> $dvec<N> {
+            /// Builds a vector with components uniformly distributed in `[low, high[`.
+            ///
+            /// Fails if `low >= high`.
+            #[inline]
+            pub fn new_uniform<R: Rng>(dim: uint, rng: &mut R, low: N, high: N) -> $dvec<N> {
+                $dvec::new_random_with(dim, rng, &Range::new(low, high))
+            }
+        }
+
+        impl<N: Cast<f64>> $dvec<N> {
+            /// Builds a vector with components following the normal distribution with mean `mean`
+            /// and standard deviation `std_dev`.
+            #[inline]
+            pub fn new_gaussian<R: Rng>(dim: uint, rng: &mut R, mean: f64, std_dev: f64) -> $dvec<N> {
+                let normal = Normal::new(mean, std_dev);
+
+                $dvec::from_fn(dim, |_| Cast::from(normal.ind_sample(rng)))
+            }
+        }
+
+        impl<N: Clone + Float + Cast<f64>> $dvec<N> {
+            /// Builds a random unit vector, uniformly distributed on the unit sphere.
+            ///
+            /// Fails if `dim` is zero.
+            #[inline]
+            pub fn new_random_unit<R: Rng>(dim: uint, rng: &mut R) -> $dvec<N> {
+                assert!(dim != 0, "A vector of dimension zero cannot have a unit norm.");
+
+                // the direction of a vector with independent gaussian components is uniformly
+                // distributed.
+                loop {
+                    let mut res: $dvec<N> = $dvec::new_gaussian(dim, rng, 0.0, 1.0);
+
+                    if !Norm::sqnorm(&res).is_zero() {
+                        let _ = res.normalize();
+
+                        return res
+                    }
+                }
+            }
+        }
+
+        impl<N: Add<N, N>> AddInPlace<$dvec<N>> for $dvec<N> {
+            #[inline]
+            fn add_inplace(&mut self, rhs: &$dvec<N>) {
+                assert!(self.len() == rhs.len(), "Addition of vectors with different dimensions.");
+
+                for (e, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) {
+                    *e = *e + *r;
+                }
+            }
+        }
+
+        impl<N: Sub<N, N>> SubInPlace<$dvec<N>> for $dvec<N> {
+            #[inline]
+            fn sub_inplace(&mut self, rhs: &$dvec<N>) {
+                assert!(self.len() == rhs.len(), "Subtraction of vectors with different dimensions.");
+
+                for (e, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) {
+                    *e = *e - *r;
+                }
+            }
+        }
+
+        impl<N: Mul<N, N>> ScaleInPlace<N> for $dvec<N> {
+            #[inline]
+            fn scale_inplace(&mut self, s: &N) {
+                for e in self.as_mut_slice().iter_mut() {
+                    *e = *e * *s;
+                }
+            }
+        }
+
         impl<N> Iterable<N> for $dvec<N> {
             #[inline]
             fn iter<'l>(&'l self) -> Items<'l, N> {
@@ -207,7 +301,7 @@ macro_rules! dvec_impl(
             }
         }
 
-        impl<N: Num + Clone> Dot<N> for $dvec<N> {
+        impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> Dot<N> for $dvec<N> {
             #[inline]
             fn dot(a: &$dvec<N>, b: &$dvec<N>) -> N {
                 assert!(a.len() == b.len());
@@ -260,6 +354,11 @@ macro_rules! dvec_impl(
                 ApproxEq::approx_epsilon(None::<N>)
             }
 
+            #[inline]
+            fn approx_ulps(_: Option<$dvec<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
             #[inline]
             fn approx_eq(a: &$dvec<N>, b: &$dvec<N>) -> bool {
                 let mut zip = a.as_slice().iter().zip(b.as_slice().iter());
@@ -273,6 +372,20 @@ macro_rules! dvec_impl(
 
                 zip.all(|(a, b)| ApproxEq::approx_eq_eps(a, b, epsilon))
             }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$dvec<N>, b: &$dvec<N>, ulps: u32) -> bool {
+                let mut zip = a.as_slice().iter().zip(b.as_slice().iter());
+
+                zip.all(|(a, b)| ApproxEq::approx_eq_ulps(a, b, ulps))
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$dvec<N>, b: &$dvec<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                let mut zip = a.as_slice().iter().zip(b.as_slice().iter());
+
+                zip.all(|(a, b)| ApproxEq::approx_eq_eps_rel(a, b, abs_eps, rel_eps))
+            }
         }
 
         dvec_scalar_mul_impl!($dvec, f64, $mul)
@@ -287,6 +400,8 @@ macro_rules! dvec_impl(
         dvec_scalar_mul_impl!($dvec, i8, $mul)
         dvec_scalar_mul_impl!($dvec, uint, $mul)
         dvec_scalar_mul_impl!($dvec, int, $mul)
+        left_scalar_mul_impl!($dvec, f64)
+        left_scalar_mul_impl!($dvec, f32)
 
         dvec_scalar_div_impl!($dvec, f64, $div)
         dvec_scalar_div_impl!($dvec, f32, $div)
@@ -484,9 +599,7 @@ macro_rules! small_dvec_from_impl (
                 let mut dim = 0;
 
                 for n in param {
-                    if dim == $dim {
-                        break;
-                    }
+                    assert!(dim != $dim, "The iterator yields too many elements.");
 
                     at[dim] = n;
 
@@ -0,0 +1,109 @@
+//! Fast Fourier transform.
+
+use std::num;
+use std::num::{Zero, One, FloatMath};
+use std::iter::range_step;
+
+/// In-place radix-2 fast Fourier transform of the complex signal `(re, im)`.
+///
+/// The inverse transform includes the normalization by the signal length. The length must be a
+/// power of two.
+pub fn fft<N: FloatMath>(re: &mut [N], im: &mut [N], inverse: bool) {
+    let n = re.len();
+    assert!(im.len() == n && n != 0 && n & (n - 1) == 0, "The signal length must be a power of two.");
+
+    // bit-reversal permutation.
+    let mut j = 0u;
+
+    for i in range(1u, n) {
+        let mut bit = n >> 1;
+
+        while j & bit != 0 {
+            j   = j ^ bit;
+            bit = bit >> 1;
+        }
+
+        j = j ^ bit;
+
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let two_pi: N = Float::two_pi();
+    let mut len   = 2u;
+
+    while len <= n {
+        let lenf: N  = num::cast(len).unwrap();
+        let ang      = if inverse { two_pi / lenf } else { -two_pi / lenf };
+        let (ws, wc) = ang.sin_cos();
+        let half     = len / 2;
+
+        for start in range_step(0u, n, len) {
+            let mut wr: N = One::one();
+            let mut wi: N = Zero::zero();
+
+            for k in range(0u, half) {
+                let (a, b) = (start + k, start + k + half);
+                let vr     = re[b] * wr - im[b] * wi;
+                let vi     = re[b] * wi + im[b] * wr;
+
+                re[b] = re[a] - vr;
+                im[b] = im[a] - vi;
+                re[a] = re[a] + vr;
+                im[a] = im[a] + vi;
+
+                let nwr = wr * wc - wi * ws;
+                wi      = wr * ws + wi * wc;
+                wr      = nwr;
+            }
+        }
+
+        len = len << 1;
+    }
+
+    if inverse {
+        let nf: N = num::cast(n).unwrap();
+
+        for i in range(0u, n) {
+            re[i] = re[i] / nf;
+            im[i] = im[i] / nf;
+        }
+    }
+}
+
+/// Linear convolution of the real signals `a` and `b`, computed in `O(n log(n))`.
+pub fn convolve<N: FloatMath>(a: &[N], b: &[N]) -> Vec<N> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new()
+    }
+
+    let len   = a.len() + b.len() - 1;
+    let mut n = 1u;
+
+    while n < len {
+        n = n << 1;
+    }
+
+    let mut ar: Vec<N> = Vec::from_fn(n, |i| if i < a.len() { a[i] } else { Zero::zero() });
+    let mut ai: Vec<N> = Vec::from_elem(n, Zero::zero());
+    let mut br: Vec<N> = Vec::from_fn(n, |i| if i < b.len() { b[i] } else { Zero::zero() });
+    let mut bi: Vec<N> = Vec::from_elem(n, Zero::zero());
+
+    fft(ar.as_mut_slice(), ai.as_mut_slice(), false);
+    fft(br.as_mut_slice(), bi.as_mut_slice(), false);
+
+    for i in range(0u, n) {
+        let r = ar[i] * br[i] - ai[i] * bi[i];
+        let m = ar[i] * bi[i] + ai[i] * br[i];
+
+        *ar.get_mut(i) = r;
+        *ai.get_mut(i) = m;
+    }
+
+    fft(ar.as_mut_slice(), ai.as_mut_slice(), true);
+    ar.truncate(len);
+
+    ar
+}
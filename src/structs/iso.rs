@@ -3,14 +3,17 @@
 #![allow(missing_doc)]
 
 use std::num::{Zero, One};
+use std::num;
+use std::mem;
+use std::ptr;
 use std::rand::{Rand, Rng};
 use structs::mat::{Mat3, Mat4, Mat5};
 use traits::structure::{Cast, Dim, Col};
 use traits::operations::{Inv, ApproxEq};
 use traits::geometry::{RotationMatrix, Rotation, Rotate, AbsoluteRotate, Transform, Transformation,
-                       Translate, Translation, ToHomogeneous};
+                       Translate, Translation, ToHomogeneous, Norm, Cross, Dot};
 
-use structs::vec::{Vec1, Vec2, Vec3, Vec4};
+use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec6};
 use structs::pnt::{Pnt2, Pnt3, Pnt4, Pnt2MulRhs, Pnt3MulRhs, Pnt4MulRhs};
 use structs::rot::{Rot2, Rot3, Rot4};
 
@@ -79,6 +82,197 @@ impl<N: Clone + Float> Iso3<N> {
         self.rotation.look_at_z(&(*at - *eye), up);
         self.translation = eye.as_vec().clone();
     }
+
+    /// Builds a right-handed view transformation, i.e., the transformation from world-space to
+    /// the local space of a camera located at `eye` and looking at `target`.
+    ///
+    /// This follows the OpenGL convention: the camera looks toward its local `-z` axis.
+    ///
+    /// # Arguments
+    ///   * eye - The position of the camera.
+    ///   * target - The point the camera looks at. It must be different from `eye`.
+    ///   * up - Vector pointing `up`. The only requirement of this parameter is to not be colinear
+    ///   with `target - eye`. Non-colinearity is not checked.
+    pub fn look_at_rh(eye: &Pnt3<N>, target: &Pnt3<N>, up: &Vec3<N>) -> Iso3<N> {
+        let mut rotation: Rot3<N> = One::one();
+
+        rotation.look_at_z(&(*eye - *target), up);
+
+        Inv::inv_cpy(&Iso3::new_with_rotmat(eye.as_vec().clone(), rotation)).unwrap()
+    }
+
+    /// Builds a left-handed view transformation, i.e., the transformation from world-space to
+    /// the local space of a camera located at `eye` and looking at `target`.
+    ///
+    /// This follows the DirectX convention: the camera looks toward its local `z` axis.
+    ///
+    /// # Arguments
+    ///   * eye - The position of the camera.
+    ///   * target - The point the camera looks at. It must be different from `eye`.
+    ///   * up - Vector pointing `up`. The only requirement of this parameter is to not be colinear
+    ///   with `target - eye`. Non-colinearity is not checked.
+    pub fn look_at_lh(eye: &Pnt3<N>, target: &Pnt3<N>, up: &Vec3<N>) -> Iso3<N> {
+        let mut rotation: Rot3<N> = One::one();
+
+        rotation.look_at_z(&(*target - *eye), up);
+
+        Inv::inv_cpy(&Iso3::new_with_rotmat(eye.as_vec().clone(), rotation)).unwrap()
+    }
+}
+
+impl<N: Num + Clone> Iso3<N> {
+    /// The 16 components of the homogeneous matrix of this isometry, in column-major order.
+    ///
+    /// This is the layout expected by graphics APIs like OpenGL.
+    pub fn to_col_major_array(&self) -> [N, ..16] {
+        let m: Mat4<N> = ToHomogeneous::to_homogeneous(self);
+
+        unsafe {
+            let res = ptr::read(m.as_col_major_array());
+            mem::forget(m);
+
+            res
+        }
+    }
+}
+
+impl<N: Clone + FloatMath> Iso3<N> {
+    /// The exponential map of the rigid-body motions group: builds the isometry corresponding
+    /// to the twist `twist`.
+    ///
+    /// The first three components of `twist` are its translational part, and the last three its
+    /// rotational part (a rotation vector, see `Rot3::exp`). This remains accurate for small
+    /// angles.
+    pub fn exp(twist: &Vec6<N>) -> Iso3<N> {
+        let _1: N    = One::one();
+        let _2: N    = _1 + _1;
+        let rho      = Vec3::new(twist.x.clone(), twist.y.clone(), twist.z.clone());
+        let phi      = Vec3::new(twist.w.clone(), twist.a.clone(), twist.b.clone());
+        let theta_sq = Norm::sqnorm(&phi);
+        let eps: N   = Float::epsilon();
+
+        // `b = (1 - cos(theta)) / theta^2` and `c = (theta - sin(theta)) / theta^3`
+        let (b, c) =
+            if theta_sq < eps.sqrt() {
+                let _6: N    = num::cast(6.0f64).unwrap();
+                let _24: N   = num::cast(24.0f64).unwrap();
+                let _120: N  = num::cast(120.0f64).unwrap();
+                let _720: N  = num::cast(720.0f64).unwrap();
+                let _5040: N = num::cast(5040.0f64).unwrap();
+
+                (_1 / _2 - theta_sq / _24 + theta_sq * theta_sq / _720,
+                 _1 / _6 - theta_sq / _120 + theta_sq * theta_sq / _5040)
+            }
+            else {
+                let theta    = theta_sq.sqrt();
+                let half_sin = (theta / _2).sin();
+
+                (_2 * half_sin * half_sin / theta_sq, (theta - theta.sin()) / (theta * theta_sq))
+            };
+
+        // t = (I + b * [phi]x + c * [phi]x^2) * rho
+        let c1 = Cross::cross(&phi, &rho);
+        let c2 = Cross::cross(&phi, &c1);
+        let t  = Vec3::new(
+            rho.x + b * c1.x + c * c2.x,
+            rho.y + b * c1.y + c * c2.y,
+            rho.z + b * c1.z + c * c2.z);
+
+        Iso3::new_with_rotmat(t, Rot3::exp(&phi))
+    }
+
+    /// The logarithm map of the rigid-body motions group: computes the twist `w` such that
+    /// `Iso3::exp(&w) == *self`.
+    ///
+    /// See `Iso3::exp` for the layout of the twist.
+    pub fn log(&self) -> Vec6<N> {
+        let _1: N    = One::one();
+        let _2: N    = _1 + _1;
+        let phi      = self.rotation.log();
+        let t        = &self.translation;
+        let theta_sq = Norm::sqnorm(&phi);
+        let eps: N   = Float::epsilon();
+
+        // `d = (1 - (theta / 2) * cot(theta / 2)) / theta^2`
+        let d =
+            if theta_sq < eps.sqrt() {
+                let _12: N    = num::cast(12.0f64).unwrap();
+                let _720: N   = num::cast(720.0f64).unwrap();
+                let _30240: N = num::cast(30240.0f64).unwrap();
+
+                _1 / _12 + theta_sq / _720 + theta_sq * theta_sq / _30240
+            }
+            else {
+                let half_theta     = theta_sq.sqrt() / _2;
+                let (sin_h, cos_h) = half_theta.sin_cos();
+
+                (_1 - half_theta * cos_h / sin_h) / theta_sq
+            };
+
+        // rho = (I - [phi]x / 2 + d * [phi]x^2) * t
+        let c1 = Cross::cross(&phi, t);
+        let c2 = Cross::cross(&phi, &c1);
+
+        Vec6::new(
+            t.x - c1.x / _2 + d * c2.x,
+            t.y - c1.y / _2 + d * c2.y,
+            t.z - c1.z / _2 + d * c2.z,
+            phi.x, phi.y, phi.z)
+    }
+
+    /// Builds the screw motion of axis the line directed by `axis` and passing through `point`.
+    ///
+    /// This is the rotation of angle `angle` around the screw axis, followed by the translation
+    /// of `pitch * angle` along it.
+    pub fn new_with_screw(axis: Vec3<N>, angle: N, pitch: N, point: Pnt3<N>) -> Iso3<N> {
+        let axis     = Norm::normalize_cpy(&axis);
+        let rotation = Rot3::new(Vec3::new(axis.x * angle, axis.y * angle, axis.z * angle));
+        let disp     = pitch * angle;
+        let t        = point.as_vec().clone() - rotation * *point.as_vec();
+
+        Iso3::new_with_rotmat(Vec3::new(t.x + axis.x * disp, t.y + axis.y * disp, t.z + axis.z * disp),
+                              rotation)
+    }
+
+    /// Computes the screw parameters `(axis, angle, pitch, point)` of this isometry.
+    ///
+    /// The returned axis is normalized, the angle is in `]0, pi]`, and the point is the point of
+    /// the screw axis closest to the origin. This is the inverse of `Iso3::new_with_screw`.
+    /// Returns `None` if the rotational part of this isometry is the identity since the pitch
+    /// of a pure translation is infinite.
+    pub fn screw(&self) -> Option<(Vec3<N>, N, N, Pnt3<N>)> {
+        let (axis, angle) = self.rotation.axis_angle();
+
+        if angle.is_zero() {
+            return None
+        }
+
+        let _1: N   = One::one();
+        let _2: N   = _1 + _1;
+        let t       = &self.translation;
+        let disp    = Dot::dot(&axis, t);
+        let t_perp  = Vec3::new(t.x - axis.x * disp, t.y - axis.y * disp, t.z - axis.z * disp);
+        let cot     = _1 / (angle / _2).tan();
+        let cross   = Cross::cross(&axis, &t_perp);
+        let point   = Pnt3::new(
+            (t_perp.x + cot * cross.x) / _2,
+            (t_perp.y + cot * cross.y) / _2,
+            (t_perp.z + cot * cross.z) / _2);
+
+        Some((axis, angle, disp / angle, point))
+    }
+
+    /// Screw linear interpolation between two isometries.
+    ///
+    /// The interpolated motion moves along the screw axis joining `self` to `other`, with
+    /// constant rotational and translational velocities, and following the shortest path.
+    /// Returns `self` if `t == 0` and `other` if `t == 1`.
+    pub fn sclerp(&self, other: &Iso3<N>, t: N) -> Iso3<N> {
+        let delta = (Inv::inv_cpy(self).unwrap() * *other).log();
+
+        *self * Iso3::exp(&Vec6::new(delta.x * t, delta.y * t, delta.z * t,
+                                     delta.w * t, delta.a * t, delta.b * t))
+    }
 }
 
 impl<N> Iso4<N> {
@@ -94,6 +288,7 @@ impl<N> Iso4<N> {
 }
 
 iso_impl!(Iso2, Rot2, Vec2, Vec1)
+rot_to_iso_impl!(Iso2, Rot2, Vec2, Pnt2, Vec1)
 double_dispatch_binop_decl_trait!(Iso2, Iso2MulRhs)
 mul_redispatch_impl!(Iso2, Iso2MulRhs)
 rotation_matrix_impl!(Iso2, Rot2, Vec2, Vec1)
@@ -115,6 +310,7 @@ iso_mul_pnt_impl!(Iso2, Pnt2, Iso2MulRhs)
 pnt_mul_iso_impl!(Iso2, Pnt2, Pnt2MulRhs)
 
 iso_impl!(Iso3, Rot3, Vec3, Vec3)
+rot_to_iso_impl!(Iso3, Rot3, Vec3, Pnt3, Vec3)
 double_dispatch_binop_decl_trait!(Iso3, Iso3MulRhs)
 mul_redispatch_impl!(Iso3, Iso3MulRhs)
 rotation_matrix_impl!(Iso3, Rot3, Vec3, Vec3)
@@ -24,6 +24,40 @@ macro_rules! iso_impl(
     )
 )
 
+macro_rules! rot_to_iso_impl(
+    ($t: ident, $trot: ident, $tv: ident, $tp: ident, $tav: ident) => (
+        impl<N: Clone + FloatMath + Num> $trot<N> {
+            /// Appends a translation to this rotation.
+            ///
+            /// The result is the isometry applying `self` first, followed by the translation `t`.
+            #[inline]
+            pub fn append_translation_to_iso(&self, t: &$tv<N>) -> $t<N> {
+                $t::new_with_rotmat(t.clone(), self.clone())
+            }
+
+            /// Prepends a translation to this rotation.
+            ///
+            /// The result is the isometry applying the translation `t` first, followed by `self`.
+            #[inline]
+            pub fn prepend_translation_to_iso(&self, t: &$tv<N>) -> $t<N> {
+                $t::new_with_rotmat(*self * *t, self.clone())
+            }
+
+            /// Appends a rotation centered on `center` to this rotation.
+            ///
+            /// The result is an isometry since the center of rotation is not necessarily the
+            /// origin.
+            #[inline]
+            pub fn append_rotation_wrt_point_to_iso(&self, amount: &$tav<N>, center: &$tp<N>) -> $t<N> {
+                let rot = $trot::new(amount.clone());
+                let c   = center.as_vec();
+
+                $t::new_with_rotmat(*c - rot * *c, rot * *self)
+            }
+        }
+    )
+)
+
 macro_rules! rotation_matrix_impl(
     ($t: ident, $trot: ident, $tlv: ident, $tav: ident) => (
         impl<N: Cast<f32> + FloatMath + Num + Clone>
@@ -276,6 +310,18 @@ macro_rules! transform_impl(
             }
         }
 
+        impl<N: Num + Clone> $trhs<N> for $tv<N> {
+            #[inline]
+            fn transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                t.rotation.rotate(v)
+            }
+
+            #[inline]
+            fn inv_transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                t.rotation.inv_rotate(v)
+            }
+        }
+
         impl<N: Num + Clone> $trhs<N> for $tp<N> {
             #[inline]
             fn transform(t: &$t<N>, p: &$tp<N>) -> $tp<N> {
@@ -340,6 +386,11 @@ macro_rules! approx_eq_impl(
                 ApproxEq::approx_epsilon(None::<N>)
             }
 
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
             #[inline]
             fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
                 ApproxEq::approx_eq(&a.rotation, &b.rotation) &&
@@ -351,6 +402,18 @@ macro_rules! approx_eq_impl(
                 ApproxEq::approx_eq_eps(&a.rotation, &b.rotation, epsilon) &&
                     ApproxEq::approx_eq_eps(&a.translation, &b.translation, epsilon)
             }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.rotation, &b.rotation, ulps) &&
+                    ApproxEq::approx_eq_ulps(&a.translation, &b.translation, ulps)
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.rotation, &b.rotation, abs_eps, rel_eps) &&
+                    ApproxEq::approx_eq_eps_rel(&a.translation, &b.translation, abs_eps, rel_eps)
+            }
         }
     )
 )
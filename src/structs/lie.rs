@@ -0,0 +1,367 @@
+//! Elements of the Lie algebras of the 3D rotation and rigid-body motion groups.
+
+use std::num::{Zero, One};
+use std::num;
+use structs::vec::{Vec3, Vec6};
+use structs::mat::{Mat3, Mat4, Mat6};
+use structs::rot::Rot3;
+use structs::iso::Iso3;
+use traits::structure::{Indexable, IterableMut};
+use traits::operations::Transpose;
+use traits::geometry::{Norm, CrossMatrix};
+
+/// An element of `so(3)`, the Lie algebra of the 3D rotation group.
+///
+/// It is represented by a rotation vector: its direction is the rotation axis and its norm the
+/// rotation angle.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct So3<N> {
+    /// The rotation vector.
+    pub vec: Vec3<N>
+}
+
+/// An element of `se(3)`, the Lie algebra of the 3D rigid-body motion group.
+///
+/// It is represented by a twist: its first three components are its translational part `rho`
+/// and its last three its rotational part `phi`. This is the same layout as the one used by
+/// `Iso3::exp`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Se3<N> {
+    /// The twist.
+    pub vec: Vec6<N>
+}
+
+// FIXME: remove this when matrices can be multiplied by a generic scalar.
+#[inline]
+fn scale_by<N: Mul<N, N>, M: IterableMut<N>>(mut m: M, s: &N) -> M {
+    for e in m.iter_mut() {
+        *e = *e * *s
+    }
+
+    m
+}
+
+// Coefficients `((1 - cos(theta)) / theta^2, (theta - sin(theta)) / theta^3)` of the left
+// Jacobian of `SO(3)`.
+fn so3_jacobian_coeffs<N: FloatMath>(theta_sq: N) -> (N, N) {
+    let _1: N  = One::one();
+    let _2: N  = _1 + _1;
+    let eps: N = Float::epsilon();
+
+    if theta_sq < eps.sqrt() {
+        let _6: N    = num::cast(6.0f64).unwrap();
+        let _24: N   = num::cast(24.0f64).unwrap();
+        let _120: N  = num::cast(120.0f64).unwrap();
+        let _720: N  = num::cast(720.0f64).unwrap();
+        let _5040: N = num::cast(5040.0f64).unwrap();
+
+        (_1 / _2 - theta_sq / _24 + theta_sq * theta_sq / _720,
+         _1 / _6 - theta_sq / _120 + theta_sq * theta_sq / _5040)
+    }
+    else {
+        let theta    = theta_sq.sqrt();
+        let half_sin = (theta / _2).sin();
+
+        (_2 * half_sin * half_sin / theta_sq, (theta - theta.sin()) / (theta * theta_sq))
+    }
+}
+
+impl<N: Clone + FloatMath> So3<N> {
+    /// Creates a new element of `so(3)` from a rotation vector.
+    #[inline]
+    pub fn new(vec: Vec3<N>) -> So3<N> {
+        So3 {
+            vec: vec
+        }
+    }
+
+    /// The logarithm of a rotation matrix.
+    #[inline]
+    pub fn log(rot: &Rot3<N>) -> So3<N> {
+        So3::new(rot.log())
+    }
+
+    /// The exponential of this element, i.e., the rotation it represents.
+    #[inline]
+    pub fn exp(&self) -> Rot3<N> {
+        Rot3::exp(&self.vec)
+    }
+
+    /// The skew-symmetric matrix `[w]x` representing this element.
+    #[inline]
+    pub fn hat(&self) -> Mat3<N> {
+        CrossMatrix::cross_matrix(&self.vec)
+    }
+
+    /// Retrieves an element of `so(3)` from its skew-symmetric matrix representation.
+    ///
+    /// Only the lower-triangular part of `m` is read.
+    #[inline]
+    pub fn vee(m: &Mat3<N>) -> So3<N> {
+        So3::new(Vec3::new(m.m32.clone(), -m.m31, m.m21.clone()))
+    }
+
+    /// The left Jacobian of `SO(3)` at this element.
+    ///
+    /// For a small `d`, `exp(w + d)` is approximately `exp(J * d) * exp(w)` where `J` is the left
+    /// Jacobian at `w`.
+    #[inline]
+    pub fn left_jacobian(&self) -> Mat3<N> {
+        let (a, b)      = so3_jacobian_coeffs(Norm::sqnorm(&self.vec));
+        let w           = self.hat();
+        let id: Mat3<N> = One::one();
+
+        id + scale_by(w.clone(), &a) + scale_by(w * w, &b)
+    }
+
+    /// The right Jacobian of `SO(3)` at this element.
+    ///
+    /// For a small `d`, `exp(w + d)` is approximately `exp(w) * exp(J * d)` where `J` is the
+    /// right Jacobian at `w`.
+    #[inline]
+    pub fn right_jacobian(&self) -> Mat3<N> {
+        Transpose::transpose_cpy(&self.left_jacobian())
+    }
+
+    /// The inverse of the left Jacobian of `SO(3)` at this element.
+    ///
+    /// The rotation angle must be smaller than `2 * pi`.
+    #[inline]
+    pub fn left_jacobian_inv(&self) -> Mat3<N> {
+        let _1: N    = One::one();
+        let _2: N    = _1 + _1;
+        let theta_sq = Norm::sqnorm(&self.vec);
+        let eps: N   = Float::epsilon();
+
+        // `d = (1 - (theta / 2) * cot(theta / 2)) / theta^2`
+        let d =
+            if theta_sq < eps.sqrt() {
+                let _12: N    = num::cast(12.0f64).unwrap();
+                let _720: N   = num::cast(720.0f64).unwrap();
+                let _30240: N = num::cast(30240.0f64).unwrap();
+
+                _1 / _12 + theta_sq / _720 + theta_sq * theta_sq / _30240
+            }
+            else {
+                let half_theta     = theta_sq.sqrt() / _2;
+                let (sin_h, cos_h) = half_theta.sin_cos();
+
+                (_1 - half_theta * cos_h / sin_h) / theta_sq
+            };
+
+        let w           = self.hat();
+        let id: Mat3<N> = One::one();
+
+        id - scale_by(w.clone(), &(_1 / _2)) + scale_by(w * w, &d)
+    }
+
+    /// The inverse of the right Jacobian of `SO(3)` at this element.
+    ///
+    /// The rotation angle must be smaller than `2 * pi`.
+    #[inline]
+    pub fn right_jacobian_inv(&self) -> Mat3<N> {
+        Transpose::transpose_cpy(&self.left_jacobian_inv())
+    }
+}
+
+impl<N: Clone + FloatMath> Se3<N> {
+    /// Creates a new element of `se(3)` from a twist.
+    #[inline]
+    pub fn new(vec: Vec6<N>) -> Se3<N> {
+        Se3 {
+            vec: vec
+        }
+    }
+
+    /// Creates a new element of `se(3)` from its translational and rotational parts.
+    #[inline]
+    pub fn new_with_parts(rho: Vec3<N>, phi: Vec3<N>) -> Se3<N> {
+        Se3::new(Vec6::new(rho.x, rho.y, rho.z, phi.x, phi.y, phi.z))
+    }
+
+    /// The logarithm of an isometry.
+    #[inline]
+    pub fn log(iso: &Iso3<N>) -> Se3<N> {
+        Se3::new(iso.log())
+    }
+
+    /// The exponential of this element, i.e., the rigid-body motion it represents.
+    #[inline]
+    pub fn exp(&self) -> Iso3<N> {
+        Iso3::exp(&self.vec)
+    }
+
+    /// The translational part `rho` of this twist.
+    #[inline]
+    pub fn rho(&self) -> Vec3<N> {
+        Vec3::new(self.vec.x.clone(), self.vec.y.clone(), self.vec.z.clone())
+    }
+
+    /// The rotational part `phi` of this twist.
+    #[inline]
+    pub fn phi(&self) -> Vec3<N> {
+        Vec3::new(self.vec.w.clone(), self.vec.a.clone(), self.vec.b.clone())
+    }
+
+    /// The `4x4` matrix representing this element: its upper-left `3x3` block is `[phi]x`, and
+    /// its last column is `rho`.
+    #[inline]
+    pub fn hat(&self) -> Mat4<N> {
+        let w                = So3::new(self.phi()).hat();
+        let mut res: Mat4<N> = Zero::zero();
+
+        for i in range(0u, 3) {
+            for j in range(0u, 3) {
+                res.set((i, j), w.at((i, j)));
+            }
+        }
+
+        res.set((0, 3), self.vec.x.clone());
+        res.set((1, 3), self.vec.y.clone());
+        res.set((2, 3), self.vec.z.clone());
+
+        res
+    }
+
+    /// Retrieves an element of `se(3)` from its `4x4` matrix representation.
+    #[inline]
+    pub fn vee(m: &Mat4<N>) -> Se3<N> {
+        Se3::new(Vec6::new(m.m14.clone(), m.m24.clone(), m.m34.clone(),
+                           m.m32.clone(), -m.m31, m.m21.clone()))
+    }
+
+    /// The left Jacobian of `SE(3)` at this element.
+    ///
+    /// For a small `d`, `exp(xi + d)` is approximately `exp(J * d) * exp(xi)` where `J` is the
+    /// left Jacobian at `xi`.
+    #[inline]
+    pub fn left_jacobian(&self) -> Mat6<N> {
+        let so3 = So3::new(self.phi());
+
+        self.jacobian_with_blocks(&so3.left_jacobian(), &self.q())
+    }
+
+    /// The right Jacobian of `SE(3)` at this element.
+    ///
+    /// For a small `d`, `exp(xi + d)` is approximately `exp(xi) * exp(J * d)` where `J` is the
+    /// right Jacobian at `xi`.
+    #[inline]
+    pub fn right_jacobian(&self) -> Mat6<N> {
+        Se3::new(-self.vec).left_jacobian()
+    }
+
+    /// The inverse of the left Jacobian of `SE(3)` at this element.
+    ///
+    /// The rotation angle must be smaller than `2 * pi`.
+    #[inline]
+    pub fn left_jacobian_inv(&self) -> Mat6<N> {
+        let _1: N = One::one();
+        let jinv  = So3::new(self.phi()).left_jacobian_inv();
+        let q     = jinv * self.q() * jinv;
+
+        self.jacobian_with_blocks(&jinv, &scale_by(q, &-_1))
+    }
+
+    /// The inverse of the right Jacobian of `SE(3)` at this element.
+    ///
+    /// The rotation angle must be smaller than `2 * pi`.
+    #[inline]
+    pub fn right_jacobian_inv(&self) -> Mat6<N> {
+        Se3::new(-self.vec).left_jacobian_inv()
+    }
+
+    // Builds the block upper-triangular matrix `[ j, q ; 0, j ]`.
+    fn jacobian_with_blocks(&self, j: &Mat3<N>, q: &Mat3<N>) -> Mat6<N> {
+        let mut res: Mat6<N> = Zero::zero();
+
+        for i in range(0u, 3) {
+            for k in range(0u, 3) {
+                res.set((i, k), j.at((i, k)));
+                res.set((i + 3, k + 3), j.at((i, k)));
+                res.set((i, k + 3), q.at((i, k)));
+            }
+        }
+
+        res
+    }
+
+    // The upper-right block of the left Jacobian of `SE(3)`.
+    fn q(&self) -> Mat3<N> {
+        let _1: N    = One::one();
+        let _2: N    = _1 + _1;
+        let _3: N    = _2 + _1;
+        let phi      = self.phi();
+        let theta_sq = Norm::sqnorm(&phi);
+        let eps: N   = Float::epsilon();
+
+        let p = So3::new(phi).hat();
+        let r = So3::new(self.rho()).hat();
+
+        // a = (theta - sin(theta)) / theta^3
+        // b = (theta^2 + 2 cos(theta) - 2) / (2 theta^4)
+        // c = (2 theta - 3 sin(theta) + theta cos(theta)) / (2 theta^5)
+        let (a, b, c) =
+            if theta_sq < eps.cbrt() {
+                let _6: N    = num::cast(6.0f64).unwrap();
+                let _24: N   = num::cast(24.0f64).unwrap();
+                let _120: N  = num::cast(120.0f64).unwrap();
+                let _720: N  = num::cast(720.0f64).unwrap();
+                let _2520: N = num::cast(2520.0f64).unwrap();
+
+                (_1 / _6 - theta_sq / _120,
+                 _1 / _24 - theta_sq / _720,
+                 _1 / _120 - theta_sq / _2520)
+            }
+            else {
+                let theta  = theta_sq.sqrt();
+                let (s, c) = theta.sin_cos();
+
+                ((theta - s) / (theta * theta_sq),
+                 (theta_sq + _2 * c - _2) / (_2 * theta_sq * theta_sq),
+                 (_2 * theta - _3 * s + theta * c) / (_2 * theta * theta_sq * theta_sq))
+            };
+
+        let pr  = p * r;
+        let rp  = r * p;
+        let prp = pr * p;
+
+        scale_by(r.clone(), &(_1 / _2)) +
+        scale_by(pr + rp + prp, &a) +
+        scale_by(p * pr + rp * p - scale_by(prp, &_3), &b) +
+        scale_by(prp * p + p * prp, &c)
+    }
+}
+
+impl<N: Clone + Num> Rot3<N> {
+    /// The adjoint matrix of this rotation.
+    ///
+    /// This is the matrix `Ad` such that `r * exp(w) * r^-1 == exp(Ad * w)`. For rotations, this
+    /// is the rotation matrix itself.
+    #[inline]
+    pub fn adjoint(&self) -> Mat3<N> {
+        self.submat().clone()
+    }
+}
+
+impl<N: Clone + Num> Iso3<N> {
+    /// The adjoint matrix of this isometry.
+    ///
+    /// This is the matrix `Ad` such that `m * exp(xi) * m^-1 == exp(Ad * xi)`. With `r` the
+    /// rotation and `t` the translation of `self`, this is `[ r, [t]x * r ; 0, r ]`.
+    #[inline]
+    pub fn adjoint(&self) -> Mat6<N> {
+        let r  = self.rotation.submat();
+        let tr = CrossMatrix::cross_matrix(&self.translation) * r.clone();
+        let mut res: Mat6<N> = Zero::zero();
+
+        for i in range(0u, 3) {
+            for j in range(0u, 3) {
+                res.set((i, j), r.at((i, j)));
+                res.set((i + 3, j + 3), r.at((i, j)));
+                res.set((i, j + 3), tr.at((i, j)));
+            }
+        }
+
+        res
+    }
+}
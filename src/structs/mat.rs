@@ -3,18 +3,27 @@
 #![allow(missing_doc)] // we allow missing to avoid having to document the mij components.
 
 use std::mem;
+use std::ptr;
+use std::fmt;
+use std::from_str::FromStr;
+use std::io::{IoResult, MemWriter, BufReader};
 use std::num::{One, Zero};
 use traits::operations::ApproxEq;
 use std::slice::{Items, MutItems};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6,
                    Vec1MulRhs, Vec4MulRhs, Vec5MulRhs, Vec6MulRhs};
-use structs::pnt::{Pnt1, Pnt4, Pnt5, Pnt6, Pnt1MulRhs, Pnt4MulRhs, Pnt5MulRhs, Pnt6MulRhs};
-use structs::dvec::{DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
+use structs::pnt::{Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6, Pnt1MulRhs, Pnt4MulRhs, Pnt5MulRhs, Pnt6MulRhs};
+use structs::dvec::{DVec, DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
+use structs::rot::{Rot2, Rot3};
+use structs::iso::Iso3;
+use structs::{pretty, parse, bareiss, band, predicates};
 
-use traits::structure::{Cast, Row, Col, Iterable, IterableMut, Dim, Indexable,
-                        Eye, ColSlice, RowSlice, Diag};
-use traits::operations::{Absolute, Transpose, Inv, Outer};
-use traits::geometry::{ToHomogeneous, FromHomogeneous, Orig};
+use traits::structure::{Bytes, Cast, Row, Col, Iterable, IterableMut, Dim, StaticDim, Indexable,
+                        Eye, ColSlice, RowSlice, Diag, Band};
+use structs::dim::{D1, D2, D3, D4, D5, D6};
+use traits::operations::{Absolute, Transpose, Inv, Outer, AddInPlace, SubInPlace, ScaleInPlace,
+                         MulInPlace, Gemm, Gemv, Conjugate, ConjTranspose};
+use traits::geometry::{ToHomogeneous, FromHomogeneous, Orig, Transform, Translation};
 
 
 /// Special identity matrix. All its operation are no-ops.
@@ -30,7 +39,7 @@ impl Identity {
 }
 
 /// Square matrix of dimension 1.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat1<N> {
     pub m11: N
 }
@@ -64,6 +73,8 @@ mat_mul_scalar_impl!(Mat1, u16, Mat1MulRhs, m11)
 mat_mul_scalar_impl!(Mat1, u8, Mat1MulRhs, m11)
 mat_mul_scalar_impl!(Mat1, uint, Mat1MulRhs, m11)
 mat_mul_scalar_impl!(Mat1, int, Mat1MulRhs, m11)
+left_scalar_mul_impl!(Mat1, f64)
+left_scalar_mul_impl!(Mat1, f32)
 
 mat_div_scalar_impl!(Mat1, f64, Mat1DivRhs, m11)
 mat_div_scalar_impl!(Mat1, f32, Mat1DivRhs, m11)
@@ -109,7 +120,18 @@ one_impl!(Mat1, One::one)
 iterable_impl!(Mat1, 1)
 iterable_mut_impl!(Mat1, 1)
 at_fast_impl!(Mat1, 1)
+mat_array_conversion_impl!(Mat1, 1)
+col_major_impl!(Mat1, 1)
+det_bareiss_impl!(Mat1, 1)
+mat_from_iter_impl!(Mat1)
+inplace_impl!(Mat1)
+mat_mul_inplace_impl!(Mat1)
+gemm_impl!(Mat1, Vec1)
+mat_show_impl!(Mat1, 1)
+mat_from_str_impl!(Mat1, 1, m11)
+bytes_impl!(Mat1, m11)
 dim_impl!(Mat1, 1)
+static_dim_impl!(Mat1, D1)
 indexable_impl!(Mat1, 1)
 index_impl!(Mat1, Vec1, 1)
 mat_mul_mat_impl!(Mat1, Mat1MulRhs, 1)
@@ -119,18 +141,21 @@ mat_mul_pnt_impl!(Mat1, Pnt1, Mat1MulRhs, 1, Orig::orig)
 pnt_mul_mat_impl!(Mat1, Pnt1, Pnt1MulRhs, 1, Orig::orig)
 // (specialized) inv_impl!(Mat1, 1)
 transpose_impl!(Mat1, 1)
+conj_transpose_impl!(Mat1)
 approx_eq_impl!(Mat1)
 row_impl!(Mat1, Vec1, 1)
 col_impl!(Mat1, Vec1, 1)
 col_slice_impl!(Mat1, Vec1, DVec1, 1)
 row_slice_impl!(Mat1, Vec1, DVec1, 1)
 diag_impl!(Mat1, Vec1, 1)
+band_impl!(Mat1)
+predicates_impl!(Mat1)
 to_homogeneous_impl!(Mat1, Mat2, 1, 2)
 from_homogeneous_impl!(Mat1, Mat2, 1, 2)
 outer_impl!(Vec1, Mat1)
 
 /// Square matrix of dimension 2.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat2<N> {
     pub m11: N, pub m21: N,
     pub m12: N, pub m22: N
@@ -167,6 +192,8 @@ mat_mul_scalar_impl!(Mat2, u16, Mat2MulRhs, m11, m12, m21, m22)
 mat_mul_scalar_impl!(Mat2, u8, Mat2MulRhs, m11, m12, m21, m22)
 mat_mul_scalar_impl!(Mat2, uint, Mat2MulRhs, m11, m12, m21, m22)
 mat_mul_scalar_impl!(Mat2, int, Mat2MulRhs, m11, m12, m21, m22)
+left_scalar_mul_impl!(Mat2, f64)
+left_scalar_mul_impl!(Mat2, f32)
 
 mat_div_scalar_impl!(Mat2, f64, Mat2DivRhs, m11, m12, m21, m22)
 mat_div_scalar_impl!(Mat2, f32, Mat2DivRhs, m11, m12, m21, m22)
@@ -214,26 +241,46 @@ one_impl!(Mat2, One::one,   Zero::zero,
 iterable_impl!(Mat2, 2)
 iterable_mut_impl!(Mat2, 2)
 dim_impl!(Mat2, 2)
+static_dim_impl!(Mat2, D2)
 indexable_impl!(Mat2, 2)
 index_impl!(Mat2, Vec2, 2)
 at_fast_impl!(Mat2, 2)
+mat_array_conversion_impl!(Mat2, 2)
+col_major_impl!(Mat2, 2)
+det_bareiss_impl!(Mat2, 2)
+mat_from_iter_impl!(Mat2)
+inplace_impl!(Mat2)
+mat_mul_inplace_impl!(Mat2)
+gemm_impl!(Mat2, Vec2)
+mat_show_impl!(Mat2, 2)
+mat_from_str_impl!(Mat2, 2,
+  m11, m21,
+  m12, m22
+)
+bytes_impl!(Mat2,
+  m11, m21,
+  m12, m22
+)
 // (specialized) mul_impl!(Mat2, 2)
 // (specialized) rmul_impl!(Mat2, Vec2, 2)
 // (specialized) lmul_impl!(Mat2, Vec2, 2)
 // (specialized) inv_impl!(Mat2, 2)
 transpose_impl!(Mat2, 2)
+conj_transpose_impl!(Mat2)
 approx_eq_impl!(Mat2)
 row_impl!(Mat2, Vec2, 2)
 col_impl!(Mat2, Vec2, 2)
 col_slice_impl!(Mat2, Vec2, DVec2, 2)
 row_slice_impl!(Mat2, Vec2, DVec2, 2)
 diag_impl!(Mat2, Vec2, 2)
+band_impl!(Mat2)
+predicates_impl!(Mat2)
 to_homogeneous_impl!(Mat2, Mat3, 2, 3)
 from_homogeneous_impl!(Mat2, Mat3, 2, 3)
 outer_impl!(Vec2, Mat2)
 
 /// Square matrix of dimension 3.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat3<N> {
     pub m11: N, pub m21: N, pub m31: N,
     pub m12: N, pub m22: N, pub m32: N,
@@ -280,6 +327,8 @@ mat_mul_scalar_impl!(Mat3, u16, Mat3MulRhs, m11, m12, m13, m21, m22, m23, m31, m
 mat_mul_scalar_impl!(Mat3, u8, Mat3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33)
 mat_mul_scalar_impl!(Mat3, uint, Mat3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33)
 mat_mul_scalar_impl!(Mat3, int, Mat3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33)
+left_scalar_mul_impl!(Mat3, f64)
+left_scalar_mul_impl!(Mat3, f32)
 
 mat_div_scalar_impl!(Mat3, f64, Mat3DivRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33)
 mat_div_scalar_impl!(Mat3, f32, Mat3DivRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33)
@@ -331,26 +380,64 @@ one_impl!(Mat3, One::one  , Zero::zero, Zero::zero,
 iterable_impl!(Mat3, 3)
 iterable_mut_impl!(Mat3, 3)
 dim_impl!(Mat3, 3)
+static_dim_impl!(Mat3, D3)
 indexable_impl!(Mat3, 3)
 index_impl!(Mat3, Vec3, 3)
 at_fast_impl!(Mat3, 3)
+mat_array_conversion_impl!(Mat3, 3)
+col_major_impl!(Mat3, 3)
+det_bareiss_impl!(Mat3, 3)
+mat_from_iter_impl!(Mat3)
+inplace_impl!(Mat3)
+mat_mul_inplace_impl!(Mat3)
+gemm_impl!(Mat3, Vec3)
+mat_show_impl!(Mat3, 3)
+mat_from_str_impl!(Mat3, 3,
+  m11, m21, m31,
+  m12, m22, m32,
+  m13, m23, m33
+)
+bytes_impl!(Mat3,
+  m11, m21, m31,
+  m12, m22, m32,
+  m13, m23, m33
+)
 // (specialized) mul_impl!(Mat3, 3)
 // (specialized) rmul_impl!(Mat3, Vec3, 3)
 // (specialized) lmul_impl!(Mat3, Vec3, 3)
 // (specialized) inv_impl!(Mat3, 3)
 transpose_impl!(Mat3, 3)
+conj_transpose_impl!(Mat3)
 approx_eq_impl!(Mat3)
 // (specialized) row_impl!(Mat3, Vec3, 3)
 // (specialized) col_impl!(Mat3, Vec3, 3)
 col_slice_impl!(Mat3, Vec3, DVec3, 3)
 row_slice_impl!(Mat3, Vec3, DVec3, 3)
 diag_impl!(Mat3, Vec3, 3)
+band_impl!(Mat3)
+predicates_impl!(Mat3)
 to_homogeneous_impl!(Mat3, Mat4, 3, 4)
 from_homogeneous_impl!(Mat3, Mat4, 3, 4)
 outer_impl!(Vec3, Mat3)
+mat_homogeneous_transform_impl!(Mat3, Pnt2, Pnt3, Vec2, Vec3)
+mat_homogeneous_translation_impl!(Mat3, Vec2, 3)
+mat_homogeneous_combinators_impl!(Mat3, Vec2, Rot2, Pnt2, 3)
+
+impl<N: Num> Mat3<N> {
+    /// Builds the homogeneous matrix of a 2D shear.
+    ///
+    /// The resulting transformation maps the point `(x, y)` to `(x + xy * y, y + yx * x)`.
+    #[inline]
+    pub fn new_shear(xy: N, yx: N) -> Mat3<N> {
+        Mat3::new(
+            One::one(),   xy,           Zero::zero(),
+            yx,           One::one(),   Zero::zero(),
+            Zero::zero(), Zero::zero(), One::one())
+    }
+}
 
 /// Square matrix of dimension 4.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat4<N> {
     pub m11: N, pub m21: N, pub m31: N, pub m41: N,
     pub m12: N, pub m22: N, pub m32: N, pub m42: N,
@@ -417,6 +504,8 @@ mat_mul_scalar_impl!(Mat4, uint, Mat4MulRhs, m11, m12, m13, m14, m21, m22, m23,
   m41, m42, m43, m44)
 mat_mul_scalar_impl!(Mat4, int, Mat4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34,
   m41, m42, m43, m44)
+left_scalar_mul_impl!(Mat4, f64)
+left_scalar_mul_impl!(Mat4, f32)
 
 mat_div_scalar_impl!(Mat4, f64, Mat4DivRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34,
   m41, m42, m43, m44)
@@ -500,28 +589,82 @@ one_impl!(Mat4, One::one  , Zero::zero, Zero::zero, Zero::zero,
 iterable_impl!(Mat4, 4)
 iterable_mut_impl!(Mat4, 4)
 dim_impl!(Mat4, 4)
+static_dim_impl!(Mat4, D4)
 indexable_impl!(Mat4, 4)
 index_impl!(Mat4, Vec4, 4)
 at_fast_impl!(Mat4, 4)
-mat_mul_mat_impl!(Mat4, Mat4MulRhs, 4)
-mat_mul_vec_impl!(Mat4, Vec4, Mat4MulRhs, 4, Zero::zero)
+mat_array_conversion_impl!(Mat4, 4)
+col_major_impl!(Mat4, 4)
+det_bareiss_impl!(Mat4, 4)
+mat_from_iter_impl!(Mat4)
+inplace_impl!(Mat4)
+mat_mul_inplace_impl!(Mat4)
+gemm_impl!(Mat4, Vec4)
+mat_show_impl!(Mat4, 4)
+mat_from_str_impl!(Mat4, 4,
+  m11, m21, m31, m41,
+  m12, m22, m32, m42,
+  m13, m23, m33, m43,
+  m14, m24, m34, m44
+)
+bytes_impl!(Mat4,
+  m11, m21, m31, m41,
+  m12, m22, m32, m42,
+  m13, m23, m33, m43,
+  m14, m24, m34, m44
+)
 vec_mul_mat_impl!(Mat4, Vec4, Vec4MulRhs, 4, Zero::zero)
 mat_mul_pnt_impl!(Mat4, Pnt4, Mat4MulRhs, 4, Orig::orig)
 pnt_mul_mat_impl!(Mat4, Pnt4, Pnt4MulRhs, 4, Orig::orig)
 inv_impl!(Mat4, 4)
 transpose_impl!(Mat4, 4)
+conj_transpose_impl!(Mat4)
 approx_eq_impl!(Mat4)
 row_impl!(Mat4, Vec4, 4)
 col_impl!(Mat4, Vec4, 4)
 col_slice_impl!(Mat4, Vec4, DVec4, 4)
 row_slice_impl!(Mat4, Vec4, DVec4, 4)
 diag_impl!(Mat4, Vec4, 4)
+band_impl!(Mat4)
+predicates_impl!(Mat4)
 to_homogeneous_impl!(Mat4, Mat5, 4, 5)
 from_homogeneous_impl!(Mat4, Mat5, 4, 5)
 outer_impl!(Vec4, Mat4)
+mat_homogeneous_transform_impl!(Mat4, Pnt3, Pnt4, Vec3, Vec4)
+mat_homogeneous_translation_impl!(Mat4, Vec3, 4)
+mat_homogeneous_combinators_impl!(Mat4, Vec3, Rot3, Pnt3, 4)
+
+impl<N: Num> Mat4<N> {
+    /// Builds the homogeneous matrix of a 3D shear.
+    ///
+    /// The resulting transformation maps the point `(x, y, z)` to
+    /// `(x + xy * y + xz * z, y + yx * x + yz * z, z + zx * x + zy * y)`.
+    #[inline]
+    pub fn new_shear(xy: N, xz: N, yx: N, yz: N, zx: N, zy: N) -> Mat4<N> {
+        Mat4::new(
+            One::one(),   xy,           xz,           Zero::zero(),
+            yx,           One::one(),   yz,           Zero::zero(),
+            zx,           zy,           One::one(),   Zero::zero(),
+            Zero::zero(), Zero::zero(), Zero::zero(), One::one())
+    }
+}
+
+impl<N: Clone + Float> Mat4<N> {
+    /// Builds a right-handed view matrix. See `Iso3::look_at_rh` for details.
+    #[inline]
+    pub fn look_at_rh(eye: &Pnt3<N>, target: &Pnt3<N>, up: &Vec3<N>) -> Mat4<N> {
+        ToHomogeneous::to_homogeneous(&Iso3::look_at_rh(eye, target, up))
+    }
+
+    /// Builds a left-handed view matrix. See `Iso3::look_at_lh` for details.
+    #[inline]
+    pub fn look_at_lh(eye: &Pnt3<N>, target: &Pnt3<N>, up: &Vec3<N>) -> Mat4<N> {
+        ToHomogeneous::to_homogeneous(&Iso3::look_at_lh(eye, target, up))
+    }
+}
 
 /// Square matrix of dimension 5.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat5<N> {
     pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N,
     pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N,
@@ -608,6 +751,8 @@ mat_mul_scalar_impl!(Mat5, uint, Mat5MulRhs, m11, m12, m13, m14, m15, m21, m22,
   m31, m32, m33, m34, m35, m41, m42, m43, m44, m45, m51, m52, m53, m54, m55)
 mat_mul_scalar_impl!(Mat5, int, Mat5MulRhs, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25,
   m31, m32, m33, m34, m35, m41, m42, m43, m44, m45, m51, m52, m53, m54, m55)
+left_scalar_mul_impl!(Mat5, f64)
+left_scalar_mul_impl!(Mat5, f32)
 
 mat_div_scalar_impl!(Mat5, f64, Mat5DivRhs, m11, m12, m13, m14, m15, m21, m22, m23, m24, m25,
   m31, m32, m33, m34, m35, m41, m42, m43, m44, m45, m51, m52, m53, m54, m55)
@@ -687,9 +832,32 @@ mat_sub_scalar_impl!(Mat5, int, Mat5SubRhs, m11, m12, m13, m14, m15, m21, m22, m
 iterable_impl!(Mat5, 5)
 iterable_mut_impl!(Mat5, 5)
 dim_impl!(Mat5, 5)
+static_dim_impl!(Mat5, D5)
 indexable_impl!(Mat5, 5)
 index_impl!(Mat5, Vec5, 5)
 at_fast_impl!(Mat5, 5)
+mat_array_conversion_impl!(Mat5, 5)
+col_major_impl!(Mat5, 5)
+det_bareiss_impl!(Mat5, 5)
+mat_from_iter_impl!(Mat5)
+inplace_impl!(Mat5)
+mat_mul_inplace_impl!(Mat5)
+gemm_impl!(Mat5, Vec5)
+mat_show_impl!(Mat5, 5)
+mat_from_str_impl!(Mat5, 5,
+  m11, m21, m31, m41, m51,
+  m12, m22, m32, m42, m52,
+  m13, m23, m33, m43, m53,
+  m14, m24, m34, m44, m54,
+  m15, m25, m35, m45, m55
+)
+bytes_impl!(Mat5,
+  m11, m21, m31, m41, m51,
+  m12, m22, m32, m42, m52,
+  m13, m23, m33, m43, m53,
+  m14, m24, m34, m44, m54,
+  m15, m25, m35, m45, m55
+)
 mat_mul_mat_impl!(Mat5, Mat5MulRhs, 5)
 mat_mul_vec_impl!(Mat5, Vec5, Mat5MulRhs, 5, Zero::zero)
 vec_mul_mat_impl!(Mat5, Vec5, Vec5MulRhs, 5, Zero::zero)
@@ -697,18 +865,21 @@ mat_mul_pnt_impl!(Mat5, Pnt5, Mat5MulRhs, 5, Orig::orig)
 pnt_mul_mat_impl!(Mat5, Pnt5, Pnt5MulRhs, 5, Orig::orig)
 inv_impl!(Mat5, 5)
 transpose_impl!(Mat5, 5)
+conj_transpose_impl!(Mat5)
 approx_eq_impl!(Mat5)
 row_impl!(Mat5, Vec5, 5)
 col_impl!(Mat5, Vec5, 5)
 col_slice_impl!(Mat5, Vec5, DVec5, 5)
 row_slice_impl!(Mat5, Vec5, DVec5, 5)
 diag_impl!(Mat5, Vec5, 5)
+band_impl!(Mat5)
+predicates_impl!(Mat5)
 to_homogeneous_impl!(Mat5, Mat6, 5, 6)
 from_homogeneous_impl!(Mat5, Mat6, 5, 6)
 outer_impl!(Vec5, Mat5)
 
 /// Square matrix of dimension 6.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Mat6<N> {
     pub m11: N, pub m21: N, pub m31: N, pub m41: N, pub m51: N, pub m61: N,
     pub m12: N, pub m22: N, pub m32: N, pub m42: N, pub m52: N, pub m62: N,
@@ -799,6 +970,8 @@ mat_mul_scalar_impl!(Mat6, uint, Mat6MulRhs, m11, m12, m13, m14, m15, m16, m21,
 mat_mul_scalar_impl!(Mat6, int, Mat6MulRhs, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26,
   m31, m32, m33, m34, m35, m36, m41, m42, m43, m44, m45, m46, m51, m52, m53, m54, m55, m56,
   m61, m62, m63, m64, m65, m66)
+left_scalar_mul_impl!(Mat6, f64)
+left_scalar_mul_impl!(Mat6, f32)
 
 mat_div_scalar_impl!(Mat6, f64, Mat6DivRhs, m11, m12, m13, m14, m15, m16, m21, m22, m23, m24, m25, m26,
   m31, m32, m33, m34, m35, m36, m41, m42, m43, m44, m45, m46, m51, m52, m53, m54, m55, m56,
@@ -926,9 +1099,34 @@ one_impl!(Mat6,
 iterable_impl!(Mat6, 6)
 iterable_mut_impl!(Mat6, 6)
 dim_impl!(Mat6, 6)
+static_dim_impl!(Mat6, D6)
 indexable_impl!(Mat6, 6)
 index_impl!(Mat6, Vec6, 6)
 at_fast_impl!(Mat6, 6)
+mat_array_conversion_impl!(Mat6, 6)
+col_major_impl!(Mat6, 6)
+det_bareiss_impl!(Mat6, 6)
+mat_from_iter_impl!(Mat6)
+inplace_impl!(Mat6)
+mat_mul_inplace_impl!(Mat6)
+gemm_impl!(Mat6, Vec6)
+mat_show_impl!(Mat6, 6)
+mat_from_str_impl!(Mat6, 6,
+  m11, m21, m31, m41, m51, m61,
+  m12, m22, m32, m42, m52, m62,
+  m13, m23, m33, m43, m53, m63,
+  m14, m24, m34, m44, m54, m64,
+  m15, m25, m35, m45, m55, m65,
+  m16, m26, m36, m46, m56, m66
+)
+bytes_impl!(Mat6,
+  m11, m21, m31, m41, m51, m61,
+  m12, m22, m32, m42, m52, m62,
+  m13, m23, m33, m43, m53, m63,
+  m14, m24, m34, m44, m54, m64,
+  m15, m25, m35, m45, m55, m65,
+  m16, m26, m36, m46, m56, m66
+)
 mat_mul_mat_impl!(Mat6, Mat6MulRhs, 6)
 mat_mul_vec_impl!(Mat6, Vec6, Mat6MulRhs, 6, Zero::zero)
 vec_mul_mat_impl!(Mat6, Vec6, Vec6MulRhs, 6, Zero::zero)
@@ -936,10 +1134,13 @@ mat_mul_pnt_impl!(Mat6, Pnt6, Mat6MulRhs, 6, Orig::orig)
 pnt_mul_mat_impl!(Mat6, Pnt6, Pnt6MulRhs, 6, Orig::orig)
 inv_impl!(Mat6, 6)
 transpose_impl!(Mat6, 6)
+conj_transpose_impl!(Mat6)
 approx_eq_impl!(Mat6)
 row_impl!(Mat6, Vec6, 6)
 col_impl!(Mat6, Vec6, 6)
 col_slice_impl!(Mat6, Vec6, DVec6, 6)
 row_slice_impl!(Mat6, Vec6, DVec6, 6)
 diag_impl!(Mat6, Vec6, 6)
+band_impl!(Mat6)
+predicates_impl!(Mat6)
 outer_impl!(Vec6, Mat6)
@@ -10,10 +10,218 @@ macro_rules! mat_impl(
                 $(, $compN: $compN )*
             }
         }
+
+        /// Creates an uninitialized matrix.
+        ///
+        /// Every component must be written before being read.
+        #[inline]
+        pub unsafe fn new_uninitialized() -> $t<N> {
+            mem::uninitialized()
+        }
     }
   )
 )
 
+macro_rules! mat_show_impl(
+    ($t: ident, $dim: expr) => (
+        impl<N: fmt::Show> fmt::Show for $t<N> {
+            /// Writes this matrix with one bracketed row per line and aligned columns.
+            ///
+            /// The width and precision flags are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let elts = unsafe { mem::transmute::<&$t<N>, &[N, ..$dim * $dim]>(self) };
+                let strs = pretty::show_strs(f, elts.as_slice());
+
+                pretty::fmt_mat(f, $dim, $dim, strs.as_slice())
+            }
+        }
+
+        impl<N: fmt::LowerExp> fmt::LowerExp for $t<N> {
+            /// Writes this matrix in scientific notation with one bracketed row per line and
+            /// aligned columns.
+            ///
+            /// The width and precision flags are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let elts = unsafe { mem::transmute::<&$t<N>, &[N, ..$dim * $dim]>(self) };
+                let strs = pretty::exp_strs(f, elts.as_slice());
+
+                pretty::fmt_mat(f, $dim, $dim, strs.as_slice())
+            }
+        }
+    )
+)
+
+macro_rules! mat_from_str_impl(
+    ($t: ident, $dim: expr, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: FromStr> $t<N> {
+            /// Parses the bracketed list of rows of this matrix, e.g., `"[[1, 2], [3, 4]]"`.
+            ///
+            /// Returns a message describing the error if the input is malformed.
+            pub fn parse(s: &str) -> Result<$t<N>, String> {
+                let (nrows, ncols, elts): (uint, uint, Vec<N>) = try!(parse::parse_mat(s));
+
+                if nrows != $dim || ncols != $dim {
+                    return Err(format!("expected a {}x{} matrix, found a {}x{} matrix.", $dim, $dim, nrows, ncols))
+                }
+
+                // the components are in column-major order, like the fields.
+                let mut elts = elts.into_iter();
+
+                Ok($t {
+                    $comp0: elts.next().unwrap()
+                    $(, $compN: elts.next().unwrap() )*
+                })
+            }
+        }
+
+        impl<N: FromStr> FromStr for $t<N> {
+            #[inline]
+            fn from_str(s: &str) -> Option<$t<N>> {
+                $t::parse(s).ok()
+            }
+        }
+    )
+)
+
+macro_rules! mat_array_conversion_impl(
+    ($t: ident, $dim: expr) => (
+        impl<N> $t<N> {
+            /// Creates a new matrix from an array of its columns.
+            #[inline]
+            pub fn from_array(array: [[N, ..$dim], ..$dim]) -> $t<N> {
+                unsafe {
+                    let res = ptr::read(mem::transmute::<&[[N, ..$dim], ..$dim], &$t<N>>(&array));
+                    mem::forget(array);
+
+                    res
+                }
+            }
+
+            /// Converts this matrix to an array of its columns.
+            #[inline]
+            pub fn to_array(self) -> [[N, ..$dim], ..$dim] {
+                unsafe {
+                    let res = ptr::read(mem::transmute::<&$t<N>, &[[N, ..$dim], ..$dim]>(&self));
+                    mem::forget(self);
+
+                    res
+                }
+            }
+
+            /// Reinterprets a reference to an array of columns as a reference to a matrix.
+            #[inline]
+            pub fn from_array_ref<'a>(array: &'a [[N, ..$dim], ..$dim]) -> &'a $t<N> {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Reinterprets a mutable reference to an array of columns as a mutable reference to
+            /// a matrix.
+            #[inline]
+            pub fn from_array_mut<'a>(array: &'a mut [[N, ..$dim], ..$dim]) -> &'a mut $t<N> {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Reinterprets this matrix as an array of its columns.
+            #[inline]
+            pub fn as_array<'a>(&'a self) -> &'a [[N, ..$dim], ..$dim] {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// Reinterprets this matrix as a mutable array of its columns.
+            #[inline]
+            pub fn as_array_mut<'a>(&'a mut self) -> &'a mut [[N, ..$dim], ..$dim] {
+                unsafe { mem::transmute(self) }
+            }
+        }
+    )
+)
+
+macro_rules! col_major_impl(
+    ($t: ident, $dim: expr) => (
+        impl<N> $t<N> {
+            /// The components of this matrix in column-major order.
+            ///
+            /// This is the layout expected by graphics APIs like OpenGL. It is also the storage
+            /// layout of this matrix so no copy is performed.
+            #[inline]
+            pub fn as_col_major_array<'a>(&'a self) -> &'a [N, ..$dim * $dim] {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// A pointer to the components of this matrix in column-major order.
+            ///
+            /// The pointer is valid as long as this matrix is not moved or dropped.
+            #[inline]
+            pub fn as_ptr(&self) -> *const N {
+                self.as_col_major_array().as_ptr()
+            }
+
+            /// A mutable pointer to the components of this matrix in column-major order.
+            ///
+            /// The pointer is valid as long as this matrix is not moved or dropped.
+            #[inline]
+            pub fn as_mut_ptr(&mut self) -> *mut N {
+                unsafe { mem::transmute::<&mut $t<N>, &mut [N, ..$dim * $dim]>(self).as_mut_ptr() }
+            }
+        }
+    )
+)
+
+macro_rules! mat_from_iter_impl(
+    ($t: ident) => (
+        impl<N: Clone + Zero> $t<N> {
+            /// Builds a matrix filled with the components yielded by an iterator in row-major
+            /// order.
+            ///
+            /// Fails if the iterator does not yield exactly as many elements as this matrix has
+            /// components.
+            #[inline]
+            pub fn from_row_iter<I: Iterator<N>>(iter: I) -> $t<N> {
+                let mut res = $t::from_col_iter(iter);
+
+                res.transpose();
+
+                res
+            }
+
+            /// Builds a matrix filled with the components yielded by an iterator in
+            /// column-major order.
+            ///
+            /// Fails if the iterator does not yield exactly as many elements as this matrix has
+            /// components.
+            #[inline]
+            pub fn from_col_iter<I: Iterator<N>>(mut iter: I) -> $t<N> {
+                let mut res: $t<N> = Zero::zero();
+
+                for e in res.iter_mut() {
+                    *e = iter.next().expect("The iterator yields too few elements.");
+                }
+
+                assert!(iter.next().is_none(), "The iterator yields too many elements.");
+
+                res
+            }
+        }
+    )
+)
+
+macro_rules! det_bareiss_impl(
+    ($t: ident, $dim: expr) => (
+        impl<N: Clone + Num> $t<N> {
+            /// The determinant of this matrix computed with the fraction-free Bareiss algorithm.
+            ///
+            /// All the divisions performed are exact, so the result is exact for integer matrices
+            /// as long as the minors of `self` do not overflow.
+            #[inline]
+            pub fn det_bareiss(&self) -> N {
+                let mut m = self.as_col_major_array().as_slice().to_vec();
+
+                bareiss::det(m.as_mut_slice(), $dim)
+            }
+        }
+    )
+)
+
 macro_rules! at_fast_impl(
     ($t: ident, $dim: expr) => (
         impl<N: Clone> $t<N> {
@@ -39,6 +247,14 @@ macro_rules! mat_cast_impl(
             fn to(v: $t<Nin>) -> $t<Nout> {
                 $t::new(Cast::from(v.$comp0.clone()) $(, Cast::from(v.$compN.clone()))*)
             }
+
+            #[inline]
+            fn try_to(v: $t<Nin>) -> Option<$t<Nout>> {
+                match (Cast::try_from(v.$comp0.clone()) $(, Cast::try_from(v.$compN.clone()))*) {
+                    (Some($comp0) $(, Some($compN))*) => Some($t::new($comp0 $(, $compN)*)),
+                    _                                 => None
+                }
+            }
         }
     )
 )
@@ -319,6 +535,64 @@ macro_rules! col_impl(
   )
 )
 
+macro_rules! band_impl(
+    ($t: ident) => (
+        impl<N: Clone + Zero> Band<DVec<N>> for $t<N> {
+            #[inline]
+            fn diag_k(&self, k: int) -> DVec<N> {
+                band::diag_k(self, k)
+            }
+
+            #[inline]
+            fn triu(&self, k: int) -> $t<N> {
+                band::triu(self, k)
+            }
+
+            #[inline]
+            fn tril(&self, k: int) -> $t<N> {
+                band::tril(self, k)
+            }
+        }
+    )
+)
+
+macro_rules! predicates_impl(
+    ($t: ident) => (
+        impl<N: Clone + Zero + One + Add<N, N> + Mul<N, N> + ApproxEq<N>> $t<N> {
+            /// Tests if this matrix is equal to its transpose, up to `eps`.
+            #[inline]
+            pub fn is_symmetric(&self, eps: &N) -> bool {
+                predicates::is_symmetric(self, eps)
+            }
+
+            /// Tests if the transpose of this matrix times this matrix is the identity, up to
+            /// `eps`.
+            #[inline]
+            pub fn is_orthogonal(&self, eps: &N) -> bool {
+                predicates::is_orthogonal(self, eps)
+            }
+
+            /// Tests if every component below the diagonal of this matrix is zero, up to `eps`.
+            #[inline]
+            pub fn is_upper_triangular(&self, eps: &N) -> bool {
+                predicates::is_upper_triangular(self, eps)
+            }
+
+            /// Tests if every off-diagonal component of this matrix is zero, up to `eps`.
+            #[inline]
+            pub fn is_diagonal(&self, eps: &N) -> bool {
+                predicates::is_diagonal(self, eps)
+            }
+
+            /// Tests if this matrix is the identity, up to `eps`.
+            #[inline]
+            pub fn is_identity(&self, eps: &N) -> bool {
+                predicates::is_identity(self, eps)
+            }
+        }
+    )
+)
+
 macro_rules! diag_impl(
     ($t: ident, $tv: ident, $dim: expr) => (
         impl<N: Clone + Zero> Diag<$tv<N>> for $t<N> {
@@ -352,9 +626,46 @@ macro_rules! diag_impl(
     )
 )
 
+macro_rules! mat_mul_inplace_impl(
+  ($t: ident) => (
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> MulInPlace<$t<N>> for $t<N> {
+        #[inline]
+        fn mul_inplace(&mut self, rhs: &$t<N>) {
+            *self = *self * *rhs
+        }
+    }
+  )
+)
+
+macro_rules! gemm_impl(
+  ($t: ident, $tv: ident) => (
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> Gemm<N> for $t<N> {
+        #[inline]
+        fn gemm(&mut self, alpha: &N, a: &$t<N>, b: &$t<N>, beta: &N) {
+            let ab = *a * *b;
+
+            for (e, p) in self.iter_mut().zip(ab.iter()) {
+                *e = *p * *alpha + *e * *beta;
+            }
+        }
+    }
+
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> Gemv<N, $t<N>> for $tv<N> {
+        #[inline]
+        fn gemv(&mut self, alpha: &N, a: &$t<N>, x: &$tv<N>, beta: &N) {
+            let ax = *a * *x;
+
+            for (e, p) in self.iter_mut().zip(ax.iter()) {
+                *e = *p * *alpha + *e * *beta;
+            }
+        }
+    }
+  )
+)
+
 macro_rules! mat_mul_mat_impl(
   ($t: ident, $trhs: ident, $dim: expr) => (
-    impl<N: Clone + Num> $trhs<N, $t<N>> for $t<N> {
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $trhs<N, $t<N>> for $t<N> {
         #[inline]
         fn binop(left: &$t<N>, right: &$t<N>) -> $t<N> {
             // careful! we need to comute other * self here (self is the rhs).
@@ -382,7 +693,7 @@ macro_rules! mat_mul_mat_impl(
 
 macro_rules! vec_mul_mat_impl(
   ($t: ident, $v: ident, $trhs: ident, $dim: expr, $zero: expr) => (
-    impl<N: Clone + Num> $trhs<N, $v<N>> for $t<N> {
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $trhs<N, $v<N>> for $t<N> {
         #[inline]
         fn binop(left: &$v<N>, right: &$t<N>) -> $v<N> {
             let mut res : $v<N> = $zero();
@@ -404,7 +715,7 @@ macro_rules! vec_mul_mat_impl(
 
 macro_rules! mat_mul_vec_impl(
   ($t: ident, $v: ident, $trhs: ident, $dim: expr, $zero: expr) => (
-    impl<N: Clone + Num> $trhs<N, $v<N>> for $v<N> {
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $trhs<N, $v<N>> for $v<N> {
         #[inline]
         fn binop(left: &$t<N>, right: &$v<N>) -> $v<N> {
             let mut res : $v<N> = $zero();
@@ -544,6 +855,30 @@ macro_rules! transpose_impl(
   )
 )
 
+macro_rules! conj_transpose_impl(
+  ($t: ident) => (
+    impl<N: Clone + Conjugate> ConjTranspose for $t<N> {
+        #[inline]
+        fn conj_transpose_cpy(m: &$t<N>) -> $t<N> {
+            let mut res = m.clone();
+
+            res.conj_transpose();
+
+            res
+        }
+
+        #[inline]
+        fn conj_transpose(&mut self) {
+            self.transpose();
+
+            for e in self.iter_mut() {
+                *e = e.conjugate();
+            }
+        }
+    }
+  )
+)
+
 macro_rules! approx_eq_impl(
   ($t: ident) => (
     impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
@@ -552,6 +887,11 @@ macro_rules! approx_eq_impl(
             ApproxEq::approx_epsilon(None::<N>)
         }
 
+        #[inline]
+        fn approx_ulps(_: Option<$t<N>>) -> u32 {
+            ApproxEq::approx_ulps(None::<N>)
+        }
+
         #[inline]
         fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
             let mut zip = a.iter().zip(b.iter());
@@ -565,6 +905,20 @@ macro_rules! approx_eq_impl(
 
             zip.all(|(a, b)| ApproxEq::approx_eq_eps(a, b, epsilon))
         }
+
+        #[inline]
+        fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+            let mut zip = a.iter().zip(b.iter());
+
+            zip.all(|(a, b)| ApproxEq::approx_eq_ulps(a, b, ulps))
+        }
+
+        #[inline]
+        fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+            let mut zip = a.iter().zip(b.iter());
+
+            zip.all(|(a, b)| ApproxEq::approx_eq_eps_rel(a, b, abs_eps, rel_eps))
+        }
     }
   )
 )
@@ -628,3 +982,263 @@ macro_rules! outer_impl(
         }
     )
 )
+
+macro_rules! mat_homogeneous_transform_impl(
+    ($t: ident, $tp: ident, $tph: ident, $tv: ident, $tvh: ident) => (
+        impl<N: Clone + Num + ApproxEq<N>> Transform<$tp<N>> for $t<N> {
+            /// Transforms a point, seeing this matrix as a homogeneous transformation.
+            #[inline]
+            fn transform(&self, p: &$tp<N>) -> $tp<N> {
+                let h: $tph<N> = ToHomogeneous::to_homogeneous(p);
+
+                FromHomogeneous::from(&(*self * h))
+            }
+
+            /// Applies the inverse of this homogeneous transformation to a point.
+            ///
+            /// This fails if the matrix is not invertible.
+            #[inline]
+            fn inv_transform(&self, p: &$tp<N>) -> $tp<N> {
+                Inv::inv_cpy(self).expect("Unable to invert a singular matrix.").transform(p)
+            }
+        }
+
+        impl<N: Clone + Num + ApproxEq<N>> Transform<$tv<N>> for $t<N> {
+            /// Transforms a vector, seeing this matrix as a homogeneous transformation.
+            ///
+            /// The vector is assumed to have a zero homogeneous coordinate, so it is not affected
+            /// by the translational part of the transformation.
+            #[inline]
+            fn transform(&self, v: &$tv<N>) -> $tv<N> {
+                let h: $tvh<N> = ToHomogeneous::to_homogeneous(v);
+
+                FromHomogeneous::from(&(*self * h))
+            }
+
+            /// Applies the inverse of this homogeneous transformation to a vector.
+            ///
+            /// This fails if the matrix is not invertible.
+            #[inline]
+            fn inv_transform(&self, v: &$tv<N>) -> $tv<N> {
+                Inv::inv_cpy(self).expect("Unable to invert a singular matrix.").transform(v)
+            }
+        }
+    )
+)
+
+macro_rules! mat_homogeneous_translation_impl(
+    ($t: ident, $tv: ident, $dim: expr) => (
+        /// The translation of a matrix seen as a homogeneous transformation.
+        ///
+        /// The translation is read from and written to the last column. Appending and prepending
+        /// a translation are done in-place, without building the full translation matrix.
+        impl<N: Clone + Num> Translation<$tv<N>> for $t<N> {
+            #[inline]
+            fn translation(&self) -> $tv<N> {
+                let mut res: $tv<N> = Zero::zero();
+
+                for i in range(0u, $dim - 1) {
+                    res.set(i, self.at((i, $dim - 1)));
+                }
+
+                res
+            }
+
+            #[inline]
+            fn inv_translation(&self) -> $tv<N> {
+                -self.translation()
+            }
+
+            #[inline]
+            fn append_translation(&mut self, t: &$tv<N>) {
+                for i in range(0u, $dim - 1) {
+                    let ti = t.at(i);
+
+                    for j in range(0u, $dim) {
+                        let mij = self.at((i, j)) + ti * self.at(($dim - 1, j));
+                        self.set((i, j), mij);
+                    }
+                }
+            }
+
+            #[inline]
+            fn append_translation_cpy(m: &$t<N>, t: &$tv<N>) -> $t<N> {
+                let mut res = m.clone();
+
+                res.append_translation(t);
+
+                res
+            }
+
+            #[inline]
+            fn prepend_translation(&mut self, t: &$tv<N>) {
+                for i in range(0u, $dim) {
+                    let mut mi = self.at((i, $dim - 1));
+
+                    for j in range(0u, $dim - 1) {
+                        mi = mi + self.at((i, j)) * t.at(j);
+                    }
+
+                    self.set((i, $dim - 1), mi);
+                }
+            }
+
+            #[inline]
+            fn prepend_translation_cpy(m: &$t<N>, t: &$tv<N>) -> $t<N> {
+                let mut res = m.clone();
+
+                res.prepend_translation(t);
+
+                res
+            }
+
+            #[inline]
+            fn set_translation(&mut self, t: $tv<N>) {
+                for i in range(0u, $dim - 1) {
+                    self.set((i, $dim - 1), t.at(i));
+                }
+            }
+        }
+    )
+)
+
+macro_rules! mat_homogeneous_combinators_impl(
+    ($t: ident, $tv: ident, $trot: ident, $tp: ident, $dim: expr) => (
+        impl<N: Clone + Num> $t<N> {
+            /// Appends a uniform scaling to this matrix seen as a homogeneous transformation.
+            ///
+            /// This is equivalent to, but faster than, multiplying `self` by the scaling matrix
+            /// on the left.
+            #[inline]
+            pub fn append_scaling(&mut self, s: &N) {
+                for i in range(0u, $dim - 1) {
+                    for j in range(0u, $dim) {
+                        let mij = self.at((i, j)) * *s;
+                        self.set((i, j), mij);
+                    }
+                }
+            }
+
+            /// Appends a uniform scaling to a copy of `m`.
+            #[inline]
+            pub fn append_scaling_cpy(m: &$t<N>, s: &N) -> $t<N> {
+                let mut res = m.clone();
+
+                res.append_scaling(s);
+
+                res
+            }
+
+            /// Prepends a uniform scaling to this matrix seen as a homogeneous transformation.
+            ///
+            /// This is equivalent to, but faster than, multiplying `self` by the scaling matrix
+            /// on the right.
+            #[inline]
+            pub fn prepend_scaling(&mut self, s: &N) {
+                for i in range(0u, $dim) {
+                    for j in range(0u, $dim - 1) {
+                        let mij = self.at((i, j)) * *s;
+                        self.set((i, j), mij);
+                    }
+                }
+            }
+
+            /// Prepends a uniform scaling to a copy of `m`.
+            #[inline]
+            pub fn prepend_scaling_cpy(m: &$t<N>, s: &N) -> $t<N> {
+                let mut res = m.clone();
+
+                res.prepend_scaling(s);
+
+                res
+            }
+
+            /// Appends a non-uniform scaling to this matrix seen as a homogeneous transformation.
+            ///
+            /// The `i`-th component of `s` is the scale factor along the `i`-th axis.
+            #[inline]
+            pub fn append_nonuniform_scaling(&mut self, s: &$tv<N>) {
+                for i in range(0u, $dim - 1) {
+                    let si = s.at(i);
+
+                    for j in range(0u, $dim) {
+                        let mij = self.at((i, j)) * si;
+                        self.set((i, j), mij);
+                    }
+                }
+            }
+
+            /// Appends a non-uniform scaling to a copy of `m`.
+            #[inline]
+            pub fn append_nonuniform_scaling_cpy(m: &$t<N>, s: &$tv<N>) -> $t<N> {
+                let mut res = m.clone();
+
+                res.append_nonuniform_scaling(s);
+
+                res
+            }
+
+            /// Prepends a non-uniform scaling to this matrix seen as a homogeneous transformation.
+            ///
+            /// The `i`-th component of `s` is the scale factor along the `i`-th axis.
+            #[inline]
+            pub fn prepend_nonuniform_scaling(&mut self, s: &$tv<N>) {
+                for j in range(0u, $dim - 1) {
+                    let sj = s.at(j);
+
+                    for i in range(0u, $dim) {
+                        let mij = self.at((i, j)) * sj;
+                        self.set((i, j), mij);
+                    }
+                }
+            }
+
+            /// Prepends a non-uniform scaling to a copy of `m`.
+            #[inline]
+            pub fn prepend_nonuniform_scaling_cpy(m: &$t<N>, s: &$tv<N>) -> $t<N> {
+                let mut res = m.clone();
+
+                res.prepend_nonuniform_scaling(s);
+
+                res
+            }
+
+            /// Appends a rotation centered on `center` to this matrix seen as a homogeneous
+            /// transformation.
+            ///
+            /// Only the rows affected by the rotation are updated.
+            #[inline]
+            pub fn append_rotation_wrt_point(&mut self, rot: &$trot<N>, center: &$tp<N>) {
+                self.append_translation(&-*center.as_vec());
+
+                let r = rot.submat();
+
+                for j in range(0u, $dim) {
+                    let mut col: $tv<N> = Zero::zero();
+
+                    for i in range(0u, $dim - 1) {
+                        col.set(i, self.at((i, j)));
+                    }
+
+                    let col = *r * col;
+
+                    for i in range(0u, $dim - 1) {
+                        self.set((i, j), col.at(i));
+                    }
+                }
+
+                self.append_translation(center.as_vec());
+            }
+
+            /// Appends a rotation centered on `center` to a copy of `m`.
+            #[inline]
+            pub fn append_rotation_wrt_point_cpy(m: &$t<N>, rot: &$trot<N>, center: &$tp<N>) -> $t<N> {
+                let mut res = m.clone();
+
+                res.append_rotation_wrt_point(rot, center);
+
+                res
+            }
+        }
+    )
+)
@@ -31,6 +31,19 @@ macro_rules! mul_redispatch_impl(
     )
 )
 
+// Macro implementing the multiplication by a primitive scalar on the left-hand side, e.g.,
+// `2.0f64 * v`, using the multiplication by a scalar on the right-hand side.
+macro_rules! left_scalar_mul_impl(
+    ($t: ident, $n: ident) => (
+        impl Mul<$t<$n>, $t<$n>> for $n {
+            #[inline]
+            fn mul(&self, right: &$t<$n>) -> $t<$n> {
+                *right * *self
+            }
+        }
+    )
+)
+
 // Macro driving the `Div` trait to use the related trait for double redispatch.
 macro_rules! div_redispatch_impl(
     ($t: ident, $trhs: ident) => (
@@ -72,6 +85,7 @@ macro_rules! double_dispatch_cast_decl_trait(
     ($t: ident, $tcast: ident) => (
         pub trait $tcast<N> {
             fn to(Self) -> $t<N>;
+            fn try_to(Self) -> Option<$t<N>>;
         }
     )
 )
@@ -83,6 +97,11 @@ macro_rules! cast_redispatch_impl(
             fn from(t: T) -> $t<N> {
                 $tcast::to(t)
             }
+
+            #[inline(always)]
+            fn try_from(t: T) -> Option<$t<N>> {
+                $tcast::try_to(t)
+            }
         }
     )
 )
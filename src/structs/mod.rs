@@ -1,15 +1,37 @@
 //! Data structures and implementations.
 
 pub use self::dmat::DMat;
+pub use self::dvec::{ConvolutionMode, FullConvolution, SameConvolution, ValidConvolution};
 pub use self::dvec::{DVec, DVec1, DVec2, DVec3, DVec4, DVec5, DVec6};
+pub use self::strided::{StridedSlice, StridedSliceMut, StridedItems};
 pub use self::vec::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
 pub use self::pnt::{Pnt0, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6};
 pub use self::mat::{Identity, Mat1, Mat2, Mat3, Mat4, Mat5, Mat6};
-pub use self::rot::{Rot2, Rot3, Rot4};
+pub use self::rect_mat::{Mat2x3, Mat3x2, Mat3x4, Mat4x3};
+pub use self::rot::{Rot2, Rot3, Rot4, EulerConvention, EulerXYZ, EulerZYX, EulerZXZ};
 pub use self::iso::{Iso2, Iso3, Iso4};
+pub use self::sim::{Sim2, Sim3};
+pub use self::scale::{Scale2, Scale3};
+pub use self::reflection::Reflection;
+pub use self::proj::{Proj2, Proj3};
 pub use self::persp::{Persp3, PerspMat3};
 pub use self::ortho::{Ortho3, OrthoMat3};
 pub use self::quat::{Quat, UnitQuat};
+pub use self::dual_quat::DualQuat;
+pub use self::unit_complex::UnitComplex;
+pub use self::barycenter::Barycenter;
+pub use self::running_stats::RunningStats;
+pub use self::multivariate_normal::MultivariateNormal;
+pub use self::lie::{So3, Se3};
+pub use self::ordered::Ordered;
+pub use self::dim::{D0, D1, D2, D3, D4, D5, D6};
+
+#[cfg(feature = "arbitrary")]
+pub use self::arbitrary::Invertible;
+#[cfg(feature = "simd")]
+pub use self::simd::{SimdVec4, SimdMat4};
+#[cfg(feature = "dual")]
+pub use self::dual::Dual;
 
 pub use self::vec::{Vec1MulRhs, Vec2MulRhs, Vec3MulRhs, Vec4MulRhs, Vec5MulRhs, Vec6MulRhs,
                     Vec1DivRhs, Vec2DivRhs, Vec3DivRhs, Vec4DivRhs, Vec5DivRhs, Vec6DivRhs,
@@ -23,9 +45,21 @@ pub use self::mat::{Mat1MulRhs, Mat2MulRhs, Mat3MulRhs, Mat4MulRhs, Mat5MulRhs,
                     Mat1DivRhs, Mat2DivRhs, Mat3DivRhs, Mat4DivRhs, Mat5DivRhs, Mat6DivRhs,
                     Mat1AddRhs, Mat2AddRhs, Mat3AddRhs, Mat4AddRhs, Mat5AddRhs, Mat6AddRhs,
                     Mat1SubRhs, Mat2SubRhs, Mat3SubRhs, Mat4SubRhs, Mat5SubRhs, Mat6SubRhs};
+pub use self::rect_mat::{Mat2x3MulRhs, Mat3x2MulRhs, Mat3x4MulRhs, Mat4x3MulRhs,
+                         Mat2x3AddRhs, Mat3x2AddRhs, Mat3x4AddRhs, Mat4x3AddRhs,
+                         Mat2x3SubRhs, Mat3x2SubRhs, Mat3x4SubRhs, Mat4x3SubRhs};
 
 mod metal;
+mod dim;
+mod blas;
+mod pretty;
+mod parse;
+mod bareiss;
+mod band;
+mod fft;
+mod predicates;
 mod dmat;
+mod strided;
 mod dvec_macros;
 mod dvec;
 mod vec_macros;
@@ -33,14 +67,37 @@ mod vec;
 mod pnt_macros;
 mod pnt;
 mod quat;
+mod dual_quat;
+mod unit_complex;
 mod mat_macros;
 mod mat;
+mod rect_mat_macros;
+mod rect_mat;
 mod rot_macros;
 mod rot;
 mod iso_macros;
 mod iso;
+mod sim_macros;
+mod sim;
+mod scale_macros;
+mod scale;
+mod reflection_macros;
+mod reflection;
+mod proj_macros;
+mod proj;
 mod persp;
 mod ortho;
+mod barycenter;
+mod running_stats;
+mod multivariate_normal;
+mod lie;
+mod ordered;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "dual")]
+mod dual;
 
 // specialization for some 1d, 2d and 3d operations
 #[doc(hidden)]
@@ -50,5 +107,6 @@ mod spec {
     mod vec0;
     mod vec;
     mod primitives;
+    mod transform_all;
     // mod complex;
 }
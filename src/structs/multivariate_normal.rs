@@ -0,0 +1,80 @@
+//! Multivariate normal distribution.
+
+use std::rand::Rng;
+use std::rand::distributions::{Sample, IndependentSample, Normal};
+use traits::structure::{Cast, Indexable};
+use linalg::cholesky;
+
+/// The multivariate normal distribution with mean `V` and covariance `M`.
+///
+/// Samples are computed as `mean + l * z` where `l` is the Cholesky factor of the covariance and
+/// `z` a vector of independent standard normal components. This works with, e.g.,
+/// `Vec3`/`Mat3` as well as `DVec`/`DMat`.
+#[deriving(Clone, Show)]
+pub struct MultivariateNormal<V, M> {
+    mean: V,
+    chol: M
+}
+
+impl<N: Float + Cast<f64>,
+     V: Clone + Indexable<uint, N>,
+     M: Clone + Indexable<(uint, uint), N>> MultivariateNormal<V, M> {
+    /// Creates a new multivariate normal distribution.
+    ///
+    /// Returns `None` if the covariance `cov` is not symmetric positive-definite. Fails if the
+    /// dimensions of `mean` and `cov` do not match.
+    pub fn new(mean: V, cov: &M) -> Option<MultivariateNormal<V, M>> {
+        let (nrows, ncols) = cov.shape();
+        assert!(mean.shape() == nrows && nrows == ncols, "Incompatible mean and covariance dimensions.");
+
+        cholesky(cov).map(|chol| MultivariateNormal { mean: mean, chol: chol })
+    }
+
+    /// The mean of this distribution.
+    #[inline]
+    pub fn mean(&self) -> &V {
+        &self.mean
+    }
+
+    /// The lower-triangular Cholesky factor of the covariance of this distribution.
+    #[inline]
+    pub fn chol(&self) -> &M {
+        &self.chol
+    }
+}
+
+impl<N: Float + Cast<f64>,
+     V: Clone + Indexable<uint, N>,
+     M: Clone + Indexable<(uint, uint), N>> IndependentSample<V> for MultivariateNormal<V, M> {
+    fn ind_sample<R: Rng>(&self, rng: &mut R) -> V {
+        let normal  = Normal::new(0.0, 1.0);
+        let dim     = self.mean.shape();
+        let mut z   = self.mean.clone();
+        let mut res = self.mean.clone();
+
+        for i in range(0u, dim) {
+            z.set(i, Cast::from(normal.ind_sample(rng)));
+        }
+
+        for i in range(0u, dim) {
+            let mut ri = res.at(i);
+
+            for k in range(0u, i + 1) {
+                ri = ri + self.chol.at((i, k)) * z.at(k);
+            }
+
+            res.set(i, ri);
+        }
+
+        res
+    }
+}
+
+impl<N: Float + Cast<f64>,
+     V: Clone + Indexable<uint, N>,
+     M: Clone + Indexable<(uint, uint), N>> Sample<V> for MultivariateNormal<V, M> {
+    #[inline]
+    fn sample<R: Rng>(&mut self, rng: &mut R) -> V {
+        self.ind_sample(rng)
+    }
+}
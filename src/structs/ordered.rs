@@ -0,0 +1,100 @@
+//! Total order over vectors and points.
+
+use structs::{DVec, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6};
+
+/// Wrapper giving a total lexicographic order to a vector or a point.
+///
+/// This allows vectors and points to be used as keys of a `TreeMap` or to be sorted. The
+/// components are compared one after the other, starting with the first one. Components that
+/// compare equal (including `0.0` and `-0.0`) are skipped. A `NaN` component is considered equal
+/// to another `NaN`, and greater than any other value.
+///
+/// Components that are neither equal nor ordered without being `NaN` make the vectors themselves
+/// incomparable: `partial_cmp` then returns `None`, and `cmp` fails. Thus, the order is total only
+/// for components totally ordered except for `NaN`, e.g., all primitive numeric types.
+#[deriving(Clone, Show)]
+pub struct Ordered<V>(pub V);
+
+impl<V> Ordered<V> {
+    /// Unwraps the vector or point.
+    #[inline]
+    pub fn unwrap(self) -> V {
+        let Ordered(v) = self;
+
+        v
+    }
+}
+
+// Compares two components with the `NaN` policy of `Ordered`. Returns `None` if they are not
+// comparable while none of them is `NaN`.
+#[inline]
+fn cmp_component<N: PartialOrd>(a: &N, b: &N) -> Option<Ordering> {
+    match a.partial_cmp(b) {
+        Some(ord) => Some(ord),
+        None      => {
+            // a NaN is the only value not equal to itself.
+            match (a != a, b != b) {
+                (true, true)   => Some(Equal),
+                (true, false)  => Some(Greater),
+                (false, true)  => Some(Less),
+                (false, false) => None
+            }
+        }
+    }
+}
+
+// Lexicographic comparison with the `NaN` policy of `Ordered`. A shorter slice is smaller than
+// any longer slice it is a prefix of.
+fn cmp_lexicographic<N: PartialOrd>(a: &[N], b: &[N]) -> Option<Ordering> {
+    for (ea, eb) in a.iter().zip(b.iter()) {
+        match cmp_component(ea, eb) {
+            Some(Equal) => { },
+            ord         => return ord
+        }
+    }
+
+    Some(a.len().cmp(&b.len()))
+}
+
+macro_rules! ordered_impl(
+    ($t: ident) => (
+        impl<N: PartialOrd> PartialEq for Ordered<$t<N>> {
+            #[inline]
+            fn eq(&self, other: &Ordered<$t<N>>) -> bool {
+                self.partial_cmp(other) == Some(Equal)
+            }
+        }
+
+        impl<N: PartialOrd> Eq for Ordered<$t<N>> { }
+
+        impl<N: PartialOrd> PartialOrd for Ordered<$t<N>> {
+            #[inline]
+            fn partial_cmp(&self, other: &Ordered<$t<N>>) -> Option<Ordering> {
+                let (&Ordered(ref a), &Ordered(ref b)) = (self, other);
+
+                cmp_lexicographic(a.as_slice(), b.as_slice())
+            }
+        }
+
+        impl<N: PartialOrd> Ord for Ordered<$t<N>> {
+            #[inline]
+            fn cmp(&self, other: &Ordered<$t<N>>) -> Ordering {
+                self.partial_cmp(other).expect("Unable to order vectors with incomparable components.")
+            }
+        }
+    )
+)
+
+ordered_impl!(Vec1)
+ordered_impl!(Vec2)
+ordered_impl!(Vec3)
+ordered_impl!(Vec4)
+ordered_impl!(Vec5)
+ordered_impl!(Vec6)
+ordered_impl!(Pnt1)
+ordered_impl!(Pnt2)
+ordered_impl!(Pnt3)
+ordered_impl!(Pnt4)
+ordered_impl!(Pnt5)
+ordered_impl!(Pnt6)
+ordered_impl!(DVec)
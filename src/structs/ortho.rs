@@ -72,6 +72,30 @@ impl<N: FloatMath + Clone> Ortho3<N> {
         self.zfar.clone()
     }
 
+    /// The left offset of the view cuboid.
+    #[inline]
+    pub fn left(&self) -> N {
+        -self.right()
+    }
+
+    /// The right offset of the view cuboid.
+    #[inline]
+    pub fn right(&self) -> N {
+        self.width / num::cast::<f64, N>(2.0).unwrap()
+    }
+
+    /// The bottom offset of the view cuboid.
+    #[inline]
+    pub fn bottom(&self) -> N {
+        -self.top()
+    }
+
+    /// The top offset of the view cuboid.
+    #[inline]
+    pub fn top(&self) -> N {
+        self.height / num::cast::<f64, N>(2.0).unwrap()
+    }
+
     /// Sets the width of the view cuboid.
     #[inline]
     pub fn set_width(&mut self, width: N) {
@@ -109,6 +133,15 @@ impl<N: FloatMath + Clone> Ortho3<N> {
         // FIXME: optimize that
         self.to_persp_mat().project_vec(p)
     }
+
+    /// Un-projects a point, i.e., computes the point which projection is `p`.
+    ///
+    /// This is the inverse of `project_pnt`.
+    #[inline]
+    pub fn unproject_pnt(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        // FIXME: optimize that
+        self.to_persp_mat().unproject_pnt(p)
+    }
 }
 
 impl<N: FloatMath> OrthoMat3<N> {
@@ -128,6 +161,21 @@ impl<N: FloatMath> OrthoMat3<N> {
         res
     }
 
+    /// Creates a new orthographic projection matrix from the left, right, bottom, top, znear and
+    /// zfar planes of the view cuboid.
+    ///
+    /// Unlike `OrthoMat3::new`, the view cuboid does not have to be centered on the `z` axis.
+    pub fn new_with_bounds(left: N, right: N, bottom: N, top: N, znear: N, zfar: N) -> OrthoMat3<N> {
+        let mat: Mat4<N> = One::one();
+
+        let mut res = OrthoMat3 { mat: mat };
+        res.set_left_and_right(left, right);
+        res.set_bottom_and_top(bottom, top);
+        res.set_znear_and_zfar(znear, zfar);
+
+        res
+    }
+
     /// Creates a new orthographic matrix from a 4D matrix.
     ///
     /// This is unsafe because the input matrix is not checked to be a orthographic projection.
@@ -168,18 +216,70 @@ impl<N: FloatMath> OrthoMat3<N> {
         (self.mat.m34 - One::one()) / self.mat.m33
     }
 
+    /// The left offset of the view cuboid.
+    #[inline]
+    pub fn left(&self) -> N {
+        let _1: N = One::one();
+        (-_1 - self.mat.m14) / self.mat.m11
+    }
+
+    /// The right offset of the view cuboid.
+    #[inline]
+    pub fn right(&self) -> N {
+        let _1: N = One::one();
+        (_1 - self.mat.m14) / self.mat.m11
+    }
+
+    /// The bottom offset of the view cuboid.
+    #[inline]
+    pub fn bottom(&self) -> N {
+        let _1: N = One::one();
+        (-_1 - self.mat.m24) / self.mat.m22
+    }
+
+    /// The top offset of the view cuboid.
+    #[inline]
+    pub fn top(&self) -> N {
+        let _1: N = One::one();
+        (_1 - self.mat.m24) / self.mat.m22
+    }
+
     /// Sets the width of the view cuboid.
+    ///
+    /// The horizontal center of the view cuboid is left unchanged.
     #[inline]
     pub fn set_width(&mut self, width: N) {
         assert!(!width.is_zero());
+        let center   = -self.mat.m14 / self.mat.m11;
         self.mat.m11 = num::cast::<f64, N>(2.0).unwrap() / width;
+        self.mat.m14 = -center * self.mat.m11;
     }
 
     /// Sets the height of the view cuboid.
+    ///
+    /// The vertical center of the view cuboid is left unchanged.
     #[inline]
     pub fn set_height(&mut self, height: N) {
         assert!(!height.is_zero());
+        let center   = -self.mat.m24 / self.mat.m22;
         self.mat.m22 = num::cast::<f64, N>(2.0).unwrap() / height;
+        self.mat.m24 = -center * self.mat.m22;
+    }
+
+    /// Sets the left and right offsets of the view cuboid.
+    #[inline]
+    pub fn set_left_and_right(&mut self, left: N, right: N) {
+        assert!(!(right - left).is_zero());
+        self.mat.m11 = num::cast::<f64, N>(2.0).unwrap() / (right - left);
+        self.mat.m14 = -(right + left) / (right - left);
+    }
+
+    /// Sets the bottom and top offsets of the view cuboid.
+    #[inline]
+    pub fn set_bottom_and_top(&mut self, bottom: N, top: N) {
+        assert!(!(top - bottom).is_zero());
+        self.mat.m22 = num::cast::<f64, N>(2.0).unwrap() / (top - bottom);
+        self.mat.m24 = -(top + bottom) / (top - bottom);
     }
 
     /// Sets the near plane offset of the view cuboid.
@@ -208,8 +308,8 @@ impl<N: FloatMath> OrthoMat3<N> {
     #[inline]
     pub fn project_pnt(&self, p: &Pnt3<N>) -> Pnt3<N> {
         Pnt3::new(
-            self.mat.m11 * p.x,
-            self.mat.m22 * p.y,
+            self.mat.m11 * p.x + self.mat.m14,
+            self.mat.m22 * p.y + self.mat.m24,
             self.mat.m33 * p.z + self.mat.m34
         )
     }
@@ -223,6 +323,18 @@ impl<N: FloatMath> OrthoMat3<N> {
             self.mat.m33 * p.z
         )
     }
+
+    /// Un-projects a point, i.e., computes the point which projection is `p`.
+    ///
+    /// This is the inverse of `project_pnt`.
+    #[inline]
+    pub fn unproject_pnt(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        Pnt3::new(
+            (p.x - self.mat.m14) / self.mat.m11,
+            (p.y - self.mat.m24) / self.mat.m22,
+            (p.z - self.mat.m34) / self.mat.m33
+        )
+    }
 }
 
 impl<N: FloatMath + Clone> OrthoMat3<N> {
@@ -0,0 +1,137 @@
+//! Parsing of vectors and matrices from their bracketed textual representation.
+
+use std::from_str::FromStr;
+
+// Removes the brackets surrounding `s`.
+fn unbracket<'a>(s: &'a str) -> Result<&'a str, String> {
+    let s = s.trim();
+
+    if !s.starts_with("[") {
+        Err(format!("expected `[` at the beginning of `{}`.", s))
+    }
+    else if !s.ends_with("]") || s.len() < 2 {
+        Err(format!("expected `]` at the end of `{}`.", s))
+    }
+    else {
+        Ok(s.slice(1, s.len() - 1))
+    }
+}
+
+/// Parses a list of components, e.g., `"[1, 2, 3]"`.
+pub fn parse_list<N: FromStr>(s: &str) -> Result<Vec<N>, String> {
+    let inner = try!(unbracket(s));
+
+    if inner.trim().is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let mut res = Vec::new();
+
+    for (i, e) in inner.split(',').enumerate() {
+        match from_str(e.trim()) {
+            Some(v) => res.push(v),
+            None    => return Err(format!("invalid component {}: `{}`.", i, e.trim()))
+        }
+    }
+
+    Ok(res)
+}
+
+/// Parses a list of lists of components, e.g., `"[[1, 2], [3, 4]]"`.
+pub fn parse_nested<N: FromStr>(s: &str) -> Result<Vec<Vec<N>>, String> {
+    let inner        = try!(unbracket(s));
+    let mut res      = Vec::new();
+    let mut depth    = 0u;
+    let mut start    = 0u;
+    let mut need_sep = false;
+
+    for (i, c) in inner.char_indices() {
+        match c {
+            '[' => {
+                if depth == 0 {
+                    if !inner.slice(start, i).trim().is_empty() {
+                        return Err(format!("unexpected `{}` before row {}.", inner.slice(start, i).trim(), res.len()))
+                    }
+
+                    if need_sep {
+                        return Err(format!("expected `,` before row {}.", res.len()))
+                    }
+
+                    start = i;
+                }
+
+                depth = depth + 1;
+            },
+            ']' => {
+                if depth == 0 {
+                    return Err(format!("unbalanced `]` in `{}`.", s.trim()))
+                }
+
+                depth = depth - 1;
+
+                if depth == 0 {
+                    match parse_list(inner.slice(start, i + 1)) {
+                        Ok(row)  => res.push(row),
+                        Err(msg) => return Err(format!("row {}: {}", res.len(), msg))
+                    }
+
+                    start    = i + 1;
+                    need_sep = true;
+                }
+            },
+            ',' if depth == 0 => {
+                if !inner.slice(start, i).trim().is_empty() {
+                    return Err(format!("unexpected `{}` before row {}.", inner.slice(start, i).trim(), res.len()))
+                }
+
+                if !need_sep {
+                    return Err(format!("unexpected `,` before row {}.", res.len()))
+                }
+
+                start    = i + 1;
+                need_sep = false;
+            },
+            _ => { }
+        }
+    }
+
+    if depth != 0 {
+        return Err(format!("unbalanced `[` in `{}`.", s.trim()))
+    }
+
+    if !inner.slice_from(start).trim().is_empty() {
+        return Err(format!("unexpected `{}` after the last row.", inner.slice_from(start).trim()))
+    }
+
+    if !need_sep && !res.is_empty() {
+        return Err(format!("unexpected `,` after the last row."))
+    }
+
+    Ok(res)
+}
+
+/// Parses a matrix given row by row, e.g., `"[[1, 2], [3, 4]]"`.
+///
+/// Returns its number of rows, its number of columns, and its components in column-major order.
+pub fn parse_mat<N: FromStr>(s: &str) -> Result<(uint, uint, Vec<N>), String> {
+    let rows: Vec<Vec<N>> = try!(parse_nested(s));
+    let nrows = rows.len();
+    let ncols = if nrows == 0 { 0 } else { rows[0].len() };
+
+    for (i, row) in rows.iter().enumerate() {
+        if row.len() != ncols {
+            return Err(format!("row {} has {} components instead of {}.", i, row.len(), ncols))
+        }
+    }
+
+    // transpose to column-major order.
+    let mut cols: Vec<Vec<N>> = range(0u, ncols).map(|_| Vec::with_capacity(nrows)).collect();
+
+    for row in rows.into_iter() {
+        for (col, e) in cols.iter_mut().zip(row.into_iter()) {
+            col.push(e);
+        }
+    }
+
+    Ok((nrows, ncols, cols.into_iter().flat_map(|col| col.into_iter()).collect()))
+}
@@ -70,6 +70,33 @@ impl<N: FloatMath + Clone> Persp3<N> {
         self.zfar.clone()
     }
 
+    /// The left offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn left(&self) -> N {
+        -self.right()
+    }
+
+    /// The right offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn right(&self) -> N {
+        self.top() * self.aspect
+    }
+
+    /// The bottom offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn bottom(&self) -> N {
+        -self.top()
+    }
+
+    /// The top offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn top(&self) -> N {
+        let _1: N = One::one();
+        let _2 = _1 + _1;
+
+        self.znear * (self.fov / _2).tan()
+    }
+
     /// Sets the `width / height` aspect ratio of the view frustrum.
     ///
     /// This method does not build any matrix.
@@ -115,6 +142,16 @@ impl<N: FloatMath + Clone> Persp3<N> {
         // FIXME: optimize that
         self.to_persp_mat().project_vec(p)
     }
+
+    /// Un-projects a point, i.e., computes the point which projection is `p`.
+    ///
+    /// This is the inverse of `project_pnt`. It is typically used to cast picking rays: un-project
+    /// the normalized device coordinates of a pixel at both depth `-1` and `1`.
+    #[inline]
+    pub fn unproject_pnt(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        // FIXME: optimize that
+        self.to_persp_mat().unproject_pnt(p)
+    }
 }
 
 impl<N: FloatMath> PerspMat3<N> {
@@ -186,6 +223,30 @@ impl<N: FloatMath> PerspMat3<N> {
         (self.mat.m34 - ratio * self.mat.m34) / _2
     }
 
+    /// Gets the left offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn left(&self) -> N {
+        -self.right()
+    }
+
+    /// Gets the right offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn right(&self) -> N {
+        self.top() * self.aspect()
+    }
+
+    /// Gets the bottom offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn bottom(&self) -> N {
+        -self.top()
+    }
+
+    /// Gets the top offset of the view frustrum on its near plane.
+    #[inline]
+    pub fn top(&self) -> N {
+        self.znear() / self.mat.m22
+    }
+
     // FIXME: add a method to retriev znear and zfar at once ?
 
     /// Updates this projection matrix with a new `width / height` aspect ratio of the view
@@ -254,6 +315,21 @@ impl<N: FloatMath> PerspMat3<N> {
             self.mat.m33
         )
     }
+
+    /// Un-projects a point, i.e., computes the point which projection is `p`.
+    ///
+    /// This is the inverse of `project_pnt`. It is typically used to cast picking rays: un-project
+    /// the normalized device coordinates of a pixel at both depth `-1` and `1`.
+    #[inline]
+    pub fn unproject_pnt(&self, p: &Pnt3<N>) -> Pnt3<N> {
+        let z = self.mat.m34 / (p.z - self.mat.m33);
+
+        Pnt3::new(
+            p.x * z / self.mat.m11,
+            p.y * z / self.mat.m22,
+            z
+        )
+    }
 }
 
 impl<N: FloatMath + Clone> PerspMat3<N> {
@@ -3,14 +3,20 @@
 #![allow(missing_doc)] // we allow missing to avoid having to document the point components.
 
 use std::mem;
+use std::ptr;
+use std::fmt;
+use std::from_str::FromStr;
+use std::io::{IoResult, MemWriter, BufReader};
 use std::num::{Zero, One, Bounded};
 use std::slice::{Items, MutItems};
 use std::iter::{Iterator, FromIterator};
 use traits::operations::{ApproxEq, PartialOrd, PartialOrdering, PartialLess, PartialEqual,
-                         PartialGreater, NotComparable, Axpy};
-use traits::structure::{Cast, Dim, Indexable, Iterable, IterableMut, PntAsVec};
+                         PartialGreater, NotComparable, Axpy, AddInPlace, SubInPlace};
+use traits::structure::{Bytes, Cast, Dim, StaticDim, Indexable, Iterable, IterableMut, PntAsVec};
+use structs::dim::{D1, D2, D3, D4, D5, D6};
 use traits::geometry::{Orig, FromHomogeneous, ToHomogeneous};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
+use structs::{pretty, parse};
 
 
 /// Point of dimension 0.
@@ -32,7 +38,7 @@ impl<N> Pnt0<N> {
 }
 
 /// Point of dimension 1.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt1<N> {
     /// First component of the point.
     pub x: N
@@ -53,11 +59,16 @@ orig_impl!(Pnt1, x)
 ord_impl!(Pnt1, x)
 vec_cast_impl!(Pnt1, Pnt1Cast, x)
 as_slice_impl!(Pnt1, 1)
+array_conversion_impl!(Pnt1, 1)
 index_impl!(Pnt1)
 indexable_impl!(Pnt1, 1)
 at_fast_impl!(Pnt1, 1)
+vec_show_impl!(Pnt1)
+vec_from_str_impl!(Pnt1, 1, x)
+bytes_impl!(Pnt1, x)
 new_repeat_impl!(Pnt1, val, x)
 dim_impl!(Pnt1, 1)
+static_dim_impl!(Pnt1, D1)
 container_impl!(Pnt1)
 pnt_as_vec_impl!(Pnt1, Vec1, x)
 pnt_sub_impl!(Pnt1, Vec1, Pnt1SubRhs)
@@ -76,6 +87,8 @@ vec_mul_scalar_impl!(Pnt1, i16, Pnt1MulRhs, x)
 vec_mul_scalar_impl!(Pnt1, i8, Pnt1MulRhs, x)
 vec_mul_scalar_impl!(Pnt1, uint, Pnt1MulRhs, x)
 vec_mul_scalar_impl!(Pnt1, int, Pnt1MulRhs, x)
+left_scalar_mul_impl!(Pnt1, f64)
+left_scalar_mul_impl!(Pnt1, f32)
 vec_div_scalar_impl!(Pnt1, f64, Pnt1DivRhs, x)
 vec_div_scalar_impl!(Pnt1, f32, Pnt1DivRhs, x)
 vec_div_scalar_impl!(Pnt1, u64, Pnt1DivRhs, x)
@@ -116,13 +129,14 @@ approx_eq_impl!(Pnt1, x)
 from_iterator_impl!(Pnt1, iterator)
 bounded_impl!(Pnt1, x)
 axpy_impl!(Pnt1, x)
+pnt_inplace_impl!(Pnt1, Vec1)
 iterable_impl!(Pnt1, 1)
 iterable_mut_impl!(Pnt1, 1)
 pnt_to_homogeneous_impl!(Pnt1, Pnt2, y, x)
 pnt_from_homogeneous_impl!(Pnt1, Pnt2, y, x)
 
 /// Point of dimension 2.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt2<N> {
     /// First component of the point.
     pub x: N,
@@ -145,11 +159,16 @@ orig_impl!(Pnt2, x, y)
 ord_impl!(Pnt2, x, y)
 vec_cast_impl!(Pnt2, Pnt2Cast, x, y)
 as_slice_impl!(Pnt2, 2)
+array_conversion_impl!(Pnt2, 2)
 index_impl!(Pnt2)
 indexable_impl!(Pnt2, 2)
 at_fast_impl!(Pnt2, 2)
+vec_show_impl!(Pnt2)
+vec_from_str_impl!(Pnt2, 2, x, y)
+bytes_impl!(Pnt2, x, y)
 new_repeat_impl!(Pnt2, val, x, y)
 dim_impl!(Pnt2, 2)
+static_dim_impl!(Pnt2, D2)
 container_impl!(Pnt2)
 pnt_as_vec_impl!(Pnt2, Vec2, x, y)
 pnt_sub_impl!(Pnt2, Vec2, Pnt2SubRhs)
@@ -168,6 +187,8 @@ vec_mul_scalar_impl!(Pnt2, i16, Pnt2MulRhs, x, y)
 vec_mul_scalar_impl!(Pnt2, i8, Pnt2MulRhs, x, y)
 vec_mul_scalar_impl!(Pnt2, uint, Pnt2MulRhs, x, y)
 vec_mul_scalar_impl!(Pnt2, int, Pnt2MulRhs, x, y)
+left_scalar_mul_impl!(Pnt2, f64)
+left_scalar_mul_impl!(Pnt2, f32)
 vec_div_scalar_impl!(Pnt2, f64, Pnt2DivRhs, x, y)
 vec_div_scalar_impl!(Pnt2, f32, Pnt2DivRhs, x, y)
 vec_div_scalar_impl!(Pnt2, u64, Pnt2DivRhs, x, y)
@@ -208,13 +229,14 @@ approx_eq_impl!(Pnt2, x, y)
 from_iterator_impl!(Pnt2, iterator, iterator)
 bounded_impl!(Pnt2, x, y)
 axpy_impl!(Pnt2, x, y)
+pnt_inplace_impl!(Pnt2, Vec2)
 iterable_impl!(Pnt2, 2)
 iterable_mut_impl!(Pnt2, 2)
 pnt_to_homogeneous_impl!(Pnt2, Pnt3, z, x, y)
 pnt_from_homogeneous_impl!(Pnt2, Pnt3, z, x, y)
 
 /// Point of dimension 3.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt3<N> {
     /// First component of the point.
     pub x: N,
@@ -239,11 +261,16 @@ orig_impl!(Pnt3, x, y, z)
 ord_impl!(Pnt3, x, y, z)
 vec_cast_impl!(Pnt3, Pnt3Cast, x, y, z)
 as_slice_impl!(Pnt3, 3)
+array_conversion_impl!(Pnt3, 3)
 index_impl!(Pnt3)
 indexable_impl!(Pnt3, 3)
 at_fast_impl!(Pnt3, 3)
+vec_show_impl!(Pnt3)
+vec_from_str_impl!(Pnt3, 3, x, y, z)
+bytes_impl!(Pnt3, x, y, z)
 new_repeat_impl!(Pnt3, val, x, y, z)
 dim_impl!(Pnt3, 3)
+static_dim_impl!(Pnt3, D3)
 container_impl!(Pnt3)
 pnt_as_vec_impl!(Pnt3, Vec3, x, y, z)
 pnt_sub_impl!(Pnt3, Vec3, Pnt3SubRhs)
@@ -262,6 +289,8 @@ vec_mul_scalar_impl!(Pnt3, i16, Pnt3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Pnt3, i8, Pnt3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Pnt3, uint, Pnt3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Pnt3, int, Pnt3MulRhs, x, y, z)
+left_scalar_mul_impl!(Pnt3, f64)
+left_scalar_mul_impl!(Pnt3, f32)
 vec_div_scalar_impl!(Pnt3, f64, Pnt3DivRhs, x, y, z)
 vec_div_scalar_impl!(Pnt3, f32, Pnt3DivRhs, x, y, z)
 vec_div_scalar_impl!(Pnt3, u64, Pnt3DivRhs, x, y, z)
@@ -302,13 +331,14 @@ approx_eq_impl!(Pnt3, x, y, z)
 from_iterator_impl!(Pnt3, iterator, iterator, iterator)
 bounded_impl!(Pnt3, x, y, z)
 axpy_impl!(Pnt3, x, y, z)
+pnt_inplace_impl!(Pnt3, Vec3)
 iterable_impl!(Pnt3, 3)
 iterable_mut_impl!(Pnt3, 3)
 pnt_to_homogeneous_impl!(Pnt3, Pnt4, w, x, y, z)
 pnt_from_homogeneous_impl!(Pnt3, Pnt4, w, x, y, z)
 
 /// Point of dimension 4.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt4<N> {
     /// First component of the point.
     pub x: N,
@@ -335,11 +365,16 @@ orig_impl!(Pnt4, x, y, z, w)
 ord_impl!(Pnt4, x, y, z, w)
 vec_cast_impl!(Pnt4, Pnt4Cast, x, y, z, w)
 as_slice_impl!(Pnt4, 4)
+array_conversion_impl!(Pnt4, 4)
 index_impl!(Pnt4)
 indexable_impl!(Pnt4, 4)
 at_fast_impl!(Pnt4, 4)
+vec_show_impl!(Pnt4)
+vec_from_str_impl!(Pnt4, 4, x, y, z, w)
+bytes_impl!(Pnt4, x, y, z, w)
 new_repeat_impl!(Pnt4, val, x, y, z, w)
 dim_impl!(Pnt4, 4)
+static_dim_impl!(Pnt4, D4)
 container_impl!(Pnt4)
 pnt_as_vec_impl!(Pnt4, Vec4, x, y, z, w)
 pnt_sub_impl!(Pnt4, Vec4, Pnt4SubRhs)
@@ -358,6 +393,8 @@ vec_mul_scalar_impl!(Pnt4, i16, Pnt4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Pnt4, i8, Pnt4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Pnt4, uint, Pnt4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Pnt4, int, Pnt4MulRhs, x, y, z, w)
+left_scalar_mul_impl!(Pnt4, f64)
+left_scalar_mul_impl!(Pnt4, f32)
 vec_div_scalar_impl!(Pnt4, f64, Pnt4DivRhs, x, y, z, w)
 vec_div_scalar_impl!(Pnt4, f32, Pnt4DivRhs, x, y, z, w)
 vec_div_scalar_impl!(Pnt4, u64, Pnt4DivRhs, x, y, z, w)
@@ -398,13 +435,14 @@ approx_eq_impl!(Pnt4, x, y, z, w)
 from_iterator_impl!(Pnt4, iterator, iterator, iterator, iterator)
 bounded_impl!(Pnt4, x, y, z, w)
 axpy_impl!(Pnt4, x, y, z, w)
+pnt_inplace_impl!(Pnt4, Vec4)
 iterable_impl!(Pnt4, 4)
 iterable_mut_impl!(Pnt4, 4)
 pnt_to_homogeneous_impl!(Pnt4, Pnt5, a, x, y, z, w)
 pnt_from_homogeneous_impl!(Pnt4, Pnt5, a, x, y, z, w)
 
 /// Point of dimension 5.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt5<N> {
     /// First component of the point.
     pub x: N,
@@ -433,11 +471,16 @@ orig_impl!(Pnt5, x, y, z, w, a)
 ord_impl!(Pnt5, x, y, z, w, a)
 vec_cast_impl!(Pnt5, Pnt5Cast, x, y, z, w, a)
 as_slice_impl!(Pnt5, 5)
+array_conversion_impl!(Pnt5, 5)
 index_impl!(Pnt5)
 indexable_impl!(Pnt5, 5)
 at_fast_impl!(Pnt5, 5)
+vec_show_impl!(Pnt5)
+vec_from_str_impl!(Pnt5, 5, x, y, z, w, a)
+bytes_impl!(Pnt5, x, y, z, w, a)
 new_repeat_impl!(Pnt5, val, x, y, z, w, a)
 dim_impl!(Pnt5, 5)
+static_dim_impl!(Pnt5, D5)
 container_impl!(Pnt5)
 pnt_as_vec_impl!(Pnt5, Vec5, x, y, z, w, a)
 pnt_sub_impl!(Pnt5, Vec5, Pnt5SubRhs)
@@ -456,6 +499,8 @@ vec_mul_scalar_impl!(Pnt5, i16, Pnt5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Pnt5, i8, Pnt5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Pnt5, uint, Pnt5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Pnt5, int, Pnt5MulRhs, x, y, z, w, a)
+left_scalar_mul_impl!(Pnt5, f64)
+left_scalar_mul_impl!(Pnt5, f32)
 vec_div_scalar_impl!(Pnt5, f64, Pnt5DivRhs, x, y, z, w, a)
 vec_div_scalar_impl!(Pnt5, f32, Pnt5DivRhs, x, y, z, w, a)
 vec_div_scalar_impl!(Pnt5, u64, Pnt5DivRhs, x, y, z, w, a)
@@ -496,13 +541,14 @@ approx_eq_impl!(Pnt5, x, y, z, w, a)
 from_iterator_impl!(Pnt5, iterator, iterator, iterator, iterator, iterator)
 bounded_impl!(Pnt5, x, y, z, w, a)
 axpy_impl!(Pnt5, x, y, z, w, a)
+pnt_inplace_impl!(Pnt5, Vec5)
 iterable_impl!(Pnt5, 5)
 iterable_mut_impl!(Pnt5, 5)
 pnt_to_homogeneous_impl!(Pnt5, Pnt6, b, x, y, z, w, a)
 pnt_from_homogeneous_impl!(Pnt5, Pnt6, b, x, y, z, w, a)
 
 /// Point of dimension 6.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand)]
 pub struct Pnt6<N> {
     /// First component of the point.
     pub x: N,
@@ -533,11 +579,16 @@ orig_impl!(Pnt6, x, y, z, w, a, b)
 ord_impl!(Pnt6, x, y, z, w, a, b)
 vec_cast_impl!(Pnt6, Pnt6Cast, x, y, z, w, a, b)
 as_slice_impl!(Pnt6, 6)
+array_conversion_impl!(Pnt6, 6)
 index_impl!(Pnt6)
 indexable_impl!(Pnt6, 6)
 at_fast_impl!(Pnt6, 6)
+vec_show_impl!(Pnt6)
+vec_from_str_impl!(Pnt6, 6, x, y, z, w, a, b)
+bytes_impl!(Pnt6, x, y, z, w, a, b)
 new_repeat_impl!(Pnt6, val, x, y, z, w, a, b)
 dim_impl!(Pnt6, 6)
+static_dim_impl!(Pnt6, D6)
 container_impl!(Pnt6)
 pnt_as_vec_impl!(Pnt6, Vec6, x, y, z, w, a, b)
 pnt_sub_impl!(Pnt6, Vec6, Pnt6SubRhs)
@@ -556,6 +607,8 @@ vec_mul_scalar_impl!(Pnt6, i16, Pnt6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Pnt6, i8, Pnt6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Pnt6, uint, Pnt6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Pnt6, int, Pnt6MulRhs, x, y, z, w, a, b)
+left_scalar_mul_impl!(Pnt6, f64)
+left_scalar_mul_impl!(Pnt6, f32)
 vec_div_scalar_impl!(Pnt6, f64, Pnt6DivRhs, x, y, z, w, a, b)
 vec_div_scalar_impl!(Pnt6, f32, Pnt6DivRhs, x, y, z, w, a, b)
 vec_div_scalar_impl!(Pnt6, u64, Pnt6DivRhs, x, y, z, w, a, b)
@@ -596,5 +649,6 @@ approx_eq_impl!(Pnt6, x, y, z, w, a, b)
 from_iterator_impl!(Pnt6, iterator, iterator, iterator, iterator, iterator, iterator)
 bounded_impl!(Pnt6, x, y, z, w, a, b)
 axpy_impl!(Pnt6, x, y, z, w, a, b)
+pnt_inplace_impl!(Pnt6, Vec6)
 iterable_impl!(Pnt6, 6)
 iterable_mut_impl!(Pnt6, 6)
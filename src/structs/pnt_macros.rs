@@ -126,5 +126,26 @@ macro_rules! pnt_from_homogeneous_impl(
                 res
             }
         }
+
+        impl<N: Clone + Div<N, N> + One + Zero> $t<N> {
+            /// Converts a point from homogeneous coordinates, dividing it by its last component.
+            ///
+            /// Returns `None` if the last component is zero, i.e., if `v` is a point at infinity.
+            #[inline]
+            pub fn from_homogeneous(v: &$t2<N>) -> Option<$t<N>> {
+                if v.$extra.is_zero() {
+                    None
+                }
+                else {
+                    Some(FromHomogeneous::from(v))
+                }
+            }
+
+            /// Converts this point to homogeneous coordinates, with a last component set to one.
+            #[inline]
+            pub fn to_homogeneous(&self) -> $t2<N> {
+                ToHomogeneous::to_homogeneous(self)
+            }
+        }
     )
 )
@@ -0,0 +1,94 @@
+//! Approximate structural predicates on matrices.
+
+use std::num::{Zero, One};
+use traits::operations::ApproxEq;
+use traits::structure::Indexable;
+
+/// Tests if `m` is square and equal to its transpose, up to `eps`.
+pub fn is_symmetric<N: ApproxEq<N>, M: Indexable<(uint, uint), N>>(m: &M, eps: &N) -> bool {
+    let (nrows, ncols) = m.shape();
+
+    if nrows != ncols {
+        return false
+    }
+
+    for j in range(0u, ncols) {
+        for i in range(j + 1, nrows) {
+            if !ApproxEq::approx_eq_eps(&m.at((i, j)), &m.at((j, i)), eps) {
+                return false
+            }
+        }
+    }
+
+    true
+}
+
+/// Tests if every component of `m` below its diagonal is zero, up to `eps`.
+pub fn is_upper_triangular<N: Zero + ApproxEq<N>, M: Indexable<(uint, uint), N>>(m: &M, eps: &N) -> bool {
+    let (nrows, ncols) = m.shape();
+    let zero: N        = Zero::zero();
+
+    for j in range(0u, ncols) {
+        for i in range(j + 1, nrows) {
+            if !ApproxEq::approx_eq_eps(&m.at((i, j)), &zero, eps) {
+                return false
+            }
+        }
+    }
+
+    true
+}
+
+/// Tests if every off-diagonal component of `m` is zero, up to `eps`.
+pub fn is_diagonal<N: Zero + ApproxEq<N>, M: Indexable<(uint, uint), N>>(m: &M, eps: &N) -> bool {
+    let (nrows, ncols) = m.shape();
+    let zero: N        = Zero::zero();
+
+    for j in range(0u, ncols) {
+        for i in range(0u, nrows) {
+            if i != j && !ApproxEq::approx_eq_eps(&m.at((i, j)), &zero, eps) {
+                return false
+            }
+        }
+    }
+
+    true
+}
+
+/// Tests if `m` is square and its columns are orthonormal, i.e., if the transpose of `m` times
+/// `m` is the identity, up to `eps`.
+pub fn is_orthogonal<N: Zero + One + Add<N, N> + Mul<N, N> + ApproxEq<N>,
+                     M: Indexable<(uint, uint), N>>(m: &M, eps: &N) -> bool {
+    let (nrows, ncols) = m.shape();
+
+    if nrows != ncols {
+        return false
+    }
+
+    for j in range(0u, ncols) {
+        for i in range(0u, j + 1) {
+            let mut dot: N = Zero::zero();
+
+            for k in range(0u, nrows) {
+                dot = dot + m.at((k, i)) * m.at((k, j));
+            }
+
+            let expected: N = if i == j { One::one() } else { Zero::zero() };
+
+            if !ApproxEq::approx_eq_eps(&dot, &expected, eps) {
+                return false
+            }
+        }
+    }
+
+    true
+}
+
+/// Tests if `m` is square with ones on its diagonal and zeros elsewhere, up to `eps`.
+pub fn is_identity<N: Zero + One + ApproxEq<N>, M: Indexable<(uint, uint), N>>(m: &M, eps: &N) -> bool {
+    let (nrows, ncols) = m.shape();
+    let one: N         = One::one();
+
+    nrows == ncols && is_diagonal(m, eps) &&
+        range(0u, nrows).all(|i| ApproxEq::approx_eq_eps(&m.at((i, i)), &one, eps))
+}
@@ -0,0 +1,79 @@
+//! Human-readable formatting of vectors and matrices.
+
+use std::fmt;
+use std::fmt::{Show, LowerExp};
+
+// Applies the width flag of `f` to the formatted component `s`.
+fn pad(f: &fmt::Formatter, s: String) -> String {
+    match f.width {
+        Some(w) => format!("{:>1$}", s, w),
+        None    => s
+    }
+}
+
+/// Formats each component with `Show`, honoring the width and precision flags of `f`.
+pub fn show_strs<N: Show>(f: &fmt::Formatter, elts: &[N]) -> Vec<String> {
+    elts.iter().map(|e| {
+        let s = match f.precision {
+            Some(p) => format!("{:.1$}", *e, p),
+            None    => format!("{}", *e)
+        };
+
+        pad(f, s)
+    }).collect()
+}
+
+/// Formats each component with `LowerExp`, honoring the width and precision flags of `f`.
+pub fn exp_strs<N: LowerExp>(f: &fmt::Formatter, elts: &[N]) -> Vec<String> {
+    elts.iter().map(|e| {
+        let s = match f.precision {
+            Some(p) => format!("{:.1$e}", *e, p),
+            None    => format!("{:e}", *e)
+        };
+
+        pad(f, s)
+    }).collect()
+}
+
+/// Writes the formatted components of a vector on a single line, e.g., `[1, 2, 3]`.
+pub fn fmt_vec(f: &mut fmt::Formatter, strs: &[String]) -> fmt::Result {
+    try!(write!(f, "["));
+
+    for (i, s) in strs.iter().enumerate() {
+        if i != 0 {
+            try!(write!(f, ", "));
+        }
+
+        try!(write!(f, "{}", *s));
+    }
+
+    write!(f, "]")
+}
+
+/// Writes the formatted components of a matrix with one bracketed row per line and right-aligned
+/// columns.
+///
+/// The components are given in column-major order.
+pub fn fmt_mat(f: &mut fmt::Formatter, nrows: uint, ncols: uint, strs: &[String]) -> fmt::Result {
+    assert!(strs.len() == nrows * ncols);
+
+    let widths: Vec<uint> = range(0u, ncols).map(|j| {
+        range(0u, nrows).map(|i| strs[i + j * nrows].as_slice().char_len()).max().unwrap_or(0)
+    }).collect();
+
+    for i in range(0u, nrows) {
+        if i != 0 {
+            try!(write!(f, "\n"));
+        }
+
+        try!(write!(f, "["));
+
+        for j in range(0u, ncols) {
+            try!(write!(f, " {:>1$}", strs[i + j * nrows], widths[j]));
+        }
+
+        try!(write!(f, " ]"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! General projective transformations.
+
+#![allow(missing_doc)]
+
+use std::num::One;
+use structs::mat::{Mat3, Mat4};
+use structs::vec::{Vec2, Vec3, Vec4};
+use structs::pnt::{Pnt2, Pnt3};
+use traits::operations::{Inv, ApproxEq};
+use traits::geometry::{Transform, ToHomogeneous, FromHomogeneous};
+
+
+/// Two dimensional projective transformation.
+///
+/// It is represented by its 3x3 homogeneous matrix. Unlike affine transformations, the image of
+/// a point is obtained by dividing the transformed homogeneous coordinates by their last
+/// component. This is the usual representation of homographies.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Proj2<N> {
+    mat: Mat3<N>
+}
+
+/// Three dimensional projective transformation.
+///
+/// It is represented by its 4x4 homogeneous matrix. Unlike affine transformations, the image of
+/// a point is obtained by dividing the transformed homogeneous coordinates by their last
+/// component.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Proj3<N> {
+    mat: Mat4<N>
+}
+
+proj_impl!(Proj2, Mat3)
+double_dispatch_binop_decl_trait!(Proj2, Proj2MulRhs)
+mul_redispatch_impl!(Proj2, Proj2MulRhs)
+proj_one_impl!(Proj2)
+proj_mul_proj_impl!(Proj2, Proj2MulRhs)
+proj_mul_pnt_impl!(Proj2, Pnt2, Proj2MulRhs)
+proj_inv_impl!(Proj2)
+proj_transform_impl!(Proj2, Pnt2)
+proj_transform_vec_impl!(Proj2, Vec2, Vec3)
+proj_to_homogeneous_impl!(Proj2, Mat3)
+proj_approx_eq_impl!(Proj2)
+
+proj_impl!(Proj3, Mat4)
+double_dispatch_binop_decl_trait!(Proj3, Proj3MulRhs)
+mul_redispatch_impl!(Proj3, Proj3MulRhs)
+proj_one_impl!(Proj3)
+proj_mul_proj_impl!(Proj3, Proj3MulRhs)
+proj_mul_pnt_impl!(Proj3, Pnt3, Proj3MulRhs)
+proj_inv_impl!(Proj3)
+proj_transform_impl!(Proj3, Pnt3)
+proj_transform_vec_impl!(Proj3, Vec3, Vec4)
+proj_to_homogeneous_impl!(Proj3, Mat4)
+proj_approx_eq_impl!(Proj3)
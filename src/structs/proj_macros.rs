@@ -0,0 +1,172 @@
+#![macro_escape]
+
+macro_rules! proj_impl(
+    ($t: ident, $tm: ident) => (
+        impl<N> $t<N> {
+            /// Creates a new projective transformation from its homogeneous matrix.
+            ///
+            /// The matrix is expected to be invertible.
+            #[inline]
+            pub fn new(mat: $tm<N>) -> $t<N> {
+                $t {
+                    mat: mat
+                }
+            }
+
+            /// Returns a reference to the homogeneous matrix of this transformation.
+            #[inline]
+            pub fn as_mat<'a>(&'a self) -> &'a $tm<N> {
+                &self.mat
+            }
+        }
+
+        impl<N: Clone> $t<N> {
+            /// Returns the homogeneous matrix of this transformation.
+            #[inline]
+            pub fn to_mat(&self) -> $tm<N> {
+                self.mat.clone()
+            }
+        }
+    )
+)
+
+macro_rules! proj_one_impl(
+    ($t: ident) => (
+        impl<N: Clone + Num> One for $t<N> {
+            #[inline]
+            fn one() -> $t<N> {
+                $t::new(One::one())
+            }
+        }
+    )
+)
+
+macro_rules! proj_mul_proj_impl(
+    ($t: ident, $tmul: ident) => (
+        impl<N: Clone + Num> $tmul<N, $t<N>> for $t<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$t<N>) -> $t<N> {
+                $t::new(left.mat * right.mat)
+            }
+        }
+    )
+)
+
+macro_rules! proj_mul_pnt_impl(
+    ($t: ident, $tp: ident, $tmul: ident) => (
+        impl<N: Clone + Num> $tmul<N, $tp<N>> for $tp<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tp<N>) -> $tp<N> {
+                FromHomogeneous::from(&(left.mat * ToHomogeneous::to_homogeneous(right)))
+            }
+        }
+    )
+)
+
+macro_rules! proj_inv_impl(
+    ($t: ident) => (
+        impl<N: Clone + Num + ApproxEq<N>> Inv for $t<N> {
+            #[inline]
+            fn inv_cpy(m: &$t<N>) -> Option<$t<N>> {
+                Inv::inv_cpy(&m.mat).map(|mat| $t::new(mat))
+            }
+
+            #[inline]
+            fn inv(&mut self) -> bool {
+                self.mat.inv()
+            }
+        }
+    )
+)
+
+macro_rules! proj_transform_impl(
+    ($t: ident, $tp: ident) => (
+        impl<N: Clone + Num + ApproxEq<N>> Transform<$tp<N>> for $t<N> {
+            #[inline]
+            fn transform(&self, p: &$tp<N>) -> $tp<N> {
+                *self * *p
+            }
+
+            /// Applies the inverse of this transformation to a point.
+            ///
+            /// This fails if the transformation is not invertible.
+            #[inline]
+            fn inv_transform(&self, p: &$tp<N>) -> $tp<N> {
+                Inv::inv_cpy(self).expect("Unable to invert a singular projective transformation.") * *p
+            }
+        }
+    )
+)
+
+macro_rules! proj_transform_vec_impl(
+    ($t: ident, $tv: ident, $tvh: ident) => (
+        impl<N: Clone + Num + ApproxEq<N>> Transform<$tv<N>> for $t<N> {
+            /// Transforms a vector.
+            ///
+            /// The vector is seen as a point at infinity, i.e., with a zero homogeneous
+            /// coordinate, and the homogeneous coordinate of the result is ignored. This is the
+            /// usual transformation of vectors if this transformation is affine.
+            #[inline]
+            fn transform(&self, v: &$tv<N>) -> $tv<N> {
+                let h: $tvh<N> = ToHomogeneous::to_homogeneous(v);
+
+                FromHomogeneous::from(&(self.mat * h))
+            }
+
+            /// Applies the inverse of this transformation to a vector.
+            ///
+            /// This fails if the transformation is not invertible.
+            #[inline]
+            fn inv_transform(&self, v: &$tv<N>) -> $tv<N> {
+                Inv::inv_cpy(self).expect("Unable to invert a singular projective transformation.").transform(v)
+            }
+        }
+    )
+)
+
+macro_rules! proj_to_homogeneous_impl(
+    ($t: ident, $tm: ident) => (
+        impl<N: Clone> ToHomogeneous<$tm<N>> for $t<N> {
+            #[inline]
+            fn to_homogeneous(m: &$t<N>) -> $tm<N> {
+                m.mat.clone()
+            }
+        }
+    )
+)
+
+macro_rules! proj_approx_eq_impl(
+    ($t: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.mat, &b.mat)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.mat, &b.mat, epsilon)
+            }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.mat, &b.mat, ulps)
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.mat, &b.mat, abs_eps, rel_eps)
+            }
+        }
+    )
+)
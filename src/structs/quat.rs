@@ -7,11 +7,13 @@ use std::num::{Zero, One, Bounded};
 use std::num;
 use std::rand::{Rand, Rng};
 use std::slice::{Items, MutItems};
-use structs::{Vec3, Pnt3, Rot3, Mat3, Vec3MulRhs, Pnt3MulRhs};
+use structs::{Vec3, Vec4, Pnt3, Rot3, Mat3, Mat4, Vec3MulRhs, Pnt3MulRhs};
+use structs::rot::{EulerConvention, EulerXYZ, EulerZYX, EulerZXZ};
 use traits::operations::{ApproxEq, Inv, PartialOrd, PartialOrdering, NotComparable, PartialLess,
-                         PartialGreater, PartialEqual, Axpy};
+                         PartialGreater, PartialEqual, Axpy, Outer};
 use traits::structure::{Cast, Indexable, Iterable, IterableMut, Dim};
 use traits::geometry::{Norm, Cross, Rotation, Rotate, Transform};
+use linalg::dominant_eigenvector;
 
 /// A quaternion.
 #[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
@@ -232,6 +234,255 @@ impl<N: FloatMath> UnitQuat<N> {
     }
 }
 
+impl<N: FloatMath + Clone> UnitQuat<N> {
+    /// Creates a new unit quaternion from Euler angles following the given convention.
+    ///
+    /// See `EulerConvention` for the meaning of the angles `(a, b, c)`.
+    #[inline]
+    pub fn from_euler_angles(convention: EulerConvention, a: N, b: N, c: N) -> UnitQuat<N> {
+        let _0: N = Zero::zero();
+
+        match convention {
+            EulerXYZ => UnitQuat::new(Vec3::new(_0, _0, c)) * UnitQuat::new(Vec3::new(_0, b, _0)) * UnitQuat::new(Vec3::new(a, _0, _0)),
+            EulerZYX => UnitQuat::new(Vec3::new(c, _0, _0)) * UnitQuat::new(Vec3::new(_0, b, _0)) * UnitQuat::new(Vec3::new(_0, _0, a)),
+            EulerZXZ => UnitQuat::new(Vec3::new(_0, _0, c)) * UnitQuat::new(Vec3::new(b, _0, _0)) * UnitQuat::new(Vec3::new(_0, _0, a))
+        }
+    }
+
+    /// Creates a new unit quaternion from a rotation matrix.
+    ///
+    /// This uses Shepperd's method: the quaternion component computed first is chosen from the
+    /// largest diagonal term so that the division remains well-conditioned, even for rotations
+    /// close to 180 degrees.
+    pub fn from_rotation_matrix(rot: &Rot3<N>) -> UnitQuat<N> {
+        let _1: N    = One::one();
+        let _0_25: N = num::cast(0.25f64).unwrap();
+        let m        = rot.submat();
+        let trace    = m.m11 + m.m22 + m.m33;
+
+        let q =
+            if trace > m.m11 && trace > m.m22 && trace > m.m33 {
+                let s = (_1 + trace).sqrt() * (_1 + _1); // s = 4 * w
+                Quat::new(s * _0_25, (m.m32 - m.m23) / s, (m.m13 - m.m31) / s, (m.m21 - m.m12) / s)
+            }
+            else if m.m11 >= m.m22 && m.m11 >= m.m33 {
+                let s = (_1 + m.m11 - m.m22 - m.m33).sqrt() * (_1 + _1); // s = 4 * i
+                Quat::new((m.m32 - m.m23) / s, s * _0_25, (m.m12 + m.m21) / s, (m.m13 + m.m31) / s)
+            }
+            else if m.m22 >= m.m33 {
+                let s = (_1 - m.m11 + m.m22 - m.m33).sqrt() * (_1 + _1); // s = 4 * j
+                Quat::new((m.m13 - m.m31) / s, (m.m12 + m.m21) / s, s * _0_25, (m.m23 + m.m32) / s)
+            }
+            else {
+                let s = (_1 - m.m11 - m.m22 + m.m33).sqrt() * (_1 + _1); // s = 4 * k
+                Quat::new((m.m21 - m.m12) / s, (m.m13 + m.m31) / s, (m.m23 + m.m32) / s, s * _0_25)
+            };
+
+        // normalize to remove the error due to a rotation matrix not perfectly orthonormal.
+        UnitQuat::new_with_quat(q)
+    }
+
+    /// Computes the axis and the angle of this rotation.
+    ///
+    /// This is the inverse of `UnitQuat::new`: the returned axis is normalized and the angle is
+    /// in `[0, pi]`. If the angle is zero, the axis is arbitrarily set to `x`.
+    #[inline]
+    pub fn axis_angle(&self) -> (Vec3<N>, N) {
+        let _2: N    = num::cast(2.0f64).unwrap();
+        let mut axis = self.q.vector().clone();
+        let sin_half = axis.normalize();
+
+        if sin_half.is_zero() {
+            (Vec3::x(), Zero::zero())
+        }
+        else if self.q.w < Zero::zero() {
+            // `q` and `-q` represent the same rotation: take the one with the smallest angle.
+            (-axis, _2 * sin_half.atan2(-self.q.w))
+        }
+        else {
+            (axis, _2 * sin_half.atan2(self.q.w))
+        }
+    }
+
+    /// The exponential map of the rotation group: builds the unit quaternion of the rotation of
+    /// axis `w / |w|` and angle `|w|`.
+    ///
+    /// This is equivalent to `UnitQuat::new(w)` but remains accurate for small angles.
+    pub fn exp(w: &Vec3<N>) -> UnitQuat<N> {
+        let _1: N    = One::one();
+        let _2: N    = _1 + _1;
+        let theta_sq = Norm::sqnorm(w);
+        let eps: N   = Float::epsilon();
+
+        // `s = sin(theta / 2) / theta` and `c = cos(theta / 2)`
+        let (s, c) =
+            if theta_sq < eps.sqrt() {
+                let _8: N    = num::cast(8.0f64).unwrap();
+                let _48: N   = num::cast(48.0f64).unwrap();
+                let _384: N  = num::cast(384.0f64).unwrap();
+                let _3840: N = num::cast(3840.0f64).unwrap();
+
+                (_1 / _2 - theta_sq / _48 + theta_sq * theta_sq / _3840,
+                 _1 - theta_sq / _8 + theta_sq * theta_sq / _384)
+            }
+            else {
+                let theta          = theta_sq.sqrt();
+                let (sin_h, cos_h) = (theta / _2).sin_cos();
+
+                (sin_h / theta, cos_h)
+            };
+
+        unsafe {
+            UnitQuat::new_with_unit_quat(Quat::new(c, w.x * s, w.y * s, w.z * s))
+        }
+    }
+
+    /// The logarithm map of the rotation group: computes the rotation vector `w` such that
+    /// `UnitQuat::exp(&w)` represents the same rotation as `self`.
+    ///
+    /// The norm of the result is in `[0, pi]`.
+    pub fn log(&self) -> Vec3<N> {
+        let (axis, angle) = self.axis_angle();
+
+        Vec3::new(axis.x * angle, axis.y * angle, axis.z * angle)
+    }
+
+    /// Raises this rotation to the power `t`, i.e., multiplies its angle by `t`.
+    ///
+    /// The rotation axis is kept unchanged and the angle of `self` is taken in `[0, pi]`. This is
+    /// computed with `UnitQuat::exp(&(self.log() * t))`.
+    #[inline]
+    pub fn powf(&self, t: N) -> UnitQuat<N> {
+        let w = self.log();
+
+        UnitQuat::exp(&Vec3::new(w.x * t, w.y * t, w.z * t))
+    }
+
+    /// Computes the average of a set of rotations.
+    ///
+    /// The result is the rotation minimizing the sum of the squared chordal distances (i.e. the
+    /// squared Frobenius norms of the differences of the rotation matrices) to each rotation of
+    /// `rots`. It is the unit eigenvector associated with the largest eigenvalue of the sum of
+    /// the outer products `q * q^T` of the input quaternions, so the sign ambiguity between `q`
+    /// and `-q` does not matter. The sign of the result is chosen to be on the same side as the
+    /// first rotation of `rots`.
+    ///
+    /// Returns `None` if `rots` is empty.
+    pub fn mean(rots: &[UnitQuat<N>]) -> Option<UnitQuat<N>> {
+        if rots.is_empty() {
+            return None
+        }
+
+        let mut m: Mat4<N> = Zero::zero();
+
+        for r in rots.iter() {
+            let v = Vec4::new(r.q.w.clone(), r.q.i.clone(), r.q.j.clone(), r.q.k.clone());
+            m = m + Outer::outer(&v, &v);
+        }
+
+        let mut v = dominant_eigenvector(m);
+        let first = &rots[0].q;
+
+        if v.x * first.w + v.y * first.i + v.z * first.j + v.w * first.k < Zero::zero() {
+            v = -v;
+        }
+
+        Some(UnitQuat::new_with_quat(Quat::new(v.x, v.y, v.z, v.w)))
+    }
+}
+
+impl<N: FloatMath + ApproxEq<N> + Clone> UnitQuat<N> {
+    /// Computes the Euler angles `(a, b, c)` of this rotation following the given convention.
+    ///
+    /// See `Rot3::to_euler_angles` for the angle ranges and the gimbal lock handling.
+    #[inline]
+    pub fn to_euler_angles(&self, convention: EulerConvention) -> (N, N, N) {
+        self.to_rot().to_euler_angles(convention)
+    }
+
+    /// Spherical linear interpolation between two unit quaternions.
+    ///
+    /// The interpolation follows the shortest path, i.e., `other` is negated if the dot product
+    /// of both quaternions is negative. Returns `self` if `t == 0` and a quaternion representing
+    /// the same rotation as `other` if `t == 1`.
+    #[inline]
+    pub fn slerp(&self, other: &UnitQuat<N>, t: N) -> UnitQuat<N> {
+        let _1: N        = One::one();
+        let mut other    = other.q.clone();
+        let mut cos_half = self.q.w * other.w + self.q.i * other.i + self.q.j * other.j + self.q.k * other.k;
+
+        if cos_half < Zero::zero() {
+            other    = -other;
+            cos_half = -cos_half;
+        }
+
+        let sin_half = (_1 - cos_half * cos_half).max(Zero::zero()).sqrt();
+
+        if ApproxEq::approx_eq(&sin_half, &Zero::zero()) {
+            // both rotations are almost the same: the linear interpolation is accurate enough
+            // and avoids a division by zero.
+            UnitQuat::lerp_and_normalize(&self.q, &other, t)
+        }
+        else {
+            let half_ang = cos_half.acos();
+            let a        = ((_1 - t) * half_ang).sin() / sin_half;
+            let b        = (t * half_ang).sin() / sin_half;
+
+            unsafe {
+                UnitQuat::new_with_unit_quat(
+                    Quat::new(
+                        a * self.q.w + b * other.w,
+                        a * self.q.i + b * other.i,
+                        a * self.q.j + b * other.j,
+                        a * self.q.k + b * other.k))
+            }
+        }
+    }
+
+    /// Normalized linear interpolation between two unit quaternions.
+    ///
+    /// This is a cheaper alternative to `slerp` which also follows the shortest path. The result
+    /// lies on the same arc as the `slerp` result, but the angular velocity is not constant: the
+    /// interpolated rotation is exact at `t == 0`, `t == 0.5` and `t == 1`, and the angular error
+    /// in-between grows with the angle between both rotations (about 0.002 radian for rotations 45
+    /// degrees apart, 0.016 radian for 90 degrees, and 0.14 radian for 180 degrees). Use it for
+    /// small interpolation steps, or when the exact timing of the interpolation does not matter.
+    #[inline]
+    pub fn nlerp(&self, other: &UnitQuat<N>, t: N) -> UnitQuat<N> {
+        let dot = self.q.w * other.q.w + self.q.i * other.q.i + self.q.j * other.q.j + self.q.k * other.q.k;
+
+        if dot < Zero::zero() {
+            UnitQuat::lerp_and_normalize(&self.q, &-other.q, t)
+        }
+        else {
+            UnitQuat::lerp_and_normalize(&self.q, &other.q, t)
+        }
+    }
+
+    #[inline]
+    fn lerp_and_normalize(q1: &Quat<N>, q2: &Quat<N>, t: N) -> UnitQuat<N> {
+        let _1: N = One::one();
+        let s     = _1 - t;
+
+        UnitQuat::new_with_quat(
+            Quat::new(
+                s * q1.w + t * q2.w,
+                s * q1.i + t * q2.i,
+                s * q1.j + t * q2.j,
+                s * q1.k + t * q2.k))
+    }
+}
+
+impl<N: FloatMath + Clone + Cast<f32>> UnitQuat<N> {
+    /// Creates a new unit quaternion from a rotation matrix.
+    ///
+    /// This is the same as `UnitQuat::from_rotation_matrix`.
+    #[inline]
+    pub fn new_with_rot(rot: &Rot3<N>) -> UnitQuat<N> {
+        UnitQuat::from_rotation_matrix(rot)
+    }
+}
+
 impl<N> UnitQuat<N> {
     /// Creates a new unit quaternion from a quaternion.
     ///
@@ -277,9 +528,25 @@ impl<N: Clone + Neg<N>> Inv for UnitQuat<N> {
 }
 
 impl<N: Clone + Rand + FloatMath> Rand for UnitQuat<N> {
+    /// Generates a unit quaternion uniformly distributed on the rotation group.
+    ///
+    /// This uses Shoemake's subgroup algorithm, which samples the unit 3-sphere uniformly. Note
+    /// that sampling random Euler angles or random axis-angle vectors is biased.
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> UnitQuat<N> {
-        UnitQuat::new(rng.gen())
+        let _1: N = One::one();
+        let u1: N = rng.gen();
+        let u2: N = rng.gen();
+        let u3: N = rng.gen();
+
+        let r1       = (_1 - u1).sqrt();
+        let r2       = u1.sqrt();
+        let (s2, c2) = (u2 * Float::two_pi()).sin_cos();
+        let (s3, c3) = (u3 * Float::two_pi()).sin_cos();
+
+        unsafe {
+            UnitQuat::new_with_unit_quat(Quat::new(r2 * c3, r1 * s2, r1 * c2, r2 * s3))
+        }
     }
 }
 
@@ -289,6 +556,11 @@ impl<N: ApproxEq<N>> ApproxEq<N> for UnitQuat<N> {
         ApproxEq::approx_epsilon(None::<N>)
     }
 
+    #[inline]
+    fn approx_ulps(_: Option<UnitQuat<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
     #[inline]
     fn approx_eq(a: &UnitQuat<N>, b: &UnitQuat<N>) -> bool {
         ApproxEq::approx_eq(&a.q, &b.q)
@@ -298,6 +570,16 @@ impl<N: ApproxEq<N>> ApproxEq<N> for UnitQuat<N> {
     fn approx_eq_eps(a: &UnitQuat<N>, b: &UnitQuat<N>, eps: &N) -> bool {
         ApproxEq::approx_eq_eps(&a.q, &b.q, eps)
     }
+
+    #[inline]
+    fn approx_eq_ulps(a: &UnitQuat<N>, b: &UnitQuat<N>, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.q, &b.q, ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &UnitQuat<N>, b: &UnitQuat<N>, abs_eps: &N, rel_eps: &N) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.q, &b.q, abs_eps, rel_eps)
+    }
 }
 
 impl<N: Float + ApproxEq<N> + Clone> Div<UnitQuat<N>, UnitQuat<N>> for UnitQuat<N> {
@@ -356,16 +638,9 @@ impl<N: Num + Clone> Pnt3MulRhs<N, Pnt3<N>> for UnitQuat<N> {
 impl<N: FloatMath + Clone> Rotation<Vec3<N>> for UnitQuat<N> {
     #[inline]
     fn rotation(&self) -> Vec3<N> {
-        let _2 = num::one::<N>() + num::one();
-        let mut v = self.q.vector().clone();
-        let ang = _2 * v.normalize().atan2(self.q.w);
+        let (axis, angle) = self.axis_angle();
 
-        if ang.is_zero() {
-            num::zero()
-        }
-        else {
-            Vec3::new(v.x * ang, v.y * ang, v.z * ang)
-        }
+        Vec3::new(axis.x * angle, axis.y * angle, axis.z * angle)
     }
 
     #[inline]
@@ -0,0 +1,176 @@
+//! Rectangular matrices with dimensions known at compile-time.
+
+#![allow(missing_doc)] // we allow missing to avoid having to document the mij components.
+
+use std::mem;
+use std::fmt;
+use std::num::Zero;
+use traits::structure::Indexable;
+use traits::operations::ApproxEq;
+use structs::vec::{Vec2, Vec3, Vec4, Vec2MulRhs, Vec3MulRhs, Vec4MulRhs};
+use structs::mat::{Mat2, Mat3, Mat4, Mat2MulRhs, Mat3MulRhs, Mat4MulRhs};
+use structs::pretty;
+
+/// Matrix with 2 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
+pub struct Mat2x3<N> {
+    pub m11: N, pub m21: N,
+    pub m12: N, pub m22: N,
+    pub m13: N, pub m23: N
+}
+
+double_dispatch_binop_decl_trait!(Mat2x3, Mat2x3MulRhs)
+double_dispatch_binop_decl_trait!(Mat2x3, Mat2x3AddRhs)
+double_dispatch_binop_decl_trait!(Mat2x3, Mat2x3SubRhs)
+mul_redispatch_impl!(Mat2x3, Mat2x3MulRhs)
+add_redispatch_impl!(Mat2x3, Mat2x3AddRhs)
+sub_redispatch_impl!(Mat2x3, Mat2x3SubRhs)
+mat_impl!(Mat2x3, m11, m12, m13,
+                  m21, m22, m23)
+add_impl!(Mat2x3, Mat2x3AddRhs, m11, m12, m13, m21, m22, m23)
+sub_impl!(Mat2x3, Mat2x3SubRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, f64, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, f32, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, i64, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, i32, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, i16, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, i8, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, u64, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, u32, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, u16, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, u8, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, uint, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+mat_mul_scalar_impl!(Mat2x3, int, Mat2x3MulRhs, m11, m12, m13, m21, m22, m23)
+rect_mat_indexable_impl!(Mat2x3, 2, 3)
+rect_mat_show_impl!(Mat2x3, 2, 3)
+rect_mat_approx_eq_impl!(Mat2x3, 2, 3)
+rect_mat_transpose_impl!(Mat2x3, Mat3x2, 2, 3)
+rect_mat_mul_vec_impl!(Mat2x3, Mat2x3MulRhs, Vec3, Vec2, 2, 3)
+vec_mul_rect_mat_impl!(Mat2x3, Vec2, Vec2MulRhs, Vec3, 2, 3)
+rect_mat_mul_mat_impl!(Mat2x3, Mat2x3MulRhs, Mat3x2, Mat2, 2, 3, 2)
+rect_mat_mul_mat_impl!(Mat2x3, Mat2x3MulRhs, Mat3, Mat2x3, 2, 3, 3)
+rect_mat_mul_mat_impl!(Mat2, Mat2MulRhs, Mat2x3, Mat2x3, 2, 2, 3)
+
+/// Matrix with 3 rows and 2 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
+pub struct Mat3x2<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N
+}
+
+double_dispatch_binop_decl_trait!(Mat3x2, Mat3x2MulRhs)
+double_dispatch_binop_decl_trait!(Mat3x2, Mat3x2AddRhs)
+double_dispatch_binop_decl_trait!(Mat3x2, Mat3x2SubRhs)
+mul_redispatch_impl!(Mat3x2, Mat3x2MulRhs)
+add_redispatch_impl!(Mat3x2, Mat3x2AddRhs)
+sub_redispatch_impl!(Mat3x2, Mat3x2SubRhs)
+mat_impl!(Mat3x2, m11, m12,
+                  m21, m22,
+                  m31, m32)
+add_impl!(Mat3x2, Mat3x2AddRhs, m11, m12, m21, m22, m31, m32)
+sub_impl!(Mat3x2, Mat3x2SubRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, f64, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, f32, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, i64, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, i32, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, i16, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, i8, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, u64, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, u32, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, u16, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, u8, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, uint, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+mat_mul_scalar_impl!(Mat3x2, int, Mat3x2MulRhs, m11, m12, m21, m22, m31, m32)
+rect_mat_indexable_impl!(Mat3x2, 3, 2)
+rect_mat_show_impl!(Mat3x2, 3, 2)
+rect_mat_approx_eq_impl!(Mat3x2, 3, 2)
+rect_mat_transpose_impl!(Mat3x2, Mat2x3, 3, 2)
+rect_mat_mul_vec_impl!(Mat3x2, Mat3x2MulRhs, Vec2, Vec3, 3, 2)
+vec_mul_rect_mat_impl!(Mat3x2, Vec3, Vec3MulRhs, Vec2, 3, 2)
+rect_mat_mul_mat_impl!(Mat3x2, Mat3x2MulRhs, Mat2x3, Mat3, 3, 2, 3)
+rect_mat_mul_mat_impl!(Mat3x2, Mat3x2MulRhs, Mat2, Mat3x2, 3, 2, 2)
+rect_mat_mul_mat_impl!(Mat3, Mat3MulRhs, Mat3x2, Mat3x2, 3, 3, 2)
+
+/// Matrix with 3 rows and 4 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
+pub struct Mat3x4<N> {
+    pub m11: N, pub m21: N, pub m31: N,
+    pub m12: N, pub m22: N, pub m32: N,
+    pub m13: N, pub m23: N, pub m33: N,
+    pub m14: N, pub m24: N, pub m34: N
+}
+
+double_dispatch_binop_decl_trait!(Mat3x4, Mat3x4MulRhs)
+double_dispatch_binop_decl_trait!(Mat3x4, Mat3x4AddRhs)
+double_dispatch_binop_decl_trait!(Mat3x4, Mat3x4SubRhs)
+mul_redispatch_impl!(Mat3x4, Mat3x4MulRhs)
+add_redispatch_impl!(Mat3x4, Mat3x4AddRhs)
+sub_redispatch_impl!(Mat3x4, Mat3x4SubRhs)
+mat_impl!(Mat3x4, m11, m12, m13, m14,
+                  m21, m22, m23, m24,
+                  m31, m32, m33, m34)
+add_impl!(Mat3x4, Mat3x4AddRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+sub_impl!(Mat3x4, Mat3x4SubRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, f64, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, f32, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, i64, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, i32, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, i16, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, i8, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, u64, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, u32, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, u16, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, u8, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, uint, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+mat_mul_scalar_impl!(Mat3x4, int, Mat3x4MulRhs, m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34)
+rect_mat_indexable_impl!(Mat3x4, 3, 4)
+rect_mat_show_impl!(Mat3x4, 3, 4)
+rect_mat_approx_eq_impl!(Mat3x4, 3, 4)
+rect_mat_transpose_impl!(Mat3x4, Mat4x3, 3, 4)
+rect_mat_mul_vec_impl!(Mat3x4, Mat3x4MulRhs, Vec4, Vec3, 3, 4)
+vec_mul_rect_mat_impl!(Mat3x4, Vec3, Vec3MulRhs, Vec4, 3, 4)
+rect_mat_mul_mat_impl!(Mat3x4, Mat3x4MulRhs, Mat4x3, Mat3, 3, 4, 3)
+rect_mat_mul_mat_impl!(Mat3x4, Mat3x4MulRhs, Mat4, Mat3x4, 3, 4, 4)
+rect_mat_mul_mat_impl!(Mat3, Mat3MulRhs, Mat3x4, Mat3x4, 3, 3, 4)
+
+/// Matrix with 4 rows and 3 columns.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
+pub struct Mat4x3<N> {
+    pub m11: N, pub m21: N, pub m31: N, pub m41: N,
+    pub m12: N, pub m22: N, pub m32: N, pub m42: N,
+    pub m13: N, pub m23: N, pub m33: N, pub m43: N
+}
+
+double_dispatch_binop_decl_trait!(Mat4x3, Mat4x3MulRhs)
+double_dispatch_binop_decl_trait!(Mat4x3, Mat4x3AddRhs)
+double_dispatch_binop_decl_trait!(Mat4x3, Mat4x3SubRhs)
+mul_redispatch_impl!(Mat4x3, Mat4x3MulRhs)
+add_redispatch_impl!(Mat4x3, Mat4x3AddRhs)
+sub_redispatch_impl!(Mat4x3, Mat4x3SubRhs)
+mat_impl!(Mat4x3, m11, m12, m13,
+                  m21, m22, m23,
+                  m31, m32, m33,
+                  m41, m42, m43)
+add_impl!(Mat4x3, Mat4x3AddRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+sub_impl!(Mat4x3, Mat4x3SubRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, f64, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, f32, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, i64, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, i32, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, i16, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, i8, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, u64, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, u32, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, u16, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, u8, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, uint, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+mat_mul_scalar_impl!(Mat4x3, int, Mat4x3MulRhs, m11, m12, m13, m21, m22, m23, m31, m32, m33, m41, m42, m43)
+rect_mat_indexable_impl!(Mat4x3, 4, 3)
+rect_mat_show_impl!(Mat4x3, 4, 3)
+rect_mat_approx_eq_impl!(Mat4x3, 4, 3)
+rect_mat_transpose_impl!(Mat4x3, Mat3x4, 4, 3)
+rect_mat_mul_vec_impl!(Mat4x3, Mat4x3MulRhs, Vec3, Vec4, 4, 3)
+vec_mul_rect_mat_impl!(Mat4x3, Vec4, Vec4MulRhs, Vec3, 4, 3)
+rect_mat_mul_mat_impl!(Mat4x3, Mat4x3MulRhs, Mat3x4, Mat4, 4, 3, 4)
+rect_mat_mul_mat_impl!(Mat4x3, Mat4x3MulRhs, Mat3, Mat4x3, 4, 3, 3)
+rect_mat_mul_mat_impl!(Mat4, Mat4MulRhs, Mat4x3, Mat4x3, 4, 4, 3)
@@ -0,0 +1,209 @@
+#![macro_escape]
+
+macro_rules! rect_mat_indexable_impl(
+  ($t: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone> Indexable<(uint, uint), N> for $t<N> {
+        #[inline]
+        fn at(&self, (i, j): (uint, uint)) -> N {
+            unsafe {
+                mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(self)[i + j * $nrows].clone()
+            }
+        }
+
+        #[inline]
+        fn set(&mut self, (i, j): (uint, uint), val: N) {
+            unsafe {
+                mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self)[i + j * $nrows] = val
+            }
+        }
+
+        #[inline]
+        fn swap(&mut self, (i1, j1): (uint, uint), (i2, j2): (uint, uint)) {
+            unsafe {
+              mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self)
+                .swap(i1 + j1 * $nrows, i2 + j2 * $nrows)
+            }
+        }
+
+        #[inline]
+        fn shape(&self) -> (uint, uint) {
+            ($nrows, $ncols)
+        }
+
+        #[inline]
+        unsafe fn unsafe_at(&self, (i, j): (uint, uint)) -> N {
+            (*mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(self).unsafe_get(i + j * $nrows)).clone()
+        }
+
+        #[inline]
+        unsafe fn unsafe_set(&mut self, (i, j): (uint, uint), val: N) {
+            (*mem::transmute::<&mut $t<N>, &mut [N, ..$nrows * $ncols]>(self).unsafe_mut(i + j * $nrows)) = val
+        }
+    }
+  )
+)
+
+macro_rules! rect_mat_show_impl(
+    ($t: ident, $nrows: expr, $ncols: expr) => (
+        impl<N: fmt::Show> fmt::Show for $t<N> {
+            /// Writes this matrix with one bracketed row per line and aligned columns.
+            ///
+            /// The width and precision flags are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let elts = unsafe { mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(self) };
+                let strs = pretty::show_strs(f, elts.as_slice());
+
+                pretty::fmt_mat(f, $nrows, $ncols, strs.as_slice())
+            }
+        }
+    )
+)
+
+macro_rules! rect_mat_approx_eq_impl(
+    ($t: ident, $nrows: expr, $ncols: expr) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                let (ea, eb) = unsafe {
+                    (mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(a),
+                     mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(b))
+                };
+
+                ea.iter().zip(eb.iter()).all(|(a, b)| ApproxEq::approx_eq(a, b))
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                let (ea, eb) = unsafe {
+                    (mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(a),
+                     mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(b))
+                };
+
+                ea.iter().zip(eb.iter()).all(|(a, b)| ApproxEq::approx_eq_eps(a, b, epsilon))
+            }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                let (ea, eb) = unsafe {
+                    (mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(a),
+                     mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(b))
+                };
+
+                ea.iter().zip(eb.iter()).all(|(a, b)| ApproxEq::approx_eq_ulps(a, b, ulps))
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                let (ea, eb) = unsafe {
+                    (mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(a),
+                     mem::transmute::<&$t<N>, &[N, ..$nrows * $ncols]>(b))
+                };
+
+                ea.iter().zip(eb.iter()).all(|(a, b)| ApproxEq::approx_eq_eps_rel(a, b, abs_eps, rel_eps))
+            }
+        }
+    )
+)
+
+macro_rules! rect_mat_transpose_impl(
+    ($t: ident, $tt: ident, $nrows: expr, $ncols: expr) => (
+        impl<N: Clone + Zero> $t<N> {
+            /// The transpose of this matrix.
+            #[inline]
+            pub fn transpose_cpy(&self) -> $tt<N> {
+                let mut res: $tt<N> = Zero::zero();
+
+                for i in range(0u, $nrows) {
+                    for j in range(0u, $ncols) {
+                        res.set((j, i), self.at((i, j)));
+                    }
+                }
+
+                res
+            }
+        }
+    )
+)
+
+// Product of a `$nrows x $nk` matrix `$tl` with a `$nk x $ncols` matrix `$tr`, `$trhs` being the
+// double dispatch trait of `$tl`.
+macro_rules! rect_mat_mul_mat_impl(
+  ($tl: ident, $trhs: ident, $tr: ident, $tres: ident, $nrows: expr, $nk: expr, $ncols: expr) => (
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $trhs<N, $tres<N>> for $tr<N> {
+        #[inline]
+        fn binop(left: &$tl<N>, right: &$tr<N>) -> $tres<N> {
+            let mut res: $tres<N> = Zero::zero();
+
+            for i in range(0u, $nrows) {
+                for j in range(0u, $ncols) {
+                    let mut acc: N = Zero::zero();
+
+                    for k in range(0u, $nk) {
+                        acc = acc + left.at((i, k)) * right.at((k, j));
+                    }
+
+                    res.set((i, j), acc);
+                }
+            }
+
+            res
+        }
+    }
+  )
+)
+
+macro_rules! rect_mat_mul_vec_impl(
+  ($t: ident, $trhs: ident, $v: ident, $vres: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $trhs<N, $vres<N>> for $v<N> {
+        #[inline]
+        fn binop(left: &$t<N>, right: &$v<N>) -> $vres<N> {
+            let mut res: $vres<N> = Zero::zero();
+
+            for i in range(0u, $nrows) {
+                let mut acc: N = Zero::zero();
+
+                for j in range(0u, $ncols) {
+                    acc = acc + left.at((i, j)) * right.at(j);
+                }
+
+                res.set(i, acc);
+            }
+
+            res
+        }
+    }
+  )
+)
+
+macro_rules! vec_mul_rect_mat_impl(
+  ($t: ident, $v: ident, $vtrhs: ident, $vres: ident, $nrows: expr, $ncols: expr) => (
+    impl<N: Clone + Add<N, N> + Mul<N, N> + Zero> $vtrhs<N, $vres<N>> for $t<N> {
+        #[inline]
+        fn binop(left: &$v<N>, right: &$t<N>) -> $vres<N> {
+            let mut res: $vres<N> = Zero::zero();
+
+            for j in range(0u, $ncols) {
+                let mut acc: N = Zero::zero();
+
+                for i in range(0u, $nrows) {
+                    acc = acc + left.at(i) * right.at((i, j));
+                }
+
+                res.set(j, acc);
+            }
+
+            res
+        }
+    }
+  )
+)
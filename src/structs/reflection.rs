@@ -0,0 +1,138 @@
+//! Reflections with respect to a hyperplane.
+
+#![allow(missing_doc)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::num::Zero;
+use std::num;
+use structs::mat::{Mat2, Mat3, Mat4};
+use structs::vec::{Vec2, Vec3};
+use structs::pnt::{Pnt2, Pnt3};
+use traits::structure::{Col, Iterable, IterableMut};
+use traits::operations::{Inv, ApproxEq};
+use traits::geometry::{Dot, Norm, Transform, ToHomogeneous};
+use linalg::householder_matrix;
+
+
+/// Reflection with respect to a hyperplane.
+///
+/// The hyperplane is the set of points `p` such that `dot(normal, p) == bias`, where `normal` is
+/// a unit vector. The linear part of a reflection is a Householder matrix.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Reflection<N, V> {
+    normal: V,
+    bias:   N
+}
+
+impl<N: Float, V: Norm<N>> Reflection<N, V> {
+    /// Creates the reflection with respect to the hyperplane of normal `normal` and bias `bias`,
+    /// i.e., the set of points `p` such that `dot(normal, p) == bias`.
+    ///
+    /// The normal does not have to be unit. Fails if it is zero.
+    #[inline]
+    pub fn new(normal: V, bias: N) -> Reflection<N, V> {
+        let mut normal = normal;
+        let n          = normal.normalize();
+
+        assert!(!n.is_zero(), "The normal of a reflection hyperplane must not be zero.");
+
+        Reflection {
+            normal: normal,
+            bias:   bias / n
+        }
+    }
+}
+
+impl<N, V> Reflection<N, V> {
+    /// The unit normal of the reflection hyperplane.
+    #[inline]
+    pub fn normal<'a>(&'a self) -> &'a V {
+        &self.normal
+    }
+
+    /// The signed distance from the origin to the reflection hyperplane.
+    #[inline]
+    pub fn bias<'a>(&'a self) -> &'a N {
+        &self.bias
+    }
+}
+
+/*
+ * FIXME: we use the double dispatch trick here so that we can reflect vectors _and_
+ * points. Remove this as soon as rust supports multidispatch.
+ */
+pub trait ReflectionRhs<N, V> {
+    fn reflect(left: &Reflection<N, V>, right: &Self) -> Self;
+}
+
+impl<N, V, T: ReflectionRhs<N, V>> Transform<T> for Reflection<N, V> {
+    #[inline(always)]
+    fn transform(&self, other: &T) -> T {
+        ReflectionRhs::reflect(self, other)
+    }
+
+    // A reflection is its own inverse.
+    #[inline(always)]
+    fn inv_transform(&self, other: &T) -> T {
+        ReflectionRhs::reflect(self, other)
+    }
+}
+
+impl<N: Clone, V: Clone> Inv for Reflection<N, V> {
+    #[inline]
+    fn inv(&mut self) -> bool {
+        // a reflection is its own inverse.
+        true
+    }
+
+    #[inline]
+    fn inv_cpy(m: &Reflection<N, V>) -> Option<Reflection<N, V>> {
+        Some(m.clone())
+    }
+}
+
+impl<N: ApproxEq<N>, V: ApproxEq<N>> ApproxEq<N> for Reflection<N, V> {
+    #[inline]
+    fn approx_epsilon(_: Option<Reflection<N, V>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<Reflection<N, V>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &Reflection<N, V>, b: &Reflection<N, V>) -> bool {
+        ApproxEq::approx_eq(&a.normal, &b.normal) && ApproxEq::approx_eq(&a.bias, &b.bias)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &Reflection<N, V>, b: &Reflection<N, V>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.normal, &b.normal, eps) && ApproxEq::approx_eq_eps(&a.bias, &b.bias, eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &Reflection<N, V>, b: &Reflection<N, V>, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.normal, &b.normal, ulps) && ApproxEq::approx_eq_ulps(&a.bias, &b.bias, ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &Reflection<N, V>, b: &Reflection<N, V>, abs_eps: &N, rel_eps: &N) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.normal, &b.normal, abs_eps, rel_eps) && ApproxEq::approx_eq_eps_rel(&a.bias, &b.bias, abs_eps, rel_eps)
+    }
+}
+
+// Computes `v - s * n`.
+#[inline]
+fn sub_scaled<N: Float, V: Iterable<N> + IterableMut<N>>(mut v: V, n: &V, s: N) -> V {
+    for (e, ne) in v.iter_mut().zip(n.iter()) {
+        *e = *e - *ne * s
+    }
+
+    v
+}
+
+reflection_transform_impl!(ReflectionRhs, Vec2, Pnt2)
+reflection_transform_impl!(ReflectionRhs, Vec3, Pnt3)
+reflection_to_homogeneous_impl!(Vec2, Mat2, Mat3, 2)
+reflection_to_homogeneous_impl!(Vec3, Mat3, Mat4, 3)
@@ -0,0 +1,50 @@
+#![macro_escape]
+
+macro_rules! reflection_transform_impl(
+    ($trhs: ident, $tv: ident, $tp: ident) => (
+        impl<N: Float + Clone> $trhs<N, $tv<N>> for $tv<N> {
+            #[inline]
+            fn reflect(r: &Reflection<N, $tv<N>>, v: &$tv<N>) -> $tv<N> {
+                let _2: N = num::cast(2.0f64).unwrap();
+
+                sub_scaled(v.clone(), &r.normal, _2 * Dot::dot(&r.normal, v))
+            }
+        }
+
+        impl<N: Float + Clone> $trhs<N, $tv<N>> for $tp<N> {
+            #[inline]
+            fn reflect(r: &Reflection<N, $tv<N>>, p: &$tp<N>) -> $tp<N> {
+                let _2: N = num::cast(2.0f64).unwrap();
+                let dist  = Dot::dot(&r.normal, p.as_vec()) - r.bias;
+
+                sub_scaled(p.as_vec().clone(), &r.normal, _2 * dist).to_pnt()
+            }
+        }
+    )
+)
+
+macro_rules! reflection_to_homogeneous_impl(
+    ($tv: ident, $tm: ident, $th: ident, $dim: expr) => (
+        impl<N: Float + Clone> Reflection<N, $tv<N>> {
+            /// The matrix of the linear part of this reflection, i.e., the reflection with respect
+            /// to the hyperplane parallel to `self` and passing through the origin.
+            #[inline]
+            pub fn to_mat(&self) -> $tm<N> {
+                householder_matrix($dim, 0, self.normal.clone())
+            }
+        }
+
+        impl<N: Float + Clone> ToHomogeneous<$th<N>> for Reflection<N, $tv<N>> {
+            fn to_homogeneous(r: &Reflection<N, $tv<N>>) -> $th<N> {
+                let _2: N           = num::cast(2.0f64).unwrap();
+                let mut res: $th<N> = ToHomogeneous::to_homogeneous(&r.to_mat());
+
+                // the origin is sent to twice its projection on the hyperplane.
+                let t: $tv<N> = sub_scaled(Zero::zero(), &r.normal, -_2 * r.bias);
+                res.set_col($dim, ToHomogeneous::to_homogeneous(t.as_pnt()).to_vec());
+
+                res
+            }
+        }
+    )
+)
@@ -3,14 +3,16 @@
 #![allow(missing_doc)]
 
 use std::num::{Zero, One};
+use std::num;
 use std::rand::{Rand, Rng};
 use traits::geometry::{Rotate, Rotation, AbsoluteRotate, RotationMatrix, Transform, ToHomogeneous,
-                       Norm, Cross};
+                       Norm, Cross, Dot};
 use traits::structure::{Cast, Dim, Row, Col};
 use traits::operations::{Absolute, Inv, Transpose, ApproxEq};
 use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec2MulRhs, Vec3MulRhs, Vec4MulRhs};
 use structs::pnt::{Pnt2, Pnt3, Pnt4, Pnt2MulRhs, Pnt3MulRhs, Pnt4MulRhs};
 use structs::mat::{Mat2, Mat3, Mat4, Mat5};
+use structs::quat::UnitQuat;
 
 
 /// Two dimensional rotation matrix.
@@ -28,12 +30,52 @@ impl<N: Clone + FloatMath + Neg<N>> Rot2<N> {
             submat: Mat2::new(coa.clone(), -sia, sia.clone(), coa)
         }
     }
+
+    /// Builds a 2 dimensional rotation matrix from an angle in radian.
+    #[inline]
+    pub fn from_angle(angle: N) -> Rot2<N> {
+        Rot2::new(Vec1::new(angle))
+    }
+
+    /// The rotation angle of this rotation matrix, in `[-pi, pi]`.
+    #[inline]
+    pub fn angle(&self) -> N {
+        (-self.submat.m12).atan2(self.submat.m11.clone())
+    }
+
+    /// Raises this rotation to the power `t`, i.e., multiplies its angle by `t`.
+    #[inline]
+    pub fn powf(&self, t: N) -> Rot2<N> {
+        Rot2::from_angle(self.angle() * t)
+    }
+
+    /// Spherical linear interpolation between two rotations.
+    ///
+    /// The interpolation follows the shortest path. Returns `self` if `t == 0` and `other` if `t
+    /// == 1`.
+    #[inline]
+    pub fn slerp(&self, other: &Rot2<N>, t: N) -> Rot2<N> {
+        let delta = (*other * Transpose::transpose_cpy(self)).angle();
+
+        Rot2::from_angle(self.angle() + delta * t)
+    }
+}
+
+impl<N> Rot2<N> {
+    /// Builds a rotation matrix from an orthogonal matrix.
+    ///
+    /// This is unsafe because the orthogonality of `mat` is not checked.
+    pub unsafe fn new_with_mat(mat: Mat2<N>) -> Rot2<N> {
+        Rot2 {
+            submat: mat
+        }
+    }
 }
 
 impl<N: FloatMath + Clone> Rotation<Vec1<N>> for Rot2<N> {
     #[inline]
     fn rotation(&self) -> Vec1<N> {
-        Vec1::new((-self.submat.m12).atan2(self.submat.m11.clone()))
+        Vec1::new(self.angle())
     }
 
     #[inline]
@@ -68,9 +110,13 @@ impl<N: FloatMath + Clone> Rotation<Vec1<N>> for Rot2<N> {
 }
 
 impl<N: Clone + Rand + FloatMath + Neg<N>> Rand for Rot2<N> {
+    /// Generates a rotation uniformly distributed on the circle, i.e., with an angle uniformly
+    /// distributed in `[-pi, pi[`.
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Rot2<N> {
-        Rot2::new(rng.gen())
+        let u: N = rng.gen();
+
+        Rot2::from_angle(u * Float::two_pi() - Float::pi())
     }
 }
 
@@ -166,6 +212,258 @@ impl<N: Clone + FloatMath> Rot3<N> {
     }
 }
 
+/// Conventions of Euler angles `(a, b, c)`.
+///
+/// The name of each convention gives the fixed axes the elemental rotations are applied about,
+/// in order: first a rotation of angle `a` around the first axis, then a rotation of angle `b`
+/// around the second axis, and finally a rotation of angle `c` around the third axis. Those
+/// extrinsic conventions are equivalent to the intrinsic ones (about the rotated axes) with the
+/// reversed order: `EulerXYZ` is the same as the intrinsic z-y'-x'' convention with the
+/// angles `(c, b, a)`.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub enum EulerConvention {
+    /// Rotation around `x`, then `y`, then `z`. This is the roll − pitch − yaw convention used
+    /// by `Rot3::new_with_euler_angles`.
+    EulerXYZ,
+    /// Rotation around `z`, then `y`, then `x`.
+    EulerZYX,
+    /// Rotation around `z`, then `x`, then `z` again.
+    EulerZXZ
+}
+
+impl<N: Clone + FloatMath> Rot3<N> {
+    /// Creates a new rotation from Euler angles following the given convention.
+    pub fn from_euler_angles(convention: EulerConvention, a: N, b: N, c: N) -> Rot3<N> {
+        let _0: N = Zero::zero();
+
+        match convention {
+            EulerXYZ => Rot3::new(Vec3::new(_0, _0, c)) * Rot3::new(Vec3::new(_0, b, _0)) * Rot3::new(Vec3::new(a, _0, _0)),
+            EulerZYX => Rot3::new(Vec3::new(c, _0, _0)) * Rot3::new(Vec3::new(_0, b, _0)) * Rot3::new(Vec3::new(_0, _0, a)),
+            EulerZXZ => Rot3::new(Vec3::new(_0, _0, c)) * Rot3::new(Vec3::new(b, _0, _0)) * Rot3::new(Vec3::new(_0, _0, a))
+        }
+    }
+
+    /// Computes the axis and the angle of this rotation.
+    ///
+    /// This is the inverse of `Rot3::new`: the returned axis is normalized and the angle is in
+    /// `[0, pi]`. If the angle is zero, the axis is arbitrarily set to `x`.
+    pub fn axis_angle(&self) -> (Vec3<N>, N) {
+        let m     = &self.submat;
+        let _1: N = One::one();
+        let _2: N = _1 + _1;
+
+        // `skew` is `2 * sin(angle) * axis` and `cos2` is `2 * cos(angle)`.
+        let skew  = Vec3::new(m.m32 - m.m23, m.m13 - m.m31, m.m21 - m.m12);
+        let cos2  = m.m11 + m.m22 + m.m33 - _1;
+        let angle = Norm::norm(&skew).atan2(cos2);
+
+        if angle.is_zero() {
+            (Vec3::x(), angle)
+        }
+        else if cos2 >= Zero::zero() {
+            (Norm::normalize_cpy(&skew), angle)
+        }
+        else {
+            // Close to pi, the skew-symmetric part vanishes and the axis is extracted from the
+            // symmetric part `cos * I + (1 - cos) * axis * axis^T` instead.
+            let cos       = cos2 / _2;
+            let one_m_cos = _1 - cos;
+            let xx = (m.m11 - cos) / one_m_cos;
+            let yy = (m.m22 - cos) / one_m_cos;
+            let zz = (m.m33 - cos) / one_m_cos;
+            let xy = (m.m12 + m.m21) / (_2 * one_m_cos);
+            let xz = (m.m13 + m.m31) / (_2 * one_m_cos);
+            let yz = (m.m23 + m.m32) / (_2 * one_m_cos);
+
+            let axis =
+                if xx >= yy && xx >= zz {
+                    Vec3::new(xx, xy, xz)
+                }
+                else if yy >= zz {
+                    Vec3::new(xy, yy, yz)
+                }
+                else {
+                    Vec3::new(xz, yz, zz)
+                };
+
+            let axis = Norm::normalize_cpy(&axis);
+
+            if Dot::dot(&axis, &skew) < Zero::zero() {
+                (-axis, angle)
+            }
+            else {
+                (axis, angle)
+            }
+        }
+    }
+
+    /// The exponential map of the rotation group: builds the rotation of axis `w / |w|` and
+    /// angle `|w|`.
+    ///
+    /// This is equivalent to `Rot3::new(w)` but remains accurate for small angles.
+    pub fn exp(w: &Vec3<N>) -> Rot3<N> {
+        let _1: N     = One::one();
+        let _2: N     = _1 + _1;
+        let theta_sq  = Norm::sqnorm(w);
+        let eps: N    = Float::epsilon();
+
+        // `a = sin(theta) / theta` and `b = (1 - cos(theta)) / theta^2`
+        let (a, b) =
+            if theta_sq < eps.sqrt() {
+                let _6: N   = num::cast(6.0f64).unwrap();
+                let _24: N  = num::cast(24.0f64).unwrap();
+                let _120: N = num::cast(120.0f64).unwrap();
+                let _720: N = num::cast(720.0f64).unwrap();
+
+                (_1 - theta_sq / _6 + theta_sq * theta_sq / _120,
+                 _1 / _2 - theta_sq / _24 + theta_sq * theta_sq / _720)
+            }
+            else {
+                let theta     = theta_sq.sqrt();
+                let half_sin  = (theta / _2).sin();
+
+                (theta.sin() / theta, _2 * half_sin * half_sin / theta_sq)
+            };
+
+        // R = I + a * [w]x + b * [w]x^2, with [w]x^2 = w * w^T - theta^2 * I
+        let diag = _1 - b * theta_sq;
+
+        unsafe {
+            Rot3::new_with_mat(Mat3::new(
+                diag + b * w.x * w.x,    b * w.x * w.y - a * w.z, b * w.x * w.z + a * w.y,
+                b * w.x * w.y + a * w.z, diag + b * w.y * w.y,    b * w.y * w.z - a * w.x,
+                b * w.x * w.z - a * w.y, b * w.y * w.z + a * w.x, diag + b * w.z * w.z))
+        }
+    }
+
+    /// The logarithm map of the rotation group: computes the rotation vector `w` such that
+    /// `Rot3::exp(&w) == *self`.
+    ///
+    /// The norm of the result is in `[0, pi]`.
+    pub fn log(&self) -> Vec3<N> {
+        let (axis, angle) = self.axis_angle();
+
+        Vec3::new(axis.x * angle, axis.y * angle, axis.z * angle)
+    }
+
+    /// Raises this rotation to the power `t`, i.e., multiplies its angle by `t`.
+    ///
+    /// The rotation axis is kept unchanged. This is computed with `Rot3::exp(&(self.log() * t))`.
+    #[inline]
+    pub fn powf(&self, t: N) -> Rot3<N> {
+        let w = self.log();
+
+        Rot3::exp(&Vec3::new(w.x * t, w.y * t, w.z * t))
+    }
+
+    /// Computes the chordal mean of a set of rotations.
+    ///
+    /// This is the rotation minimizing the sum of the squared Frobenius norms of its differences
+    /// with each element of `rots`, i.e., the projection of their arithmetic mean on the rotation
+    /// group. It is computed with `UnitQuat::mean`.
+    ///
+    /// Returns `None` if `rots` is empty.
+    pub fn mean(rots: &[Rot3<N>]) -> Option<Rot3<N>> {
+        let quats: Vec<UnitQuat<N>> = rots.iter().map(|r| UnitQuat::from_rotation_matrix(r)).collect();
+
+        UnitQuat::mean(quats.as_slice()).map(|q| q.to_rot())
+    }
+
+    /// Re-orthonormalizes this rotation matrix.
+    ///
+    /// This removes the numerical drift accumulated by repeated compositions, which makes the
+    /// matrix slowly stop being orthogonal. The orthogonality error between the first two columns
+    /// is distributed evenly between them, the third column is recomputed with a cross product,
+    /// and all the columns are normalized.
+    pub fn renormalize(&mut self) {
+        let _1: N      = One::one();
+        let _2: N      = _1 + _1;
+        let a: Vec3<N> = self.submat.col(0);
+        let b: Vec3<N> = self.submat.col(1);
+        let half_err   = Dot::dot(&a, &b) / _2;
+
+        let x = Norm::normalize_cpy(&Vec3::new(a.x - b.x * half_err, a.y - b.y * half_err, a.z - b.z * half_err));
+        let y = Norm::normalize_cpy(&Vec3::new(b.x - a.x * half_err, b.y - a.y * half_err, b.z - a.z * half_err));
+        let z = Norm::normalize_cpy(&Cross::cross(&x, &y));
+
+        self.submat.set_col(0, x);
+        self.submat.set_col(1, y);
+        self.submat.set_col(2, z);
+    }
+}
+
+impl<N: Clone + FloatMath + ApproxEq<N>> Rot3<N> {
+    /// Checks that this matrix is orthogonal with a determinant equal to 1, up to `eps`.
+    ///
+    /// This is always true for a rotation matrix up to the numerical drift due to repeated
+    /// compositions. See `renormalize` to remove that drift.
+    pub fn is_special_orthogonal(&self, eps: &N) -> bool {
+        let _0: N      = Zero::zero();
+        let _1: N      = One::one();
+        let x: Vec3<N> = self.submat.col(0);
+        let y: Vec3<N> = self.submat.col(1);
+        let z: Vec3<N> = self.submat.col(2);
+
+        ApproxEq::approx_eq_eps(&Dot::dot(&x, &x), &_1, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&y, &y), &_1, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&z, &z), &_1, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&x, &y), &_0, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&x, &z), &_0, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&y, &z), &_0, eps) &&
+        ApproxEq::approx_eq_eps(&Dot::dot(&Cross::cross(&x, &y), &z), &_1, eps)
+    }
+
+    /// Computes the Euler angles `(a, b, c)` of this rotation following the given convention.
+    ///
+    /// The middle angle `b` is in `[-pi / 2, pi / 2]` for `EulerXYZ` and `EulerZYX`, and in `[0,
+    /// pi]` for `EulerZXZ`. The other angles are in `[-pi, pi]`.
+    ///
+    /// At a gimbal lock, i.e. when `b` is `+/- pi / 2` for `EulerXYZ` and `EulerZYX` or `0` or
+    /// `pi` for `EulerZXZ`, the first and last rotation axes are aligned and only the combination
+    /// of `a` and `c` is known. In that case, `a` is set to zero and `c` accounts for the whole
+    /// rotation around the aligned axes.
+    pub fn to_euler_angles(&self, convention: EulerConvention) -> (N, N, N) {
+        let m = &self.submat;
+        let _0: N = Zero::zero();
+
+        match convention {
+            EulerXYZ => {
+                let cb = (m.m11 * m.m11 + m.m21 * m.m21).sqrt();
+                let b  = (-m.m31).atan2(cb);
+
+                if ApproxEq::approx_eq(&cb, &_0) {
+                    (_0, b, (-m.m12).atan2(m.m22.clone()))
+                }
+                else {
+                    (m.m32.atan2(m.m33.clone()), b, m.m21.atan2(m.m11.clone()))
+                }
+            },
+            EulerZYX => {
+                let cb = (m.m11 * m.m11 + m.m12 * m.m12).sqrt();
+                let b  = m.m13.atan2(cb);
+
+                if ApproxEq::approx_eq(&cb, &_0) {
+                    (_0, b, m.m32.atan2(m.m22.clone()))
+                }
+                else {
+                    ((-m.m12).atan2(m.m11.clone()), b, (-m.m23).atan2(m.m33.clone()))
+                }
+            },
+            EulerZXZ => {
+                let sb = (m.m31 * m.m31 + m.m32 * m.m32).sqrt();
+                let b  = sb.atan2(m.m33.clone());
+
+                if ApproxEq::approx_eq(&sb, &_0) {
+                    (_0, b, m.m21.atan2(m.m11.clone()))
+                }
+                else {
+                    (m.m31.atan2(m.m32.clone()), b, m.m13.atan2(-m.m23))
+                }
+            }
+        }
+    }
+}
+
 impl<N: Clone + Float> Rot3<N> {
     /// Reorient this matrix such that its local `x` axis points to a given point. Note that the
     /// usually known `look_at` function does the same thing but with the `z` axis. See `look_at_z`
@@ -209,33 +507,9 @@ impl<N: Clone + FloatMath + Cast<f32>>
 Rotation<Vec3<N>> for Rot3<N> {
     #[inline]
     fn rotation(&self) -> Vec3<N> {
-        let angle = ((self.submat.m11 + self.submat.m22 + self.submat.m33 - One::one()) / Cast::from(2.0)).acos();
-
-        if angle != angle {
-            // FIXME: handle that correctly
-            Zero::zero()
-        }
-        else if angle.is_zero() {
-            Zero::zero()
-        }
-        else {
-            let m32_m23 = self.submat.m32 - self.submat.m23;
-            let m13_m31 = self.submat.m13 - self.submat.m31;
-            let m21_m12 = self.submat.m21 - self.submat.m12;
+        let (axis, angle) = self.axis_angle();
 
-            let denom = (m32_m23 * m32_m23 + m13_m31 * m13_m31 + m21_m12 * m21_m12).sqrt();
-
-            if denom.is_zero() {
-                // XXX: handle that properly
-                // fail!("Internal error: singularity.")
-                Zero::zero()
-            }
-            else {
-                let a_d = angle / denom;
-
-                Vec3::new(m32_m23 * a_d, m13_m31 * a_d, m21_m12 * a_d)
-            }
-        }
+        Vec3::new(axis.x * angle, axis.y * angle, axis.z * angle)
     }
 
     #[inline]
@@ -272,9 +546,14 @@ Rotation<Vec3<N>> for Rot3<N> {
 
 impl<N: Clone + Rand + FloatMath>
 Rand for Rot3<N> {
+    /// Generates a rotation uniformly distributed on the rotation group.
+    ///
+    /// See the `Rand` implementation of `UnitQuat`.
     #[inline]
     fn rand<R: Rng>(rng: &mut R) -> Rot3<N> {
-        Rot3::new(rng.gen())
+        let q: UnitQuat<N> = rng.gen();
+
+        q.to_rot()
     }
 }
 
@@ -299,6 +299,11 @@ macro_rules! approx_eq_impl(
                 ApproxEq::approx_epsilon(None::<N>)
             }
 
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
             #[inline]
             fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
                 ApproxEq::approx_eq(&a.submat, &b.submat)
@@ -308,6 +313,16 @@ macro_rules! approx_eq_impl(
             fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
                 ApproxEq::approx_eq_eps(&a.submat, &b.submat, epsilon)
             }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.submat, &b.submat, ulps)
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.submat, &b.submat, abs_eps, rel_eps)
+            }
         }
     )
 )
@@ -0,0 +1,117 @@
+//! Streaming computation of the mean and covariance of observations.
+
+use traits::operations::{CovNormalization, PopulationCov, SampleCov};
+use traits::structure::{Cast, Indexable};
+use structs::dvec::DVec;
+use structs::dmat::DMat;
+
+/// Accumulates observations one at a time to compute their mean and covariance.
+///
+/// The observations are not stored: the mean and the sum of the squared deviations from the mean
+/// are updated with Welford's algorithm, which is numerically stable. Two accumulators can be
+/// merged, e.g., after processing different parts of the data in parallel.
+#[deriving(Clone, Show)]
+pub struct RunningStats<N> {
+    count: uint,
+    mean:  DVec<N>,
+    m2:    DMat<N>
+}
+
+impl<N: Clone + Num + Cast<f64>> RunningStats<N> {
+    /// Creates a new accumulator of `dim`-dimensional observations.
+    #[inline]
+    pub fn new(dim: uint) -> RunningStats<N> {
+        RunningStats {
+            count: 0,
+            mean:  DVec::new_zeros(dim),
+            m2:    DMat::new_zeros(dim, dim)
+        }
+    }
+
+    /// The number of observations accumulated so far.
+    #[inline]
+    pub fn count(&self) -> uint {
+        self.count
+    }
+
+    /// The mean of the observations accumulated so far.
+    #[inline]
+    pub fn mean(&self) -> &DVec<N> {
+        &self.mean
+    }
+
+    /// Accumulates an observation.
+    pub fn push(&mut self, x: &DVec<N>) {
+        let dim = self.mean.len();
+        assert!(x.len() == dim, "The observation has the wrong dimension.");
+
+        self.count = self.count + 1;
+
+        let n: N  = Cast::from(self.count as f64);
+        let delta = DVec::from_fn(dim, |i| x.at(i) - self.mean.at(i));
+
+        for i in range(0u, dim) {
+            let mi = self.mean.at(i) + delta.at(i) / n;
+            self.mean.set(i, mi);
+        }
+
+        for j in range(0u, dim) {
+            let dj = x.at(j) - self.mean.at(j);
+
+            for i in range(0u, dim) {
+                let mij = self.m2.at((i, j)) + delta.at(i) * dj;
+                self.m2.set((i, j), mij);
+            }
+        }
+    }
+
+    /// Accumulates every observation accumulated by `other`.
+    pub fn merge(&mut self, other: &RunningStats<N>) {
+        let dim = self.mean.len();
+        assert!(other.mean.len() == dim, "Cannot merge statistics with different dimensions.");
+
+        if other.count == 0 {
+            return
+        }
+
+        let na: N = Cast::from(self.count as f64);
+        let nb: N = Cast::from(other.count as f64);
+        let n     = na + nb;
+        let delta = DVec::from_fn(dim, |i| other.mean.at(i) - self.mean.at(i));
+
+        for j in range(0u, dim) {
+            for i in range(0u, dim) {
+                let mij = self.m2.at((i, j)) + other.m2.at((i, j)) +
+                          delta.at(i) * delta.at(j) * na * nb / n;
+                self.m2.set((i, j), mij);
+            }
+        }
+
+        for i in range(0u, dim) {
+            let mi = self.mean.at(i) + delta.at(i) * nb / n;
+            self.mean.set(i, mi);
+        }
+
+        self.count = self.count + other.count;
+    }
+
+    /// The covariance of the observations accumulated so far.
+    ///
+    /// Fails if there are not enough observations for the required normalization.
+    pub fn cov(&self, normalization: CovNormalization) -> DMat<N> {
+        let normalizer = match normalization {
+            PopulationCov => {
+                assert!(self.count > 0, "The covariance of no observation is undefined.");
+                self.count
+            },
+            SampleCov => {
+                assert!(self.count > 1, "The sample covariance requires two observations.");
+                self.count - 1
+            }
+        };
+
+        let normalizer: N = Cast::from(normalizer as f64);
+
+        DMat::from_fn(self.m2.nrows(), self.m2.ncols(), |i, j| self.m2.at((i, j)) / normalizer)
+    }
+}
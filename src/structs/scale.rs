@@ -0,0 +1,68 @@
+//! Non-uniform scaling transformations.
+
+#![allow(missing_doc)]
+
+use std::num::{Zero, One};
+use std::rand::{Rand, Rng};
+use structs::mat::{Mat2, Mat3, Mat4};
+use traits::structure::{Dim, Diag, Iterable, IterableMut};
+use traits::operations::{Inv, ApproxEq};
+use traits::geometry::{Transform, ToHomogeneous};
+
+use structs::vec::{Vec2, Vec3};
+use structs::pnt::{Pnt2, Pnt3};
+use structs::rot::{Rot2, Rot3, Rot2MulRhs, Rot3MulRhs};
+use structs::iso::{Iso2, Iso3, Iso2MulRhs, Iso3MulRhs};
+
+
+/// Two dimensional non-uniform scaling.
+///
+/// This scales each axis independently. Composing it with a rotation or an isometry gives a
+/// general matrix since the result is no longer guaranteed to conserve angles.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Scale2<N> {
+    /// The scale factors along each axis.
+    pub scale: Vec2<N>
+}
+
+/// Three dimensional non-uniform scaling.
+///
+/// This scales each axis independently. Composing it with a rotation or an isometry gives a
+/// general matrix since the result is no longer guaranteed to conserve angles.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Scale3<N> {
+    /// The scale factors along each axis.
+    pub scale: Vec3<N>
+}
+
+scale_impl!(Scale2, Vec2, Mat2)
+double_dispatch_binop_decl_trait!(Scale2, Scale2MulRhs)
+mul_redispatch_impl!(Scale2, Scale2MulRhs)
+dim_impl!(Scale2, 2)
+scale_one_impl!(Scale2)
+scale_rand_impl!(Scale2, Vec2)
+scale_approx_eq_impl!(Scale2)
+scale_to_homogeneous_impl!(Scale2, Mat3)
+scale_inv_impl!(Scale2)
+scale_transform_impl!(Scale2TransformRhs, Scale2, Vec2, Pnt2)
+scale_mul_scale_impl!(Scale2, Scale2MulRhs)
+scale_mul_vec_impl!(Scale2, Vec2, Scale2MulRhs)
+scale_mul_pnt_impl!(Scale2, Pnt2, Scale2MulRhs)
+scale_mul_rot_impl!(Scale2, Rot2, Mat2, Scale2MulRhs, Rot2MulRhs)
+scale_mul_iso_impl!(Scale2, Iso2, Mat3, Scale2MulRhs, Iso2MulRhs)
+
+scale_impl!(Scale3, Vec3, Mat3)
+double_dispatch_binop_decl_trait!(Scale3, Scale3MulRhs)
+mul_redispatch_impl!(Scale3, Scale3MulRhs)
+dim_impl!(Scale3, 3)
+scale_one_impl!(Scale3)
+scale_rand_impl!(Scale3, Vec3)
+scale_approx_eq_impl!(Scale3)
+scale_to_homogeneous_impl!(Scale3, Mat4)
+scale_inv_impl!(Scale3)
+scale_transform_impl!(Scale3TransformRhs, Scale3, Vec3, Pnt3)
+scale_mul_scale_impl!(Scale3, Scale3MulRhs)
+scale_mul_vec_impl!(Scale3, Vec3, Scale3MulRhs)
+scale_mul_pnt_impl!(Scale3, Pnt3, Scale3MulRhs)
+scale_mul_rot_impl!(Scale3, Rot3, Mat3, Scale3MulRhs, Rot3MulRhs)
+scale_mul_iso_impl!(Scale3, Iso3, Mat4, Scale3MulRhs, Iso3MulRhs)
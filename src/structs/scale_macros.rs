@@ -0,0 +1,251 @@
+#![macro_escape]
+
+macro_rules! scale_impl(
+    ($t: ident, $tv: ident, $tm: ident) => (
+        impl<N> $t<N> {
+            /// Creates a new scaling from its per-axis scale factors.
+            #[inline]
+            pub fn new(scale: $tv<N>) -> $t<N> {
+                $t {
+                    scale: scale
+                }
+            }
+        }
+
+        impl<N: Clone + Zero> $t<N> {
+            /// The diagonal matrix of this scaling.
+            #[inline]
+            pub fn to_mat(&self) -> $tm<N> {
+                Diag::from_diag(&self.scale)
+            }
+        }
+    )
+)
+
+macro_rules! scale_one_impl(
+    ($t: ident) => (
+        impl<N: One> One for $t<N> {
+            #[inline]
+            fn one() -> $t<N> {
+                $t::new(One::one())
+            }
+        }
+    )
+)
+
+macro_rules! scale_mul_scale_impl(
+    ($t: ident, $tmul: ident) => (
+        impl<N: Mul<N, N>> $tmul<N, $t<N>> for $t<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$t<N>) -> $t<N> {
+                $t::new(left.scale * right.scale)
+            }
+        }
+    )
+)
+
+macro_rules! scale_mul_vec_impl(
+    ($t: ident, $tv: ident, $tmul: ident) => (
+        impl<N: Mul<N, N>> $tmul<N, $tv<N>> for $tv<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tv<N>) -> $tv<N> {
+                left.scale * *right
+            }
+        }
+    )
+)
+
+macro_rules! scale_mul_pnt_impl(
+    ($t: ident, $tp: ident, $tmul: ident) => (
+        impl<N: Mul<N, N>> $tmul<N, $tp<N>> for $tp<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tp<N>) -> $tp<N> {
+                (left.scale * *right.as_vec()).to_pnt()
+            }
+        }
+    )
+)
+
+macro_rules! scale_mul_rot_impl(
+    ($t: ident, $trot: ident, $tm: ident, $tmul: ident, $trotmul: ident) => (
+        impl<N: Num + Clone> $tmul<N, $tm<N>> for $trot<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$trot<N>) -> $tm<N> {
+                left.to_mat() * *right.submat()
+            }
+        }
+
+        impl<N: Num + Clone> $trotmul<N, $tm<N>> for $t<N> {
+            #[inline]
+            fn binop(left: &$trot<N>, right: &$t<N>) -> $tm<N> {
+                *left.submat() * right.to_mat()
+            }
+        }
+    )
+)
+
+macro_rules! scale_mul_iso_impl(
+    ($t: ident, $tiso: ident, $th: ident, $tmul: ident, $tisomul: ident) => (
+        impl<N: Num + Clone> $tmul<N, $th<N>> for $tiso<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tiso<N>) -> $th<N> {
+                let l: $th<N> = ToHomogeneous::to_homogeneous(left);
+                let r: $th<N> = ToHomogeneous::to_homogeneous(right);
+
+                l * r
+            }
+        }
+
+        impl<N: Num + Clone> $tisomul<N, $th<N>> for $t<N> {
+            #[inline]
+            fn binop(left: &$tiso<N>, right: &$t<N>) -> $th<N> {
+                let l: $th<N> = ToHomogeneous::to_homogeneous(left);
+                let r: $th<N> = ToHomogeneous::to_homogeneous(right);
+
+                l * r
+            }
+        }
+    )
+)
+
+macro_rules! scale_transform_impl(
+    ($trhs: ident, $t: ident, $tv: ident, $tp: ident) => (
+        /*
+         * FIXME: we use the double dispatch trick here so that we can transform vectors _and_
+         * points. Remove this as soon as rust supports multidispatch.
+         */
+        pub trait $trhs<N> {
+            fn transform(left: &$t<N>, right: &Self) -> Self;
+            fn inv_transform(left: &$t<N>, right: &Self) -> Self;
+        }
+
+        impl<N, V: $trhs<N>> Transform<V> for $t<N> {
+            #[inline(always)]
+            fn transform(&self, other: &V) -> V {
+                $trhs::transform(self, other)
+            }
+
+            #[inline(always)]
+            fn inv_transform(&self, other: &V) -> V {
+                $trhs::inv_transform(self, other)
+            }
+        }
+
+        impl<N: Num> $trhs<N> for $tv<N> {
+            #[inline]
+            fn transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                t * *v
+            }
+
+            #[inline]
+            fn inv_transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                *v / t.scale
+            }
+        }
+
+        impl<N: Num> $trhs<N> for $tp<N> {
+            #[inline]
+            fn transform(t: &$t<N>, p: &$tp<N>) -> $tp<N> {
+                t * *p
+            }
+
+            #[inline]
+            fn inv_transform(t: &$t<N>, p: &$tp<N>) -> $tp<N> {
+                (*p.as_vec() / t.scale).to_pnt()
+            }
+        }
+    )
+)
+
+macro_rules! scale_inv_impl(
+    ($t: ident) => (
+        impl<N: Clone + Num> Inv for $t<N> {
+            #[inline]
+            fn inv(&mut self) -> bool {
+                if self.scale.iter().any(|e| e.is_zero()) {
+                    return false
+                }
+
+                let _1: N = One::one();
+
+                for e in self.scale.iter_mut() {
+                    *e = _1 / *e
+                }
+
+                true
+            }
+
+            #[inline]
+            fn inv_cpy(m: &$t<N>) -> Option<$t<N>> {
+                let mut res = m.clone();
+
+                if res.inv() {
+                    Some(res)
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+macro_rules! scale_to_homogeneous_impl(
+    ($t: ident, $th: ident) => (
+        impl<N: Num + Clone> ToHomogeneous<$th<N>> for $t<N> {
+            fn to_homogeneous(m: &$t<N>) -> $th<N> {
+                ToHomogeneous::to_homogeneous(&m.to_mat())
+            }
+        }
+    )
+)
+
+macro_rules! scale_approx_eq_impl(
+    ($t: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.scale, &b.scale)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.scale, &b.scale, epsilon)
+            }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.scale, &b.scale, ulps)
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.scale, &b.scale, abs_eps, rel_eps)
+            }
+        }
+    )
+)
+
+macro_rules! scale_rand_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: Rand + Num> Rand for $t<N> {
+            #[inline]
+            fn rand<R: Rng>(rng: &mut R) -> $t<N> {
+                // the scale factors are in [1, 2[ to make sure they are never zero.
+                let scale: $tv<N> = rng.gen();
+
+                $t::new(scale + One::one())
+            }
+        }
+    )
+)
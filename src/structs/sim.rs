@@ -0,0 +1,88 @@
+//! Similarity transformations.
+
+#![allow(missing_doc)]
+
+use std::num::{Zero, One};
+use std::rand::{Rand, Rng};
+use structs::mat::{Mat3, Mat4};
+use traits::structure::{Dim, Col, Indexable, IterableMut};
+use traits::operations::{Inv, ApproxEq};
+use traits::geometry::{Transform, Transformation, ToHomogeneous};
+
+use structs::vec::{Vec1, Vec2, Vec3};
+use structs::pnt::{Pnt2, Pnt3};
+use structs::rot::{Rot2, Rot3};
+use structs::iso::{Iso2, Iso3};
+
+
+/// Two dimensional similarity.
+///
+/// This is the composition of a uniform scaling, followed by a rotation, followed by a
+/// translation. Similarities conserve angles and distance ratios, hence do not allow shearing
+/// nor non-uniform scaling.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Sim2<N> {
+    /// The rotation applicable by this similarity.
+    pub rotation:    Rot2<N>,
+    /// The translation applicable by this similarity.
+    pub translation: Vec2<N>,
+    /// The uniform scale factor applicable by this similarity. Always strictly positive.
+    pub scale:       N
+}
+
+/// Three dimensional similarity.
+///
+/// This is the composition of a uniform scaling, followed by a rotation, followed by a
+/// translation. Similarities conserve angles and distance ratios, hence do not allow shearing
+/// nor non-uniform scaling.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub struct Sim3<N> {
+    /// The rotation applicable by this similarity.
+    pub rotation:    Rot3<N>,
+    /// The translation applicable by this similarity.
+    pub translation: Vec3<N>,
+    /// The uniform scale factor applicable by this similarity. Always strictly positive.
+    pub scale:       N
+}
+
+// FIXME: remove this when vectors and points can be multiplied by a generic scalar.
+#[inline]
+fn scale_by<N: Mul<N, N>, V: IterableMut<N>>(mut v: V, s: &N) -> V {
+    for e in v.iter_mut() {
+        *e = *e * *s
+    }
+
+    v
+}
+
+sim_impl!(Sim2, Rot2, Vec2, Vec1, Iso2)
+sim_scaling_impl!(Sim2, Iso2)
+double_dispatch_binop_decl_trait!(Sim2, Sim2MulRhs)
+mul_redispatch_impl!(Sim2, Sim2MulRhs)
+dim_impl!(Sim2, 2)
+sim_one_impl!(Sim2)
+sim_rand_impl!(Sim2)
+sim_approx_eq_impl!(Sim2)
+sim_to_homogeneous_impl!(Sim2, Mat3)
+sim_inv_impl!(Sim2)
+sim_transform_impl!(Sim2TransformRhs, Sim2, Vec2, Pnt2)
+sim_transformation_impl!(Sim2)
+sim_mul_sim_impl!(Sim2, Sim2MulRhs)
+sim_mul_pnt_impl!(Sim2, Pnt2, Sim2MulRhs)
+sim_mul_vec_impl!(Sim2, Vec2, Sim2MulRhs)
+
+sim_impl!(Sim3, Rot3, Vec3, Vec3, Iso3)
+sim_scaling_impl!(Sim3, Iso3)
+double_dispatch_binop_decl_trait!(Sim3, Sim3MulRhs)
+mul_redispatch_impl!(Sim3, Sim3MulRhs)
+dim_impl!(Sim3, 3)
+sim_one_impl!(Sim3)
+sim_rand_impl!(Sim3)
+sim_approx_eq_impl!(Sim3)
+sim_to_homogeneous_impl!(Sim3, Mat4)
+sim_inv_impl!(Sim3)
+sim_transform_impl!(Sim3TransformRhs, Sim3, Vec3, Pnt3)
+sim_transformation_impl!(Sim3)
+sim_mul_sim_impl!(Sim3, Sim3MulRhs)
+sim_mul_pnt_impl!(Sim3, Pnt3, Sim3MulRhs)
+sim_mul_vec_impl!(Sim3, Vec3, Sim3MulRhs)
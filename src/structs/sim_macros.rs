@@ -0,0 +1,334 @@
+#![macro_escape]
+
+macro_rules! sim_impl(
+    ($t: ident, $submat: ident, $subvec: ident, $subrotvec: ident, $iso: ident) => (
+        impl<N: Clone + FloatMath + Num> $t<N> {
+            /// Creates a new similarity from a translation, a rotation, and a scale factor.
+            ///
+            /// The scale factor must be strictly positive.
+            #[inline]
+            pub fn new(translation: $subvec<N>, rotation: $subrotvec<N>, scale: N) -> $t<N> {
+                $t::new_with_rotmat(translation, $submat::new(rotation), scale)
+            }
+
+            /// Creates a new similarity from a translation, a rotation matrix, and a scale factor.
+            ///
+            /// The scale factor must be strictly positive.
+            #[inline]
+            pub fn new_with_rotmat(translation: $subvec<N>, rotation: $submat<N>, scale: N) -> $t<N> {
+                assert!(scale > Zero::zero(), "The scale factor of a similarity must be positive.");
+
+                $t {
+                    rotation:    rotation,
+                    translation: translation,
+                    scale:       scale
+                }
+            }
+
+            /// Creates a new similarity from an isometry and a scale factor.
+            ///
+            /// The scaling is applied before the isometry.
+            #[inline]
+            pub fn new_with_iso(iso: $iso<N>, scale: N) -> $t<N> {
+                $t::new_with_rotmat(iso.translation, iso.rotation, scale)
+            }
+
+            /// The isometric part of this similarity, i.e., this similarity without its scaling.
+            #[inline]
+            pub fn to_iso(&self) -> $iso<N> {
+                $iso::new_with_rotmat(self.translation.clone(), self.rotation.clone())
+            }
+        }
+    )
+)
+
+macro_rules! sim_scaling_impl(
+    ($t: ident, $iso: ident) => (
+        impl<N: Clone + FloatMath + Num> $t<N> {
+            /// Appends a uniform scaling to this similarity, i.e., applies it after `self`.
+            ///
+            /// The scale factor must be strictly positive.
+            #[inline]
+            pub fn append_scaling(&mut self, s: &N) {
+                assert!(*s > Zero::zero(), "The scale factor of a similarity must be positive.");
+
+                self.translation = scale_by(self.translation.clone(), s);
+                self.scale       = self.scale * *s;
+            }
+
+            /// Prepends a uniform scaling to this similarity, i.e., applies it before `self`.
+            ///
+            /// The scale factor must be strictly positive.
+            #[inline]
+            pub fn prepend_scaling(&mut self, s: &N) {
+                assert!(*s > Zero::zero(), "The scale factor of a similarity must be positive.");
+
+                self.scale = self.scale * *s;
+            }
+        }
+
+        impl<N: Clone + FloatMath + Num> $iso<N> {
+            /// Appends a uniform scaling to this isometry.
+            ///
+            /// The result is the similarity applying `self` first, followed by the scaling.
+            #[inline]
+            pub fn append_scaling_to_sim(&self, s: &N) -> $t<N> {
+                $t::new_with_rotmat(scale_by(self.translation.clone(), s), self.rotation.clone(), s.clone())
+            }
+
+            /// Prepends a uniform scaling to this isometry.
+            ///
+            /// The result is the similarity applying the scaling first, followed by `self`.
+            #[inline]
+            pub fn prepend_scaling_to_sim(&self, s: &N) -> $t<N> {
+                $t::new_with_iso(self.clone(), s.clone())
+            }
+        }
+    )
+)
+
+macro_rules! sim_one_impl(
+    ($t: ident) => (
+        impl<N: FloatMath + Clone> One for $t<N> {
+            #[inline]
+            fn one() -> $t<N> {
+                $t::new_with_rotmat(Zero::zero(), One::one(), One::one())
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_sim_impl(
+    ($t: ident, $tmul: ident) => (
+        impl<N: FloatMath + Clone> $tmul<N, $t<N>> for $t<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$t<N>) -> $t<N> {
+                $t::new_with_rotmat(
+                    left.translation + scale_by(left.rotation * right.translation, &left.scale),
+                    left.rotation * right.rotation,
+                    left.scale * right.scale)
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_pnt_impl(
+    ($t: ident, $tp: ident, $tmul: ident) => (
+        impl<N: Num + Clone> $tmul<N, $tp<N>> for $tp<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tp<N>) -> $tp<N> {
+                scale_by(left.rotation * *right, &left.scale) + left.translation
+            }
+        }
+    )
+)
+
+macro_rules! sim_mul_vec_impl(
+    ($t: ident, $tv: ident, $tmul: ident) => (
+        impl<N: Num + Clone> $tmul<N, $tv<N>> for $tv<N> {
+            #[inline]
+            fn binop(left: &$t<N>, right: &$tv<N>) -> $tv<N> {
+                scale_by(left.rotation * *right, &left.scale)
+            }
+        }
+    )
+)
+
+macro_rules! sim_transform_impl(
+    ($trhs: ident, $t: ident, $tv: ident, $tp: ident) => (
+        /*
+         * FIXME: we use the double dispatch trick here so that we can transform vectors _and_
+         * points. Remove this as soon as rust supports multidispatch.
+         */
+        pub trait $trhs<N> {
+            fn transform(left: &$t<N>, right: &Self) -> Self;
+            fn inv_transform(left: &$t<N>, right: &Self) -> Self;
+        }
+
+        impl<N, V: $trhs<N>> Transform<V> for $t<N> {
+            #[inline(always)]
+            fn transform(&self, other: &V) -> V {
+                $trhs::transform(self, other)
+            }
+
+            #[inline(always)]
+            fn inv_transform(&self, other: &V) -> V {
+                $trhs::inv_transform(self, other)
+            }
+        }
+
+        impl<N: Num + Clone> $trhs<N> for $tv<N> {
+            #[inline]
+            fn transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                t * *v
+            }
+
+            #[inline]
+            fn inv_transform(t: &$t<N>, v: &$tv<N>) -> $tv<N> {
+                let _1: N = One::one();
+
+                scale_by(t.rotation.inv_transform(v), &(_1 / t.scale))
+            }
+        }
+
+        impl<N: Num + Clone> $trhs<N> for $tp<N> {
+            #[inline]
+            fn transform(t: &$t<N>, p: &$tp<N>) -> $tp<N> {
+                t * *p
+            }
+
+            #[inline]
+            fn inv_transform(t: &$t<N>, p: &$tp<N>) -> $tp<N> {
+                let _1: N = One::one();
+
+                scale_by(t.rotation.inv_transform(&(p - t.translation)), &(_1 / t.scale))
+            }
+        }
+    )
+)
+
+macro_rules! sim_transformation_impl(
+    ($t: ident) => (
+        impl<N: FloatMath + Clone> Transformation<$t<N>> for $t<N> {
+            fn transformation(&self) -> $t<N> {
+                self.clone()
+            }
+
+            fn inv_transformation(&self) -> $t<N> {
+                // inversion will never fails
+                Inv::inv_cpy(self).unwrap()
+            }
+
+            fn append_transformation(&mut self, t: &$t<N>) {
+                *self = *t * *self
+            }
+
+            fn append_transformation_cpy(sim: &$t<N>, t: &$t<N>) -> $t<N> {
+                t * *sim
+            }
+
+            fn prepend_transformation(&mut self, t: &$t<N>) {
+                *self = *self * *t
+            }
+
+            fn prepend_transformation_cpy(sim: &$t<N>, t: &$t<N>) -> $t<N> {
+                *sim * *t
+            }
+
+            fn set_transformation(&mut self, t: $t<N>) {
+                *self = t
+            }
+        }
+    )
+)
+
+macro_rules! sim_inv_impl(
+    ($t: ident) => (
+        impl<N: Clone + Num> Inv for $t<N> {
+            #[inline]
+            fn inv(&mut self) -> bool {
+                let _1: N = One::one();
+
+                self.scale = _1 / self.scale;
+                self.rotation.inv();
+                self.translation = scale_by(self.rotation * -self.translation, &self.scale);
+
+                // always succeed
+                true
+            }
+
+            #[inline]
+            fn inv_cpy(m: &$t<N>) -> Option<$t<N>> {
+                let mut res = m.clone();
+
+                res.inv();
+
+                // always succeed
+                Some(res)
+            }
+        }
+    )
+)
+
+macro_rules! sim_to_homogeneous_impl(
+    ($t: ident, $th: ident) => (
+        impl<N: Num + Clone> ToHomogeneous<$th<N>> for $t<N> {
+            fn to_homogeneous(m: &$t<N>) -> $th<N> {
+                let mut res = ToHomogeneous::to_homogeneous(&m.rotation);
+
+                // scale the rotation
+                let dim = Dim::dim(None::<$th<N>>);
+
+                for i in range(0u, dim - 1) {
+                    for j in range(0u, dim - 1) {
+                        let rij = res.at((i, j));
+                        res.set((i, j), rij * m.scale);
+                    }
+                }
+
+                // copy the translation
+                res.set_col(dim - 1, ToHomogeneous::to_homogeneous(m.translation.as_pnt()).to_vec());
+
+                res
+            }
+        }
+    )
+)
+
+macro_rules! sim_approx_eq_impl(
+    ($t: ident) => (
+        impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
+            #[inline]
+            fn approx_epsilon(_: Option<$t<N>>) -> N {
+                ApproxEq::approx_epsilon(None::<N>)
+            }
+
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
+            #[inline]
+            fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
+                ApproxEq::approx_eq(&a.rotation, &b.rotation) &&
+                    ApproxEq::approx_eq(&a.translation, &b.translation) &&
+                    ApproxEq::approx_eq(&a.scale, &b.scale)
+            }
+
+            #[inline]
+            fn approx_eq_eps(a: &$t<N>, b: &$t<N>, epsilon: &N) -> bool {
+                ApproxEq::approx_eq_eps(&a.rotation, &b.rotation, epsilon) &&
+                    ApproxEq::approx_eq_eps(&a.translation, &b.translation, epsilon) &&
+                    ApproxEq::approx_eq_eps(&a.scale, &b.scale, epsilon)
+            }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.rotation, &b.rotation, ulps) &&
+                    ApproxEq::approx_eq_ulps(&a.translation, &b.translation, ulps) &&
+                    ApproxEq::approx_eq_ulps(&a.scale, &b.scale, ulps)
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.rotation, &b.rotation, abs_eps, rel_eps) &&
+                    ApproxEq::approx_eq_eps_rel(&a.translation, &b.translation, abs_eps, rel_eps) &&
+                    ApproxEq::approx_eq_eps_rel(&a.scale, &b.scale, abs_eps, rel_eps)
+            }
+        }
+    )
+)
+
+macro_rules! sim_rand_impl(
+    ($t: ident) => (
+        impl<N: Rand + Clone + FloatMath> Rand for $t<N> {
+            #[inline]
+            fn rand<R: Rng>(rng: &mut R) -> $t<N> {
+                // the scale factor is in [1, 2[ to make sure it is never zero.
+                let scale: N = rng.gen();
+
+                $t::new(rng.gen(), rng.gen(), scale + One::one())
+            }
+        }
+    )
+)
@@ -0,0 +1,394 @@
+//! Four-dimensional vector and matrix of `f32` stored in SIMD registers.
+
+#![allow(experimental)]
+
+use std::simd::f32x4;
+use std::fmt;
+use std::num::Zero;
+use traits::operations::{Transpose, ApproxEq};
+use traits::geometry::{Dot, Norm};
+use structs::vec::Vec4;
+use structs::mat::Mat4;
+
+/// Four-dimensional vector of `f32` stored in a 16-bytes aligned SIMD register.
+///
+/// This is a separate, opt-in type for performance-critical code, not a replacement for
+/// `Vec4<f32>`: it only implements the arithmetic operators, `Dot`, `Norm` and `ApproxEq`, each
+/// performed on the four components at once. Use `to_vec4` to access the full API of `Vec4`.
+pub struct SimdVec4 {
+    data: f32x4
+}
+
+/// Four-dimensional square matrix of `f32` with its columns stored in SIMD registers.
+///
+/// This is a separate, opt-in type for performance-critical code, not a replacement for
+/// `Mat4<f32>`: it only implements the products, additions, `Transpose` and `ApproxEq`, computed
+/// with SIMD operations. Use `to_mat4` to access the full API of `Mat4` (e.g. `Inv` or `Det`).
+pub struct SimdMat4 {
+    cols: [f32x4, ..4]
+}
+
+#[inline(always)]
+fn splat(e: f32) -> f32x4 {
+    f32x4(e, e, e, e)
+}
+
+#[inline(always)]
+fn hsum(v: f32x4) -> f32 {
+    let f32x4(x, y, z, w) = v;
+
+    (x + y) + (z + w)
+}
+
+impl SimdVec4 {
+    /// Creates a new vector.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> SimdVec4 {
+        SimdVec4 { data: f32x4(x, y, z, w) }
+    }
+
+    /// Creates a new vector with all its components equal to `e`.
+    #[inline]
+    pub fn new_repeat(e: f32) -> SimdVec4 {
+        SimdVec4 { data: splat(e) }
+    }
+
+    /// Converts a `Vec4<f32>` to a SIMD vector.
+    #[inline]
+    pub fn from_vec4(v: &Vec4<f32>) -> SimdVec4 {
+        SimdVec4::new(v.x, v.y, v.z, v.w)
+    }
+
+    /// Converts this vector to a `Vec4<f32>`.
+    #[inline]
+    pub fn to_vec4(&self) -> Vec4<f32> {
+        let f32x4(x, y, z, w) = self.data;
+
+        Vec4::new(x, y, z, w)
+    }
+}
+
+// `f32x4` implements neither `Clone` nor `PartialEq`, so those cannot be derived.
+impl Clone for SimdVec4 {
+    #[inline]
+    fn clone(&self) -> SimdVec4 {
+        SimdVec4 { data: self.data }
+    }
+}
+
+impl PartialEq for SimdVec4 {
+    #[inline]
+    fn eq(&self, other: &SimdVec4) -> bool {
+        self.to_vec4() == other.to_vec4()
+    }
+}
+
+impl fmt::Show for SimdVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_vec4().fmt(f)
+    }
+}
+
+impl Zero for SimdVec4 {
+    #[inline]
+    fn zero() -> SimdVec4 {
+        SimdVec4::new_repeat(0.0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == Zero::zero()
+    }
+}
+
+impl Add<SimdVec4, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn add(&self, other: &SimdVec4) -> SimdVec4 {
+        SimdVec4 { data: self.data + other.data }
+    }
+}
+
+impl Sub<SimdVec4, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn sub(&self, other: &SimdVec4) -> SimdVec4 {
+        SimdVec4 { data: self.data - other.data }
+    }
+}
+
+impl Mul<SimdVec4, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn mul(&self, other: &SimdVec4) -> SimdVec4 {
+        SimdVec4 { data: self.data * other.data }
+    }
+}
+
+impl Div<SimdVec4, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn div(&self, other: &SimdVec4) -> SimdVec4 {
+        SimdVec4 { data: self.data / other.data }
+    }
+}
+
+impl Mul<f32, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn mul(&self, other: &f32) -> SimdVec4 {
+        SimdVec4 { data: self.data * splat(*other) }
+    }
+}
+
+impl Div<f32, SimdVec4> for SimdVec4 {
+    #[inline]
+    fn div(&self, other: &f32) -> SimdVec4 {
+        SimdVec4 { data: self.data / splat(*other) }
+    }
+}
+
+impl Neg<SimdVec4> for SimdVec4 {
+    #[inline]
+    fn neg(&self) -> SimdVec4 {
+        SimdVec4 { data: splat(0.0) - self.data }
+    }
+}
+
+impl Dot<f32> for SimdVec4 {
+    #[inline]
+    fn dot(a: &SimdVec4, b: &SimdVec4) -> f32 {
+        hsum(a.data * b.data)
+    }
+}
+
+impl Norm<f32> for SimdVec4 {
+    #[inline]
+    fn sqnorm(v: &SimdVec4) -> f32 {
+        Dot::dot(v, v)
+    }
+
+    #[inline]
+    fn normalize_cpy(v: &SimdVec4) -> SimdVec4 {
+        *v / Norm::norm(v)
+    }
+
+    #[inline]
+    fn normalize(&mut self) -> f32 {
+        let l = Norm::norm(self);
+
+        self.data = self.data / splat(l);
+
+        l
+    }
+}
+
+impl ApproxEq<f32> for SimdVec4 {
+    #[inline]
+    fn approx_epsilon(_: Option<SimdVec4>) -> f32 {
+        ApproxEq::approx_epsilon(None::<f32>)
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<SimdVec4>) -> u32 {
+        ApproxEq::approx_ulps(None::<f32>)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &SimdVec4, b: &SimdVec4, eps: &f32) -> bool {
+        ApproxEq::approx_eq_eps(&a.to_vec4(), &b.to_vec4(), eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &SimdVec4, b: &SimdVec4, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.to_vec4(), &b.to_vec4(), ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &SimdVec4, b: &SimdVec4, abs_eps: &f32, rel_eps: &f32) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.to_vec4(), &b.to_vec4(), abs_eps, rel_eps)
+    }
+}
+
+impl SimdMat4 {
+    /// Creates a new matrix from its components given row by row.
+    #[inline]
+    pub fn new(m11: f32, m12: f32, m13: f32, m14: f32,
+               m21: f32, m22: f32, m23: f32, m24: f32,
+               m31: f32, m32: f32, m33: f32, m34: f32,
+               m41: f32, m42: f32, m43: f32, m44: f32)
+               -> SimdMat4 {
+        SimdMat4 {
+            cols: [
+                f32x4(m11, m21, m31, m41),
+                f32x4(m12, m22, m32, m42),
+                f32x4(m13, m23, m33, m43),
+                f32x4(m14, m24, m34, m44)
+            ]
+        }
+    }
+
+    /// Creates the identity matrix.
+    #[inline]
+    pub fn new_identity() -> SimdMat4 {
+        SimdMat4::new(1.0, 0.0, 0.0, 0.0,
+                      0.0, 1.0, 0.0, 0.0,
+                      0.0, 0.0, 1.0, 0.0,
+                      0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Converts a `Mat4<f32>` to a SIMD matrix.
+    #[inline]
+    pub fn from_mat4(m: &Mat4<f32>) -> SimdMat4 {
+        SimdMat4::new(m.m11, m.m12, m.m13, m.m14,
+                      m.m21, m.m22, m.m23, m.m24,
+                      m.m31, m.m32, m.m33, m.m34,
+                      m.m41, m.m42, m.m43, m.m44)
+    }
+
+    /// Converts this matrix to a `Mat4<f32>`.
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4<f32> {
+        let f32x4(m11, m21, m31, m41) = self.cols[0];
+        let f32x4(m12, m22, m32, m42) = self.cols[1];
+        let f32x4(m13, m23, m33, m43) = self.cols[2];
+        let f32x4(m14, m24, m34, m44) = self.cols[3];
+
+        Mat4::new(m11, m12, m13, m14,
+                  m21, m22, m23, m24,
+                  m31, m32, m33, m34,
+                  m41, m42, m43, m44)
+    }
+
+    /// The `i`-th column of this matrix.
+    #[inline]
+    pub fn col(&self, i: uint) -> SimdVec4 {
+        SimdVec4 { data: self.cols[i] }
+    }
+
+    // Linear combination of the columns of `self` with the components of `v` as coefficients.
+    #[inline(always)]
+    fn combine(&self, v: f32x4) -> f32x4 {
+        let f32x4(x, y, z, w) = v;
+
+        (self.cols[0] * splat(x) + self.cols[1] * splat(y)) +
+        (self.cols[2] * splat(z) + self.cols[3] * splat(w))
+    }
+}
+
+impl Clone for SimdMat4 {
+    #[inline]
+    fn clone(&self) -> SimdMat4 {
+        SimdMat4 { cols: self.cols }
+    }
+}
+
+impl PartialEq for SimdMat4 {
+    #[inline]
+    fn eq(&self, other: &SimdMat4) -> bool {
+        self.to_mat4() == other.to_mat4()
+    }
+}
+
+impl fmt::Show for SimdMat4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_mat4().fmt(f)
+    }
+}
+
+impl Mul<SimdVec4, SimdVec4> for SimdMat4 {
+    #[inline]
+    fn mul(&self, v: &SimdVec4) -> SimdVec4 {
+        SimdVec4 { data: self.combine(v.data) }
+    }
+}
+
+impl Mul<SimdMat4, SimdMat4> for SimdMat4 {
+    #[inline]
+    fn mul(&self, other: &SimdMat4) -> SimdMat4 {
+        SimdMat4 {
+            cols: [
+                self.combine(other.cols[0]),
+                self.combine(other.cols[1]),
+                self.combine(other.cols[2]),
+                self.combine(other.cols[3])
+            ]
+        }
+    }
+}
+
+impl Add<SimdMat4, SimdMat4> for SimdMat4 {
+    #[inline]
+    fn add(&self, other: &SimdMat4) -> SimdMat4 {
+        SimdMat4 {
+            cols: [
+                self.cols[0] + other.cols[0],
+                self.cols[1] + other.cols[1],
+                self.cols[2] + other.cols[2],
+                self.cols[3] + other.cols[3]
+            ]
+        }
+    }
+}
+
+impl Sub<SimdMat4, SimdMat4> for SimdMat4 {
+    #[inline]
+    fn sub(&self, other: &SimdMat4) -> SimdMat4 {
+        SimdMat4 {
+            cols: [
+                self.cols[0] - other.cols[0],
+                self.cols[1] - other.cols[1],
+                self.cols[2] - other.cols[2],
+                self.cols[3] - other.cols[3]
+            ]
+        }
+    }
+}
+
+impl Transpose for SimdMat4 {
+    #[inline]
+    fn transpose_cpy(m: &SimdMat4) -> SimdMat4 {
+        let mut res = m.clone();
+
+        res.transpose();
+
+        res
+    }
+
+    #[inline]
+    fn transpose(&mut self) {
+        let f32x4(m11, m21, m31, m41) = self.cols[0];
+        let f32x4(m12, m22, m32, m42) = self.cols[1];
+        let f32x4(m13, m23, m33, m43) = self.cols[2];
+        let f32x4(m14, m24, m34, m44) = self.cols[3];
+
+        self.cols = [
+            f32x4(m11, m12, m13, m14),
+            f32x4(m21, m22, m23, m24),
+            f32x4(m31, m32, m33, m34),
+            f32x4(m41, m42, m43, m44)
+        ];
+    }
+}
+
+impl ApproxEq<f32> for SimdMat4 {
+    #[inline]
+    fn approx_epsilon(_: Option<SimdMat4>) -> f32 {
+        ApproxEq::approx_epsilon(None::<f32>)
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<SimdMat4>) -> u32 {
+        ApproxEq::approx_ulps(None::<f32>)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &SimdMat4, b: &SimdMat4, eps: &f32) -> bool {
+        ApproxEq::approx_eq_eps(&a.to_mat4(), &b.to_mat4(), eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &SimdMat4, b: &SimdMat4, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.to_mat4(), &b.to_mat4(), ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &SimdMat4, b: &SimdMat4, abs_eps: &f32, rel_eps: &f32) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.to_mat4(), &b.to_mat4(), abs_eps, rel_eps)
+    }
+}
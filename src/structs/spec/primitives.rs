@@ -3,24 +3,142 @@
 #![allow(missing_doc)]
 #![allow(non_camel_case_types)]
 
-use traits::structure::Cast;
+use std::io::IoResult;
+use std::num::{Bounded, Float};
+use traits::structure::{Cast, Bytes};
+use traits::operations::Conjugate;
 
 // Double dispatch traits to drive the Cast method for primitive types.
 macro_rules! primitive_double_dispatch_cast_decl_trait(
     ($t: ident, $tcast: ident) => (
         pub trait $tcast {
             fn to(Self) -> $t;
+            fn try_to(Self) -> Option<$t>;
         }
     )
 )
 
-macro_rules! primitive_double_dispatch_cast_impl(
+// The `as` operator is undefined if a float is involved and the value does not fit in the target
+// type. Thus, `try_to` checks the range of the value before any cast, and checks the precision by
+// reverting the cast afterwards.
+
+// Casts between integers are always defined, so the result can be reverted and compared.
+macro_rules! int_to_int_cast_impl(
+    ($ttarget: ident, $tself: ident, $tcast: ident) => (
+        impl $tcast for $tself{
+            #[inline(always)]
+            fn to(v: $tself) -> $ttarget {
+                v as $ttarget
+            }
+
+            #[inline]
+            fn try_to(v: $tself) -> Option<$ttarget> {
+                let res = v as $ttarget;
+
+                if res as $tself == v && (v < 0 as $tself) == (res < 0 as $ttarget) {
+                    Some(res)
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+// `max as F + 1.0` is the power of two right above the `max` of an integer type: either `max`
+// is exactly representable by the float type `F`, or it is rounded up to this power of two. Every
+// comparison with NaN is false.
+macro_rules! float_to_int_cast_impl(
+    ($ttarget: ident, $tself: ident, $tcast: ident) => (
+        impl $tcast for $tself{
+            #[inline(always)]
+            fn to(v: $tself) -> $ttarget {
+                v as $ttarget
+            }
+
+            #[inline]
+            fn try_to(v: $tself) -> Option<$ttarget> {
+                let min: $ttarget = Bounded::min_value();
+                let max: $ttarget = Bounded::max_value();
+
+                if v >= min as $tself && v < max as $tself + 1.0 {
+                    let res = v as $ttarget;
+
+                    if res as $tself == v {
+                        Some(res)
+                    }
+                    else {
+                        None
+                    }
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+// The integer is rounded to the nearest float, which may be out of the range of the integer type,
+// e.g., `u64::MAX as f32` is `2^64`. So the result is reverted only if it is in range.
+macro_rules! int_to_float_cast_impl(
+    ($ttarget: ident, $tself: ident, $tcast: ident) => (
+        impl $tcast for $tself{
+            #[inline(always)]
+            fn to(v: $tself) -> $ttarget {
+                v as $ttarget
+            }
+
+            #[inline]
+            fn try_to(v: $tself) -> Option<$ttarget> {
+                let min: $tself = Bounded::min_value();
+                let max: $tself = Bounded::max_value();
+                let res = v as $ttarget;
+
+                if res >= min as $ttarget && res < max as $ttarget + 1.0 && res as $tself == v {
+                    Some(res)
+                }
+                else {
+                    None
+                }
+            }
+        }
+    )
+)
+
+// NaN and infinities exist in every float type. Other values are compared with the bounds of the
+// target type as `f64`, which represents every `f32` exactly.
+macro_rules! float_to_float_cast_impl(
     ($ttarget: ident, $tself: ident, $tcast: ident) => (
         impl $tcast for $tself{
             #[inline(always)]
             fn to(v: $tself) -> $ttarget {
                 v as $ttarget
             }
+
+            #[inline]
+            fn try_to(v: $tself) -> Option<$ttarget> {
+                let wide = v as f64;
+                let max: $ttarget = Bounded::max_value();
+
+                if wide.is_nan() || wide.is_infinite() {
+                    Some(v as $ttarget)
+                }
+                else if wide >= -(max as f64) && wide <= max as f64 {
+                    let res = v as $ttarget;
+
+                    if res as $tself == v {
+                        Some(res)
+                    }
+                    else {
+                        None
+                    }
+                }
+                else {
+                    None
+                }
+            }
         }
     )
 )
@@ -32,6 +150,11 @@ macro_rules! primitive_cast_redispatch_impl(
             fn from(t: T) -> $t {
                 $tcast::to(t)
             }
+
+            #[inline(always)]
+            fn try_from(t: T) -> Option<$t> {
+                $tcast::try_to(t)
+            }
         }
     )
 )
@@ -62,158 +185,221 @@ primitive_cast_redispatch_impl!(u8,   u8Cast)
 primitive_cast_redispatch_impl!(int,  intCast)
 primitive_cast_redispatch_impl!(uint, uintCast)
 
-primitive_double_dispatch_cast_impl!(f64, f64,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, f32,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, i64,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, i32,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, i16,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, i8,   f64Cast)
-primitive_double_dispatch_cast_impl!(f64, u64,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, u32,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, u16,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, u8,   f64Cast)
-primitive_double_dispatch_cast_impl!(f64, int,  f64Cast)
-primitive_double_dispatch_cast_impl!(f64, uint, f64Cast)
-
-primitive_double_dispatch_cast_impl!(f32, f64,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, f32,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, i64,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, i32,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, i16,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, i8,   f32Cast)
-primitive_double_dispatch_cast_impl!(f32, u64,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, u32,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, u16,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, u8,   f32Cast)
-primitive_double_dispatch_cast_impl!(f32, int,  f32Cast)
-primitive_double_dispatch_cast_impl!(f32, uint, f32Cast)
-
-primitive_double_dispatch_cast_impl!(i64, f64,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, f32,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, i64,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, i32,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, i16,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, i8,   i64Cast)
-primitive_double_dispatch_cast_impl!(i64, u64,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, u32,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, u16,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, u8,   i64Cast)
-primitive_double_dispatch_cast_impl!(i64, int,  i64Cast)
-primitive_double_dispatch_cast_impl!(i64, uint, i64Cast)
-
-primitive_double_dispatch_cast_impl!(i32, f64,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, f32,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, i64,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, i32,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, i16,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, i8,   i32Cast)
-primitive_double_dispatch_cast_impl!(i32, u64,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, u32,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, u16,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, u8,   i32Cast)
-primitive_double_dispatch_cast_impl!(i32, int,  i32Cast)
-primitive_double_dispatch_cast_impl!(i32, uint, i32Cast)
-
-primitive_double_dispatch_cast_impl!(i16, f64,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, f32,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, i64,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, i32,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, i16,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, i8,   i16Cast)
-primitive_double_dispatch_cast_impl!(i16, u64,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, u32,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, u16,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, u8,   i16Cast)
-primitive_double_dispatch_cast_impl!(i16, int,  i16Cast)
-primitive_double_dispatch_cast_impl!(i16, uint, i16Cast)
-
-primitive_double_dispatch_cast_impl!(i8, f64,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, f32,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, i64,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, i32,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, i16,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, i8,   i8Cast)
-primitive_double_dispatch_cast_impl!(i8, u64,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, u32,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, u16,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, u8,   i8Cast)
-primitive_double_dispatch_cast_impl!(i8, int,  i8Cast)
-primitive_double_dispatch_cast_impl!(i8, uint, i8Cast)
-
-primitive_double_dispatch_cast_impl!(u64, f64,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, f32,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, i64,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, i32,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, i16,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, i8,   u64Cast)
-primitive_double_dispatch_cast_impl!(u64, u64,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, u32,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, u16,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, u8,   u64Cast)
-primitive_double_dispatch_cast_impl!(u64, int,  u64Cast)
-primitive_double_dispatch_cast_impl!(u64, uint, u64Cast)
-
-primitive_double_dispatch_cast_impl!(u32, f64,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, f32,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, i64,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, i32,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, i16,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, i8,   u32Cast)
-primitive_double_dispatch_cast_impl!(u32, u64,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, u32,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, u16,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, u8,   u32Cast)
-primitive_double_dispatch_cast_impl!(u32, int,  u32Cast)
-primitive_double_dispatch_cast_impl!(u32, uint, u32Cast)
-
-primitive_double_dispatch_cast_impl!(u16, f64,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, f32,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, i64,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, i32,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, i16,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, i8,   u16Cast)
-primitive_double_dispatch_cast_impl!(u16, u64,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, u32,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, u16,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, u8,   u16Cast)
-primitive_double_dispatch_cast_impl!(u16, int,  u16Cast)
-primitive_double_dispatch_cast_impl!(u16, uint, u16Cast)
-
-primitive_double_dispatch_cast_impl!(u8, f64,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, f32,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, i64,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, i32,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, i16,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, i8,   u8Cast)
-primitive_double_dispatch_cast_impl!(u8, u64,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, u32,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, u16,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, u8,   u8Cast)
-primitive_double_dispatch_cast_impl!(u8, int,  u8Cast)
-primitive_double_dispatch_cast_impl!(u8, uint, u8Cast)
-
-primitive_double_dispatch_cast_impl!(uint, f64,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, f32,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, i64,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, i32,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, i16,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, i8,   uintCast)
-primitive_double_dispatch_cast_impl!(uint, u64,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, u32,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, u16,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, u8,   uintCast)
-primitive_double_dispatch_cast_impl!(uint, int,  uintCast)
-primitive_double_dispatch_cast_impl!(uint, uint, uintCast)
-
-primitive_double_dispatch_cast_impl!(int, f64,  intCast)
-primitive_double_dispatch_cast_impl!(int, f32,  intCast)
-primitive_double_dispatch_cast_impl!(int, i64,  intCast)
-primitive_double_dispatch_cast_impl!(int, i32,  intCast)
-primitive_double_dispatch_cast_impl!(int, i16,  intCast)
-primitive_double_dispatch_cast_impl!(int, i8,   intCast)
-primitive_double_dispatch_cast_impl!(int, u64,  intCast)
-primitive_double_dispatch_cast_impl!(int, u32,  intCast)
-primitive_double_dispatch_cast_impl!(int, u16,  intCast)
-primitive_double_dispatch_cast_impl!(int, u8,   intCast)
-primitive_double_dispatch_cast_impl!(int, int,  intCast)
-primitive_double_dispatch_cast_impl!(int, uint, intCast)
+float_to_float_cast_impl!(f64, f64,  f64Cast)
+float_to_float_cast_impl!(f64, f32,  f64Cast)
+int_to_float_cast_impl!(f64, i64,  f64Cast)
+int_to_float_cast_impl!(f64, i32,  f64Cast)
+int_to_float_cast_impl!(f64, i16,  f64Cast)
+int_to_float_cast_impl!(f64, i8,   f64Cast)
+int_to_float_cast_impl!(f64, u64,  f64Cast)
+int_to_float_cast_impl!(f64, u32,  f64Cast)
+int_to_float_cast_impl!(f64, u16,  f64Cast)
+int_to_float_cast_impl!(f64, u8,   f64Cast)
+int_to_float_cast_impl!(f64, int,  f64Cast)
+int_to_float_cast_impl!(f64, uint, f64Cast)
+
+float_to_float_cast_impl!(f32, f64,  f32Cast)
+float_to_float_cast_impl!(f32, f32,  f32Cast)
+int_to_float_cast_impl!(f32, i64,  f32Cast)
+int_to_float_cast_impl!(f32, i32,  f32Cast)
+int_to_float_cast_impl!(f32, i16,  f32Cast)
+int_to_float_cast_impl!(f32, i8,   f32Cast)
+int_to_float_cast_impl!(f32, u64,  f32Cast)
+int_to_float_cast_impl!(f32, u32,  f32Cast)
+int_to_float_cast_impl!(f32, u16,  f32Cast)
+int_to_float_cast_impl!(f32, u8,   f32Cast)
+int_to_float_cast_impl!(f32, int,  f32Cast)
+int_to_float_cast_impl!(f32, uint, f32Cast)
+
+float_to_int_cast_impl!(i64, f64,  i64Cast)
+float_to_int_cast_impl!(i64, f32,  i64Cast)
+int_to_int_cast_impl!(i64, i64,  i64Cast)
+int_to_int_cast_impl!(i64, i32,  i64Cast)
+int_to_int_cast_impl!(i64, i16,  i64Cast)
+int_to_int_cast_impl!(i64, i8,   i64Cast)
+int_to_int_cast_impl!(i64, u64,  i64Cast)
+int_to_int_cast_impl!(i64, u32,  i64Cast)
+int_to_int_cast_impl!(i64, u16,  i64Cast)
+int_to_int_cast_impl!(i64, u8,   i64Cast)
+int_to_int_cast_impl!(i64, int,  i64Cast)
+int_to_int_cast_impl!(i64, uint, i64Cast)
+
+float_to_int_cast_impl!(i32, f64,  i32Cast)
+float_to_int_cast_impl!(i32, f32,  i32Cast)
+int_to_int_cast_impl!(i32, i64,  i32Cast)
+int_to_int_cast_impl!(i32, i32,  i32Cast)
+int_to_int_cast_impl!(i32, i16,  i32Cast)
+int_to_int_cast_impl!(i32, i8,   i32Cast)
+int_to_int_cast_impl!(i32, u64,  i32Cast)
+int_to_int_cast_impl!(i32, u32,  i32Cast)
+int_to_int_cast_impl!(i32, u16,  i32Cast)
+int_to_int_cast_impl!(i32, u8,   i32Cast)
+int_to_int_cast_impl!(i32, int,  i32Cast)
+int_to_int_cast_impl!(i32, uint, i32Cast)
+
+float_to_int_cast_impl!(i16, f64,  i16Cast)
+float_to_int_cast_impl!(i16, f32,  i16Cast)
+int_to_int_cast_impl!(i16, i64,  i16Cast)
+int_to_int_cast_impl!(i16, i32,  i16Cast)
+int_to_int_cast_impl!(i16, i16,  i16Cast)
+int_to_int_cast_impl!(i16, i8,   i16Cast)
+int_to_int_cast_impl!(i16, u64,  i16Cast)
+int_to_int_cast_impl!(i16, u32,  i16Cast)
+int_to_int_cast_impl!(i16, u16,  i16Cast)
+int_to_int_cast_impl!(i16, u8,   i16Cast)
+int_to_int_cast_impl!(i16, int,  i16Cast)
+int_to_int_cast_impl!(i16, uint, i16Cast)
+
+float_to_int_cast_impl!(i8, f64,  i8Cast)
+float_to_int_cast_impl!(i8, f32,  i8Cast)
+int_to_int_cast_impl!(i8, i64,  i8Cast)
+int_to_int_cast_impl!(i8, i32,  i8Cast)
+int_to_int_cast_impl!(i8, i16,  i8Cast)
+int_to_int_cast_impl!(i8, i8,   i8Cast)
+int_to_int_cast_impl!(i8, u64,  i8Cast)
+int_to_int_cast_impl!(i8, u32,  i8Cast)
+int_to_int_cast_impl!(i8, u16,  i8Cast)
+int_to_int_cast_impl!(i8, u8,   i8Cast)
+int_to_int_cast_impl!(i8, int,  i8Cast)
+int_to_int_cast_impl!(i8, uint, i8Cast)
+
+float_to_int_cast_impl!(u64, f64,  u64Cast)
+float_to_int_cast_impl!(u64, f32,  u64Cast)
+int_to_int_cast_impl!(u64, i64,  u64Cast)
+int_to_int_cast_impl!(u64, i32,  u64Cast)
+int_to_int_cast_impl!(u64, i16,  u64Cast)
+int_to_int_cast_impl!(u64, i8,   u64Cast)
+int_to_int_cast_impl!(u64, u64,  u64Cast)
+int_to_int_cast_impl!(u64, u32,  u64Cast)
+int_to_int_cast_impl!(u64, u16,  u64Cast)
+int_to_int_cast_impl!(u64, u8,   u64Cast)
+int_to_int_cast_impl!(u64, int,  u64Cast)
+int_to_int_cast_impl!(u64, uint, u64Cast)
+
+float_to_int_cast_impl!(u32, f64,  u32Cast)
+float_to_int_cast_impl!(u32, f32,  u32Cast)
+int_to_int_cast_impl!(u32, i64,  u32Cast)
+int_to_int_cast_impl!(u32, i32,  u32Cast)
+int_to_int_cast_impl!(u32, i16,  u32Cast)
+int_to_int_cast_impl!(u32, i8,   u32Cast)
+int_to_int_cast_impl!(u32, u64,  u32Cast)
+int_to_int_cast_impl!(u32, u32,  u32Cast)
+int_to_int_cast_impl!(u32, u16,  u32Cast)
+int_to_int_cast_impl!(u32, u8,   u32Cast)
+int_to_int_cast_impl!(u32, int,  u32Cast)
+int_to_int_cast_impl!(u32, uint, u32Cast)
+
+float_to_int_cast_impl!(u16, f64,  u16Cast)
+float_to_int_cast_impl!(u16, f32,  u16Cast)
+int_to_int_cast_impl!(u16, i64,  u16Cast)
+int_to_int_cast_impl!(u16, i32,  u16Cast)
+int_to_int_cast_impl!(u16, i16,  u16Cast)
+int_to_int_cast_impl!(u16, i8,   u16Cast)
+int_to_int_cast_impl!(u16, u64,  u16Cast)
+int_to_int_cast_impl!(u16, u32,  u16Cast)
+int_to_int_cast_impl!(u16, u16,  u16Cast)
+int_to_int_cast_impl!(u16, u8,   u16Cast)
+int_to_int_cast_impl!(u16, int,  u16Cast)
+int_to_int_cast_impl!(u16, uint, u16Cast)
+
+float_to_int_cast_impl!(u8, f64,  u8Cast)
+float_to_int_cast_impl!(u8, f32,  u8Cast)
+int_to_int_cast_impl!(u8, i64,  u8Cast)
+int_to_int_cast_impl!(u8, i32,  u8Cast)
+int_to_int_cast_impl!(u8, i16,  u8Cast)
+int_to_int_cast_impl!(u8, i8,   u8Cast)
+int_to_int_cast_impl!(u8, u64,  u8Cast)
+int_to_int_cast_impl!(u8, u32,  u8Cast)
+int_to_int_cast_impl!(u8, u16,  u8Cast)
+int_to_int_cast_impl!(u8, u8,   u8Cast)
+int_to_int_cast_impl!(u8, int,  u8Cast)
+int_to_int_cast_impl!(u8, uint, u8Cast)
+
+float_to_int_cast_impl!(uint, f64,  uintCast)
+float_to_int_cast_impl!(uint, f32,  uintCast)
+int_to_int_cast_impl!(uint, i64,  uintCast)
+int_to_int_cast_impl!(uint, i32,  uintCast)
+int_to_int_cast_impl!(uint, i16,  uintCast)
+int_to_int_cast_impl!(uint, i8,   uintCast)
+int_to_int_cast_impl!(uint, u64,  uintCast)
+int_to_int_cast_impl!(uint, u32,  uintCast)
+int_to_int_cast_impl!(uint, u16,  uintCast)
+int_to_int_cast_impl!(uint, u8,   uintCast)
+int_to_int_cast_impl!(uint, int,  uintCast)
+int_to_int_cast_impl!(uint, uint, uintCast)
+
+float_to_int_cast_impl!(int, f64,  intCast)
+float_to_int_cast_impl!(int, f32,  intCast)
+int_to_int_cast_impl!(int, i64,  intCast)
+int_to_int_cast_impl!(int, i32,  intCast)
+int_to_int_cast_impl!(int, i16,  intCast)
+int_to_int_cast_impl!(int, i8,   intCast)
+int_to_int_cast_impl!(int, u64,  intCast)
+int_to_int_cast_impl!(int, u32,  intCast)
+int_to_int_cast_impl!(int, u16,  intCast)
+int_to_int_cast_impl!(int, u8,   intCast)
+int_to_int_cast_impl!(int, int,  intCast)
+int_to_int_cast_impl!(int, uint, intCast)
+
+// `int` and `uint` are not given a binary representation since their size is platform-dependent.
+macro_rules! primitive_bytes_impl(
+    ($t: ident, $write_le: ident, $write_be: ident, $read_le: ident, $read_be: ident) => (
+        impl Bytes for $t {
+            #[inline]
+            fn write_le<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+                w.$write_le(*self)
+            }
+
+            #[inline]
+            fn write_be<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+                w.$write_be(*self)
+            }
+
+            #[inline]
+            fn read_le<R: Reader>(r: &mut R) -> IoResult<$t> {
+                r.$read_le()
+            }
+
+            #[inline]
+            fn read_be<R: Reader>(r: &mut R) -> IoResult<$t> {
+                r.$read_be()
+            }
+        }
+    )
+)
+
+primitive_bytes_impl!(f64, write_le_f64, write_be_f64, read_le_f64, read_be_f64)
+primitive_bytes_impl!(f32, write_le_f32, write_be_f32, read_le_f32, read_be_f32)
+primitive_bytes_impl!(i64, write_le_i64, write_be_i64, read_le_i64, read_be_i64)
+primitive_bytes_impl!(i32, write_le_i32, write_be_i32, read_le_i32, read_be_i32)
+primitive_bytes_impl!(i16, write_le_i16, write_be_i16, read_le_i16, read_be_i16)
+primitive_bytes_impl!(i8,  write_i8,     write_i8,     read_i8,     read_i8)
+primitive_bytes_impl!(u64, write_le_u64, write_be_u64, read_le_u64, read_be_u64)
+primitive_bytes_impl!(u32, write_le_u32, write_be_u32, read_le_u32, read_be_u32)
+primitive_bytes_impl!(u16, write_le_u16, write_be_u16, read_le_u16, read_be_u16)
+primitive_bytes_impl!(u8,  write_u8,     write_u8,     read_u8,     read_u8)
+
+// Real scalars are their own conjugate.
+macro_rules! primitive_conjugate_impl(
+    ($t: ident) => (
+        impl Conjugate for $t {
+            #[inline(always)]
+            fn conjugate(&self) -> $t {
+                *self
+            }
+        }
+    )
+)
+
+primitive_conjugate_impl!(f64)
+primitive_conjugate_impl!(f32)
+primitive_conjugate_impl!(i64)
+primitive_conjugate_impl!(i32)
+primitive_conjugate_impl!(i16)
+primitive_conjugate_impl!(i8)
+primitive_conjugate_impl!(u64)
+primitive_conjugate_impl!(u32)
+primitive_conjugate_impl!(u16)
+primitive_conjugate_impl!(u8)
+primitive_conjugate_impl!(int)
+primitive_conjugate_impl!(uint)
@@ -0,0 +1,179 @@
+use structs::vec::Vec3;
+use structs::pnt::Pnt3;
+use structs::mat::Mat4;
+use structs::rot::Rot3;
+use structs::iso::Iso3;
+use traits::operations::{Inv, ApproxEq};
+use traits::geometry::TransformAll;
+
+// Number of elements processed at once by the structure-of-arrays kernel.
+static CHUNK_SIZE: uint = 256;
+
+// Applies the affine (or projective if `w` is `Some`) transformation with rows `r1`, `r2`, `r3`
+// to every element of `vs`.
+//
+// The coordinates are first gathered into separate arrays so that the arithmetic loop operates
+// on contiguous data, which lets it vectorize well.
+fn transform_soa<N: Clone + Num, V>(r1: [N, ..4], r2: [N, ..4], r3: [N, ..4], w: Option<[N, ..4]>,
+                                    vs: &mut [V],
+                                    get: |&V| -> (N, N, N),
+                                    set: |&mut V, N, N, N|) {
+    let mut xs = Vec::with_capacity(CHUNK_SIZE);
+    let mut ys = Vec::with_capacity(CHUNK_SIZE);
+    let mut zs = Vec::with_capacity(CHUNK_SIZE);
+    let mut ws = Vec::with_capacity(CHUNK_SIZE);
+
+    for chunk in vs.chunks_mut(CHUNK_SIZE) {
+        xs.truncate(0);
+        ys.truncate(0);
+        zs.truncate(0);
+        ws.truncate(0);
+
+        for v in chunk.iter() {
+            let (x, y, z) = get(v);
+
+            xs.push(x);
+            ys.push(y);
+            zs.push(z);
+        }
+
+        match w {
+            Some(ref r4) => {
+                for ((x, y), z) in xs.iter().zip(ys.iter()).zip(zs.iter()) {
+                    ws.push(r4[0] * *x + r4[1] * *y + r4[2] * *z + r4[3]);
+                }
+            },
+            None => { }
+        }
+
+        for (((x, y), z), i) in xs.iter_mut().zip(ys.iter_mut()).zip(zs.iter_mut()).zip(range(0u, CHUNK_SIZE)) {
+            let nx = r1[0] * *x + r1[1] * *y + r1[2] * *z + r1[3];
+            let ny = r2[0] * *x + r2[1] * *y + r2[2] * *z + r2[3];
+            let nz = r3[0] * *x + r3[1] * *y + r3[2] * *z + r3[3];
+
+            if w.is_some() {
+                let wi = ws[i].clone();
+
+                *x = nx / wi;
+                *y = ny / wi;
+                *z = nz / wi;
+            }
+            else {
+                *x = nx;
+                *y = ny;
+                *z = nz;
+            }
+        }
+
+        for (v, ((x, y), z)) in chunk.iter_mut().zip(xs.iter().zip(ys.iter()).zip(zs.iter())) {
+            set(v, x.clone(), y.clone(), z.clone());
+        }
+    }
+}
+
+fn transform_pnts_soa<N: Clone + Num>(r1: [N, ..4], r2: [N, ..4], r3: [N, ..4], w: Option<[N, ..4]>,
+                                      pts: &mut [Pnt3<N>]) {
+    transform_soa(r1, r2, r3, w, pts,
+                  |p| (p.x.clone(), p.y.clone(), p.z.clone()),
+                  |p, x, y, z| { p.x = x; p.y = y; p.z = z; })
+}
+
+fn transform_vecs_soa<N: Clone + Num>(r1: [N, ..4], r2: [N, ..4], r3: [N, ..4], vs: &mut [Vec3<N>]) {
+    transform_soa(r1, r2, r3, None, vs,
+                  |v| (v.x.clone(), v.y.clone(), v.z.clone()),
+                  |v, x, y, z| { v.x = x; v.y = y; v.z = z; })
+}
+
+// Rows of the linear part of `m`, with the translation `t` as their last element.
+#[inline]
+fn rows<N: Clone + Num>(m: &Mat4<N>, t: &Vec3<N>) -> ([N, ..4], [N, ..4], [N, ..4]) {
+    ([m.m11.clone(), m.m12.clone(), m.m13.clone(), t.x.clone()],
+     [m.m21.clone(), m.m22.clone(), m.m23.clone(), t.y.clone()],
+     [m.m31.clone(), m.m32.clone(), m.m33.clone(), t.z.clone()])
+}
+
+#[inline]
+fn rot_rows<N: Clone + Num>(r: &Rot3<N>, t: &Vec3<N>) -> ([N, ..4], [N, ..4], [N, ..4]) {
+    let m = r.submat();
+
+    ([m.m11.clone(), m.m12.clone(), m.m13.clone(), t.x.clone()],
+     [m.m21.clone(), m.m22.clone(), m.m23.clone(), t.y.clone()],
+     [m.m31.clone(), m.m32.clone(), m.m33.clone(), t.z.clone()])
+}
+
+impl<N: Clone + Num> TransformAll<Pnt3<N>> for Rot3<N> {
+    fn transform_all(&self, pts: &mut [Pnt3<N>]) {
+        let (r1, r2, r3) = rot_rows(self, &::zero());
+        transform_pnts_soa(r1, r2, r3, None, pts)
+    }
+
+    fn inv_transform_all(&self, pts: &mut [Pnt3<N>]) {
+        Inv::inv_cpy(self).unwrap().transform_all(pts)
+    }
+}
+
+impl<N: Clone + Num> TransformAll<Vec3<N>> for Rot3<N> {
+    fn transform_all(&self, vs: &mut [Vec3<N>]) {
+        let (r1, r2, r3) = rot_rows(self, &::zero());
+        transform_vecs_soa(r1, r2, r3, vs)
+    }
+
+    fn inv_transform_all(&self, vs: &mut [Vec3<N>]) {
+        Inv::inv_cpy(self).unwrap().transform_all(vs)
+    }
+}
+
+impl<N: Clone + Num> TransformAll<Pnt3<N>> for Iso3<N> {
+    fn transform_all(&self, pts: &mut [Pnt3<N>]) {
+        let (r1, r2, r3) = rot_rows(&self.rotation, &self.translation);
+        transform_pnts_soa(r1, r2, r3, None, pts)
+    }
+
+    fn inv_transform_all(&self, pts: &mut [Pnt3<N>]) {
+        Inv::inv_cpy(self).unwrap().transform_all(pts)
+    }
+}
+
+impl<N: Clone + Num> TransformAll<Vec3<N>> for Iso3<N> {
+    /// Rotates each vector of `vs`, vectors being unaffected by the translation.
+    fn transform_all(&self, vs: &mut [Vec3<N>]) {
+        self.rotation.transform_all(vs)
+    }
+
+    fn inv_transform_all(&self, vs: &mut [Vec3<N>]) {
+        self.rotation.inv_transform_all(vs)
+    }
+}
+
+impl<N: Clone + Num + ApproxEq<N>> TransformAll<Pnt3<N>> for Mat4<N> {
+    /// Transforms each point of `pts`, seeing this matrix as a homogeneous transformation.
+    fn transform_all(&self, pts: &mut [Pnt3<N>]) {
+        let (r1, r2, r3) = rows(self, &Vec3::new(self.m14.clone(), self.m24.clone(), self.m34.clone()));
+        let r4           = [self.m41.clone(), self.m42.clone(), self.m43.clone(), self.m44.clone()];
+
+        transform_pnts_soa(r1, r2, r3, Some(r4), pts)
+    }
+
+    /// Applies the inverse of this homogeneous transformation to each point of `pts`.
+    ///
+    /// This fails if the matrix is not invertible.
+    fn inv_transform_all(&self, pts: &mut [Pnt3<N>]) {
+        Inv::inv_cpy(self).expect("Unable to invert a singular matrix.").transform_all(pts)
+    }
+}
+
+impl<N: Clone + Num + ApproxEq<N>> TransformAll<Vec3<N>> for Mat4<N> {
+    /// Transforms each vector of `vs`, seeing this matrix as a homogeneous transformation.
+    fn transform_all(&self, vs: &mut [Vec3<N>]) {
+        let (r1, r2, r3) = rows(self, &::zero());
+
+        transform_vecs_soa(r1, r2, r3, vs)
+    }
+
+    /// Applies the inverse of this homogeneous transformation to each vector of `vs`.
+    ///
+    /// This fails if the matrix is not invertible.
+    fn inv_transform_all(&self, vs: &mut [Vec3<N>]) {
+        Inv::inv_cpy(self).expect("Unable to invert a singular matrix.").transform_all(vs)
+    }
+}
@@ -1,7 +1,13 @@
+use std::mem;
 use std::num::{Zero, One};
+use std::rand::{Rng, XorShiftRng};
+use std::rand::distributions::normal::StandardNormal;
 use traits::structure::{Cast, Row, Basis};
 use traits::geometry::{Norm, Cross, CrossMatrix, UniformSphereSample};
-use structs::vec::{Vec1, Vec2, Vec3, Vec4};
+use structs::vec::{Vec1, Vec2, Vec3, Vec4, Vec5, Vec6};
+use structs::pnt::Pnt3;
+use structs::dvec::DVec;
+use structs::quat::Quat;
 use structs::mat::Mat3;
 
 impl<N: Mul<N, N> + Sub<N, N>> Cross<Vec1<N>> for Vec2<N> {
@@ -114,6 +120,114 @@ impl<N: Clone + PartialOrd + Float + Signed> Basis for Vec3<N> {
     }
 }
 
+impl<N: FloatMath> Vec2<N> {
+    /// Builds a vector from its polar coordinates.
+    ///
+    /// `r` is the norm of the vector and `theta` the angle between the `x` axis and the vector,
+    /// measured toward the `y` axis.
+    #[inline]
+    pub fn from_polar(r: N, theta: N) -> Vec2<N> {
+        let (s, c) = theta.sin_cos();
+
+        Vec2::new(r * c, r * s)
+    }
+
+    /// Computes the polar coordinates `(r, theta)` of this vector.
+    ///
+    /// See `from_polar` for the conventions. `theta` is in `[-pi, pi]` and is zero for the null
+    /// vector.
+    #[inline]
+    pub fn to_polar(&self) -> (N, N) {
+        (self.x.hypot(self.y), self.y.atan2(self.x))
+    }
+}
+
+impl<N: FloatMath> Vec3<N> {
+    /// Builds a vector from its spherical coordinates.
+    ///
+    /// The convention is the one used in physics (ISO 80000-2):
+    ///   * `r` - the radial distance.
+    ///   * `theta` - the polar angle, i.e., the angle between the vector and the `z` axis, in
+    ///   `[0, pi]`.
+    ///   * `phi` - the azimuthal angle, i.e., the angle between the `x` axis and the projection
+    ///   of the vector on the `xy` plane, measured toward the `y` axis.
+    #[inline]
+    pub fn from_spherical(r: N, theta: N, phi: N) -> Vec3<N> {
+        let (st, ct) = theta.sin_cos();
+        let (sp, cp) = phi.sin_cos();
+
+        Vec3::new(r * st * cp, r * st * sp, r * ct)
+    }
+
+    /// Computes the spherical coordinates `(r, theta, phi)` of this vector.
+    ///
+    /// See `from_spherical` for the axis conventions. `theta` is in `[0, pi]` and `phi` in
+    /// `[-pi, pi]`. Both angles are zero for the null vector.
+    #[inline]
+    pub fn to_spherical(&self) -> (N, N, N) {
+        let rho = self.x.hypot(self.y);
+        let r   = rho.hypot(self.z);
+
+        (r, rho.atan2(self.z), self.y.atan2(self.x))
+    }
+
+    /// Builds a vector from its cylindrical coordinates.
+    ///
+    /// The cylinder axis is the `z` axis:
+    ///   * `rho` - the distance to the `z` axis.
+    ///   * `phi` - the azimuthal angle, i.e., the angle between the `x` axis and the projection
+    ///   of the vector on the `xy` plane, measured toward the `y` axis.
+    ///   * `z` - the height along the `z` axis.
+    #[inline]
+    pub fn from_cylindrical(rho: N, phi: N, z: N) -> Vec3<N> {
+        let (sp, cp) = phi.sin_cos();
+
+        Vec3::new(rho * cp, rho * sp, z)
+    }
+
+    /// Computes the cylindrical coordinates `(rho, phi, z)` of this vector.
+    ///
+    /// See `from_cylindrical` for the axis conventions. `phi` is in `[-pi, pi]`.
+    #[inline]
+    pub fn to_cylindrical(&self) -> (N, N, N) {
+        (self.x.hypot(self.y), self.y.atan2(self.x), self.z)
+    }
+}
+
+impl<N: FloatMath> Pnt3<N> {
+    /// Builds a point from its spherical coordinates relative to the origin.
+    ///
+    /// See `Vec3::from_spherical` for the axis conventions.
+    #[inline]
+    pub fn from_spherical(r: N, theta: N, phi: N) -> Pnt3<N> {
+        Vec3::from_spherical(r, theta, phi).to_pnt()
+    }
+
+    /// Computes the spherical coordinates `(r, theta, phi)` of this point relative to the origin.
+    ///
+    /// See `Vec3::to_spherical` for the axis conventions.
+    #[inline]
+    pub fn to_spherical(&self) -> (N, N, N) {
+        self.as_vec().to_spherical()
+    }
+
+    /// Builds a point from its cylindrical coordinates.
+    ///
+    /// See `Vec3::from_cylindrical` for the axis conventions.
+    #[inline]
+    pub fn from_cylindrical(rho: N, phi: N, z: N) -> Pnt3<N> {
+        Vec3::from_cylindrical(rho, phi, z).to_pnt()
+    }
+
+    /// Computes the cylindrical coordinates `(rho, phi, z)` of this point.
+    ///
+    /// See `Vec3::to_cylindrical` for the axis conventions.
+    #[inline]
+    pub fn to_cylindrical(&self) -> (N, N, N) {
+        self.as_vec().to_cylindrical()
+    }
+}
+
 // FIXME: this bad: this fixes definitly the number of samples…
 static SAMPLES_2_F32: [Vec2<f32>, ..21] = [
     Vec2 { x: 1.0,         y: 0.0         },
@@ -210,12 +324,126 @@ impl<N: Cast<f32> + Clone> UniformSphereSample for Vec3<N> {
     }
 }
 
+// Number of samples generated for the dimensions that do not have precomputed samples.
+static NSAMPLES_HIGH_DIM: uint = 1000;
+
+// Samples the unit sphere of dimension `dim` by normalizing gaussian random vectors. This is
+// uniform because the gaussian distribution is isotropic. The random number generator is
+// seeded deterministically so that each call yields the same samples.
+fn sample_sphere_gaussian(dim: uint, nsamples: uint, f: |&[f32]| -> ()) {
+    let mut rng    = XorShiftRng::new_unseeded();
+    let mut sample = Vec::from_elem(dim, 0.0f32);
+    let mut i      = 0u;
+
+    while i < nsamples {
+        let mut sqnorm = 0.0f64;
+
+        for e in sample.iter_mut() {
+            let StandardNormal(x) = rng.gen::<StandardNormal>();
+
+            *e     = x as f32;
+            sqnorm = sqnorm + x * x;
+        }
+
+        // the probability of this being false is zero, but we never know...
+        if sqnorm != 0.0 {
+            let norm = sqnorm.sqrt() as f32;
+
+            for e in sample.iter_mut() {
+                *e = *e / norm;
+            }
+
+            f(sample.as_slice());
+            i = i + 1;
+        }
+    }
+}
+
 impl<N: Cast<f32> + Clone> UniformSphereSample for Vec4<N> {
     #[inline(always)]
-    fn sample(_: |Vec4<N>| -> ()) {
-        fail!("UniformSphereSample::<Vec4<N>>::sample : Not yet implemented.")
-        // for sample in SAMPLES_3_F32.iter() {
-        //     f(Cast::from(*sample))
-        // }
+    fn sample(f: |Vec4<N>| -> ()) {
+        sample_sphere_gaussian(4, NSAMPLES_HIGH_DIM, |s| {
+            f(Vec4::new(Cast::from(s[0]), Cast::from(s[1]), Cast::from(s[2]), Cast::from(s[3])))
+        })
+    }
+}
+
+impl<N: Cast<f32> + Clone> UniformSphereSample for Vec5<N> {
+    #[inline(always)]
+    fn sample(f: |Vec5<N>| -> ()) {
+        sample_sphere_gaussian(5, NSAMPLES_HIGH_DIM, |s| {
+            f(Vec5::new(Cast::from(s[0]), Cast::from(s[1]), Cast::from(s[2]), Cast::from(s[3]),
+                        Cast::from(s[4])))
+        })
+    }
+}
+
+impl<N: Cast<f32> + Clone> UniformSphereSample for Vec6<N> {
+    #[inline(always)]
+    fn sample(f: |Vec6<N>| -> ()) {
+        sample_sphere_gaussian(6, NSAMPLES_HIGH_DIM, |s| {
+            f(Vec6::new(Cast::from(s[0]), Cast::from(s[1]), Cast::from(s[2]), Cast::from(s[3]),
+                        Cast::from(s[4]), Cast::from(s[5])))
+        })
     }
 }
+
+impl<N: Cast<f32>> DVec<N> {
+    /// Samples `nsamples` points of the unit sphere of dimension `dim`.
+    ///
+    /// The samples are uniformly distributed, and the same at each call. This is the equivalent
+    /// of `UniformSphereSample::sample` which cannot be implemented by `DVec` since its dimension
+    /// is not known at compile-time.
+    pub fn sample_sphere(dim: uint, nsamples: uint, f: |DVec<N>| -> ()) {
+        sample_sphere_gaussian(dim, nsamples, |s| {
+            f(DVec::from_fn(dim, |i| Cast::from(s[i])))
+        })
+    }
+}
+
+// Approximation of `1 / sqrt(x)`: an initial guess is read from the bit pattern of `x`, and
+// refined by one Newton-Raphson step. The relative error is below 0.2%.
+#[inline(always)]
+fn fast_inv_sqrt(x: f32) -> f32 {
+    let i: u32 = unsafe { mem::transmute(x) };
+    let y: f32 = unsafe { mem::transmute(0x5f3759dfu32 - (i >> 1)) };
+
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+macro_rules! normalize_fast_impl(
+    ($t: ident) => (
+        impl $t<f32> {
+            /// Normalizes this vector approximately, with a relative error on its norm below 0.2%.
+            ///
+            /// This is faster than `normalize` since no square root nor division is computed. A
+            /// zero vector is left unchanged.
+            #[inline]
+            pub fn normalize_fast(&mut self) {
+                let inv_norm = fast_inv_sqrt(Norm::sqnorm(self));
+
+                for e in self.as_mut_slice().iter_mut() {
+                    *e = *e * inv_norm;
+                }
+            }
+
+            /// Approximately normalized copy of this vector. See `normalize_fast`.
+            #[inline]
+            pub fn normalize_fast_cpy(&self) -> $t<f32> {
+                let mut res = self.clone();
+
+                res.normalize_fast();
+
+                res
+            }
+        }
+    )
+)
+
+normalize_fast_impl!(Vec1)
+normalize_fast_impl!(Vec2)
+normalize_fast_impl!(Vec3)
+normalize_fast_impl!(Vec4)
+normalize_fast_impl!(Vec5)
+normalize_fast_impl!(Vec6)
+normalize_fast_impl!(Quat)
@@ -3,9 +3,10 @@ use std::num::{Zero, One, Float, Bounded};
 use std::slice::{Items, MutItems};
 use std::iter::{Iterator, FromIterator};
 use traits::operations::ApproxEq;
-use traits::structure::{Iterable, IterableMut, Indexable, Basis, Dim};
+use traits::structure::{Iterable, IterableMut, Indexable, Basis, Dim, StaticDim};
 use traits::geometry::{Translation, Dot, Norm};
 use structs::vec;
+use structs::dim::D0;
 
 impl<N> Indexable<uint, N> for vec::Vec0<N> {
     #[inline]
@@ -57,6 +58,8 @@ impl<N> Dim for vec::Vec0<N> {
     }
 }
 
+impl<N> StaticDim<D0> for vec::Vec0<N> { }
+
 impl<N> Basis for vec::Vec0<N> {
     #[inline(always)]
     fn canonical_basis(_: |vec::Vec0<N>| -> bool) { }
@@ -171,6 +174,11 @@ impl<N: ApproxEq<N>> ApproxEq<N> for vec::Vec0<N> {
         ApproxEq::approx_epsilon(None::<N>)
     }
 
+    #[inline]
+    fn approx_ulps(_: Option<vec::Vec0<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
     #[inline]
     fn approx_eq(_: &vec::Vec0<N>, _: &vec::Vec0<N>) -> bool {
         true
@@ -180,6 +188,16 @@ impl<N: ApproxEq<N>> ApproxEq<N> for vec::Vec0<N> {
     fn approx_eq_eps(_: &vec::Vec0<N>, _: &vec::Vec0<N>, _: &N) -> bool {
         true
     }
+
+    #[inline]
+    fn approx_eq_ulps(_: &vec::Vec0<N>, _: &vec::Vec0<N>, _: u32) -> bool {
+        true
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(_: &vec::Vec0<N>, _: &vec::Vec0<N>, _: &N, _: &N) -> bool {
+        true
+    }
 }
 
 impl<N: One> One for vec::Vec0<N> {
@@ -191,7 +209,9 @@ impl<N: One> One for vec::Vec0<N> {
 
 impl<N> FromIterator<N> for vec::Vec0<N> {
     #[inline]
-    fn from_iter<I: Iterator<N>>(_: I) -> vec::Vec0<N> {
+    fn from_iter<I: Iterator<N>>(mut param: I) -> vec::Vec0<N> {
+        assert!(param.next().is_none(), "The iterator yields too many elements.");
+
         vec::Vec0
     }
 }
@@ -0,0 +1,284 @@
+//! Strided views over the storage of dynamically sized vectors and matrices.
+
+use std::num::{Zero, Float};
+use traits::operations::{Axpy, ScaleInPlace};
+use traits::structure::Indexable;
+use traits::geometry::Dot;
+
+/// Read-only view on `len` elements of a slice, starting at `offset` and separated by `stride`.
+///
+/// This allows BLAS-1 style operations on, e.g., a row of a `DMat` without copying it.
+pub struct StridedSlice<'a, N: 'a> {
+    data:   &'a [N],
+    offset: uint,
+    stride: uint,
+    len:    uint
+}
+
+/// Read-write view on `len` elements of a slice, starting at `offset` and separated by `stride`.
+pub struct StridedSliceMut<'a, N: 'a> {
+    data:   &'a mut [N],
+    offset: uint,
+    stride: uint,
+    len:    uint
+}
+
+/// Iterator through the elements of a strided view.
+pub struct StridedItems<'a, N: 'a> {
+    data:   &'a [N],
+    curr:   uint,
+    stride: uint,
+    left:   uint
+}
+
+impl<'a, N> StridedSlice<'a, N> {
+    /// Creates a new strided view.
+    ///
+    /// Fails if the last element of the view is out of the bounds of `data`.
+    #[inline]
+    pub fn new(data: &'a [N], offset: uint, stride: uint, len: uint) -> StridedSlice<'a, N> {
+        assert!(len == 0 || offset + (len - 1) * stride < data.len(), "Strided view out of bounds.");
+
+        StridedSlice {
+            data:   data,
+            offset: offset,
+            stride: stride,
+            len:    len
+        }
+    }
+
+    /// The number of elements of this view.
+    #[inline]
+    pub fn len(&self) -> uint {
+        self.len
+    }
+
+    /// Reference to the `i`-th element of this view.
+    #[inline]
+    pub fn get(&self, i: uint) -> &'a N {
+        assert!(i < self.len, "Index out of bounds.");
+        &self.data[self.offset + i * self.stride]
+    }
+
+    /// Gets an iterator through the elements of this view.
+    ///
+    /// Note that the `Iterable` trait cannot be implemented since it requires the elements to
+    /// be contiguous.
+    #[inline]
+    pub fn iter(&self) -> StridedItems<'a, N> {
+        StridedItems {
+            data:   self.data,
+            curr:   self.offset,
+            stride: self.stride,
+            left:   self.len
+        }
+    }
+}
+
+impl<'a, N: Float> StridedSlice<'a, N> {
+    /// The squared norm of this view.
+    ///
+    /// Note that the `Norm` trait cannot be implemented since `normalize_cpy` would have to
+    /// allocate the storage of the returned view.
+    #[inline]
+    pub fn sqnorm(&self) -> N {
+        self.iter().fold(Zero::zero(), |acc: N, e| acc + *e * *e)
+    }
+
+    /// The norm of this view.
+    #[inline]
+    pub fn norm(&self) -> N {
+        self.sqnorm().sqrt()
+    }
+}
+
+impl<'a, N> StridedSliceMut<'a, N> {
+    /// Creates a new mutable strided view.
+    ///
+    /// Fails if the last element of the view is out of the bounds of `data`.
+    #[inline]
+    pub fn new(data: &'a mut [N], offset: uint, stride: uint, len: uint) -> StridedSliceMut<'a, N> {
+        assert!(len == 0 || offset + (len - 1) * stride < data.len(), "Strided view out of bounds.");
+
+        StridedSliceMut {
+            data:   data,
+            offset: offset,
+            stride: stride,
+            len:    len
+        }
+    }
+
+    /// The number of elements of this view.
+    #[inline]
+    pub fn len(&self) -> uint {
+        self.len
+    }
+
+    /// Reference to the `i`-th element of this view.
+    #[inline]
+    pub fn get<'b>(&'b self, i: uint) -> &'b N {
+        assert!(i < self.len, "Index out of bounds.");
+        &self.data[self.offset + i * self.stride]
+    }
+
+    /// Mutable reference to the `i`-th element of this view.
+    #[inline]
+    pub fn get_mut<'b>(&'b mut self, i: uint) -> &'b mut N {
+        assert!(i < self.len, "Index out of bounds.");
+        &mut self.data[self.offset + i * self.stride]
+    }
+
+    /// Gets an iterator through the elements of this view.
+    #[inline]
+    pub fn iter<'b>(&'b self) -> StridedItems<'b, N> {
+        StridedItems {
+            data:   &*self.data,
+            curr:   self.offset,
+            stride: self.stride,
+            left:   self.len
+        }
+    }
+
+    /// Reborrows this view as a read-only view.
+    #[inline]
+    pub fn as_strided_slice<'b>(&'b self) -> StridedSlice<'b, N> {
+        StridedSlice {
+            data:   &*self.data,
+            offset: self.offset,
+            stride: self.stride,
+            len:    self.len
+        }
+    }
+}
+
+impl<'a, N: Float> StridedSliceMut<'a, N> {
+    /// The squared norm of this view.
+    #[inline]
+    pub fn sqnorm(&self) -> N {
+        self.as_strided_slice().sqnorm()
+    }
+
+    /// The norm of this view.
+    #[inline]
+    pub fn norm(&self) -> N {
+        self.as_strided_slice().norm()
+    }
+
+    /// Normalizes the viewed elements in-place and returns the norm they had before.
+    #[inline]
+    pub fn normalize(&mut self) -> N {
+        let n = self.norm();
+
+        for i in range(0u, self.len) {
+            let ei = self.get_mut(i);
+
+            *ei = *ei / n;
+        }
+
+        n
+    }
+}
+
+impl<'a, N: Clone> Indexable<uint, N> for StridedSliceMut<'a, N> {
+    #[inline]
+    fn at(&self, i: uint) -> N {
+        self.get(i).clone()
+    }
+
+    #[inline]
+    fn set(&mut self, i: uint, val: N) {
+        *self.get_mut(i) = val
+    }
+
+    #[inline]
+    fn swap(&mut self, i: uint, j: uint) {
+        assert!(i < self.len && j < self.len, "Index out of bounds.");
+        self.data.swap(self.offset + i * self.stride, self.offset + j * self.stride)
+    }
+
+    #[inline]
+    fn shape(&self) -> uint {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn unsafe_at(&self, i: uint) -> N {
+        (*self.data.unsafe_get(self.offset + i * self.stride)).clone()
+    }
+
+    #[inline]
+    unsafe fn unsafe_set(&mut self, i: uint, val: N) {
+        *self.data.unsafe_mut(self.offset + i * self.stride) = val
+    }
+}
+
+impl<'a, N> Iterator<&'a N> for StridedItems<'a, N> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a N> {
+        if self.left == 0 {
+            None
+        }
+        else {
+            let res = &self.data[self.curr];
+
+            self.left = self.left - 1;
+
+            if self.left != 0 {
+                self.curr = self.curr + self.stride;
+            }
+
+            Some(res)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<'a, N: Mul<N, N> + Add<N, N> + Zero> Dot<N> for StridedSlice<'a, N> {
+    #[inline]
+    fn dot(a: &StridedSlice<'a, N>, b: &StridedSlice<'a, N>) -> N {
+        assert!(a.len() == b.len());
+
+        let mut res: N = Zero::zero();
+
+        for (ea, eb) in a.iter().zip(b.iter()) {
+            res = res + *ea * *eb;
+        }
+
+        res
+    }
+}
+
+impl<'a, N: Mul<N, N> + Add<N, N> + Zero> Dot<N> for StridedSliceMut<'a, N> {
+    #[inline]
+    fn dot(a: &StridedSliceMut<'a, N>, b: &StridedSliceMut<'a, N>) -> N {
+        Dot::dot(&a.as_strided_slice(), &b.as_strided_slice())
+    }
+}
+
+impl<'a, N: Mul<N, N> + Add<N, N>> Axpy<N> for StridedSliceMut<'a, N> {
+    #[inline]
+    fn axpy(&mut self, a: &N, x: &StridedSliceMut<'a, N>) {
+        assert!(self.len() == x.len());
+
+        for i in range(0u, self.len) {
+            let si = self.get_mut(i);
+
+            *si = *si + *a * *x.get(i);
+        }
+    }
+}
+
+impl<'a, N: Mul<N, N>> ScaleInPlace<N> for StridedSliceMut<'a, N> {
+    #[inline]
+    fn scale_inplace(&mut self, s: &N) {
+        for i in range(0u, self.len) {
+            let si = self.get_mut(i);
+
+            *si = *si * *s;
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Unit complex numbers representing 2D rotations.
+
+#![allow(missing_doc)] // we allow missing to avoid having to document the dispatch trait.
+
+use std::num::{Zero, One};
+use std::rand::{Rand, Rng};
+use structs::{Vec1, Vec2, Pnt2, Rot2, Mat2, Vec2MulRhs, Pnt2MulRhs};
+use traits::operations::{ApproxEq, Inv};
+use traits::structure::Dim;
+use traits::geometry::{Rotation, Rotate, Transform};
+
+/// A unit complex number that can represent a 2D rotation.
+///
+/// This plays the same role as `UnitQuat` in 3D: its real part is the cosine of the rotation
+/// angle and its imaginary part is its sine. Composing two rotations requires only four
+/// multiplications, and the result can be renormalized cheaply to remove numerical drift.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Show)]
+pub struct UnitComplex<N> {
+    re: N,
+    im: N
+}
+
+impl<N: FloatMath> UnitComplex<N> {
+    /// Creates a new unit complex number from a rotation angle in radian.
+    #[inline]
+    pub fn new(angle: Vec1<N>) -> UnitComplex<N> {
+        UnitComplex::from_angle(angle.x)
+    }
+
+    /// Creates a new unit complex number from a rotation angle in radian.
+    #[inline]
+    pub fn from_angle(angle: N) -> UnitComplex<N> {
+        let (s, c) = angle.sin_cos();
+
+        UnitComplex {
+            re: c,
+            im: s
+        }
+    }
+
+    /// Creates a new unit complex number from the cosine and the sine of an angle.
+    ///
+    /// The input will be normalized.
+    #[inline]
+    pub fn new_with_cos_sin(cos: N, sin: N) -> UnitComplex<N> {
+        let mut res = UnitComplex {
+            re: cos,
+            im: sin
+        };
+
+        res.renormalize();
+
+        res
+    }
+
+    /// The rotation angle of this unit complex number, in `[-pi, pi]`.
+    #[inline]
+    pub fn angle(&self) -> N {
+        self.im.atan2(self.re)
+    }
+
+    /// Normalizes this complex number.
+    ///
+    /// This is useful to remove the numerical drift due to successive compositions.
+    #[inline]
+    pub fn renormalize(&mut self) {
+        let n = self.re.hypot(self.im);
+
+        self.re = self.re / n;
+        self.im = self.im / n;
+    }
+
+    /// Raises this rotation to the power `t`, i.e., multiplies its angle by `t`.
+    #[inline]
+    pub fn powf(&self, t: N) -> UnitComplex<N> {
+        UnitComplex::from_angle(self.angle() * t)
+    }
+}
+
+impl<N: FloatMath + Clone> UnitComplex<N> {
+    /// Creates a new unit complex number from a rotation matrix.
+    #[inline]
+    pub fn new_with_rot(rot: &Rot2<N>) -> UnitComplex<N> {
+        let m = rot.submat();
+
+        UnitComplex::new_with_cos_sin(m.m11.clone(), m.m21.clone())
+    }
+
+    /// Builds a rotation matrix from this unit complex number.
+    #[inline]
+    pub fn to_rot(&self) -> Rot2<N> {
+        unsafe {
+            Rot2::new_with_mat(Mat2::new(self.re.clone(), -self.im, self.im.clone(), self.re.clone()))
+        }
+    }
+
+    /// Spherical linear interpolation between two rotations.
+    ///
+    /// The interpolation follows the shortest path. Returns `self` if `t == 0` and `other` if `t
+    /// == 1`.
+    #[inline]
+    pub fn slerp(&self, other: &UnitComplex<N>, t: N) -> UnitComplex<N> {
+        let delta = (*other * Inv::inv_cpy(self).unwrap()).angle();
+
+        *self * UnitComplex::from_angle(delta * t)
+    }
+}
+
+impl<N> UnitComplex<N> {
+    /// Creates a new unit complex number from its real and imaginary parts.
+    ///
+    /// This is unsafe because the input is not normalized.
+    #[inline]
+    pub unsafe fn new_with_unit_complex(re: N, im: N) -> UnitComplex<N> {
+        UnitComplex {
+            re: re,
+            im: im
+        }
+    }
+
+    /// The real part of this complex number, i.e., the cosine of the rotation angle.
+    #[inline]
+    pub fn re<'a>(&'a self) -> &'a N {
+        &self.re
+    }
+
+    /// The imaginary part of this complex number, i.e., the sine of the rotation angle.
+    #[inline]
+    pub fn im<'a>(&'a self) -> &'a N {
+        &self.im
+    }
+}
+
+impl<N> Dim for UnitComplex<N> {
+    #[inline]
+    fn dim(_: Option<UnitComplex<N>>) -> uint {
+        2
+    }
+}
+
+impl<N: Num + Clone> One for UnitComplex<N> {
+    #[inline]
+    fn one() -> UnitComplex<N> {
+        UnitComplex {
+            re: One::one(),
+            im: Zero::zero()
+        }
+    }
+}
+
+impl<N: Clone + Neg<N>> Inv for UnitComplex<N> {
+    #[inline]
+    fn inv_cpy(m: &UnitComplex<N>) -> Option<UnitComplex<N>> {
+        let mut cpy = m.clone();
+
+        cpy.inv();
+        Some(cpy)
+    }
+
+    #[inline]
+    fn inv(&mut self) -> bool {
+        // the inverse of a unit complex number is its conjugate.
+        self.im = -self.im;
+
+        true
+    }
+}
+
+impl<N: Clone + Rand + FloatMath> Rand for UnitComplex<N> {
+    /// Generates a rotation uniformly distributed on the circle, i.e., with an angle uniformly
+    /// distributed in `[-pi, pi[`.
+    #[inline]
+    fn rand<R: Rng>(rng: &mut R) -> UnitComplex<N> {
+        let u: N = rng.gen();
+
+        UnitComplex::from_angle(u * Float::two_pi() - Float::pi())
+    }
+}
+
+impl<N: ApproxEq<N>> ApproxEq<N> for UnitComplex<N> {
+    #[inline]
+    fn approx_epsilon(_: Option<UnitComplex<N>>) -> N {
+        ApproxEq::approx_epsilon(None::<N>)
+    }
+
+    #[inline]
+    fn approx_ulps(_: Option<UnitComplex<N>>) -> u32 {
+        ApproxEq::approx_ulps(None::<N>)
+    }
+
+    #[inline]
+    fn approx_eq(a: &UnitComplex<N>, b: &UnitComplex<N>) -> bool {
+        ApproxEq::approx_eq(&a.re, &b.re) && ApproxEq::approx_eq(&a.im, &b.im)
+    }
+
+    #[inline]
+    fn approx_eq_eps(a: &UnitComplex<N>, b: &UnitComplex<N>, eps: &N) -> bool {
+        ApproxEq::approx_eq_eps(&a.re, &b.re, eps) && ApproxEq::approx_eq_eps(&a.im, &b.im, eps)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &UnitComplex<N>, b: &UnitComplex<N>, ulps: u32) -> bool {
+        ApproxEq::approx_eq_ulps(&a.re, &b.re, ulps) && ApproxEq::approx_eq_ulps(&a.im, &b.im, ulps)
+    }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &UnitComplex<N>, b: &UnitComplex<N>, abs_eps: &N, rel_eps: &N) -> bool {
+        ApproxEq::approx_eq_eps_rel(&a.re, &b.re, abs_eps, rel_eps) && ApproxEq::approx_eq_eps_rel(&a.im, &b.im, abs_eps, rel_eps)
+    }
+}
+
+impl<N: Num + Clone> UnitComplexMulRhs<N, UnitComplex<N>> for UnitComplex<N> {
+    #[inline]
+    fn binop(left: &UnitComplex<N>, right: &UnitComplex<N>) -> UnitComplex<N> {
+        UnitComplex {
+            re: left.re * right.re - left.im * right.im,
+            im: left.re * right.im + left.im * right.re
+        }
+    }
+}
+
+impl<N: Num + Clone> UnitComplexMulRhs<N, Vec2<N>> for Vec2<N> {
+    #[inline]
+    fn binop(left: &UnitComplex<N>, right: &Vec2<N>) -> Vec2<N> {
+        Vec2::new(
+            left.re * right.x - left.im * right.y,
+            left.im * right.x + left.re * right.y)
+    }
+}
+
+impl<N: Num + Clone> UnitComplexMulRhs<N, Pnt2<N>> for Pnt2<N> {
+    #[inline]
+    fn binop(left: &UnitComplex<N>, right: &Pnt2<N>) -> Pnt2<N> {
+        ::orig::<Pnt2<N>>() + *left * *right.as_vec()
+    }
+}
+
+impl<N: Num + Clone> Vec2MulRhs<N, Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn binop(left: &Vec2<N>, right: &UnitComplex<N>) -> Vec2<N> {
+        let mut inv_complex = right.clone();
+        inv_complex.inv();
+
+        inv_complex * *left
+    }
+}
+
+impl<N: Num + Clone> Pnt2MulRhs<N, Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn binop(left: &Pnt2<N>, right: &UnitComplex<N>) -> Pnt2<N> {
+        ::orig::<Pnt2<N>>() + *left.as_vec() * *right
+    }
+}
+
+impl<N: FloatMath + Clone> Rotation<Vec1<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotation(&self) -> Vec1<N> {
+        Vec1::new(self.angle())
+    }
+
+    #[inline]
+    fn inv_rotation(&self) -> Vec1<N> {
+        -self.rotation()
+    }
+
+    #[inline]
+    fn append_rotation(&mut self, amount: &Vec1<N>) {
+        *self = Rotation::append_rotation_cpy(self, amount)
+    }
+
+    #[inline]
+    fn append_rotation_cpy(t: &UnitComplex<N>, amount: &Vec1<N>) -> UnitComplex<N> {
+        UnitComplex::new(amount.clone()) * *t
+    }
+
+    #[inline]
+    fn prepend_rotation(&mut self, amount: &Vec1<N>) {
+        *self = Rotation::prepend_rotation_cpy(self, amount)
+    }
+
+    #[inline]
+    fn prepend_rotation_cpy(t: &UnitComplex<N>, amount: &Vec1<N>) -> UnitComplex<N> {
+        *t * UnitComplex::new(amount.clone())
+    }
+
+    #[inline]
+    fn set_rotation(&mut self, v: Vec1<N>) {
+        *self = UnitComplex::new(v)
+    }
+}
+
+impl<N: Num + Clone> Rotate<Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotate(&self, v: &Vec2<N>) -> Vec2<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_rotate(&self, v: &Vec2<N>) -> Vec2<N> {
+        *v * *self
+    }
+}
+
+impl<N: Num + Clone> Rotate<Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn rotate(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_rotate(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *p * *self
+    }
+}
+
+impl<N: Num + Clone> Transform<Vec2<N>> for UnitComplex<N> {
+    #[inline]
+    fn transform(&self, v: &Vec2<N>) -> Vec2<N> {
+        *self * *v
+    }
+
+    #[inline]
+    fn inv_transform(&self, v: &Vec2<N>) -> Vec2<N> {
+        *v * *self
+    }
+}
+
+impl<N: Num + Clone> Transform<Pnt2<N>> for UnitComplex<N> {
+    #[inline]
+    fn transform(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *self * *p
+    }
+
+    #[inline]
+    fn inv_transform(&self, p: &Pnt2<N>) -> Pnt2<N> {
+        *p * *self
+    }
+}
+
+double_dispatch_binop_decl_trait!(UnitComplex, UnitComplexMulRhs)
+mul_redispatch_impl!(UnitComplex, UnitComplexMulRhs)
@@ -3,15 +3,24 @@
 #![allow(missing_doc)] // we allow missing to avoid having to document the dispatch traits.
 
 use std::mem;
+use std::ptr;
+use std::fmt;
+use std::from_str::FromStr;
+use std::io::{IoResult, MemWriter, BufReader};
 use std::num::{Zero, One, Float, Bounded};
+use std::rand::Rng;
+use std::rand::distributions::StandardNormal;
 use std::slice::{Items, MutItems};
 use std::iter::{Iterator, FromIterator};
 use traits::operations::{ApproxEq, PartialOrd, PartialOrdering, PartialLess, PartialEqual,
-                         PartialGreater, NotComparable, Axpy};
+                         PartialGreater, NotComparable, Axpy, AddInPlace, SubInPlace,
+                         ScaleInPlace};
 use traits::geometry::{Transform, Rotate, FromHomogeneous, ToHomogeneous, Dot, Norm,
                        Translation, Translate};
-use traits::structure::{Basis, Cast, Dim, Indexable, Iterable, IterableMut, VecAsPnt};
+use traits::structure::{Basis, Bytes, Cast, Dim, StaticDim, Indexable, Iterable, IterableMut, VecAsPnt};
+use structs::dim::{D1, D2, D3, D4, D5, D6};
 use structs::pnt::{Pnt1, Pnt2, Pnt3, Pnt4, Pnt5, Pnt6};
+use structs::{pretty, parse};
 
 
 /// Vector of dimension 0.
@@ -33,7 +42,7 @@ impl<N> Vec0<N> {
 }
 
 /// Vector of dimension 1.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec1<N> {
     /// First component of the vector.
     pub x: N
@@ -54,11 +63,16 @@ ord_impl!(Vec1, x)
 vec_axis_impl!(Vec1, x)
 vec_cast_impl!(Vec1, Vec1Cast, x)
 as_slice_impl!(Vec1, 1)
+array_conversion_impl!(Vec1, 1)
 index_impl!(Vec1)
 indexable_impl!(Vec1, 1)
 at_fast_impl!(Vec1, 1)
+vec_show_impl!(Vec1)
+vec_from_str_impl!(Vec1, 1, x)
+bytes_impl!(Vec1, x)
 new_repeat_impl!(Vec1, val, x)
 dim_impl!(Vec1, 1)
+static_dim_impl!(Vec1, D1)
 container_impl!(Vec1)
 // (specialized) basis_impl!(Vec1, 1)
 add_impl!(Vec1, Vec1AddRhs, x)
@@ -79,6 +93,8 @@ vec_mul_scalar_impl!(Vec1, i16, Vec1MulRhs, x)
 vec_mul_scalar_impl!(Vec1, i8, Vec1MulRhs, x)
 vec_mul_scalar_impl!(Vec1, uint, Vec1MulRhs, x)
 vec_mul_scalar_impl!(Vec1, int, Vec1MulRhs, x)
+left_scalar_mul_impl!(Vec1, f64)
+left_scalar_mul_impl!(Vec1, f32)
 vec_div_scalar_impl!(Vec1, f64, Vec1DivRhs, x)
 vec_div_scalar_impl!(Vec1, f32, Vec1DivRhs, x)
 vec_div_scalar_impl!(Vec1, u64, Vec1DivRhs, x)
@@ -117,11 +133,13 @@ vec_sub_scalar_impl!(Vec1, uint, Vec1SubRhs, x)
 vec_sub_scalar_impl!(Vec1, int, Vec1SubRhs, x)
 translation_impl!(Vec1)
 norm_impl!(Vec1, x)
+random_unit_impl!(Vec1, x)
 approx_eq_impl!(Vec1, x)
 one_impl!(Vec1, x)
 from_iterator_impl!(Vec1, iterator)
 bounded_impl!(Vec1, x)
 axpy_impl!(Vec1, x)
+inplace_impl!(Vec1)
 iterable_impl!(Vec1, 1)
 iterable_mut_impl!(Vec1, 1)
 vec_to_homogeneous_impl!(Vec1, Vec2, y, x)
@@ -133,7 +151,7 @@ transform_impl!(Vec1, Pnt1)
 vec_as_pnt_impl!(Vec1, Pnt1, x)
 
 /// Vector of dimension 2.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec2<N> {
     /// First component of the vector.
     pub x: N,
@@ -156,11 +174,16 @@ ord_impl!(Vec2, x, y)
 vec_axis_impl!(Vec2, x, y)
 vec_cast_impl!(Vec2, Vec2Cast, x, y)
 as_slice_impl!(Vec2, 2)
+array_conversion_impl!(Vec2, 2)
 index_impl!(Vec2)
 indexable_impl!(Vec2, 2)
 at_fast_impl!(Vec2, 2)
+vec_show_impl!(Vec2)
+vec_from_str_impl!(Vec2, 2, x, y)
+bytes_impl!(Vec2, x, y)
 new_repeat_impl!(Vec2, val, x, y)
 dim_impl!(Vec2, 2)
+static_dim_impl!(Vec2, D2)
 container_impl!(Vec2)
 // (specialized) basis_impl!(Vec2, 1)
 add_impl!(Vec2, Vec2AddRhs, x, y)
@@ -181,6 +204,8 @@ vec_mul_scalar_impl!(Vec2, i16, Vec2MulRhs, x, y)
 vec_mul_scalar_impl!(Vec2, i8, Vec2MulRhs, x, y)
 vec_mul_scalar_impl!(Vec2, uint, Vec2MulRhs, x, y)
 vec_mul_scalar_impl!(Vec2, int, Vec2MulRhs, x, y)
+left_scalar_mul_impl!(Vec2, f64)
+left_scalar_mul_impl!(Vec2, f32)
 vec_div_scalar_impl!(Vec2, f64, Vec2DivRhs, x, y)
 vec_div_scalar_impl!(Vec2, f32, Vec2DivRhs, x, y)
 vec_div_scalar_impl!(Vec2, u64, Vec2DivRhs, x, y)
@@ -219,11 +244,13 @@ vec_sub_scalar_impl!(Vec2, uint, Vec2SubRhs, x, y)
 vec_sub_scalar_impl!(Vec2, int, Vec2SubRhs, x, y)
 translation_impl!(Vec2)
 norm_impl!(Vec2, x, y)
+random_unit_impl!(Vec2, x, y)
 approx_eq_impl!(Vec2, x, y)
 one_impl!(Vec2, x, y)
 from_iterator_impl!(Vec2, iterator, iterator)
 bounded_impl!(Vec2, x, y)
 axpy_impl!(Vec2, x, y)
+inplace_impl!(Vec2)
 iterable_impl!(Vec2, 2)
 iterable_mut_impl!(Vec2, 2)
 vec_to_homogeneous_impl!(Vec2, Vec3, z, x, y)
@@ -235,7 +262,7 @@ transform_impl!(Vec2, Pnt2)
 vec_as_pnt_impl!(Vec2, Pnt2, x, y)
 
 /// Vector of dimension 3.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec3<N> {
     /// First component of the vector.
     pub x: N,
@@ -260,11 +287,16 @@ ord_impl!(Vec3, x, y, z)
 vec_axis_impl!(Vec3, x, y, z)
 vec_cast_impl!(Vec3, Vec3Cast, x, y, z)
 as_slice_impl!(Vec3, 3)
+array_conversion_impl!(Vec3, 3)
 index_impl!(Vec3)
 indexable_impl!(Vec3, 3)
 at_fast_impl!(Vec3, 3)
+vec_show_impl!(Vec3)
+vec_from_str_impl!(Vec3, 3, x, y, z)
+bytes_impl!(Vec3, x, y, z)
 new_repeat_impl!(Vec3, val, x, y, z)
 dim_impl!(Vec3, 3)
+static_dim_impl!(Vec3, D3)
 container_impl!(Vec3)
 // (specialized) basis_impl!(Vec3, 1)
 add_impl!(Vec3, Vec3AddRhs, x, y, z)
@@ -285,6 +317,8 @@ vec_mul_scalar_impl!(Vec3, i16, Vec3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Vec3, i8, Vec3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Vec3, uint, Vec3MulRhs, x, y, z)
 vec_mul_scalar_impl!(Vec3, int, Vec3MulRhs, x, y, z)
+left_scalar_mul_impl!(Vec3, f64)
+left_scalar_mul_impl!(Vec3, f32)
 
 vec_div_scalar_impl!(Vec3, f64, Vec3DivRhs, x, y, z)
 vec_div_scalar_impl!(Vec3, f32, Vec3DivRhs, x, y, z)
@@ -326,11 +360,13 @@ vec_sub_scalar_impl!(Vec3, uint, Vec3SubRhs, x, y, z)
 vec_sub_scalar_impl!(Vec3, int, Vec3SubRhs, x, y, z)
 translation_impl!(Vec3)
 norm_impl!(Vec3, x, y ,z)
+random_unit_impl!(Vec3, x, y, z)
 approx_eq_impl!(Vec3, x, y, z)
 one_impl!(Vec3, x, y, z)
 from_iterator_impl!(Vec3, iterator, iterator, iterator)
 bounded_impl!(Vec3, x, y, z)
 axpy_impl!(Vec3, x, y, z)
+inplace_impl!(Vec3)
 iterable_impl!(Vec3, 3)
 iterable_mut_impl!(Vec3, 3)
 vec_to_homogeneous_impl!(Vec3, Vec4, w, x, y, z)
@@ -343,7 +379,7 @@ vec_as_pnt_impl!(Vec3, Pnt3, x, y, z)
 
 
 /// Vector of dimension 4.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec4<N> {
     /// First component of the vector.
     pub x: N,
@@ -370,11 +406,16 @@ ord_impl!(Vec4, x, y, z, w)
 vec_axis_impl!(Vec4, x, y, z, w)
 vec_cast_impl!(Vec4, Vec4Cast, x, y, z, w)
 as_slice_impl!(Vec4, 4)
+array_conversion_impl!(Vec4, 4)
 index_impl!(Vec4)
 indexable_impl!(Vec4, 4)
 at_fast_impl!(Vec4, 4)
+vec_show_impl!(Vec4)
+vec_from_str_impl!(Vec4, 4, x, y, z, w)
+bytes_impl!(Vec4, x, y, z, w)
 new_repeat_impl!(Vec4, val, x, y, z, w)
 dim_impl!(Vec4, 4)
+static_dim_impl!(Vec4, D4)
 container_impl!(Vec4)
 basis_impl!(Vec4, Vec4MulRhs, 4)
 add_impl!(Vec4, Vec4AddRhs, x, y, z, w)
@@ -395,6 +436,8 @@ vec_mul_scalar_impl!(Vec4, i16, Vec4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Vec4, i8, Vec4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Vec4, uint, Vec4MulRhs, x, y, z, w)
 vec_mul_scalar_impl!(Vec4, int, Vec4MulRhs, x, y, z, w)
+left_scalar_mul_impl!(Vec4, f64)
+left_scalar_mul_impl!(Vec4, f32)
 vec_div_scalar_impl!(Vec4, f64, Vec4DivRhs, x, y, z, w)
 vec_div_scalar_impl!(Vec4, f32, Vec4DivRhs, x, y, z, w)
 vec_div_scalar_impl!(Vec4, u64, Vec4DivRhs, x, y, z, w)
@@ -433,11 +476,13 @@ vec_sub_scalar_impl!(Vec4, uint, Vec4SubRhs, x, y, z, w)
 vec_sub_scalar_impl!(Vec4, int, Vec4SubRhs, x, y, z, w)
 translation_impl!(Vec4)
 norm_impl!(Vec4, x, y, z, w)
+random_unit_impl!(Vec4, x, y, z, w)
 approx_eq_impl!(Vec4, x, y, z, w)
 one_impl!(Vec4, x, y, z, w)
 from_iterator_impl!(Vec4, iterator, iterator, iterator, iterator)
 bounded_impl!(Vec4, x, y, z, w)
 axpy_impl!(Vec4, x, y, z, w)
+inplace_impl!(Vec4)
 iterable_impl!(Vec4, 4)
 iterable_mut_impl!(Vec4, 4)
 vec_to_homogeneous_impl!(Vec4, Vec5, a, x, y, z, w)
@@ -449,7 +494,7 @@ transform_impl!(Vec4, Pnt4)
 vec_as_pnt_impl!(Vec4, Pnt4, x, y, z, w)
 
 /// Vector of dimension 5.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec5<N> {
     /// First component of the vector.
     pub x: N,
@@ -478,11 +523,16 @@ ord_impl!(Vec5, x, y, z, w, a)
 vec_axis_impl!(Vec5, x, y, z, w, a)
 vec_cast_impl!(Vec5, Vec5Cast, x, y, z, w, a)
 as_slice_impl!(Vec5, 5)
+array_conversion_impl!(Vec5, 5)
 index_impl!(Vec5)
 indexable_impl!(Vec5, 5)
 at_fast_impl!(Vec5, 5)
+vec_show_impl!(Vec5)
+vec_from_str_impl!(Vec5, 5, x, y, z, w, a)
+bytes_impl!(Vec5, x, y, z, w, a)
 new_repeat_impl!(Vec5, val, x, y, z, w, a)
 dim_impl!(Vec5, 5)
+static_dim_impl!(Vec5, D5)
 container_impl!(Vec5)
 basis_impl!(Vec5, Vec5MulRhs, 5)
 add_impl!(Vec5, Vec5AddRhs, x, y, z, w, a)
@@ -503,6 +553,8 @@ vec_mul_scalar_impl!(Vec5, i16, Vec5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Vec5, i8, Vec5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Vec5, uint, Vec5MulRhs, x, y, z, w, a)
 vec_mul_scalar_impl!(Vec5, int, Vec5MulRhs, x, y, z, w, a)
+left_scalar_mul_impl!(Vec5, f64)
+left_scalar_mul_impl!(Vec5, f32)
 vec_div_scalar_impl!(Vec5, f64, Vec5DivRhs, x, y, z, w, a)
 vec_div_scalar_impl!(Vec5, f32, Vec5DivRhs, x, y, z, w, a)
 vec_div_scalar_impl!(Vec5, u64, Vec5DivRhs, x, y, z, w, a)
@@ -541,11 +593,13 @@ vec_sub_scalar_impl!(Vec5, uint, Vec5SubRhs, x, y, z, w, a)
 vec_sub_scalar_impl!(Vec5, int, Vec5SubRhs, x, y, z, w, a)
 translation_impl!(Vec5)
 norm_impl!(Vec5, x, y, z, w, a)
+random_unit_impl!(Vec5, x, y, z, w, a)
 approx_eq_impl!(Vec5, x, y, z, w, a)
 one_impl!(Vec5, x, y, z, w, a)
 from_iterator_impl!(Vec5, iterator, iterator, iterator, iterator, iterator)
 bounded_impl!(Vec5, x, y, z, w, a)
 axpy_impl!(Vec5, x, y, z, w, a)
+inplace_impl!(Vec5)
 iterable_impl!(Vec5, 5)
 iterable_mut_impl!(Vec5, 5)
 vec_to_homogeneous_impl!(Vec5, Vec6, b, x, y, z, w, a)
@@ -557,7 +611,7 @@ transform_impl!(Vec5, Pnt5)
 vec_as_pnt_impl!(Vec5, Pnt5, x, y, z, w, a)
 
 /// Vector of dimension 6.
-#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero, Show)]
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Hash, Rand, Zero)]
 pub struct Vec6<N> {
     /// First component of the vector.
     pub x: N,
@@ -588,11 +642,16 @@ ord_impl!(Vec6, x, y, z, w, a, b)
 vec_axis_impl!(Vec6, x, y, z, w, a, b)
 vec_cast_impl!(Vec6, Vec6Cast, x, y, z, w, a, b)
 as_slice_impl!(Vec6, 6)
+array_conversion_impl!(Vec6, 6)
 index_impl!(Vec6)
 indexable_impl!(Vec6, 6)
 at_fast_impl!(Vec6, 6)
+vec_show_impl!(Vec6)
+vec_from_str_impl!(Vec6, 6, x, y, z, w, a, b)
+bytes_impl!(Vec6, x, y, z, w, a, b)
 new_repeat_impl!(Vec6, val, x, y, z, w, a, b)
 dim_impl!(Vec6, 6)
+static_dim_impl!(Vec6, D6)
 container_impl!(Vec6)
 basis_impl!(Vec6, Vec6MulRhs, 6)
 add_impl!(Vec6, Vec6AddRhs, x, y, z, w, a, b)
@@ -613,6 +672,8 @@ vec_mul_scalar_impl!(Vec6, i16, Vec6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Vec6, i8, Vec6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Vec6, uint, Vec6MulRhs, x, y, z, w, a, b)
 vec_mul_scalar_impl!(Vec6, int, Vec6MulRhs, x, y, z, w, a, b)
+left_scalar_mul_impl!(Vec6, f64)
+left_scalar_mul_impl!(Vec6, f32)
 vec_div_scalar_impl!(Vec6, f64, Vec6DivRhs, x, y, z, w, a, b)
 vec_div_scalar_impl!(Vec6, f32, Vec6DivRhs, x, y, z, w, a, b)
 vec_div_scalar_impl!(Vec6, u64, Vec6DivRhs, x, y, z, w, a, b)
@@ -651,11 +712,13 @@ vec_sub_scalar_impl!(Vec6, uint, Vec6SubRhs, x, y, z, w, a, b)
 vec_sub_scalar_impl!(Vec6, int, Vec6SubRhs, x, y, z, w, a, b)
 translation_impl!(Vec6)
 norm_impl!(Vec6, x, y, z, w, a, b)
+random_unit_impl!(Vec6, x, y, z, w, a, b)
 approx_eq_impl!(Vec6, x, y, z, w, a, b)
 one_impl!(Vec6, x, y, z, w, a, b)
 from_iterator_impl!(Vec6, iterator, iterator, iterator, iterator, iterator, iterator)
 bounded_impl!(Vec6, x, y, z, w, a, b)
 axpy_impl!(Vec6, x, y, z, w, a, b)
+inplace_impl!(Vec6)
 iterable_impl!(Vec6, 6)
 iterable_mut_impl!(Vec6, 6)
 translate_impl!(Vec6, Pnt6)
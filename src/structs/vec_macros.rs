@@ -11,6 +11,14 @@ macro_rules! new_impl(
                     $(, $compN: $compN )*
                 }
             }
+
+            /// Creates an uninitialized vector.
+            ///
+            /// Every component must be written before being read.
+            #[inline]
+            pub unsafe fn new_uninitialized() -> $t<N> {
+                mem::uninitialized()
+            }
         }
     )
 )
@@ -35,6 +43,194 @@ macro_rules! as_slice_impl(
     )
 )
 
+macro_rules! bytes_impl(
+    ($t: ident, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: Bytes> $t<N> {
+            /// Writes the components of `self` to `w`, in memory order and in little-endian byte
+            /// order.
+            #[inline]
+            pub fn write_le<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+                try!(self.$comp0.write_le(w));
+                $( try!(self.$compN.write_le(w)); )*
+
+                Ok(())
+            }
+
+            /// Writes the components of `self` to `w`, in memory order and in big-endian byte order.
+            #[inline]
+            pub fn write_be<W: Writer>(&self, w: &mut W) -> IoResult<()> {
+                try!(self.$comp0.write_be(w));
+                $( try!(self.$compN.write_be(w)); )*
+
+                Ok(())
+            }
+
+            /// Reads components written by `write_le` from `r`.
+            #[inline]
+            pub fn read_le<R: Reader>(r: &mut R) -> IoResult<$t<N>> {
+                Ok($t {
+                    $comp0: try!(Bytes::read_le(r))
+                    $(, $compN: try!(Bytes::read_le(r)) )*
+                })
+            }
+
+            /// Reads components written by `write_be` from `r`.
+            #[inline]
+            pub fn read_be<R: Reader>(r: &mut R) -> IoResult<$t<N>> {
+                Ok($t {
+                    $comp0: try!(Bytes::read_be(r))
+                    $(, $compN: try!(Bytes::read_be(r)) )*
+                })
+            }
+
+            /// The binary representation of `self`, i.e., its components in memory order and with
+            /// the native byte order.
+            pub fn to_bytes(&self) -> Vec<u8> {
+                let mut w = MemWriter::new();
+
+                if cfg!(target_endian = "little") {
+                    self.write_le(&mut w).unwrap();
+                }
+                else {
+                    self.write_be(&mut w).unwrap();
+                }
+
+                w.unwrap()
+            }
+
+            /// Builds a value from its binary representation, as returned by `to_bytes`.
+            ///
+            /// Returns `None` if `bytes` does not have the exact size of the binary
+            /// representation.
+            pub fn from_bytes(bytes: &[u8]) -> Option<$t<N>> {
+                let mut r = BufReader::new(bytes);
+
+                let res = if cfg!(target_endian = "little") {
+                    $t::read_le(&mut r)
+                }
+                else {
+                    $t::read_be(&mut r)
+                };
+
+                match res {
+                    Ok(res) if r.eof() => Some(res),
+                    _                  => None
+                }
+            }
+        }
+    )
+)
+
+macro_rules! vec_show_impl(
+    ($t: ident) => (
+        impl<N: fmt::Show> fmt::Show for $t<N> {
+            /// Writes the components of this vector on a single line, e.g., `[1, 2, 3]`.
+            ///
+            /// The width and precision flags are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let strs = pretty::show_strs(f, self.as_slice());
+
+                pretty::fmt_vec(f, strs.as_slice())
+            }
+        }
+
+        impl<N: fmt::LowerExp> fmt::LowerExp for $t<N> {
+            /// Writes the components of this vector in scientific notation on a single line.
+            ///
+            /// The width and precision flags are applied to each component.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let strs = pretty::exp_strs(f, self.as_slice());
+
+                pretty::fmt_vec(f, strs.as_slice())
+            }
+        }
+    )
+)
+
+macro_rules! vec_from_str_impl(
+    ($t: ident, $dim: expr, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: FromStr> $t<N> {
+            /// Parses the bracketed list of components of this type, e.g., `"[1, 2, 3]"`.
+            ///
+            /// Returns a message describing the error if the input is malformed.
+            pub fn parse(s: &str) -> Result<$t<N>, String> {
+                let elts: Vec<N> = try!(parse::parse_list(s));
+
+                if elts.len() != $dim {
+                    return Err(format!("expected {} components, found {}.", $dim, elts.len()))
+                }
+
+                let mut elts = elts.into_iter();
+
+                Ok($t {
+                    $comp0: elts.next().unwrap()
+                    $(, $compN: elts.next().unwrap() )*
+                })
+            }
+        }
+
+        impl<N: FromStr> FromStr for $t<N> {
+            #[inline]
+            fn from_str(s: &str) -> Option<$t<N>> {
+                $t::parse(s).ok()
+            }
+        }
+    )
+)
+
+macro_rules! array_conversion_impl(
+    ($t: ident, $dim: expr) => (
+        impl<N> $t<N> {
+            /// Creates a new value from an array of its components.
+            #[inline]
+            pub fn from_array(array: [N, ..$dim]) -> $t<N> {
+                unsafe {
+                    let res = ptr::read(mem::transmute::<&[N, ..$dim], &$t<N>>(&array));
+                    mem::forget(array);
+
+                    res
+                }
+            }
+
+            /// Converts this value to an array of its components.
+            #[inline]
+            pub fn to_array(self) -> [N, ..$dim] {
+                unsafe {
+                    let res = ptr::read(mem::transmute::<&$t<N>, &[N, ..$dim]>(&self));
+                    mem::forget(self);
+
+                    res
+                }
+            }
+
+            /// Reinterprets a reference to an array of components as a reference to this type.
+            #[inline]
+            pub fn from_array_ref<'a>(array: &'a [N, ..$dim]) -> &'a $t<N> {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Reinterprets a mutable reference to an array of components as a mutable reference
+            /// to this type.
+            #[inline]
+            pub fn from_array_mut<'a>(array: &'a mut [N, ..$dim]) -> &'a mut $t<N> {
+                unsafe { mem::transmute(array) }
+            }
+
+            /// Reinterprets this value as an array of its components.
+            #[inline]
+            pub fn as_array<'a>(&'a self) -> &'a [N, ..$dim] {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// Reinterprets this value as a mutable array of its components.
+            #[inline]
+            pub fn as_array_mut<'a>(&'a mut self) -> &'a mut [N, ..$dim] {
+                unsafe { mem::transmute(self) }
+            }
+        }
+    )
+)
+
 macro_rules! at_fast_impl(
     ($t: ident, $dim: expr) => (
         impl<N: Clone> $t<N> {
@@ -164,6 +360,14 @@ macro_rules! vec_cast_impl(
             fn to(v: $t<Nin>) -> $t<Nout> {
                 $t::new(Cast::from(v.$comp0.clone()) $(, Cast::from(v.$compN.clone()))*)
             }
+
+            #[inline]
+            fn try_to(v: $t<Nin>) -> Option<$t<Nout>> {
+                match (Cast::try_from(v.$comp0.clone()) $(, Cast::try_from(v.$compN.clone()))*) {
+                    (Some($comp0) $(, Some($compN))*) => Some($t::new($comp0 $(, $compN)*)),
+                    _                                 => None
+                }
+            }
         }
     )
 )
@@ -278,6 +482,12 @@ macro_rules! dim_impl(
     )
 )
 
+macro_rules! static_dim_impl(
+    ($t: ident, $d: ident) => (
+        impl<N> StaticDim<$d> for $t<N> { }
+    )
+)
+
 macro_rules! container_impl(
     ($t: ident) => (
         impl<N> Collection for $t<N> {
@@ -343,6 +553,59 @@ macro_rules! basis_impl(
     )
 )
 
+macro_rules! inplace_impl(
+    ($t: ident) => (
+        impl<N: Add<N, N>> AddInPlace<$t<N>> for $t<N> {
+            #[inline]
+            fn add_inplace(&mut self, rhs: &$t<N>) {
+                for (e, r) in self.iter_mut().zip(rhs.iter()) {
+                    *e = *e + *r;
+                }
+            }
+        }
+
+        impl<N: Sub<N, N>> SubInPlace<$t<N>> for $t<N> {
+            #[inline]
+            fn sub_inplace(&mut self, rhs: &$t<N>) {
+                for (e, r) in self.iter_mut().zip(rhs.iter()) {
+                    *e = *e - *r;
+                }
+            }
+        }
+
+        impl<N: Mul<N, N>> ScaleInPlace<N> for $t<N> {
+            #[inline]
+            fn scale_inplace(&mut self, s: &N) {
+                for e in self.iter_mut() {
+                    *e = *e * *s;
+                }
+            }
+        }
+    )
+)
+
+macro_rules! pnt_inplace_impl(
+    ($t: ident, $tv: ident) => (
+        impl<N: Add<N, N>> AddInPlace<$tv<N>> for $t<N> {
+            #[inline]
+            fn add_inplace(&mut self, rhs: &$tv<N>) {
+                for (e, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) {
+                    *e = *e + *r;
+                }
+            }
+        }
+
+        impl<N: Sub<N, N>> SubInPlace<$tv<N>> for $t<N> {
+            #[inline]
+            fn sub_inplace(&mut self, rhs: &$tv<N>) {
+                for (e, r) in self.as_mut_slice().iter_mut().zip(rhs.as_slice().iter()) {
+                    *e = *e - *r;
+                }
+            }
+        }
+    )
+)
+
 macro_rules! axpy_impl(
     ($t: ident, $comp0: ident $(,$compN: ident)*) => (
         impl<N: Add<N, N> + Mul<N, N>> Axpy<N> for $t<N> {
@@ -412,7 +675,7 @@ macro_rules! neg_impl(
 
 macro_rules! dot_impl(
     ($t: ident, $comp0: ident $(,$compN: ident)*) => (
-        impl<N: Num> Dot<N> for $t<N> {
+        impl<N: Add<N, N> + Mul<N, N>> Dot<N> for $t<N> {
             #[inline]
             fn dot(a: &$t<N>, b: &$t<N>) -> N {
                 a.$comp0 * b.$comp0 $(+ a.$compN * b.$compN )*
@@ -541,6 +804,31 @@ macro_rules! norm_impl(
     )
 )
 
+macro_rules! random_unit_impl(
+    ($t: ident, $comp0: ident $(,$compN: ident)*) => (
+        impl<N: Clone + Float + Cast<f64>> $t<N> {
+            /// Builds a random unit vector, uniformly distributed on the unit sphere.
+            #[inline]
+            pub fn new_random_unit<R: Rng>(rng: &mut R) -> $t<N> {
+                // the direction of a vector with independent gaussian components is uniformly
+                // distributed.
+                loop {
+                    let mut res = $t {
+                        $comp0: { let StandardNormal(e) = rng.gen(); Cast::from(e) }
+                        $(, $compN: { let StandardNormal(e) = rng.gen(); Cast::from(e) })*
+                    };
+
+                    if !Norm::sqnorm(&res).is_zero() {
+                        let _ = res.normalize();
+
+                        return res
+                    }
+                }
+            }
+        }
+    )
+)
+
 macro_rules! approx_eq_impl(
     ($t: ident, $comp0: ident $(,$compN: ident)*) => (
         impl<N: ApproxEq<N>> ApproxEq<N> for $t<N> {
@@ -549,6 +837,11 @@ macro_rules! approx_eq_impl(
                 ApproxEq::approx_epsilon(None::<N>)
             }
 
+            #[inline]
+            fn approx_ulps(_: Option<$t<N>>) -> u32 {
+                ApproxEq::approx_ulps(None::<N>)
+            }
+
             #[inline]
             fn approx_eq(a: &$t<N>, b: &$t<N>) -> bool {
                 ApproxEq::approx_eq(&a.$comp0, &b.$comp0)
@@ -560,6 +853,18 @@ macro_rules! approx_eq_impl(
                 ApproxEq::approx_eq_eps(&a.$comp0, &b.$comp0, eps)
                 $(&& ApproxEq::approx_eq_eps(&a.$compN, &b.$compN, eps))*
             }
+
+            #[inline]
+            fn approx_eq_ulps(a: &$t<N>, b: &$t<N>, ulps: u32) -> bool {
+                ApproxEq::approx_eq_ulps(&a.$comp0, &b.$comp0, ulps)
+                $(&& ApproxEq::approx_eq_ulps(&a.$compN, &b.$compN, ulps))*
+            }
+
+            #[inline]
+            fn approx_eq_eps_rel(a: &$t<N>, b: &$t<N>, abs_eps: &N, rel_eps: &N) -> bool {
+                ApproxEq::approx_eq_eps_rel(&a.$comp0, &b.$comp0, abs_eps, rel_eps)
+                $(&& ApproxEq::approx_eq_eps_rel(&a.$compN, &b.$compN, abs_eps, rel_eps))*
+            }
         }
     )
 )
@@ -583,7 +888,12 @@ macro_rules! from_iterator_impl(
         impl<N> FromIterator<N> for $t<N> {
             #[inline]
             fn from_iter<I: Iterator<N>>(mut $param0: I) -> $t<N> {
-                $t::new($param0.next().unwrap() $(, $paramN.next().unwrap())*)
+                let res = $t::new($param0.next().expect("The iterator yields too few elements.")
+                                  $(, $paramN.next().expect("The iterator yields too few elements."))*);
+
+                assert!($param0.next().is_none(), "The iterator yields too many elements.");
+
+                res
             }
         }
     )
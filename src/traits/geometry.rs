@@ -197,6 +197,70 @@ pub trait Transform<V> {
     fn inv_transform(&self, &V) -> V;
 }
 
+/// Trait of objects able to transform both points and vectors.
+///
+/// Points are affected by the whole transformation while vectors are only affected by its linear
+/// part, e.g., they are not translated. This is automatically implemented by every type
+/// implementing both `Transform<P>` and `Transform<V>` so that generic code needs a single bound
+/// to transform points and vectors.
+pub trait PntVecTransform<P, V> {
+    /// Applies a transformation to a point.
+    fn transform_pnt(&self, &P) -> P;
+
+    /// Applies a transformation to a vector.
+    fn transform_vec(&self, &V) -> V;
+
+    /// Applies an inverse transformation to a point.
+    fn inv_transform_pnt(&self, &P) -> P;
+
+    /// Applies an inverse transformation to a vector.
+    fn inv_transform_vec(&self, &V) -> V;
+}
+
+impl<P, V, T: Transform<P> + Transform<V>> PntVecTransform<P, V> for T {
+    #[inline(always)]
+    fn transform_pnt(&self, p: &P) -> P {
+        self.transform(p)
+    }
+
+    #[inline(always)]
+    fn transform_vec(&self, v: &V) -> V {
+        self.transform(v)
+    }
+
+    #[inline(always)]
+    fn inv_transform_pnt(&self, p: &P) -> P {
+        self.inv_transform(p)
+    }
+
+    #[inline(always)]
+    fn inv_transform_vec(&self, v: &V) -> V {
+        self.inv_transform(v)
+    }
+}
+
+/// Trait of objects able to transform a whole slice of objects at once.
+///
+/// The default implementation applies `Transform::transform` to each element. Implementors
+/// transforming large sets of points or vectors should override it with a faster path.
+pub trait TransformAll<V>: Transform<V> {
+    /// Applies a transformation to each element of `vs`, in-place.
+    #[inline]
+    fn transform_all(&self, vs: &mut [V]) {
+        for v in vs.iter_mut() {
+            *v = self.transform(v)
+        }
+    }
+
+    /// Applies an inverse transformation to each element of `vs`, in-place.
+    #[inline]
+    fn inv_transform_all(&self, vs: &mut [V]) {
+        for v in vs.iter_mut() {
+            *v = self.inv_transform(v)
+        }
+    }
+}
+
 /// Traits of objects having a dot product.
 pub trait Dot<N> {
     /// Computes the dot (inner) product of two vectors.
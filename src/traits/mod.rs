@@ -1,16 +1,21 @@
 //! Mathematical traits.
 
 pub use self::geometry::{AbsoluteRotate, Cross, CrossMatrix, Dot, FromHomogeneous, Norm, Orig,
-                         Rotate, Rotation, RotationMatrix, RotationWithTranslation, ToHomogeneous,
-                         Transform, Transformation, Translate, Translation, UniformSphereSample};
+                         PntVecTransform, Rotate, Rotation, RotationMatrix, RotationWithTranslation,
+                         ToHomogeneous, Transform, TransformAll, Transformation, Translate, Translation,
+                         UniformSphereSample};
 
-pub use self::structure::{FloatVec, FloatVecExt, FloatPnt, FloatPntExt, Basis, Cast, Col, Dim,
+pub use self::structure::{FloatVec, FloatVecExt, FloatPnt, FloatPntExt, Basis, Bytes, Cast, Col, Dim,
                           Indexable, Iterable, IterableMut, Mat, Row, AnyVec, VecExt, AnyPnt,
-                          PntExt, PntAsVec, VecAsPnt, ColSlice, RowSlice, Diag, Eye};
+                          PntExt, PntAsVec, VecAsPnt, ColSlice, RowSlice, Diag, Band, Eye, StaticDim,
+                          TypeDim};
 
-pub use self::operations::{Absolute, ApproxEq, Axpy, Cov, Det, Inv, LMul, Mean, Outer, PartialOrd,
+pub use self::operations::{Absolute, ApproxEq, Axpy, Cov, WeightedCov, WeightedMean, Det, Inv, LMul, Mean, Outer, PartialOrd,
                            RMul, ScalarAdd, ScalarSub, ScalarMul, ScalarDiv, Transpose};
+pub use self::operations::{AddInPlace, SubInPlace, ScaleInPlace, MulInPlace, Gemv, Gemm};
+pub use self::operations::{Conjugate, ConjTranspose};
 pub use self::operations::{PartialOrdering, PartialLess, PartialEqual, PartialGreater, NotComparable};
+pub use self::operations::{CovNormalization, PopulationCov, SampleCov};
 
 pub mod geometry;
 pub mod structure;
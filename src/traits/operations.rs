@@ -1,5 +1,6 @@
 //! Low level operations on vectors and matrices.
 
+use std::mem;
 
 
 /// Result of a partial ordering.
@@ -152,9 +153,25 @@ pub trait ApproxEq<Eps> {
     /// Default epsilon for approximation.
     fn approx_epsilon(unused_self: Option<Self>) -> Eps;
 
+    /// Default number of units in the last place (ULPs) for approximation.
+    fn approx_ulps(unused_self: Option<Self>) -> u32;
+
     /// Tests approximate equality using a custom epsilon.
     fn approx_eq_eps(a: &Self, other: &Self, epsilon: &Eps) -> bool;
 
+    /// Tests approximate equality using both an absolute and a relative epsilon.
+    ///
+    /// Two floats `a` and `b` are equal if `|a - b|` is smaller than `abs_eps`, or smaller than
+    /// `rel_eps` times the greatest of `|a|` and `|b|`. The absolute epsilon handles values close
+    /// to zero, while the relative one handles values of large or small magnitudes.
+    fn approx_eq_eps_rel(a: &Self, other: &Self, abs_eps: &Eps, rel_eps: &Eps) -> bool;
+
+    /// Tests approximate equality using a custom number of units in the last place (ULPs).
+    ///
+    /// Two floats are equal within `ulps` ULPs if there are less than `ulps` other floats between
+    /// them. Unlike an epsilon, this tolerance scales with the magnitude of the compared values.
+    fn approx_eq_ulps(a: &Self, other: &Self, ulps: u32) -> bool;
+
     /// Tests approximate equality.
     #[inline]
     fn approx_eq(a: &Self, b: &Self) -> bool {
@@ -168,10 +185,41 @@ impl ApproxEq<f32> for f32 {
         1.0e-6
     }
 
+    #[inline]
+    fn approx_ulps(_: Option<f32>) -> u32 {
+        8
+    }
+
     #[inline]
     fn approx_eq_eps(a: &f32, b: &f32, epsilon: &f32) -> bool {
         (*a - *b).abs() < *epsilon
     }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &f32, b: &f32, abs_eps: &f32, rel_eps: &f32) -> bool {
+        let diff = (*a - *b).abs();
+
+        diff < *abs_eps || diff < *rel_eps * a.abs().max(b.abs())
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &f32, b: &f32, ulps: u32) -> bool {
+        // handles -0.0 == 0.0.
+        if *a == *b {
+            return true
+        }
+
+        // floats of different signs are never equal, even if they are within `ulps` ULPs.
+        if a.signum() != b.signum() {
+            return false
+        }
+
+        // the bits of floats of the same sign are ordered like the integers they represent.
+        let ia: i32 = unsafe { mem::transmute(*a) };
+        let ib: i32 = unsafe { mem::transmute(*b) };
+
+        (ia - ib).abs() as u32 <= ulps
+    }
 }
 
 impl ApproxEq<f64> for f64 {
@@ -180,10 +228,41 @@ impl ApproxEq<f64> for f64 {
         1.0e-6
     }
 
+    #[inline]
+    fn approx_ulps(_: Option<f64>) -> u32 {
+        8
+    }
+
     #[inline]
     fn approx_eq_eps(a: &f64, b: &f64, approx_epsilon: &f64) -> bool {
         (*a - *b).abs() < *approx_epsilon
     }
+
+    #[inline]
+    fn approx_eq_eps_rel(a: &f64, b: &f64, abs_eps: &f64, rel_eps: &f64) -> bool {
+        let diff = (*a - *b).abs();
+
+        diff < *abs_eps || diff < *rel_eps * a.abs().max(b.abs())
+    }
+
+    #[inline]
+    fn approx_eq_ulps(a: &f64, b: &f64, ulps: u32) -> bool {
+        // handles -0.0 == 0.0.
+        if *a == *b {
+            return true
+        }
+
+        // floats of different signs are never equal, even if they are within `ulps` ULPs.
+        if a.signum() != b.signum() {
+            return false
+        }
+
+        // the bits of floats of the same sign are ordered like the integers they represent.
+        let ia: i64 = unsafe { mem::transmute(*a) };
+        let ib: i64 = unsafe { mem::transmute(*b) };
+
+        (ia - ib).abs() as u64 <= ulps as u64
+    }
 }
 
 /// Trait of objects having an absolute value.
@@ -218,6 +297,26 @@ pub trait Transpose {
     fn transpose(&mut self);
 }
 
+/// Trait of scalars having a complex conjugate.
+///
+/// The conjugate of a real scalar is the scalar itself.
+pub trait Conjugate {
+    /// Computes the complex conjugate of `self`.
+    fn conjugate(&self) -> Self;
+}
+
+/// Trait of objects which can be conjugate-transposed, i.e., transposed and conjugated
+/// componentwise.
+///
+/// For matrices of real scalars, this is the same as `Transpose`.
+pub trait ConjTranspose {
+    /// Computes the conjugate transpose of a matrix.
+    fn conj_transpose_cpy(m: &Self) -> Self;
+
+    /// In-place version of `conj_transpose_cpy`.
+    fn conj_transpose(&mut self);
+}
+
 /// Traits of objects having an outer product.
 pub trait Outer<M> {
     /// Computes the outer product: `a * b`
@@ -250,6 +349,37 @@ pub trait Mean<N> {
     fn mean(v: &Self) -> N;
 }
 
+/// Normalization of a covariance.
+#[deriving(Eq, PartialEq, Encodable, Decodable, Clone, Show)]
+pub enum CovNormalization {
+    /// The observations are the whole population: the sum of the weights is the normalizer.
+    PopulationCov,
+    /// The observations are a sample of the population: the unbiased normalizer is used. With
+    /// unit weights, this is the number of observations minus one.
+    SampleCov
+}
+
+/// Trait for computing the weighted mean of a set of data.
+pub trait WeightedMean<N, W> {
+    /// Computes the mean of the observations stored by `v`, the `i`-th observation having the
+    /// weight `weights[i]`.
+    ///
+    ///   * For matrices, observations are stored in its rows.
+    fn weighted_mean(v: &Self, weights: &W) -> N;
+}
+
+/// Trait for computing the weighted covariance of a set of data.
+pub trait WeightedCov<M, W> {
+    /// Computes the covariance of the observations stored by `m`, the `i`-th observation having
+    /// the weight `weights[i]`.
+    ///
+    /// With `SampleCov`, the weights are assumed to be reliability weights, i.e., the normalizer
+    /// is `V1 - V2 / V1` where `V1` is the sum of the weights and `V2` the sum of their squares.
+    ///
+    ///   * For matrices, observations are stored in its rows.
+    fn weighted_cov(m: &Self, weights: &W, normalization: CovNormalization) -> M;
+}
+
 
 // /// Cholesky decomposition.
 // pub trait Chol {
@@ -366,3 +496,40 @@ pub trait Axpy<N> {
     /// Adds $$a * x$$ to `self`.
     fn axpy(&mut self, a: &N, x: &Self);
 }
+
+/// Trait of objects that can be added to another one in-place, without any allocation.
+pub trait AddInPlace<R> {
+    /// Sets `self` to `self + rhs`.
+    fn add_inplace(&mut self, rhs: &R);
+}
+
+/// Trait of objects that can be subtracted from another one in-place, without any allocation.
+pub trait SubInPlace<R> {
+    /// Sets `self` to `self - rhs`.
+    fn sub_inplace(&mut self, rhs: &R);
+}
+
+/// Trait of objects that can be multiplied by a scalar in-place, without any allocation.
+pub trait ScaleInPlace<N> {
+    /// Sets `self` to `self * s`.
+    fn scale_inplace(&mut self, s: &N);
+}
+
+/// Trait of objects that can be multiplied by another one in-place.
+pub trait MulInPlace<R> {
+    /// Sets `self` to `self * rhs`.
+    fn mul_inplace(&mut self, rhs: &R);
+}
+
+/// Trait of vectors implementing the `y = alpha * a * x + beta * y` operation, where `a` is a
+/// matrix.
+pub trait Gemv<N, M> {
+    /// Sets `self` to `alpha * a * x + beta * self`.
+    fn gemv(&mut self, alpha: &N, a: &M, x: &Self, beta: &N);
+}
+
+/// Trait of matrices implementing the `c = alpha * a * b + beta * c` operation.
+pub trait Gemm<N> {
+    /// Sets `self` to `alpha * a * b + beta * self`.
+    fn gemm(&mut self, alpha: &N, a: &Self, b: &Self, beta: &N);
+}
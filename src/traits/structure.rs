@@ -2,6 +2,7 @@
 
 use std::num::{Zero, Bounded};
 use std::slice::{Items, MutItems};
+use std::io::IoResult;
 use traits::operations::{RMul, LMul, ScalarAdd, ScalarSub, Axpy};
 use traits::geometry::{Dot, Norm, UniformSphereSample, Orig};
 
@@ -9,6 +10,11 @@ use traits::geometry::{Dot, Norm, UniformSphereSample, Orig};
 pub trait Cast<T> {
     /// Converts an element of type `T` to an element of type `Self`.
     fn from(t: T) -> Self;
+
+    /// Converts an element of type `T` to an element of type `Self` without loss of information.
+    ///
+    /// Returns `None` if any converted component overflows, changes sign, or loses precision.
+    fn try_from(t: T) -> Option<Self>;
 }
 
 /// Trait of matrices.
@@ -84,6 +90,20 @@ pub trait Dim {
     fn dim(unused_self: Option<Self>) -> uint;
 }
 
+/// Trait of type-level dimensions, e.g., `D3`.
+pub trait TypeDim {
+    /// The dimension represented by this type.
+    fn value(unused_self: Option<Self>) -> uint;
+}
+
+/// Trait of objects having a dimension given by the type `D`.
+///
+/// This allows generic code to check at compile-time that objects have the same dimension, e.g.,
+/// `fn f<D: TypeDim, V: StaticDim<D>, M: StaticDim<D>>(v: &V, m: &M)` accepts a `Vec3` with a
+/// `Mat3` but not with a `Mat4`.
+pub trait StaticDim<D: TypeDim>: Dim {
+}
+
 /// Trait to get the diagonal of square matrices.
 pub trait Diag<V> {
     /// Creates a new matrix with the given diagonal.
@@ -96,6 +116,21 @@ pub trait Diag<V> {
     fn diag(&self) -> V;
 }
 
+/// Trait of matrices with accessible off-diagonals and triangular parts.
+///
+/// The diagonal `k` is the main diagonal if `k == 0`, above it if `k > 0`, and below it if
+/// `k < 0`.
+pub trait Band<V> {
+    /// The `k`-th diagonal of this matrix. It is empty if it lies outside of the matrix.
+    fn diag_k(&self, k: int) -> V;
+
+    /// Copy of this matrix with every component below its `k`-th diagonal set to zero.
+    fn triu(&self, k: int) -> Self;
+
+    /// Copy of this matrix with every component above its `k`-th diagonal set to zero.
+    fn tril(&self, k: int) -> Self;
+}
+
 // FIXME: this trait should not be on nalgebra.
 // however, it is needed because std::ops::Index is (strangely) to poor: it
 // does not have a function to set values.
@@ -142,6 +177,19 @@ pub trait IterableMut<N> {
     fn iter_mut<'l>(&'l mut self) -> MutItems<'l, N>;
 }
 
+/// Trait of scalars that can be written to and read from a binary stream with an explicit
+/// endianness.
+pub trait Bytes {
+    /// Writes `self` to `w` in little-endian byte order.
+    fn write_le<W: Writer>(&self, w: &mut W) -> IoResult<()>;
+    /// Writes `self` to `w` in big-endian byte order.
+    fn write_be<W: Writer>(&self, w: &mut W) -> IoResult<()>;
+    /// Reads a scalar from `r` in little-endian byte order.
+    fn read_le<R: Reader>(r: &mut R) -> IoResult<Self>;
+    /// Reads a scalar from `r` in big-endian byte order.
+    fn read_be<R: Reader>(r: &mut R) -> IoResult<Self>;
+}
+
 /*
  * Vec related traits.
  */
@@ -1,12 +1,21 @@
 #![feature(macro_rules)]
 
 extern crate "nalgebra" as na;
+#[cfg(feature = "arbitrary")]
+extern crate quickcheck;
+extern crate serialize;
 
-use std::num::{Float, abs};
-use std::rand::random;
+use std::num::{Float, Zero, abs};
+use std::rand::{random, task_rng};
+use std::rand::distributions::IndependentSample;
 use std::cmp::{min, max};
-use na::{Vec1, Vec3, Mat1, Mat2, Mat3, Mat4, Mat5, Mat6, Rot3, Persp3, PerspMat3, Ortho3, OrthoMat3,
-         DMat, DVec, Indexable, Row, Col};
+use std::io::{MemWriter, BufReader};
+use serialize::json;
+use na::{Vec1, Vec2, Vec3, Vec4, Pnt2, Pnt3, Pnt4, Sim3, Iso3, Scale3, Reflection, Proj2, Proj3, Mat1, Rot2, Mat2, Mat3, Mat4, Mat5, Mat6, Rot3, Persp3, PerspMat3, Ortho3, OrthoMat3,
+         DMat, DVec, Indexable, Row, Col, Axpy, PntVecTransform, Translation,
+         Vec6, Mat6, So3, Se3, Gemm, Gemv, IterableMut, TypeDim, StaticDim,
+         Mat2x3, Mat3x2, Mat3x4, ColSlice, RowSlice, ScaleInPlace, Band,
+         RunningStats, MultivariateNormal};
 
 macro_rules! test_inv_mat_impl(
   ($t: ty) => (
@@ -137,6 +146,27 @@ fn test_rotation2() {
     }
 }
 
+#[test]
+fn test_rotation2_angle_slerp() {
+    for _ in range(0u, 10000) {
+        let a1: f64 = (random::<f64>() - 0.5) * Float::pi();
+        let a2: f64 = (random::<f64>() - 0.5) * Float::pi();
+        let r1 = Rot2::from_angle(a1);
+        let r2 = Rot2::from_angle(a2);
+
+        assert!(na::approx_eq(&r1.angle(), &a1));
+        assert!(na::approx_eq(&r1.powf(0.5).angle(), &(a1 * 0.5)));
+        assert!(na::approx_eq(&r1.slerp(&r2, 0.0), &r1));
+        assert!(na::approx_eq(&r1.slerp(&r2, 1.0), &r2));
+        assert!(na::approx_eq(&r1.slerp(&r2, 0.5).angle(), &((a1 + a2) * 0.5)));
+    }
+
+    // shortest path across the [-pi, pi] boundary.
+    let _pi: f64 = Float::pi();
+    let r = Rot2::from_angle(_pi - 0.1).slerp(&Rot2::from_angle(-_pi + 0.1), 0.5);
+    assert!(na::approx_eq(&r.angle().abs(), &_pi));
+}
+
 #[test]
 fn test_index_mat2() {
   let mat: Mat2<f64> = random();
@@ -266,6 +296,37 @@ fn test_qr() {
     }
 }
 
+#[test]
+fn test_qr_explicit_type_params() {
+    // the type parameters of `qr` and `eigen_qr` are unchanged, including the column type.
+    let m = Mat3::new(4.0f64, 1.0, 0.0,
+                      1.0,    3.0, 1.0,
+                      0.0,    1.0, 2.0);
+
+    let (q, r) = na::qr::<f64, na::DVec3<f64>, Mat3<f64>>(&m);
+    assert!(na::approx_eq(&(q * r), &m));
+
+    let (_, eigenvalues) = na::eigen_qr::<f64, na::DVec3<f64>, Vec3<f64>, Mat3<f64>>(&m, &1.0e-12, 1000);
+    assert!(na::approx_eq_eps(&(eigenvalues.x + eigenvalues.y + eigenvalues.z), &9.0, &1.0e-6));
+}
+
+#[test]
+fn test_qr_with_workspace() {
+    let mut workspace = na::DecompositionWorkspace::new();
+
+    for _ in range(0u, 10) {
+        let dim1: uint = random();
+        let dim2: uint = random();
+        let rows = min(40, max(dim1, dim2));
+        let cols = min(40, min(dim1, dim2));
+        let randmat: DMat<f64> = DMat::new_random(rows, cols);
+        let (q, r) = na::qr_with_workspace(&randmat, &mut workspace);
+        let recomp = q * r;
+
+        assert!(na::approx_eq(&randmat,  &recomp));
+    }
+}
+
 #[test]
 fn test_qr_mat1() {
     test_qr_impl!(Mat1<f64>);
@@ -429,3 +490,1718 @@ fn test_ortho() {
     assert!(na::approx_eq(&pm.znear(),  &24.0));
     assert!(na::approx_eq(&pm.zfar(),   &61.0));
 }
+
+#[test]
+fn test_strided_dmat() {
+    let mut mat = DMat::from_row_vec(
+        3,
+        2,
+        [
+            1.0f64, 2.0,
+            3.0,    4.0,
+            5.0,    6.0
+        ]
+    );
+
+    let row: Vec<f64> = mat.row_strided(1).iter().map(|e| *e).collect();
+    let col: Vec<f64> = mat.col_strided(1).iter().map(|e| *e).collect();
+    assert!(row == vec!(3.0, 4.0));
+    assert!(col == vec!(2.0, 4.0, 6.0));
+
+    assert!(na::dot(&mat.col_strided(0), &mat.col_strided(1)) == 44.0);
+
+    let mut v = DVec::from_slice(2, [1.0f64, 2.0]);
+
+    {
+        let mut r0 = mat.row_strided_mut(0);
+        r0.axpy(&2.0, &v.strided_mut(0, 1, 2));
+    }
+
+    assert!(mat.at((0, 0)) == 3.0);
+    assert!(mat.at((0, 1)) == 6.0);
+    assert!(mat.at((1, 0)) == 3.0);
+}
+
+#[test]
+fn test_slice_ref_dmat() {
+    let mut mat = DMat::from_row_vec(
+        3,
+        3,
+        [
+            1.0f64, 2.0, 3.0,
+            4.0,    5.0, 6.0,
+            7.0,    8.0, 9.0
+        ]
+    );
+
+    let col: Vec<f64> = mat.col_slice_ref(1, 1, 3).iter().map(|e| *e).collect();
+    let row: Vec<f64> = mat.row_slice_ref(2, 0, 2).iter().map(|e| *e).collect();
+    assert!(col == mat.col_slice(1, 1, 3).as_slice().to_vec());
+    assert!(row == mat.row_slice(2, 0, 2).as_slice().to_vec());
+    assert!(mat.row_slice_ref(0, 1, 3).sqnorm() == 13.0);
+
+    {
+        let mut c0 = mat.col_strided_mut(0);
+        c0.set(2, 2.0);
+        c0.swap(0, 2);
+        assert!(c0.normalize() == 21.0f64.sqrt());
+    }
+
+    assert!(na::approx_eq(&mat.col_slice_ref(0, 0, 3).norm(), &1.0));
+}
+
+#[test]
+fn test_row_col_strided_mut_scale_dmat() {
+    let mut mat = DMat::from_row_vec(
+        2,
+        3,
+        [
+            1.0f64, 2.0, 3.0,
+            4.0,    5.0, 6.0
+        ]
+    );
+
+    mat.row_mut(1).scale_inplace(&2.0);
+    assert!(mat == DMat::from_row_vec(2, 3, &[1.0, 2.0, 3.0, 8.0, 10.0, 12.0]));
+
+    let mut v = DVec::from_slice(2, [1.0f64, -1.0]);
+    mat.col_mut(2).axpy(&3.0, &v.strided_mut(0, 1, 2));
+    mat.col_mut(0).set(0, 0.0);
+    assert!(mat == DMat::from_row_vec(2, 3, &[0.0, 2.0, 6.0, 8.0, 10.0, 9.0]));
+
+    // the strided names are aliases.
+    mat.row_strided_mut(0).scale_inplace(&-1.0);
+    mat.col_strided_mut(1).set(1, 1.0);
+    assert!(mat == DMat::from_row_vec(2, 3, &[0.0, -2.0, -6.0, 8.0, 1.0, 9.0]));
+}
+
+#[test]
+fn test_sim3() {
+    for _ in range(0u, 10000) {
+        let s1: Sim3<f64> = random();
+        let s2: Sim3<f64> = random();
+        let p:  Pnt3<f64> = random();
+        let v:  Vec3<f64> = random();
+
+        let expected = s1.rotation * (s2.rotation * v * s2.scale) * s1.scale;
+        assert!(na::approx_eq(&((s1 * s2) * v), &expected));
+        assert!(na::approx_eq(&((s1 * s2) * p), &(s1 * (s2 * p))));
+
+        assert!(na::approx_eq(&(na::inv(&s1).unwrap() * s1), &na::one()));
+        assert!(na::approx_eq(&na::inv_transform(&s1, &(s1 * p)), &p));
+        assert!(na::approx_eq(&na::inv_transform(&s1, &(s1 * v)), &v));
+
+        let h = na::to_homogeneous(&s1);
+        assert!(na::approx_eq(&na::from_homogeneous::<Pnt4<f64>, Pnt3<f64>>(&(h * na::to_homogeneous(&p))), &(s1 * p)));
+    }
+}
+
+#[test]
+fn test_proj() {
+    for _ in range(0u, 10000) {
+        // adding the identity keeps the last homogeneous coordinate away from zero.
+        let m: Mat3<f64> = random::<Mat3<f64>>() + na::one::<Mat3<f64>>();
+        let p: Pnt2<f64> = random();
+        let h = Proj2::new(m);
+
+        assert!(na::approx_eq(&((h * h) * p), &(h * (h * p))));
+
+        match na::inv(&h) {
+            Some(inv) => assert!(na::approx_eq(&(inv * (h * p)), &p)),
+            None      => { }
+        }
+    }
+
+    let persp = PerspMat3::new(800.0f64 / 600.0, 3.14 / 2.0, 1.0, 1000.0);
+    let proj  = Proj3::new(persp.to_mat());
+    let p     = Pnt3::new(1.0f64, 2.0, -3.0);
+
+    assert!(na::approx_eq(&(proj * p), &persp.project_pnt(&p)));
+    assert!(na::approx_eq(&na::inv_transform(&proj, &(proj * p)), &p));
+}
+
+#[test]
+fn test_persp_unproject() {
+    let p  = Persp3::new(800.0f64 / 600.0, 3.14 / 2.0, 1.0, 1000.0);
+    let pm = p.to_persp_mat();
+
+    for _ in range(0u, 10000) {
+        let mut pt: Pnt3<f64> = random();
+        pt.z = pt.z * 500.0 + 1.0;
+
+        assert!(na::approx_eq_eps(&pm.unproject_pnt(&pm.project_pnt(&pt)), &pt, &1.0e-5));
+        assert!(na::approx_eq_eps(&p.unproject_pnt(&p.project_pnt(&pt)), &pt, &1.0e-5));
+    }
+}
+
+#[test]
+fn test_proj_bounds() {
+    let o = Ortho3::new(800.0f64, 600.0, 1.0, 1000.0);
+
+    assert!(na::approx_eq(&o.left(), &-400.0));
+    assert!(na::approx_eq(&o.right(), &400.0));
+    assert!(na::approx_eq(&o.bottom(), &-300.0));
+    assert!(na::approx_eq(&o.top(), &300.0));
+
+    // with a 90 degrees field of view, the top offset is equal to the near plane offset.
+    let _pi: f64 = Float::pi();
+    let p  = Persp3::new(2.0f64, _pi / 2.0, 10.0, 1000.0);
+    let pm = PerspMat3::new(2.0f64, _pi / 2.0, 10.0, 1000.0);
+
+    assert!(na::approx_eq(&p.left(), &-20.0));
+    assert!(na::approx_eq(&p.right(), &20.0));
+    assert!(na::approx_eq(&p.bottom(), &-10.0));
+    assert!(na::approx_eq(&p.top(), &10.0));
+
+    assert!(na::approx_eq_eps(&pm.left(), &-20.0, &1.0e-7));
+    assert!(na::approx_eq_eps(&pm.right(), &20.0, &1.0e-7));
+    assert!(na::approx_eq_eps(&pm.bottom(), &-10.0, &1.0e-7));
+    assert!(na::approx_eq_eps(&pm.top(), &10.0, &1.0e-7));
+}
+
+#[test]
+fn test_ortho_bounds_unproject() {
+    let o  = Ortho3::new(800.0f64, 600.0, 1.0, 1000.0);
+    let om = OrthoMat3::new_with_bounds(-100.0f64, 300.0, 50.0, 250.0, 1.0, 1000.0);
+
+    assert!(na::approx_eq(&om.left(), &-100.0));
+    assert!(na::approx_eq(&om.right(), &300.0));
+    assert!(na::approx_eq(&om.bottom(), &50.0));
+    assert!(na::approx_eq(&om.top(), &250.0));
+    assert!(na::approx_eq(&om.width(), &400.0));
+    assert!(na::approx_eq(&om.height(), &200.0));
+
+    // the corners of the view cuboid are mapped to the corners of the unit cube.
+    assert!(na::approx_eq(&om.project_pnt(&Pnt3::new(-100.0, 50.0, -1.0)), &Pnt3::new(-1.0, -1.0, -1.0)));
+    assert!(na::approx_eq(&om.project_pnt(&Pnt3::new(300.0, 250.0, -1000.0)), &Pnt3::new(1.0, 1.0, 1.0)));
+
+    for _ in range(0u, 10000) {
+        let pt: Pnt3<f64> = random();
+
+        assert!(na::approx_eq_eps(&om.unproject_pnt(&om.project_pnt(&pt)), &pt, &1.0e-5));
+        assert!(na::approx_eq_eps(&o.unproject_pnt(&o.project_pnt(&pt)), &pt, &1.0e-5));
+    }
+}
+
+#[test]
+fn test_look_at_rh_lh() {
+    let eye    = Pnt3::new(1.0f64, 2.0, 3.0);
+    let target = Pnt3::new(1.0f64, 2.0, 0.0);
+    let up     = Vec3::new(0.0f64, 1.0, 0.0);
+
+    let rh = Iso3::look_at_rh(&eye, &target, &up);
+    let lh = Iso3::look_at_lh(&eye, &target, &up);
+
+    assert!(na::approx_eq(&(rh * eye), &na::orig()));
+    assert!(na::approx_eq(&(rh * target), &Pnt3::new(0.0, 0.0, -3.0)));
+    assert!(na::approx_eq(&(rh * (eye + up)), &Pnt3::new(0.0, 1.0, 0.0)));
+    assert!(na::approx_eq(&(rh * Pnt3::new(2.0, 2.0, 3.0)), &Pnt3::new(1.0, 0.0, 0.0)));
+
+    assert!(na::approx_eq(&(lh * eye), &na::orig()));
+    assert!(na::approx_eq(&(lh * target), &Pnt3::new(0.0, 0.0, 3.0)));
+    assert!(na::approx_eq(&(lh * (eye + up)), &Pnt3::new(0.0, 1.0, 0.0)));
+    assert!(na::approx_eq(&(lh * Pnt3::new(2.0, 2.0, 3.0)), &Pnt3::new(-1.0, 0.0, 0.0)));
+
+    let m = Mat4::look_at_rh(&eye, &target, &up);
+    assert!(na::approx_eq(&m, &na::to_homogeneous(&rh)));
+}
+
+#[test]
+fn test_decompose_trs() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let s: Vec3<f64>   = random::<Vec3<f64>>() + 0.5f64;
+        let s = Vec3::new(-s.x, s.y, s.z);
+
+        let scaling = Mat4::new(
+            s.x, 0.0, 0.0, 0.0,
+            0.0, s.y, 0.0, 0.0,
+            0.0, 0.0, s.z, 0.0,
+            0.0, 0.0, 0.0, 1.0);
+        let m = na::to_homogeneous(&iso) * scaling;
+
+        let (t, r, s2) = na::decompose_trs(&m).unwrap();
+
+        assert!(na::approx_eq(&t, &iso.translation));
+        assert!(na::approx_eq(&r, &iso.rotation));
+        assert!(na::approx_eq(&s2, &s));
+    }
+
+    let shear = Mat4::new(
+        1.0f64, 0.5, 0.0, 0.0,
+        0.0,    1.0, 0.0, 0.0,
+        0.0,    0.0, 1.0, 0.0,
+        0.0,    0.0, 0.0, 1.0);
+    assert!(na::decompose_trs(&shear).is_none());
+
+    let degenerate = Mat4::new(
+        1.0f64, 0.0, 0.0, 0.0,
+        0.0,    0.0, 0.0, 0.0,
+        0.0,    0.0, 1.0, 0.0,
+        0.0,    0.0, 0.0, 1.0);
+    assert!(na::decompose_trs(&degenerate).is_none());
+
+    let persp = PerspMat3::new(800.0f64 / 600.0, 3.14 / 2.0, 1.0, 1000.0);
+    assert!(na::decompose_trs(persp.as_mat()).is_none());
+
+    // small-scale transformations are not degenerate.
+    let iso: Iso3<f64> = random();
+    let tiny = Mat4::new(
+        1.0e-7f64, 0.0,    0.0,    0.0,
+        0.0,       1.0e-7, 0.0,    0.0,
+        0.0,       0.0,    1.0e-7, 0.0,
+        0.0,       0.0,    0.0,    1.0);
+    let (_, r, s) = na::decompose_trs(&(na::to_homogeneous(&iso) * tiny)).unwrap();
+    assert!(na::approx_eq(&r, &iso.rotation));
+    assert!(na::approx_eq_eps_rel(&s.x, &1.0e-7, &0.0, &1.0e-6));
+    assert!(na::approx_eq_eps_rel(&s.y, &1.0e-7, &0.0, &1.0e-6));
+    assert!(na::approx_eq_eps_rel(&s.z, &1.0e-7, &0.0, &1.0e-6));
+}
+
+#[test]
+fn test_shear() {
+    let sh2 = Mat3::new_shear(2.0f64, 3.0);
+    assert!(na::approx_eq(&(sh2 * Vec3::new(1.0, 1.0, 1.0)), &Vec3::new(3.0, 4.0, 1.0)));
+
+    let sh3 = Mat4::new_shear(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert!(na::approx_eq(&(sh3 * Pnt4::new(1.0, 1.0, 1.0, 1.0)), &Pnt4::new(4.0, 8.0, 12.0, 1.0)));
+
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let h: Vec3<f64>   = random::<Vec3<f64>>() - 0.5f64;
+        let s: Vec3<f64>   = random::<Vec3<f64>>() + 0.5f64;
+
+        let scaling = Mat4::new(
+            s.x, 0.0, 0.0, 0.0,
+            0.0, s.y, 0.0, 0.0,
+            0.0, 0.0, s.z, 0.0,
+            0.0, 0.0, 0.0, 1.0);
+        let shear = Mat4::new_shear(h.x, h.y, 0.0, h.z, 0.0, 0.0);
+        let m     = na::to_homogeneous(&iso) * shear * scaling;
+
+        let (t, r, h2, s2) = na::decompose_trs_shear(&m).unwrap();
+
+        assert!(na::approx_eq(&t, &iso.translation));
+        assert!(na::approx_eq(&r, &iso.rotation));
+        assert!(na::approx_eq(&h2, &h));
+        assert!(na::approx_eq(&s2, &s));
+    }
+}
+
+#[test]
+fn test_scale3() {
+    for _ in range(0u, 10000) {
+        let s: Scale3<f64> = random();
+        let r: Rot3<f64>   = random();
+        let i: Iso3<f64>   = random();
+        let p: Pnt3<f64>   = random();
+        let v: Vec3<f64>   = random();
+
+        assert!(na::approx_eq(&(s * v), &Vec3::new(s.scale.x * v.x, s.scale.y * v.y, s.scale.z * v.z)));
+        assert!(na::approx_eq(&na::inv_transform(&s, &(s * p)), &p));
+        assert!(na::approx_eq(&(na::inv(&s).unwrap() * s), &na::one()));
+
+        assert!(na::approx_eq(&((r * s) * v), &(r * (s * v))));
+        assert!(na::approx_eq(&((s * r) * v), &(s * (r * v))));
+
+        let is = i * s;
+        assert!(na::approx_eq(&na::from_homogeneous::<Pnt4<f64>, Pnt3<f64>>(&(is * na::to_homogeneous(&p))), &(i * (s * p))));
+    }
+
+    assert!(na::inv(&Scale3::new(Vec3::new(1.0f64, 0.0, 2.0))).is_none());
+}
+
+#[test]
+fn test_reflection() {
+    for _ in range(0u, 10000) {
+        let n: Vec3<f64> = random::<Vec3<f64>>() + 0.1f64;
+        let b: f64       = random();
+        let r            = Reflection::new(n, b);
+        let p: Pnt3<f64> = random();
+        let v: Vec3<f64> = random();
+
+        // the reflected point is on the other side of the hyperplane, at the same distance.
+        let rp = na::transform(&r, &p);
+        assert!(na::approx_eq(&(na::dot(r.normal(), rp.as_vec()) - *r.bias()),
+                              &-(na::dot(r.normal(), p.as_vec()) - *r.bias())));
+        assert!(na::approx_eq(&na::transform(&r, &rp), &p));
+        assert!(na::approx_eq(&na::norm(&na::transform(&r, &v)), &na::norm(&v)));
+
+        let h = na::to_homogeneous(&r);
+        assert!(na::approx_eq(&na::from_homogeneous::<Pnt4<f64>, Pnt3<f64>>(&(h * na::to_homogeneous(&p))), &rp));
+        assert!(na::approx_eq(&(r.to_mat() * v), &na::transform(&r, &v)));
+    }
+}
+
+// Checks that `t` transforms points and vectors like its homogeneous matrix `h`.
+fn check_pnt_vec_transform<T: PntVecTransform<Pnt3<f64>, Vec3<f64>>>(t: &T, h: &Mat4<f64>) {
+    let p: Pnt3<f64> = random();
+    let v: Vec3<f64> = random();
+
+    let hp = na::from_homogeneous::<Pnt4<f64>, Pnt3<f64>>(&(*h * na::to_homogeneous(&p)));
+    let hv: Vec4<f64> = na::to_homogeneous(&v);
+    let hv             = *h * hv;
+
+    assert!(na::approx_eq(&t.transform_pnt(&p), &hp));
+    assert!(na::approx_eq(&t.transform_vec(&v), &Vec3::new(hv.x, hv.y, hv.z)));
+    assert!(na::approx_eq(&t.inv_transform_pnt(&t.transform_pnt(&p)), &p));
+    assert!(na::approx_eq(&t.inv_transform_vec(&t.transform_vec(&v)), &v));
+}
+
+#[test]
+fn test_pnt_vec_transform() {
+    for _ in range(0u, 1000) {
+        let r: Rot3<f64>  = random();
+        let i: Iso3<f64>  = random();
+        let s: Sim3<f64>  = random();
+        let m: Mat4<f64>  = na::to_homogeneous(&s);
+        let p: Proj3<f64> = Proj3::new(na::to_homogeneous(&i));
+
+        check_pnt_vec_transform(&r, &na::to_homogeneous(&r));
+        check_pnt_vec_transform(&i, &na::to_homogeneous(&i));
+        check_pnt_vec_transform(&s, &m);
+        check_pnt_vec_transform(&m, &m);
+        check_pnt_vec_transform(&p, &na::to_homogeneous(&i));
+    }
+}
+
+#[test]
+fn test_rot3_renormalize() {
+    for _ in range(0u, 1000) {
+        let r: Rot3<f64> = random();
+        let mut drift    = r.clone();
+
+        // accumulate some numerical drift.
+        for _ in range(0u, 1000) {
+            let perturbation: Mat3<f64> = (random::<Mat3<f64>>() - random::<Mat3<f64>>()) * 1.0e-5;
+            drift = unsafe { Rot3::new_with_mat(*drift.submat() + perturbation) };
+        }
+
+        assert!(!drift.is_special_orthogonal(&1.0e-7));
+
+        drift.renormalize();
+
+        assert!(drift.is_special_orthogonal(&1.0e-7));
+        assert!(na::approx_eq_eps(&drift, &r, &1.0e-2));
+        assert!(r.is_special_orthogonal(&1.0e-7));
+    }
+}
+
+#[test]
+fn test_homogeneous_combinators() {
+    for _ in range(0u, 10000) {
+        let m: Mat4<f64>  = random();
+        let t: Vec3<f64>  = random();
+        let s: Vec3<f64>  = random();
+        let c: Pnt3<f64>  = random();
+        let r: Rot3<f64>  = random();
+        let iso: Iso3<f64> = random();
+        let u             = random::<f64>() + 1.0;
+
+        let tm: Mat4<f64> = na::to_homogeneous(&Iso3::new(t, na::zero()));
+        let sm: Mat4<f64> = na::to_homogeneous(&Scale3::new(s));
+        let um: Mat4<f64> = na::to_homogeneous(&Scale3::new(Vec3::new(u, u, u)));
+        let rm: Mat4<f64> = na::to_homogeneous(&r);
+        let cm: Mat4<f64> = na::to_homogeneous(&Iso3::new(*c.as_vec(), na::zero()));
+        let cinv: Mat4<f64> = na::to_homogeneous(&Iso3::new(-*c.as_vec(), na::zero()));
+
+        assert!(na::approx_eq(&na::append_translation(&m, &t), &(tm * m)));
+        assert!(na::approx_eq(&na::translation(&na::append_translation(&na::one::<Mat4<f64>>(), &t)), &t));
+        assert!(na::approx_eq(&Mat4::append_scaling_cpy(&m, &u), &(um * m)));
+        assert!(na::approx_eq(&Mat4::prepend_scaling_cpy(&m, &u), &(m * um)));
+        assert!(na::approx_eq(&Mat4::append_nonuniform_scaling_cpy(&m, &s), &(sm * m)));
+        assert!(na::approx_eq(&Mat4::prepend_nonuniform_scaling_cpy(&m, &s), &(m * sm)));
+        assert!(na::approx_eq(&Mat4::append_rotation_wrt_point_cpy(&m, &r, &c), &(cm * rm * cinv * m)));
+
+        let mut pm = m.clone();
+        pm.prepend_translation(&t);
+        assert!(na::approx_eq(&pm, &(m * tm)));
+
+        // rotations to isometries and isometries to similarities.
+        let axisangle = na::rotation(&r);
+        assert!(na::approx_eq(&r.append_translation_to_iso(&t), &Iso3::new(t, axisangle)));
+        assert!(na::approx_eq(&(r.prepend_translation_to_iso(&t) * c), &(r * (c + t))));
+
+        let pnt: Pnt3<f64> = random();
+        let wrt = r.append_rotation_wrt_point_to_iso(&axisangle, &c);
+        assert!(na::approx_eq(&(wrt * pnt), &(r * (r * pnt - *c.as_vec()) + *c.as_vec())));
+
+        assert!(na::approx_eq(&(iso.append_scaling_to_sim(&u) * pnt), &((iso * pnt) * u)));
+        assert!(na::approx_eq(&(iso.prepend_scaling_to_sim(&u) * pnt), &(iso * (pnt * u))));
+
+        let mut sim = iso.prepend_scaling_to_sim(&u);
+        sim.append_scaling(&u);
+        assert!(na::approx_eq(&(sim * pnt), &((iso * (pnt * u)) * u)));
+    }
+}
+
+#[test]
+fn test_lie_algebra_jacobians() {
+    for _ in range(0u, 1000) {
+        let w: Vec3<f64>  = random::<Vec3<f64>>() - 0.5f64;
+        let xi: Vec6<f64> = random::<Vec6<f64>>() - 0.5f64;
+        let d: Vec3<f64>  = (random::<Vec3<f64>>() - 0.5f64) * 1.0e-6;
+        let dx: Vec6<f64> = (random::<Vec6<f64>>() - 0.5f64) * 1.0e-6;
+
+        let so3 = So3::new(w);
+        let se3 = Se3::new(xi);
+
+        // hat and vee are inverse of each other.
+        assert!(So3::vee(&so3.hat()) == so3);
+        assert!(Se3::vee(&se3.hat()) == se3);
+
+        // first-order approximations.
+        let jl = so3.left_jacobian();
+        let jr = so3.right_jacobian();
+        assert!(na::approx_eq_eps(&Rot3::exp(&(w + d)), &(Rot3::exp(&(jl * d)) * so3.exp()), &1.0e-10));
+        assert!(na::approx_eq_eps(&Rot3::exp(&(w + d)), &(so3.exp() * Rot3::exp(&(jr * d))), &1.0e-10));
+        assert!(na::approx_eq(&(jl * so3.left_jacobian_inv()), &na::one()));
+        assert!(na::approx_eq(&(jr * so3.right_jacobian_inv()), &na::one()));
+
+        let jl = se3.left_jacobian();
+        let jr = se3.right_jacobian();
+        assert!(na::approx_eq_eps(&Iso3::exp(&(xi + dx)), &(Iso3::exp(&(jl * dx)) * se3.exp()), &1.0e-10));
+        assert!(na::approx_eq_eps(&Iso3::exp(&(xi + dx)), &(se3.exp() * Iso3::exp(&(jr * dx))), &1.0e-10));
+        assert!(na::approx_eq(&(jl * se3.left_jacobian_inv()), &na::one::<Mat6<f64>>()));
+        assert!(na::approx_eq(&(jr * se3.right_jacobian_inv()), &na::one::<Mat6<f64>>()));
+
+        // adjoints.
+        let r: Rot3<f64>   = random();
+        let iso: Iso3<f64> = random();
+        assert!(na::approx_eq(&(r * so3.exp() * na::inv(&r).unwrap()), &Rot3::exp(&(r.adjoint() * w))));
+        assert!(na::approx_eq(&(iso * se3.exp() * na::inv(&iso).unwrap()), &Iso3::exp(&(iso.adjoint() * xi))));
+    }
+
+    // small angles.
+    let small = Se3::new(Vec6::new(1.0f64, 2.0, 3.0, 1.0e-5, -2.0e-5, 1.0e-5));
+    let dx    = Vec6::new(1.0e-7f64, -1.0e-7, 2.0e-7, 1.0e-7, 1.0e-7, -1.0e-7);
+    assert!(na::approx_eq_eps(&Iso3::exp(&(small.vec + dx)),
+                              &(Iso3::exp(&(small.left_jacobian() * dx)) * small.exp()), &1.0e-12));
+}
+
+#[test]
+fn test_transform_all() {
+    let rot: Rot3<f64> = random();
+    let iso: Iso3<f64> = random();
+    let a: Mat4<f64>   = na::to_homogeneous(&iso);
+    let m: Mat4<f64>   = a + Mat4::new(0.0, 0.0, 0.0, 0.0,
+                                       0.0, 0.0, 0.0, 0.0,
+                                       0.0, 0.0, 0.0, 0.0,
+                                       0.1, 0.2, 0.3, 0.0);
+
+    // more than one chunk to exercise the chunking.
+    let pts: Vec<Pnt3<f64>> = range(0u, 1000).map(|_| random()).collect();
+    let vs:  Vec<Vec3<f64>> = range(0u, 1000).map(|_| random()).collect();
+
+    macro_rules! check(
+        ($t: expr, $elts: expr) => ({
+            let mut res = $elts.clone();
+
+            na::transform_all(&$t, res.as_mut_slice());
+            for (r, e) in res.iter().zip($elts.iter()) {
+                assert!(na::approx_eq(r, &na::transform(&$t, e)));
+            }
+
+            na::inv_transform_all(&$t, res.as_mut_slice());
+            for (r, e) in res.iter().zip($elts.iter()) {
+                assert!(na::approx_eq_eps(r, e, &1.0e-7));
+            }
+        })
+    )
+
+    check!(rot, pts);
+    check!(rot, vs);
+    check!(iso, pts);
+    check!(iso, vs);
+    check!(m, pts);
+    check!(a, vs);
+}
+
+#[test]
+fn test_best_fit_alignment() {
+    for _ in range(0u, 100) {
+        let a: Vec<Pnt3<f64>> = range(0u, 20).map(|_| random::<Pnt3<f64>>() * 10.0).collect();
+        let rot: Rot3<f64>    = random();
+        let iso: Iso3<f64>    = random();
+        let sim               = Sim3::new_with_iso(random(), 2.5f64);
+
+        let b: Vec<Pnt3<f64>> = a.iter().map(|p| rot * *p).collect();
+        assert!(na::approx_eq(&na::best_fit_rotation(a.as_slice(), b.as_slice()).unwrap(), &rot));
+
+        let b: Vec<Pnt3<f64>> = a.iter().map(|p| iso * *p).collect();
+        assert!(na::approx_eq(&na::best_fit_isometry(a.as_slice(), b.as_slice()).unwrap(), &iso));
+
+        let b: Vec<Pnt3<f64>> = a.iter().map(|p| na::transform(&sim, p)).collect();
+        let res               = na::best_fit_similarity(a.as_slice(), b.as_slice()).unwrap();
+        assert!(na::approx_eq(&res.to_iso(), &sim.to_iso()));
+        assert!(na::approx_eq(&res.scale, &sim.scale));
+    }
+
+    assert!(na::best_fit_isometry::<f64>(&[], &[]).is_none());
+}
+
+#[test]
+fn test_left_scalar_mul() {
+    let m: Mat3<f32> = random();
+    let n: Mat6<f64> = random();
+    let d: DMat<f64> = DMat::new_random(2, 5);
+
+    assert!(2.0f32 * m == m * 2.0f32);
+    assert!(0.5f64 * n == n * 0.5f64);
+    assert!(3.0f64 * d == d * 3.0f64);
+}
+
+#[test]
+fn test_encode_decode() {
+    // integer components so that the text representation is exact.
+    let a: Mat3<int> = random();
+    let p: Pnt3<int> = random();
+
+    assert!(json::decode::<Mat3<int>>(json::encode(&a).as_slice()).unwrap() == a);
+    assert!(json::decode::<Pnt3<int>>(json::encode(&p).as_slice()).unwrap() == p);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn test_encode_decode_dmat() {
+    let m: DMat<int> = DMat::new_random(3, 5);
+    let v: DVec<int> = DVec::new_random(7);
+
+    assert!(json::decode::<DMat<int>>(json::encode(&m).as_slice()).unwrap() == m);
+    assert!(json::decode::<DVec<int>>(json::encode(&v).as_slice()).unwrap() == v);
+
+    // the number of components does not match the dimensions.
+    assert!(json::decode::<DMat<int>>("{\"nrows\":2,\"ncols\":2,\"mij\":[1,2,3]}").is_err());
+    assert!(json::decode::<DMat<int>>("{\"nrows\":2,\"ncols\":2,\"mij\":[1,2,3,4,5]}").is_err());
+    assert!(json::decode::<DMat<int>>("{\"nrows\":2,\"ncols\":2,\"mij\":[1,2,3,4]}").is_ok());
+}
+
+#[test]
+fn test_matrix_market() {
+    let coordinate = "%%MatrixMarket matrix coordinate real symmetric\n\
+                      % a comment\n\
+                      3 3 4\n\
+                      1 1 1.5\n\
+                      2 1 2.0\n\
+                      3 2 -3.0\n\
+                      3 3 4.0\n";
+    let expected = DMat::from_row_vec(3, 3, &[1.5f64, 2.0,  0.0,
+                                              2.0,    0.0,  -3.0,
+                                              0.0,    -3.0, 4.0]);
+    let m: DMat<f64> = na::io::read_matrix_market(&mut BufReader::new(coordinate.as_bytes())).unwrap();
+    assert!(m == expected);
+
+    let skew = "%%MatrixMarket matrix array integer skew-symmetric\n2 2\n5\n";
+    let m: DMat<int> = na::io::read_matrix_market(&mut BufReader::new(skew.as_bytes())).unwrap();
+    assert!(m == DMat::from_row_vec(2, 2, &[0, -5, 5, 0]));
+
+    // round trips.
+    for symmetric in [false, true].iter() {
+        let mut w = MemWriter::new();
+        na::io::write_matrix_market(&mut w, &expected, *symmetric).unwrap();
+        let bytes = w.unwrap();
+        let m: DMat<f64> = na::io::read_matrix_market(&mut BufReader::new(bytes.as_slice())).unwrap();
+        assert!(m == expected);
+    }
+
+    let bad = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+    assert!(na::io::read_matrix_market::<f64, _>(&mut BufReader::new(bad.as_bytes())).is_err());
+}
+
+#[test]
+fn test_csv() {
+    let csv = "a; b; c\n\
+               1.0; 2.0; 3.0\n\
+               \n\
+               4.0; 5.0; 6.5\n";
+    let m: DMat<f64> = DMat::from_csv(&mut BufReader::new(csv.as_bytes()), ';', true).unwrap();
+    assert!(m == DMat::from_row_vec(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.5]));
+
+    let mut w = MemWriter::new();
+    m.to_csv(&mut w, ',', Some(&["a", "b", "c"])).unwrap();
+    let bytes = w.unwrap();
+    assert!(DMat::from_csv(&mut BufReader::new(bytes.as_slice()), ',', true).unwrap() == m);
+
+    let v: DVec<int> = DVec::from_csv(&mut BufReader::new("1\n2\n3\n".as_bytes()), ',', false).unwrap();
+    assert!(v.at == vec!(1, 2, 3));
+
+    let mut w = MemWriter::new();
+    v.to_csv(&mut w, None).unwrap();
+    assert!(w.unwrap().as_slice() == "1\n2\n3\n".as_bytes());
+
+    let ragged = "1, 2\n3\n";
+    assert!(DMat::<int>::from_csv(&mut BufReader::new(ragged.as_bytes()), ',', false).is_err());
+}
+
+#[test]
+fn test_npy() {
+    let m: DMat<f64> = DMat::new_random(3, 5);
+    let v: DVec<f32> = DVec::new_random(7);
+
+    let mut w = MemWriter::new();
+    m.to_npy(&mut w).unwrap();
+    let bytes = w.unwrap();
+    assert!(bytes.len() % 64 == 3 * 5 * 8 % 64);
+    assert!(DMat::from_npy(&mut BufReader::new(bytes.as_slice())).unwrap() == m);
+    assert!(DVec::<f64>::from_npy(&mut BufReader::new(bytes.as_slice())).is_err());
+
+    let mut w = MemWriter::new();
+    v.to_npy(&mut w).unwrap();
+    let bytes = w.unwrap();
+    assert!(DVec::from_npy(&mut BufReader::new(bytes.as_slice())).unwrap() == v);
+    assert!(DVec::<f64>::from_npy(&mut BufReader::new(bytes.as_slice())).is_err());
+
+    // C order and big-endian components, as written by `np.save` with `dtype='>f4'`.
+    let header = "{'descr': '>f4', 'fortran_order': False, 'shape': (2, 2), }";
+    let mut w  = MemWriter::new();
+    w.write(b"\x93NUMPY\x01\x00").unwrap();
+    w.write_le_u16(header.len() as u16).unwrap();
+    w.write_str(header).unwrap();
+
+    for e in [1.0f32, 2.0, 3.0, 4.0].iter() {
+        w.write_be_f32(*e).unwrap();
+    }
+
+    let bytes = w.unwrap();
+    let m: DMat<f32> = DMat::from_npy(&mut BufReader::new(bytes.as_slice())).unwrap();
+    assert!(m == DMat::from_row_vec(2, 2, &[1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn test_show() {
+    let v = Vec3::new(1i, 2, 3);
+    assert!(format!("{}", v).as_slice() == "[1, 2, 3]");
+    assert!(format!("{}", Pnt2::new(1.5f64, -2.25)).as_slice() == "[1.5, -2.25]");
+
+    let m = Mat2::new(1i,  -20,
+                      300, 4);
+    assert!(format!("{}", m).as_slice() == "[   1 -20 ]\n[ 300   4 ]");
+
+    let d = DMat::from_row_vec(2, 3, &[1i, 2, 3, 40, 50, 60]);
+    assert!(format!("{}", d).as_slice() == "[  1  2  3 ]\n[ 40 50 60 ]");
+
+    let v = DVec::from_slice(2, &[1i, 2]);
+    assert!(format!("{}", v).as_slice() == "[1, 2]");
+}
+
+#[test]
+fn test_show_precision_and_exp() {
+    let v = Vec2::new(1.0f64, 2.5);
+    assert!(format!("{:.2}", v).as_slice() == "[1.00, 2.50]");
+    assert!(format!("{:5}", Vec2::new(1i, 2)).as_slice() == "[    1,     2]");
+    assert!(format!("{:.1e}", Vec2::new(1500.0f64, 0.25)).as_slice() == "[1.5e3, 2.5e-1]");
+
+    let m = Mat2::new(1.0f64, 0.125,
+                      10.0,   2.0);
+    assert!(format!("{:.1}", m).as_slice() == "[  1.0 0.1 ]\n[ 10.0 2.0 ]");
+    assert!(format!("{:.0e}", m).as_slice() == "[ 1e0 1e-1 ]\n[ 1e1  2e0 ]");
+
+    let d = DMat::from_row_vec(1, 2, &[0.5f64, 3.0]);
+    assert!(format!("{:.3}", d).as_slice() == "[ 0.500 3.000 ]");
+}
+
+#[test]
+fn test_from_str() {
+    let v: Option<Vec3<f64>> = from_str("[1, 2.5, -3]");
+    assert!(v == Some(Vec3::new(1.0, 2.5, -3.0)));
+
+    let p: Pnt2<int> = from_str(" [ 1 ,2 ] ").unwrap();
+    assert!(p == Pnt2::new(1, 2));
+
+    let m: Mat2<int> = from_str("[[1, 2], [3, 4]]").unwrap();
+    assert!(m == Mat2::new(1, 2, 3, 4));
+
+    let d: DMat<int> = from_str("[[1, 2, 3], [4, 5, 6]]").unwrap();
+    assert!(d == DMat::from_row_vec(2, 3, &[1, 2, 3, 4, 5, 6]));
+
+    let d: DVec<int> = from_str("[]").unwrap();
+    assert!(d.at.is_empty());
+
+    // error messages.
+    assert!(Vec3::<int>::parse("[1, 2]") == Err("expected 3 components, found 2.".to_string()));
+    assert!(Vec3::<int>::parse("1, 2, 3]") == Err("expected `[` at the beginning of `1, 2, 3]`.".to_string()));
+    assert!(Vec2::<int>::parse("[1, a]") == Err("invalid component 1: `a`.".to_string()));
+    assert!(Mat2::<int>::parse("[[1, 2], [3]]") == Err("row 1 has 1 components instead of 2.".to_string()));
+    assert!(Mat2::<int>::parse("[[1, 2], [3, 4]").is_err());
+    assert!(DMat::<int>::parse("[[1, 2] [3, 4]]") == Err("expected `,` before row 1.".to_string()));
+    assert!(DMat::<int>::parse("[[1, 2], [3, 4],]").is_err());
+    assert!(DMat::<int>::parse("[[1, 2], 5, [3, 4]]").is_err());
+}
+
+#[test]
+fn test_array_conversions() {
+    let v = Vec4::new(1.0f32, 2.0, 3.0, 4.0);
+    assert!(v.to_array() == [1.0, 2.0, 3.0, 4.0]);
+    assert!(Vec4::from_array([1.0f32, 2.0, 3.0, 4.0]) == v);
+    assert!(*Vec4::from_array_ref(v.as_array()) == v);
+
+    // the inner arrays are the columns.
+    let m = Mat3::new(1i, 2, 3,
+                      4,  5, 6,
+                      7,  8, 9);
+    assert!(m.to_array() == [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+    assert!(Mat3::from_array([[1i, 4, 7], [2, 5, 8], [3, 6, 9]]) == m);
+    assert!(m.as_array()[1][2] == m.at((2, 1)));
+
+    let mut cols = [[0i, 0], [0, 0]];
+    Mat2::from_array_mut(&mut cols).m12 = 5;
+    assert!(cols == [[0, 0], [5, 0]]);
+
+    let mut p = Pnt3::new(1i, 2, 3);
+    p.as_array_mut()[2] = 10;
+    assert!(p == Pnt3::new(1, 2, 10));
+}
+
+#[test]
+fn test_col_major_access() {
+    let m = Mat4::new(1.0f32, 0.0, 0.0, 10.0,
+                      0.0,    1.0, 0.0, 20.0,
+                      0.0,    0.0, 1.0, 30.0,
+                      0.0,    0.0, 0.0, 1.0);
+
+    // the translation is stored in the last four components, as OpenGL expects.
+    assert!(m.as_col_major_array().slice(12, 16) == [10.0, 20.0, 30.0, 1.0].as_slice());
+    assert!(unsafe { *m.as_ptr().offset(13) } == 20.0);
+
+    let iso = Iso3::new(Vec3::new(10.0f32, 20.0, 30.0), na::zero());
+    assert!(iso.to_col_major_array() == *m.as_col_major_array());
+}
+
+#[test]
+fn test_large_dmat_mul() {
+    // large enough for the product to be dispatched to BLAS when the `blas` feature is enabled.
+    let a: DMat<f64> = DMat::from_fn(40, 50, |i, j| (i as f64) - (j as f64) * 0.5);
+    let b: DMat<f64> = DMat::from_fn(50, 30, |i, j| (i * j % 7) as f64);
+    let v: DVec<f64> = DVec::from_fn(50, |i| (i % 3) as f64);
+    let w: DVec<f64> = DVec::from_fn(40, |i| (i % 5) as f64);
+
+    let ab = a * b;
+    let av = a * v;
+    let wa = w * a;
+
+    for i in range(0u, 40) {
+        for j in range(0u, 30) {
+            let mut acc = 0.0;
+
+            for k in range(0u, 50) {
+                acc = acc + a.at((i, k)) * b.at((k, j));
+            }
+
+            assert!(na::approx_eq(&ab.at((i, j)), &acc));
+        }
+
+        let acc = range(0u, 50).fold(0.0, |acc, k| acc + a.at((i, k)) * v.at[k]);
+        assert!(na::approx_eq(&av.at[i], &acc));
+    }
+
+    for j in range(0u, 50) {
+        let acc = range(0u, 40).fold(0.0, |acc, k| acc + w.at[k] * a.at((k, j)));
+        assert!(na::approx_eq(&wa.at[j], &acc));
+    }
+
+    let mut y: DVec<f64> = DVec::from_fn(40000, |i| i as f64);
+    let x: DVec<f64>     = DVec::from_fn(40000, |i| (i % 10) as f64);
+    y.axpy(&2.0, &x);
+
+    for i in range(0u, 40000) {
+        assert!(y.at[i] == (i as f64) + 2.0 * ((i % 10) as f64));
+    }
+}
+
+#[test]
+fn test_eigen_qr_sym_dmat() {
+    let m: DMat<f64> = DMat::from_row_vec(3, 3, [
+        4.0, 1.0, 0.0,
+        1.0, 3.0, 1.0,
+        0.0, 1.0, 2.0
+    ]);
+    let (eigenvectors, eigenvalues) = na::eigen_qr(&m, &1.0e-12, 1000);
+
+    // the eigenvalues are sorted whether LAPACK is used or not.
+    assert!(eigenvalues.at[0] <= eigenvalues.at[1] && eigenvalues.at[1] <= eigenvalues.at[2]);
+
+    for j in range(0u, 3) {
+        let v: DVec<f64> = DVec::from_fn(3, |i| eigenvectors.at((i, j)));
+        let mv = m * v;
+
+        for i in range(0u, 3) {
+            assert!(abs(mv.at[i] - eigenvalues.at[j] * v.at[i]) < 1.0e-6);
+        }
+    }
+}
+
+#[test]
+fn test_mat4_mul() {
+    for _ in range(0u, 100) {
+        let a: Mat4<f64> = random();
+        let b: Mat4<f64> = random();
+        let v: Vec4<f64> = random();
+
+        let da = DMat::from_col_vec(4, 4, a.as_col_major_array().as_slice());
+        let db = DMat::from_col_vec(4, 4, b.as_col_major_array().as_slice());
+        let dv = DVec::from_slice(4, v.as_slice());
+
+        let ab = a * b;
+        let av = a * v;
+
+        assert!(na::approx_eq(&DMat::from_col_vec(4, 4, ab.as_col_major_array().as_slice()), &(da * db)));
+        assert!(na::approx_eq(&DVec::from_slice(4, av.as_slice()), &(da * dv)));
+    }
+}
+
+#[test]
+fn test_par_dmat_mul() {
+    let a: DMat<f64> = DMat::from_fn(150, 130, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+    let b: DMat<f64> = DMat::from_fn(130, 140, |i, j| ((i + j * 5) % 13) as f64 * 0.5);
+    let ab = a.par_mul(&b);
+
+    assert!(ab == a * b);
+
+    assert!(ab.nrows() == 150 && ab.ncols() == 140);
+
+    for i in range(0u, 150) {
+        for j in range(0u, 140) {
+            let acc = range(0u, 130).fold(0.0, |acc, k| acc + a.at((i, k)) * b.at((k, j)));
+
+            assert!(ab.at((i, j)) == acc);
+        }
+    }
+}
+
+#[test]
+fn test_mul_strassen() {
+    for n in [ 10u, 64, 65, 131 ].iter() {
+        let a: DMat<int> = DMat::from_fn(*n, *n, |i, j| ((i * 3 + j * 7) % 17) as int - 8);
+        let b: DMat<int> = DMat::from_fn(*n, *n, |i, j| ((i * 5 + j) % 11) as int - 5);
+
+        assert!(a.mul_strassen(&b) == a * b);
+    }
+}
+
+#[test]
+fn test_fused_ops() {
+    let a: DMat<int> = DMat::from_fn(3, 4, |i, j| (i * 4 + j) as int);
+    let b: DMat<int> = DMat::from_fn(4, 2, |i, j| (i + j) as int - 2);
+    let c: DMat<int> = DMat::from_fn(3, 2, |i, j| (i * j) as int);
+    let x: DVec<int> = DVec::from_fn(4, |i| i as int);
+    let y: DVec<int> = DVec::from_fn(3, |i| 10 - i as int);
+
+    let ab = a * b;
+    let expected: DMat<int> = DMat::from_fn(3, 2, |i, j| ab.at((i, j)) + c.at((i, j)));
+    assert!(a.mul_add(&b, &c) == expected);
+
+    let ax = a * x;
+    assert!(a.mul_vec_add(&x, &y) == DVec::from_fn(3, |i| ax.at[i] + y.at[i]));
+
+    assert!(DMat::add3(&c, &c, &c) == DMat::from_fn(3, 2, |i, j| 3 * c.at((i, j))));
+    assert!(DVec::add3(&y, &y, &y) == DVec::from_fn(3, |i| 3 * y.at[i]));
+    assert!(DVec::lin_comb(&2, &y, &-1, &y) == y);
+}
+
+#[test]
+fn test_gemm_gemv() {
+    let a: DMat<int> = DMat::from_fn(3, 4, |i, j| (i + 2 * j) as int);
+    let b: DMat<int> = DMat::from_fn(4, 2, |i, j| (i as int) - (j as int));
+    let c: DMat<int> = DMat::from_fn(3, 2, |i, j| (i * j) as int + 1);
+    let x: DVec<int> = DVec::from_fn(4, |i| i as int);
+    let y: DVec<int> = DVec::from_fn(3, |i| 10 - i as int);
+
+    let ab = a * b;
+    let mut res = c.clone();
+    res.gemm(&2, &a, &b, &3);
+    assert!(res == DMat::from_fn(3, 2, |i, j| 2 * ab.at((i, j)) + 3 * c.at((i, j))));
+
+    let ax = a * x;
+    let mut res = y.clone();
+    res.gemv(&-1, &a, &x, &2);
+    assert!(res == DVec::from_fn(3, |i| 2 * y.at[i] - ax.at[i]));
+
+    let m = Mat3::new(1i, 2, 3, 4, 5, 6, 7, 8, 9);
+    let v = Vec3::new(1i, -1, 2);
+    let mut mres = m;
+    mres.gemm(&1, &m, &m, &0);
+    assert!(mres == m * m);
+
+    let mut vres = v;
+    vres.gemv(&1, &m, &v, &1);
+    assert!(vres == m * v + v);
+}
+
+#[test]
+fn test_dmat_to_buffers() {
+    let a: DMat<int> = DMat::from_fn(3, 4, |i, j| (i + 2 * j) as int);
+    let b: DMat<int> = DMat::from_fn(4, 2, |i, j| (i as int) - (j as int));
+    let x: DVec<int> = DVec::from_fn(4, |i| i as int);
+
+    let mut out = DMat::new_zeros(3, 2);
+    a.mul_to(&b, &mut out);
+    assert!(out == a * b);
+
+    let mut vout = DVec::new_zeros(3);
+    a.mul_vec_to(&x, &mut vout);
+    assert!(vout == a * x);
+
+    // the previous content of the output is discarded, even if it is not finite.
+    let af: DMat<f64> = DMat::from_fn(3, 4, |i, j| (i + 2 * j) as f64);
+    let bf: DMat<f64> = DMat::from_fn(4, 2, |i, j| (i as f64) - (j as f64));
+    let xf: DVec<f64> = DVec::from_fn(4, |i| i as f64);
+    let _nan: f64     = Float::nan();
+
+    let mut fout = DMat::from_elem(3, 2, _nan);
+    af.mul_to(&bf, &mut fout);
+    assert!(fout == af * bf);
+
+    let mut fvout = DVec::from_elem(3, _nan);
+    af.mul_vec_to(&xf, &mut fvout);
+    assert!(fvout == af * xf);
+
+    let mut tout = DMat::new_zeros(4, 3);
+    a.transpose_to(&mut tout);
+    assert!(tout == na::transpose(&a));
+
+    let mut sout = DMat::new_zeros(3, 4);
+    a.add_to(&a, &mut sout);
+    assert!(sout == a * 2);
+
+    let mut xout = DVec::new_zeros(4);
+    x.add_to(&x, &mut xout);
+    assert!(xout == x * 2);
+}
+
+#[test]
+fn test_dmat_ops_borrow_operands() {
+    let a: DMat<int> = DMat::from_fn(3, 3, |i, j| (i + 2 * j) as int);
+    let b: DMat<int> = DMat::from_fn(3, 3, |i, j| (i as int) - (j as int));
+    let x: DVec<int> = DVec::from_fn(3, |i| i as int);
+
+    // none of those expressions move their operands.
+    let sum  = a + b;
+    let diff = a - b;
+    let prod = a * b;
+    let ax   = a * x;
+
+    assert!(sum  == DMat::from_fn(3, 3, |i, j| a.at((i, j)) + b.at((i, j))));
+    assert!(diff == DMat::from_fn(3, 3, |i, j| a.at((i, j)) - b.at((i, j))));
+    assert!(prod == DMat::from_fn(3, 3, |i, j| {
+        range(0u, 3).fold(0, |acc, k| acc + a.at((i, k)) * b.at((k, j)))
+    }));
+    assert!(ax == DVec::from_fn(3, |i| range(0u, 3).fold(0, |acc, k| acc + a.at((i, k)) * x.at[k])));
+
+    // the operands are still usable.
+    assert!(a.at((2, 1)) == 4 && b.at((2, 1)) == 1 && x.at[2] == 2);
+}
+
+#[test]
+fn test_dmat_from_buffer() {
+    let buf: Vec<f64> = Vec::with_capacity(12);
+    let ptr = buf.as_ptr();
+
+    let m = DMat::from_buffer(3, 4, buf, 1.0f64);
+    assert!(m == DMat::new_ones(3, 4));
+
+    let m2 = DMat::from_buffer(4, 2, m.into_vec(), 0.0f64);
+    assert!(m2 == DMat::new_zeros(4, 2));
+    assert!(m2.as_vec().as_ptr() == ptr);
+
+    let v = DVec::from_buffer(5, m2.into_vec(), 2.0f64);
+    assert!(v == DVec::from_elem(5, 2.0));
+    assert!(v.at.as_ptr() == ptr);
+}
+
+#[test]
+fn test_new_uninitialized() {
+    let mut m: Mat3<f64> = unsafe { Mat3::new_uninitialized() };
+    let mut v: Vec3<f64> = unsafe { Vec3::new_uninitialized() };
+    let mut d: DMat<f64> = unsafe { DMat::new_uninitialized(2, 3) };
+
+    for (i, e) in m.iter_mut().enumerate() {
+        *e = i as f64;
+    }
+
+    for (i, e) in v.iter_mut().enumerate() {
+        *e = i as f64;
+    }
+
+    for (i, e) in d.as_mut_vec().iter_mut().enumerate() {
+        *e = i as f64;
+    }
+
+    assert!(m == Mat3::new(0.0, 3.0, 6.0, 1.0, 4.0, 7.0, 2.0, 5.0, 8.0));
+    assert!(v == Vec3::new(0.0, 1.0, 2.0));
+    assert!(d == DMat::from_col_vec(2, 3, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+}
+
+#[test]
+fn test_dmat_dvec_vec_conversions() {
+    let buf = vec!(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let ptr = buf.as_ptr();
+
+    let m = DMat::from_vec(2, 3, buf);
+    assert!(m.at((1, 0)) == 2.0 && m.at((0, 2)) == 5.0);
+    assert!(m.as_vec().as_ptr() == ptr);
+
+    let v = DVec::from_vec(m.into_vec());
+    assert!(v.as_vec() == [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].as_slice());
+    assert!(v.into_vec().as_ptr() == ptr);
+}
+
+#[test]
+fn test_mat4_inverse_affine() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let s: Vec3<f64>   = random::<Vec3<f64>>() + 0.5f64;
+
+        let scaling = Mat4::new(
+            s.x, 0.0, 0.0, 0.0,
+            0.0, s.y, 0.0, 0.0,
+            0.0, 0.0, s.z, 0.0,
+            0.0, 0.0, 0.0, 1.0);
+        let rigid  = na::to_homogeneous(&iso);
+        let affine = rigid * scaling;
+
+        assert!(na::approx_eq(&rigid.inverse_rigid(), &na::to_homogeneous(&na::inv(&iso).unwrap())));
+        assert!(na::approx_eq(&rigid.inverse_affine().unwrap(), &na::inv(&rigid).unwrap()));
+        assert!(na::approx_eq(&(affine * affine.inverse_affine().unwrap()), &na::one()));
+    }
+
+    assert!(Mat4::new(1.0f64, 0.0, 0.0, 1.0,
+                      0.0,    0.0, 0.0, 2.0,
+                      0.0,    0.0, 1.0, 3.0,
+                      0.0,    0.0, 0.0, 1.0).inverse_affine().is_none());
+}
+
+#[test]
+fn test_eigen_mat2_mat3() {
+    for _ in range(0u, 10000) {
+        // triangular matrices have real eigenvalues.
+        let m2: Mat2<f64> = random();
+        let m2 = Mat2::new(m2.m11, m2.m12, 0.0, m2.m22);
+        let (vecs2, vals2) = na::eigen_mat2(&m2).unwrap();
+
+        assert!(vals2.x <= vals2.y);
+        assert!(na::approx_eq(&(m2 * vecs2.col(0)), &(vecs2.col(0) * vals2.x)));
+        assert!(na::approx_eq(&(m2 * vecs2.col(1)), &(vecs2.col(1) * vals2.y)));
+
+        let m3: Mat3<f64> = random();
+        let m3 = Mat3::new(m3.m11, m3.m12, m3.m13,
+                           0.0,    m3.m22, m3.m23,
+                           0.0,    0.0,    m3.m33);
+        let (vecs3, vals3) = na::eigen_mat3(&m3).unwrap();
+
+        assert!(vals3.x <= vals3.y && vals3.y <= vals3.z);
+
+        for i in range(0u, 3) {
+            assert!(na::approx_eq_eps(&(m3 * vecs3.col(i)), &(vecs3.col(i) * vals3.at(i)), &1.0e-5));
+        }
+    }
+
+    let (vecs, vals) = na::eigen_mat3(&Mat3::new(2.0f64, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0)).unwrap();
+    assert!(na::approx_eq(&vals, &Vec3::new(2.0, 2.0, 2.0)));
+    assert!(na::approx_eq(&vecs, &na::one()));
+
+    // rotations have complex eigenvalues.
+    assert!(na::eigen_mat2(&Mat2::new(0.0f64, -1.0, 1.0, 0.0)).is_none());
+    assert!(na::eigen_mat3(&Mat3::new(0.0f64, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0)).is_none());
+}
+
+#[test]
+fn test_eigen_sym3() {
+    for _ in range(0u, 10000) {
+        let m: Mat3<f64> = random();
+        let m = m + na::transpose(&m);
+
+        let (vecs, vals) = na::eigen_sym3(&m);
+
+        assert!(vals.x <= vals.y && vals.y <= vals.z);
+        assert!(na::approx_eq(&(na::transpose(&vecs) * vecs), &na::one()));
+
+        let diag = Mat3::new(vals.x, 0.0, 0.0, 0.0, vals.y, 0.0, 0.0, 0.0, vals.z);
+        assert!(na::approx_eq_eps(&(vecs * diag * na::transpose(&vecs)), &m, &1.0e-7));
+    }
+
+    // repeated eigenvalues.
+    let rot: Rot3<f64> = random();
+    let diag = Mat3::new(1.0f64, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0);
+    let m    = *rot.submat() * diag * na::transpose(rot.submat());
+
+    let (vecs, vals) = na::eigen_sym3(&m);
+
+    assert!(na::approx_eq(&vals, &Vec3::new(1.0, 3.0, 3.0)));
+    assert!(na::approx_eq(&(na::transpose(&vecs) * vecs), &na::one()));
+    assert!(na::approx_eq(&(vecs * diag * na::transpose(&vecs)), &m));
+
+    let (vecs, vals) = na::eigen_sym3(&(diag * 0.0));
+    assert!(na::approx_eq(&vals, &na::zero()) && vecs == na::one());
+}
+
+#[test]
+fn test_conj_transpose_real() {
+    let m: Mat4<f64>  = random();
+    let d: DMat<f64>  = DMat::new_random(3, 5);
+
+    assert!(na::conj_transpose(&m) == na::transpose(&m));
+    assert!(na::conj_transpose(&d) == na::transpose(&d));
+}
+
+#[test]
+fn test_dmat_solve() {
+    for _ in range(0u, 100) {
+        let m: DMat<f64> = DMat::new_random(10, 10);
+        let x: DVec<f64> = DVec::new_random(10);
+        let b = m * x;
+
+        assert!(na::approx_eq(&m.solve_partial_pivot(&b).unwrap(), &x));
+    }
+
+    let singular = DMat::from_row_vec(2, 2, [1.0f64, 2.0, 2.0, 4.0]);
+    assert!(singular.solve_partial_pivot(&DVec::from_slice(2, [1.0, 1.0])).is_none());
+    assert!(singular.solve(&DVec::from_slice(2, [1.0, 1.0])).is_none());
+}
+
+#[test]
+fn test_dmat_solve_exact() {
+    // the first pivot is zero, and the elimination involves non-integer intermediate factors.
+    let m = DMat::from_row_vec(3, 3, [0i64, 2, 3,
+                                      4, 5, 6,
+                                      7, 8, 10]);
+    let x = DVec::from_slice(3, [3i64, -7, 11]);
+    let b = m * x;
+
+    assert!(m.solve(&b).unwrap() == x);
+
+    let singular = DMat::from_row_vec(3, 3, [1i64, 2, 3,
+                                             2, 4, 6,
+                                             1, 0, 1]);
+    assert!(singular.solve(&DVec::from_slice(3, [1, 1, 1])).is_none());
+}
+
+// Minimal interval scalar: it implements neither `Div`, `Rem`, nor `One`, thus not `Num`.
+#[deriving(Clone, PartialEq, Show)]
+struct Interval {
+    lo: f64,
+    hi: f64
+}
+
+impl Add<Interval, Interval> for Interval {
+    fn add(&self, other: &Interval) -> Interval {
+        Interval { lo: self.lo + other.lo, hi: self.hi + other.hi }
+    }
+}
+
+impl Mul<Interval, Interval> for Interval {
+    fn mul(&self, other: &Interval) -> Interval {
+        let (a, b, c, d) = (self.lo * other.lo, self.lo * other.hi, self.hi * other.lo, self.hi * other.hi);
+
+        Interval { lo: a.min(b).min(c.min(d)), hi: a.max(b).max(c.max(d)) }
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Interval {
+        Interval { lo: 0.0, hi: 0.0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+#[test]
+fn test_interval_products() {
+    let i = |lo: f64, hi: f64| Interval { lo: lo, hi: hi };
+    let m = Mat2::new(i(1.0, 2.0), i(0.0, 0.0), i(-1.0, 1.0), i(2.0, 2.0));
+    let v = Vec2::new(i(1.0, 1.0), i(-1.0, 0.0));
+
+    assert!(m * v == Vec2::new(i(1.0, 2.0), i(-3.0, 1.0)));
+    assert!(na::dot(&v, &v) == i(1.0, 2.0));
+    assert!((m * m).m11 == i(1.0, 4.0));
+
+    let dm = DMat::from_row_vec(1, 1, &[i(1.0, 2.0)]);
+    assert!((dm * dm).at((0, 0)) == i(1.0, 4.0));
+}
+
+#[test]
+fn test_det_bareiss() {
+    let m = DMat::from_row_vec(4, 4, &[0i64, 2, 1, 3,
+                                       1,    0, 2, 1,
+                                       3,    1, 0, 2,
+                                       2,    3, 1, 0]);
+    assert!(m.det_bareiss() == -62);
+
+    let m = Mat5::new(2i, 0, 0, 0, 0,
+                      0,  0, 3, 0, 0,
+                      0,  1, 0, 0, 0,
+                      0,  0, 0, 0, 5,
+                      0,  0, 0, 7, 0);
+    assert!(m.det_bareiss() == 210);
+
+    // with `f64`, both products of this determinant round to the same value.
+    let a = 100_000_000i64;
+    assert!(Mat2::new(a, a + 1, a - 1, a).det_bareiss() == 1);
+    assert!(Mat3::new(1i64, 2, 3, 2, 4, 6, 7, 8, 9).det_bareiss() == 0);
+
+    let m = Mat3::new(2i, -1, 5, 3, 4, -2, 0, 6, 1);
+    assert!(m.det_bareiss() == na::det(&m));
+}
+
+// Only compiles if `v` and `m` have the same dimension.
+fn common_dim<D: TypeDim, V: StaticDim<D>, M: StaticDim<D>>(_: &V, _: &M) -> uint {
+    TypeDim::value(None::<D>)
+}
+
+#[test]
+fn test_static_dim() {
+    let m3: Mat3<f64> = na::one();
+    let m4: Mat4<f64> = na::one();
+
+    assert!(common_dim(&Vec3::new(1.0f64, 2.0, 3.0), &m3) == 3);
+    assert!(common_dim(&Pnt4::new(1.0f64, 2.0, 3.0, 4.0), &m4) == 4);
+}
+
+#[test]
+fn test_mat_from_iter() {
+    let m = Mat2::from_row_iter(range(1i, 5));
+    assert!(m == Mat2::new(1, 2, 3, 4));
+    assert!(Mat2::from_col_iter(range(1i, 5)) == na::transpose(&m));
+
+    let d = DMat::from_row_iter(2, 3, range(1i, 7));
+    assert!(d == DMat::from_row_vec(2, 3, &[1, 2, 3, 4, 5, 6]));
+    assert!(DMat::from_col_iter(2, 3, range(1i, 7)) == DMat::from_col_vec(2, 3, &[1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+#[should_fail]
+fn test_dmat_from_iter_too_short() {
+    let _ = DMat::from_col_iter(2, 3, range(1i, 6));
+}
+
+#[test]
+fn test_rect_mat_products() {
+    let a = Mat2x3::new(1i, 2, 3,
+                        4, 5, 6);
+    let b = a.transpose_cpy();
+
+    assert!(b == Mat3x2::new(1, 4,
+                             2, 5,
+                             3, 6));
+    assert!(a * Vec3::new(1i, 0, -1) == Vec2::new(-2, -2));
+    assert!(Vec2::new(1i, 1) * a == Vec3::new(5, 7, 9));
+    assert!(a * b == Mat2::new(14, 32, 32, 77));
+    assert!(b * a == Mat3::new(17, 22, 27, 22, 29, 36, 27, 36, 45));
+    assert!(Mat2::new(0i, 1, 1, 0) * a == Mat2x3::new(4, 5, 6, 1, 2, 3));
+}
+
+#[test]
+fn test_rect_mat_approx_eq() {
+    let a = Mat2x3::new(1.0f64, 2.0, 3.0,
+                        4.0,    5.0, 6.0);
+    let b = Mat2x3::new(1.0f64, 2.0, 3.0,
+                        4.0,    5.0, 6.0 + 1.0e-9);
+    let c = Mat2x3::new(1.0f64, 2.0, 3.0,
+                        4.0,    5.1, 6.0);
+
+    assert!(na::approx_eq(&a, &b));
+    assert!(!na::approx_eq(&a, &c));
+    assert!(na::approx_eq_eps(&a, &c, &0.2));
+    assert!(na::approx_eq(&a.transpose_cpy(), &b.transpose_cpy()));
+    assert!(!na::approx_eq(&a.transpose_cpy(), &c.transpose_cpy()));
+
+    let m = Mat3x4::new(1.0f64, 2.0, 3.0,  4.0,
+                        5.0,    6.0, 7.0,  8.0,
+                        9.0,   10.0, 11.0, 12.0);
+    let mut n = m.clone();
+    n.m34 = n.m34 + 1.0e-9;
+
+    assert!(na::approx_eq(&m, &n));
+    assert!(na::approx_eq(&m.transpose_cpy(), &n.transpose_cpy()));
+
+    n.m21 = 0.0;
+    assert!(!na::approx_eq(&m, &n));
+    assert!(!na::approx_eq(&m.transpose_cpy(), &n.transpose_cpy()));
+}
+
+#[test]
+fn test_band() {
+    let m = Mat3::new(1i, 2, 3,
+                      4, 5, 6,
+                      7, 8, 9);
+
+    assert!(m.diag_k(0).as_slice() == [1, 5, 9].as_slice());
+    assert!(m.diag_k(1).as_slice() == [2, 6].as_slice());
+    assert!(m.diag_k(-2).as_slice() == [7].as_slice());
+    assert!(m.diag_k(3).len() == 0);
+    assert!(m.triu(0) == Mat3::new(1, 2, 3, 0, 5, 6, 0, 0, 9));
+    assert!(m.tril(-1) == Mat3::new(0, 0, 0, 4, 0, 0, 7, 8, 0));
+    assert!(m.triu(0) + m.tril(-1) == m);
+
+    let d = DMat::from_row_vec(2, 3, &[1i, 2, 3, 4, 5, 6]);
+    assert!(d.diag_k(1).as_slice() == [2, 6].as_slice());
+    assert!(d.diag_k(-1).as_slice() == [4].as_slice());
+    assert!(d.tril(1) == DMat::from_row_vec(2, 3, &[1, 2, 0, 4, 5, 6]));
+}
+
+#[test]
+fn test_structural_predicates() {
+    let r: Rot3<f64>  = random();
+    let rm            = *r.submat();
+    let sym           = na::transpose(&rm) + rm;
+    let eps           = 1.0e-7f64;
+
+    assert!(rm.is_orthogonal(&eps));
+    assert!(!sym.is_orthogonal(&eps));
+    assert!(sym.is_symmetric(&eps));
+    assert!(Mat3::new(1.0f64, 2.0, 3.0, 1.0e-9, 4.0, 5.0, 0.0, 0.0, 6.0).is_upper_triangular(&eps));
+    assert!(!Mat3::new(1.0f64, 2.0, 3.0, 1.0e-9, 4.0, 5.0, 0.0, 0.0, 6.0).is_diagonal(&eps));
+
+    let d = DMat::from_row_vec(2, 3, &[1.0f64, 0.0, 0.0, 0.0, 2.0, 0.0]);
+    assert!(d.is_diagonal(&eps));
+    assert!(d.is_upper_triangular(&eps));
+    assert!(!d.is_symmetric(&eps));
+    assert!(!d.is_orthogonal(&eps));
+}
+
+#[test]
+fn test_relative_eq_norm() {
+    let a = DMat::from_row_vec(2, 2, &[1.0e6f64, 2.0e6, 3.0e6, 4.0e6]);
+    let b = DMat::from_row_vec(2, 2, &[1.0e6f64 + 1.0, 2.0e6, 3.0e6, 4.0e6 - 1.0]);
+
+    assert!(!na::approx_eq_eps(&a, &b, &1.0e-3));
+    assert!(na::relative_eq_norm(&a, &b, &1.0e-3));
+    assert!(!na::relative_eq_norm(&a, &b, &1.0e-9));
+
+    let zero: Mat2<f64> = na::zero();
+    assert!(na::relative_eq_norm(&zero, &Mat2::new(1.0e-8, 0.0, 0.0, 0.0), &1.0e-7));
+    assert!(!na::relative_eq_norm(&zero, &Mat2::new(1.0e-6, 0.0, 0.0, 0.0), &1.0e-7));
+}
+
+#[test]
+fn test_weighted_mean_cov_dmat() {
+    let mat = DMat::from_row_vec(
+        5,
+        3,
+        [
+            4.0f64, 2.0, 0.60,
+            4.2f64, 2.1, 0.59,
+            3.9f64, 2.0, 0.58,
+            4.3f64, 2.1, 0.62,
+            4.1f64, 2.2, 0.63
+        ]
+    );
+
+    // unit weights give back the unweighted mean and covariance.
+    let ones = DVec::from_elem(5, 1.0f64);
+    assert!(na::approx_eq(&na::weighted_mean(&mat, &ones), &na::mean(&mat)));
+    assert!(na::approx_eq(&na::weighted_cov(&mat, &ones, na::SampleCov), &na::cov(&mat)));
+
+    // integer weights are equivalent to repeated observations.
+    let data = DMat::from_row_vec(2, 2, &[1.0f64, 2.0, 3.0, 6.0]);
+    let rep  = DMat::from_row_vec(3, 2, &[1.0f64, 2.0, 3.0, 6.0, 3.0, 6.0]);
+    let w    = DVec::from_slice(2, [1.0f64, 2.0]);
+    let rep_mean: DVec<f64> = na::mean(&rep);
+    let pop_cov = na::weighted_cov(&data, &w, na::PopulationCov);
+
+    assert!(na::approx_eq(&na::weighted_mean(&data, &w), &rep_mean));
+    assert!(na::approx_eq(&(pop_cov * 3.0), &(na::cov(&rep) * 2.0)));
+}
+
+#[test]
+fn test_running_stats() {
+    let mat = DMat::from_row_vec(
+        5,
+        3,
+        [
+            4.0f64, 2.0, 0.60,
+            4.2f64, 2.1, 0.59,
+            3.9f64, 2.0, 0.58,
+            4.3f64, 2.1, 0.62,
+            4.1f64, 2.2, 0.63
+        ]
+    );
+
+    let mut all   = RunningStats::new(3);
+    let mut first = RunningStats::new(3);
+    let mut last  = RunningStats::new(3);
+
+    for i in range(0u, 5) {
+        let row = mat.row_slice(i, 0, 3);
+
+        all.push(&row);
+
+        if i < 2 { first.push(&row) } else { last.push(&row) }
+    }
+
+    first.merge(&last);
+
+    let mean: DVec<f64> = na::mean(&mat);
+    assert!(all.count() == 5 && first.count() == 5);
+    assert!(na::approx_eq(all.mean(), &mean));
+    assert!(na::approx_eq(first.mean(), &mean));
+    assert!(na::approx_eq(&all.cov(na::SampleCov), &na::cov(&mat)));
+    assert!(na::approx_eq(&first.cov(na::SampleCov), &na::cov(&mat)));
+    assert!(na::approx_eq(&(all.cov(na::PopulationCov) * 5.0), &(na::cov(&mat) * 4.0)));
+}
+
+#[test]
+fn test_cholesky() {
+    let l = Mat3::new(2.0f64, 0.0, 0.0,
+                      1.0,    3.0, 0.0,
+                      -1.0,   0.5, 1.5);
+    let m = l * na::transpose(&l);
+
+    assert!(na::approx_eq(&na::cholesky(&m).unwrap(), &l));
+
+    let d = DMat::from_row_vec(2, 2, &[4.0f64, 2.0, 2.0, 1.0]);
+    assert!(na::cholesky(&d).is_none());
+    assert!(na::cholesky(&DMat::from_row_vec(2, 2, &[4.0f64, 2.0, 2.0, 2.0])).is_some());
+}
+
+#[test]
+fn test_multivariate_normal() {
+    let mean = DVec::from_slice(2, [1.0f64, -2.0]);
+    let cov  = DMat::from_row_vec(2, 2, &[2.0f64, 0.6, 0.6, 0.5]);
+    let mvn  = MultivariateNormal::new(mean.clone(), &cov).unwrap();
+
+    let mut rng   = task_rng();
+    let mut stats = RunningStats::new(2);
+
+    for _ in range(0u, 100000) {
+        stats.push(&mvn.ind_sample(&mut rng));
+    }
+
+    assert!(na::approx_eq_eps(stats.mean(), &mean, &0.05));
+    assert!(na::approx_eq_eps(&stats.cov(na::SampleCov), &cov, &0.05));
+    assert!(MultivariateNormal::new(mean, &DMat::from_row_vec(2, 2, &[1.0f64, 2.0, 2.0, 1.0])).is_none());
+}
+
+#[test]
+fn test_random_orthogonal() {
+    for n in range(1u, 8) {
+        let q: DMat<f64> = DMat::new_random_orthogonal(n);
+
+        assert!(q.nrows() == n && q.ncols() == n);
+        assert!(q.is_orthogonal(&1.0e-7));
+    }
+}
+
+#[test]
+fn test_random_spd() {
+    let m: DMat<f64> = DMat::new_random_spd(5, 100.0);
+
+    assert!(m == na::transpose(&m));
+    assert!(na::cholesky(&m).is_some());
+
+    let (_, eigs): (DMat<f64>, DVec<f64>) = na::eigen_qr(&m, &1.0e-9, 1000);
+    let mut lo = eigs.at(0);
+    let mut hi = eigs.at(0);
+
+    for i in range(1u, 5) {
+        lo = lo.min(eigs.at(i));
+        hi = hi.max(eigs.at(i));
+    }
+
+    assert!(na::approx_eq_eps(&lo, &1.0, &1.0e-6));
+    assert!(na::approx_eq_eps(&hi, &100.0, &1.0e-4));
+}
+
+#[test]
+fn test_roots() {
+    // (x - 1)(x - 2)(x - 3)
+    let mut rs = na::roots(&[-6.0f64, 11.0, -6.0, 1.0, 0.0]).unwrap();
+    rs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!(rs.len() == 3);
+
+    for (i, &(re, im)) in rs.iter().enumerate() {
+        assert!(na::approx_eq(&re, &((i + 1) as f64)));
+        assert!(im == 0.0);
+    }
+
+    // (x^2 + 1)(x + 2)
+    let rs = na::roots(&[2.0f64, 1.0, 2.0, 1.0]).unwrap();
+    let complex: Vec<&(f64, f64)> = rs.iter().filter(|r| r.val1() != 0.0).collect();
+
+    assert!(complex.len() == 2);
+    assert!(complex.iter().all(|r| na::approx_eq(&r.val0(), &0.0) && na::approx_eq(&r.val1().abs(), &1.0)));
+    assert!(rs.iter().any(|r| r.val1() == 0.0 && na::approx_eq(&r.val0(), &-2.0)));
+    assert!(na::roots(&[3.0f64]).unwrap().is_empty());
+}
+
+#[test]
+fn test_vandermonde() {
+    let v = DMat::vandermonde(&[1i, 2, 3], 3);
+
+    assert!(v == DMat::from_row_vec(3, 4, &[1, 1, 1, 1,
+                                            1, 2, 4, 8,
+                                            1, 3, 9, 27]));
+
+    // evaluates 1 - x + 2x^2 at every point.
+    assert!(v * DVec::from_slice(4, [1i, -1, 2, 0]) == DVec::from_slice(3, [2i, 7, 16]));
+}
+
+#[test]
+fn test_toeplitz_circulant() {
+    let t = DMat::toeplitz(&[1i, 2, 3], &[1i, 4]);
+    assert!(t == DMat::from_row_vec(3, 2, &[1, 4,
+                                            2, 1,
+                                            3, 2]));
+
+    let c = DMat::circulant(&[1i, 2, 3]);
+    assert!(c == DMat::from_row_vec(3, 3, &[1, 3, 2,
+                                            2, 1, 3,
+                                            3, 2, 1]));
+
+    for n in range(1u, 12) {
+        let col: DVec<f64> = DVec::new_random(n);
+        let x: DVec<f64>   = DVec::new_random(n);
+        let c              = DMat::circulant(col.as_slice());
+
+        assert!(na::approx_eq(&x.circular_convolve(&col), &(c * x)));
+    }
+}
+
+#[test]
+fn test_gallery() {
+    let h: DMat<f64> = na::gallery::hilbert(4);
+    assert!(h.at((1, 2)) == 0.25 && h.is_symmetric(&0.0001));
+    assert!(na::cholesky(&h).is_some());
+
+    let l: DMat<f64> = na::gallery::lehmer(5);
+    assert!(l.at((3, 1)) == 0.5 && na::cholesky(&l).is_some());
+
+    let w: DMat<f64> = na::gallery::wilkinson(21);
+    assert!(w.at((0, 0)) == 10.0 && w.at((10, 10)) == 0.0 && w.at((3, 4)) == 1.0);
+    assert!(w.triu(2).is_diagonal(&1.0e-12) && w.tril(-2).is_diagonal(&1.0e-12));
+
+    let b: DMat<f64> = na::gallery::random_banded(6, 1, 2);
+    assert!(b.tril(-2).is_diagonal(&1.0e-12) && b.triu(3).is_diagonal(&1.0e-12));
+
+    for n in [1u, 3, 4, 5, 6, 8, 10, 11, 12].iter() {
+        let n = *n;
+        let m: DMat<uint> = na::gallery::magic(n);
+        let sum = n * (n * n + 1) / 2;
+        let mut diag = 0u;
+        let mut anti = 0u;
+
+        for i in range(0u, n) {
+            assert!(range(0u, n).fold(0u, |acc, j| acc + m.at((i, j))) == sum);
+            assert!(range(0u, n).fold(0u, |acc, j| acc + m.at((j, i))) == sum);
+            diag = diag + m.at((i, i));
+            anti = anti + m.at((i, n - 1 - i));
+        }
+
+        assert!(diag == sum && anti == sum);
+
+        let mut elts = m.as_vec().to_vec();
+        elts.sort();
+        assert!(elts == range(1u, n * n + 1).collect());
+    }
+}
+
+#[test]
+fn test_is_identity_is_zero() {
+    let r: Rot3<f64> = random();
+    let i            = r * na::inv(&r).unwrap();
+    let m            = na::to_homogeneous(&i);
+
+    assert!(na::is_identity(&i, &1.0e-7));
+    assert!(!na::is_identity(&r, &1.0e-7));
+    assert!(m.is_identity(&1.0e-7));
+    assert!(na::is_zero(&(m - m * m), &1.0e-7));
+    assert!(!na::is_zero(&m, &1.0e-7));
+
+    let d = DMat::from_row_vec(2, 2, &[1.0f64 + 1.0e-10, 0.0, -1.0e-10, 1.0]);
+    assert!(d.is_identity(&1.0e-7));
+    assert!(!DMat::from_row_vec(1, 2, &[1.0f64, 0.0]).is_identity(&1.0e-7));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_mat() {
+    fn prop_mat_transpose(m: Mat3<int>, n: Mat3<int>) -> bool {
+        na::transpose(&(m * n)) == na::transpose(&n) * na::transpose(&m)
+    }
+
+    fn prop_invertible(m: na::Invertible<Mat4<f64>>) -> bool {
+        match na::inv(&m.mat) {
+            Some(i) => na::approx_eq_eps(&(m.mat * i), &na::one(), &1.0e-7),
+            None    => false
+        }
+    }
+
+    fn prop_dmat_transpose(m: DMat<int>) -> bool {
+        na::transpose(&na::transpose(&m)) == m
+    }
+
+    fn prop_invertible_dmat(m: na::Invertible<DMat<f64>>) -> bool {
+        na::inv(&m.mat).is_some()
+    }
+
+    quickcheck::quickcheck(prop_mat_transpose as fn(Mat3<int>, Mat3<int>) -> bool);
+    quickcheck::quickcheck(prop_invertible as fn(na::Invertible<Mat4<f64>>) -> bool);
+    quickcheck::quickcheck(prop_dmat_transpose as fn(DMat<int>) -> bool);
+    quickcheck::quickcheck(prop_invertible_dmat as fn(na::Invertible<DMat<f64>>) -> bool);
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd_vec4_mat4() {
+    use na::{SimdVec4, SimdMat4, Dot, Norm, Transpose};
+
+    for _ in range(0u, 1000) {
+        let a: Vec4<f32> = random();
+        let b: Vec4<f32> = random::<Vec4<f32>>() + 1.0f32;
+        let s: f32       = random::<f32>() + 1.0;
+        let m: Mat4<f32> = random();
+        let n: Mat4<f32> = random();
+
+        let sa = SimdVec4::from_vec4(&a);
+        let sb = SimdVec4::from_vec4(&b);
+        let sm = SimdMat4::from_mat4(&m);
+        let sn = SimdMat4::from_mat4(&n);
+
+        // conversions.
+        assert!(sa.to_vec4() == a);
+        assert!(sm.to_mat4() == m);
+        assert!(SimdVec4::new(a.x, a.y, a.z, a.w) == sa);
+        assert!(SimdVec4::new_repeat(s).to_vec4() == Vec4::new(s, s, s, s));
+        assert!(SimdMat4::new_identity().to_mat4() == na::one());
+
+        for i in range(0u, 4) {
+            assert!(sm.col(i).to_vec4() == m.col(i));
+        }
+
+        // componentwise operations are computed with the same float operations.
+        assert!((sa + sb).to_vec4() == a + b);
+        assert!((sa - sb).to_vec4() == a - b);
+        assert!((sa * sb).to_vec4() == a * b);
+        assert!((sa / sb).to_vec4() == a / b);
+        assert!((sa * s).to_vec4() == a * s);
+        assert!((sa / s).to_vec4() == a / s);
+        assert!((-sa).to_vec4() == -a);
+        assert!(na::zero::<SimdVec4>().is_zero() && !sb.is_zero());
+
+        // reductions may add the components in a different order.
+        assert!(na::approx_eq(&Dot::dot(&sa, &sb), &na::dot(&a, &b)));
+        assert!(na::approx_eq(&Norm::sqnorm(&sa), &na::sqnorm(&a)));
+        assert!(na::approx_eq(&Norm::norm(&sb), &na::norm(&b)));
+        assert!(na::approx_eq(&Norm::normalize_cpy(&sb).to_vec4(), &na::normalize(&b)));
+
+        let mut nb = sb.clone();
+        assert!(na::approx_eq(&nb.normalize(), &na::norm(&b)));
+        assert!(na::approx_eq(&nb.to_vec4(), &na::normalize(&b)));
+
+        // matrix operations.
+        assert!(na::approx_eq(&(sm * sa).to_vec4(), &(m * a)));
+        assert!(na::approx_eq(&(sm * sn).to_mat4(), &(m * n)));
+        assert!((sm + sn).to_mat4() == m + n);
+        assert!((sm - sn).to_mat4() == m - n);
+        assert!(Transpose::transpose_cpy(&sm).to_mat4() == na::transpose(&m));
+
+        let mut tm = sm.clone();
+        tm.transpose();
+        assert!(tm.to_mat4() == na::transpose(&m));
+
+        assert!(na::approx_eq(&sa, &SimdVec4::from_vec4(&a)));
+        assert!(na::approx_eq(&sm, &SimdMat4::from_mat4(&m)));
+    }
+}
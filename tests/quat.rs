@@ -1,9 +1,12 @@
 #![feature(macro_rules)]
 
 extern crate "nalgebra" as na;
+#[cfg(feature = "arbitrary")]
+extern crate quickcheck;
 
-use na::{Pnt3, Vec3, Rot3, UnitQuat, Rotation};
+use na::{Quat, Pnt2, Pnt3, Vec1, Vec2, Vec3, Vec6, Rot2, Rot3, Iso3, UnitQuat, UnitComplex, DualQuat, Rotation, EulerXYZ, EulerZYX, EulerZXZ};
 use std::rand::random;
+use std::num::Float;
 
 #[test]
 fn test_quat_as_mat() {
@@ -70,3 +73,358 @@ fn test_quat_euler_angles() {
         assert!(na::approx_eq(&q.to_rot(), &m))
     }
 }
+
+#[test]
+fn test_quat_from_rot() {
+    for _ in range(0u, 10000) {
+        let axis_angle: Vec3<f64> = random();
+
+        let r = Rot3::new(axis_angle);
+        let q = UnitQuat::new_with_rot(&r);
+
+        assert!(na::approx_eq(&q, &UnitQuat::new(axis_angle)));
+        assert!(na::approx_eq(&q.to_rot(), &r))
+    }
+}
+
+#[test]
+fn test_quat_slerp() {
+    for _ in range(0u, 10000) {
+        let axis_angle1: Vec3<f64> = random();
+        let axis_angle2: Vec3<f64> = random();
+
+        let q1 = UnitQuat::new(axis_angle1);
+        let q2 = UnitQuat::new(axis_angle2);
+
+        assert!(na::approx_eq(&q1.slerp(&q2, 0.0), &q1));
+        assert!(na::approx_eq(&q1.slerp(&q2, 1.0).to_rot(), &q2.to_rot()));
+
+        let id: UnitQuat<f64> = na::one();
+        assert!(na::approx_eq(&id.slerp(&q1, 0.5), &UnitQuat::new(axis_angle1 * 0.5)))
+    }
+}
+
+#[test]
+fn test_quat_nlerp() {
+    for _ in range(0u, 10000) {
+        let axis_angle1: Vec3<f64> = random();
+        let axis_angle2: Vec3<f64> = random();
+
+        let q1 = UnitQuat::new(axis_angle1);
+        let q2 = UnitQuat::new(axis_angle2);
+
+        assert!(na::approx_eq(&q1.nlerp(&q2, 0.0), &q1));
+        assert!(na::approx_eq(&q1.nlerp(&q2, 0.5), &q1.slerp(&q2, 0.5)));
+        assert!(na::approx_eq(&q1.nlerp(&q2, 1.0).to_rot(), &q2.to_rot()));
+    }
+}
+
+#[test]
+fn test_dual_quat_as_iso() {
+    for _ in range(0u, 10000) {
+        let iso: Iso3<f64> = random();
+        let pnt: Pnt3<f64> = random();
+
+        let dq = DualQuat::new_with_iso(&iso);
+
+        assert!(na::approx_eq(&dq.to_iso(), &iso));
+        assert!(na::approx_eq(&(dq * pnt), &(iso * pnt)));
+        assert!(na::approx_eq(&na::inv_transform(&dq, &(dq * pnt)), &pnt));
+    }
+}
+
+#[test]
+fn test_dual_quat_sclerp() {
+    for _ in range(0u, 10000) {
+        let dq1: DualQuat<f64> = random();
+        let dq2: DualQuat<f64> = random();
+
+        assert!(na::approx_eq(&dq1.sclerp(&dq2, 0.0), &dq1));
+        assert!(na::approx_eq(&dq1.sclerp(&dq2, 1.0).to_iso(), &dq2.to_iso()));
+
+        let half = dq1.sclerp(&dq2, 0.5);
+        let step = half * na::inv(&dq1).unwrap();
+        assert!(na::approx_eq(&(step * step).to_iso(), &(dq2 * na::inv(&dq1).unwrap()).to_iso()));
+    }
+}
+
+#[test]
+fn test_euler_angles_conventions() {
+    for _ in range(0u, 10000) {
+        let angles: Vec3<f64> = random();
+
+        let m = Rot3::from_euler_angles(EulerXYZ, angles.x, angles.y, angles.z);
+        assert!(na::approx_eq(&m, &Rot3::new_with_euler_angles(angles.x, angles.y, angles.z)));
+
+        for conv in [EulerXYZ, EulerZYX, EulerZXZ].iter() {
+            let m = Rot3::from_euler_angles(*conv, angles.x, angles.y, angles.z);
+            let q = UnitQuat::from_euler_angles(*conv, angles.x, angles.y, angles.z);
+            assert!(na::approx_eq(&q.to_rot(), &m));
+
+            let (a, b, c) = m.to_euler_angles(*conv);
+            assert!(na::approx_eq(&Vec3::new(a, b, c), &angles));
+
+            let (a, b, c) = q.to_euler_angles(*conv);
+            assert!(na::approx_eq(&Vec3::new(a, b, c), &angles));
+        }
+    }
+}
+
+#[test]
+fn test_euler_angles_gimbal_lock() {
+    let _pi_2: f64 = Float::frac_pi_2();
+
+    for conv in [EulerXYZ, EulerZYX].iter() {
+        let m = Rot3::from_euler_angles(*conv, 0.3, _pi_2, 0.2);
+        let (a, b, c) = m.to_euler_angles(*conv);
+
+        assert!(a == 0.0);
+        assert!(na::approx_eq(&b, &_pi_2));
+        assert!(na::approx_eq(&Rot3::from_euler_angles(*conv, a, b, c), &m));
+    }
+
+    let m = Rot3::from_euler_angles(EulerZXZ, 0.3, 0.0, 0.2);
+    let (a, b, c) = m.to_euler_angles(EulerZXZ);
+
+    assert!(a == 0.0 && b == 0.0);
+    assert!(na::approx_eq(&c, &0.5));
+}
+
+#[test]
+fn test_axis_angle() {
+    for _ in range(0u, 10000) {
+        let axis   = na::normalize(&(random::<Vec3<f64>>() - 0.5));
+        let angle  = random::<f64>() * Float::pi();
+        let near_pi: f64 = Float::pi() - random::<f64>() * 1.0e-9;
+
+        for ang in [angle, near_pi].iter() {
+            let (raxis, rangle) = Rot3::new(axis * *ang).axis_angle();
+            let (qaxis, qangle) = UnitQuat::new(axis * *ang).axis_angle();
+
+            assert!(na::approx_eq(&raxis, &axis) && na::approx_eq(&rangle, ang));
+            assert!(na::approx_eq(&qaxis, &axis) && na::approx_eq(&qangle, ang));
+        }
+    }
+
+    let (axis, angle) = Rot3::new(na::zero::<Vec3<f64>>()).axis_angle();
+    assert!(axis == Vec3::x() && angle == 0.0);
+}
+
+#[test]
+fn test_exp_log() {
+    for _ in range(0u, 10000) {
+        let w: Vec3<f64>     = random();
+        let twist: Vec6<f64> = random();
+
+        assert!(na::approx_eq(&Rot3::exp(&w), &Rot3::new(w)));
+        assert!(na::approx_eq(&Rot3::exp(&w).log(), &w));
+        assert!(na::approx_eq(&UnitQuat::exp(&w).to_rot(), &Rot3::new(w)));
+        assert!(na::approx_eq(&UnitQuat::exp(&w).log(), &w));
+        assert!(na::approx_eq(&Iso3::exp(&twist).log(), &twist));
+
+        // a twist with no rotational part is a pure translation.
+        let trans = Vec6::new(twist.x, twist.y, twist.z, 0.0, 0.0, 0.0);
+        assert!(na::approx_eq(&Iso3::exp(&trans), &Iso3::new(Vec3::new(twist.x, twist.y, twist.z), na::zero())));
+
+        // small angles.
+        let small = w * 1.0e-9;
+        assert!(na::approx_eq_eps(&Rot3::exp(&small).log(), &small, &1.0e-15));
+        assert!(na::approx_eq_eps(&UnitQuat::exp(&small).log(), &small, &1.0e-15));
+    }
+}
+
+#[test]
+fn test_screw() {
+    for _ in range(0u, 10000) {
+        let axis: Vec3<f64>  = random::<Vec3<f64>>() + 0.1f64;
+        let angle: f64       = random::<f64>() * 3.0 + 0.01;
+        let pitch: f64       = random();
+        let point: Pnt3<f64> = random();
+
+        let iso = Iso3::new_with_screw(axis, angle, pitch, point);
+
+        // the points of the screw axis are only translated along it.
+        let on_axis = point + axis * 2.0;
+        assert!(na::approx_eq(&(iso * on_axis), &(on_axis + na::normalize(&axis) * (pitch * angle))));
+
+        let (axis2, angle2, pitch2, point2) = iso.screw().unwrap();
+        assert!(na::approx_eq(&axis2, &na::normalize(&axis)));
+        assert!(na::approx_eq(&angle2, &angle));
+        assert!(na::approx_eq(&pitch2, &pitch));
+        assert!(na::approx_eq(&Iso3::new_with_screw(axis2, angle2, pitch2, point2), &iso));
+
+        let other: Iso3<f64> = random();
+        assert!(na::approx_eq(&iso.sclerp(&other, 0.0), &iso));
+        assert!(na::approx_eq(&iso.sclerp(&other, 1.0), &other));
+    }
+
+    assert!(Iso3::new(Vec3::new(1.0f64, 2.0, 3.0), na::zero()).screw().is_none());
+}
+
+#[test]
+fn test_uniform_random_rotations() {
+    let n = 100000u;
+    let mut mean_rotated = na::zero::<Vec3<f64>>();
+    let mut mean_sq      = [ 0.0f64, 0.0, 0.0, 0.0 ];
+
+    for _ in range(0u, n) {
+        let q: UnitQuat<f64> = random();
+        let r: Rot3<f64>     = random();
+
+        assert!(na::approx_eq(&na::norm(q.quat()), &1.0));
+
+        mean_rotated = mean_rotated + r * Vec3::new(1.0, 0.0, 0.0) / (n as f64);
+
+        let q = q.quat();
+        mean_sq[0] += q.w * q.w / (n as f64);
+        mean_sq[1] += q.i * q.i / (n as f64);
+        mean_sq[2] += q.j * q.j / (n as f64);
+        mean_sq[3] += q.k * q.k / (n as f64);
+    }
+
+    // for a uniform distribution, the rotated vectors are centered at the origin and all the
+    // quaternion components have the same variance.
+    assert!(na::approx_eq_eps(&mean_rotated, &na::zero(), &0.02));
+
+    for e in mean_sq.iter() {
+        assert!(na::approx_eq_eps(e, &0.25, &0.01));
+    }
+}
+
+#[test]
+fn test_quat_from_rotation_matrix_near_pi() {
+    for _ in range(0u, 10000) {
+        let axis: Vec3<f64> = na::normalize(&(random::<Vec3<f64>>() - 0.5f64));
+        let angle           = Float::pi() - random::<f64>() * 1.0e-7;
+
+        let r = Rot3::new(axis * angle);
+        let q = UnitQuat::from_rotation_matrix(&r);
+
+        // `q` and `-q` represent the same rotation.
+        let expected = UnitQuat::new(axis * angle);
+        assert!(na::approx_eq(&q, &expected) || na::approx_eq(&-*q.quat(), expected.quat()));
+        assert!(na::approx_eq(&q.to_rot(), &r));
+
+        let r = Rot3::new(random::<Vec3<f64>>() * 3.0f64);
+        assert!(na::approx_eq(&UnitQuat::from_rotation_matrix(&r).to_rot(), &r));
+    }
+}
+
+#[test]
+fn test_unit_complex() {
+    for _ in range(0u, 10000) {
+        let a: UnitComplex<f64> = random();
+        let b: UnitComplex<f64> = random();
+        let v: Vec2<f64>        = random();
+        let p: Pnt2<f64>        = random();
+
+        let (ra, rb) = (a.to_rot(), b.to_rot());
+
+        assert!(na::approx_eq(&(a * b).to_rot(), &(ra * rb)));
+        assert!(na::approx_eq(&(a * v), &(ra * v)));
+        assert!(na::approx_eq(&(a * p), &(ra * p)));
+        assert!(na::approx_eq(&(v * a), &(v * ra)));
+        assert!(na::approx_eq(&UnitComplex::new_with_rot(&ra), &a));
+        assert!(na::approx_eq(&a.angle(), &ra.angle()));
+        assert!(na::approx_eq(&(na::inv(&a).unwrap() * a), &na::one()));
+        assert!(na::approx_eq(&na::rotation(&a), &Vec1::new(a.angle())));
+        assert!(na::approx_eq(&a.slerp(&b, 1.0), &b));
+        assert!(na::approx_eq(&a.slerp(&b, 0.0), &a));
+
+        let r2 = Rot2::from_angle(a.angle());
+        assert!(na::approx_eq(&UnitComplex::from_angle(a.angle()).to_rot(), &r2));
+
+        // renormalization removes the drift.
+        let c = UnitComplex::new_with_cos_sin(*a.re() * 1.1, *a.im() * 1.1);
+        assert!(na::approx_eq(&c, &a));
+    }
+}
+
+#[test]
+fn test_rotation_powf() {
+    for _ in range(0u, 10000) {
+        let w: Vec3<f64> = random::<Vec3<f64>>() - 0.5f64;
+        let t: f64       = random::<f64>() * 4.0 - 2.0;
+        let r            = Rot3::new(w);
+        let q            = UnitQuat::new(w);
+
+        assert!(na::approx_eq(&r.powf(t), &Rot3::new(w * t)));
+        assert!(na::approx_eq(&q.powf(t), &UnitQuat::new(w * t)));
+        assert!(na::approx_eq(&(r.powf(0.5) * r.powf(0.5)), &r));
+        assert!(na::approx_eq(&q.powf(1.0), &q));
+        assert!(na::approx_eq(&q.powf(0.0), &na::one()));
+
+        let r2 = Rot2::new(Vec1::new(w.x));
+        assert!(na::approx_eq(&r2.powf(t), &Rot2::new(Vec1::new(w.x * t))));
+    }
+}
+
+#[test]
+fn test_rotation_mean() {
+    assert!(UnitQuat::<f64>::mean(&[]).is_none());
+
+    for _ in range(0u, 1000) {
+        let center: UnitQuat<f64> = random();
+        let w: Vec3<f64>          = (random::<Vec3<f64>>() - 0.5f64) * 0.2f64;
+
+        // symmetric perturbations around `center`, with opposite quaternion signs.
+        let a = center * UnitQuat::new(w);
+        let b = center * UnitQuat::new(-w);
+        let b = UnitQuat::new_with_quat(-*b.quat());
+
+        let mean = UnitQuat::mean(&[a, b, center]).unwrap();
+        assert!(na::approx_eq(&mean.to_rot(), &center.to_rot()));
+
+        let rmean = Rot3::mean(&[a.to_rot(), b.to_rot(), center.to_rot()]).unwrap();
+        assert!(na::approx_eq(&rmean, &center.to_rot()));
+
+        assert!(na::approx_eq(&Rot3::mean(&[center.to_rot()]).unwrap(), &center.to_rot()));
+    }
+
+    // the diagonal of the accumulated matrix is the largest for `q2`, which is orthogonal to the
+    // dominant eigenvector `q1`.
+    let s  = 1.0f64 / 3.0f64.sqrt();
+    let q1 = UnitQuat::new_with_quat(Quat::new(s, s, s, 0.0));
+    let q2 = UnitQuat::new_with_quat(Quat::new(0.0, 0.0, 0.0, 1.0f64));
+
+    let mean = UnitQuat::mean(&[q1, q1, q1, q2, q2]).unwrap();
+    assert!(na::approx_eq(&mean, &q1));
+}
+
+#[test]
+fn test_normalize_fast() {
+    for _ in range(0u, 10000) {
+        let v: Vec3<f32> = random();
+        let q: Quat<f32> = random();
+
+        assert!((na::norm(&v.normalize_fast_cpy()) - 1.0).abs() < 2.0e-3);
+        assert!((na::norm(&q.normalize_fast_cpy()) - 1.0).abs() < 2.0e-3);
+    }
+
+    assert!(Vec2::new(0.0f32, 0.0).normalize_fast_cpy() == na::zero());
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_rot_iso() {
+    fn prop_unit_quat(q: UnitQuat<f64>) -> bool {
+        na::approx_eq(&na::norm(q.quat()), &1.0)
+    }
+
+    fn prop_rot3_norm(r: Rot3<f64>, v: Vec3<f64>) -> bool {
+        na::approx_eq_eps(&na::norm(&(r * v)), &na::norm(&v), &1.0e-7)
+    }
+
+    fn prop_rot2_inv(r: Rot2<f64>) -> bool {
+        na::approx_eq(&(r * na::inv(&r).unwrap()), &na::one())
+    }
+
+    fn prop_iso3_inv(m: Iso3<f64>, p: Pnt3<f64>) -> bool {
+        na::approx_eq_eps(&(na::inv(&m).unwrap() * (m * p)), &p, &1.0e-7)
+    }
+
+    quickcheck::quickcheck(prop_unit_quat as fn(UnitQuat<f64>) -> bool);
+    quickcheck::quickcheck(prop_rot3_norm as fn(Rot3<f64>, Vec3<f64>) -> bool);
+    quickcheck::quickcheck(prop_rot2_inv as fn(Rot2<f64>) -> bool);
+    quickcheck::quickcheck(prop_iso3_inv as fn(Iso3<f64>, Pnt3<f64>) -> bool);
+}
@@ -1,9 +1,16 @@
-#![feature(macro_rules)]
+#![feature(macro_rules, phase)]
 
+#[phase(plugin, link)]
 extern crate "nalgebra" as na;
+#[cfg(feature = "arbitrary")]
+extern crate quickcheck;
 
-use std::rand::random;
-use na::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Mat3, Iterable, IterableMut};
+use std::num::{Float, abs};
+use std::rand::{random, task_rng};
+use std::rand::distributions::Range;
+use std::io::{MemWriter, BufReader};
+use na::{Vec0, Vec1, Vec2, Vec3, Vec4, Vec5, Vec6, Pnt3, Pnt4, Mat2, Mat3, DVec, DMat, Ordered, Barycenter, Iterable, IterableMut,
+         AddInPlace, SubInPlace, ScaleInPlace, MulInPlace};
 
 macro_rules! test_iterator_impl(
     ($t: ty, $n: ty) => (
@@ -318,3 +325,513 @@ fn test_outer_vec3() {
             8.0, 10.0, 12.0,
             12.0, 15.0, 18.0));
 }
+
+#[test]
+fn test_grow_dvec() {
+    let mut v: DVec<f64> = DVec::new_zeros(0);
+
+    v.push(1.0);
+    v.push(2.0);
+    v.extend(range(3u, 6).map(|e| e as f64));
+
+    assert!(v.len() == 5);
+    assert!(v.as_slice() == [1.0, 2.0, 3.0, 4.0, 5.0].as_slice());
+
+    assert!(v.pop() == Some(5.0));
+    v.truncate(2);
+
+    assert!(v.as_slice() == [1.0, 2.0].as_slice());
+    v.truncate(10);
+    assert!(v.len() == 2);
+}
+
+#[test]
+fn test_sample_sphere_high_dim() {
+    let mut nsamples = 0u;
+    let mut mean     = na::zero::<Vec6<f64>>();
+
+    na::sample_sphere(|s: Vec6<f64>| {
+        assert!(na::approx_eq_eps(&na::norm(&s), &1.0, &1.0e-6));
+        nsamples = nsamples + 1;
+        mean     = mean + s;
+    });
+
+    assert!(nsamples > 0);
+    assert!(na::approx_eq_eps(&(mean / (nsamples as f64)), &na::zero(), &0.1));
+
+    let mut nsamples = 0u;
+    DVec::sample_sphere(10, 100, |s: DVec<f64>| {
+        assert!(s.len() == 10);
+        assert!(na::approx_eq_eps(&na::norm(&s), &1.0, &1.0e-6));
+        nsamples = nsamples + 1;
+    });
+
+    assert!(nsamples == 100);
+}
+
+#[test]
+fn test_barycenter_affine_combination() {
+    let a = Pnt3::new(1.0f64, 2.0, 3.0);
+    let b = Pnt3::new(3.0f64, 0.0, -1.0);
+    let c = Pnt3::new(2.0f64, 4.0, 1.0);
+
+    assert!(na::approx_eq(&na::barycenter(&[a, b, c]), &Pnt3::new(2.0, 2.0, 1.0)));
+    assert!(na::approx_eq(&na::barycenter(&[a, b]), &na::center(&a, &b)));
+    assert!(na::approx_eq(&na::affine_combination(&[a, b, c], &[0.5, 0.25, 0.25]), &Pnt3::new(1.75, 2.0, 1.5)));
+
+    // the result does not depend on the origin.
+    let far = Pnt3::new(1.0e8f64, -1.0e8, 1.0e8);
+    let t   = *far.as_vec();
+    assert!(na::approx_eq(&(na::barycenter(&[a + t, b + t, c + t]) - far), &Vec3::new(2.0, 2.0, 1.0)));
+
+    let mut acc = Barycenter::new();
+    assert!(acc.barycenter().is_none());
+
+    acc.add_weighted(&a, &2.0f64);
+    acc.add(&b);
+    acc.add(&c);
+
+    assert!(na::approx_eq(&acc.total_weight(), &4.0));
+    assert!(na::approx_eq(&acc.barycenter().unwrap(), &Pnt3::new(1.75, 2.0, 1.5)));
+
+    let mut acc = Barycenter::new();
+    acc.add_weighted(&a, &1.0f64);
+    acc.add_weighted(&b, &-1.0f64);
+    assert!(acc.barycenter().is_none());
+}
+
+#[test]
+fn test_pnt_from_homogeneous() {
+    let p = Pnt4::new(2.0f64, 4.0, 6.0, 2.0);
+
+    assert!(Pnt3::from_homogeneous(&p) == Some(Pnt3::new(1.0, 2.0, 3.0)));
+    assert!(Pnt3::from_homogeneous(&Pnt4::new(2.0f64, 4.0, 6.0, 0.0)).is_none());
+    assert!(Pnt3::new(1.0f64, 2.0, 3.0).to_homogeneous() == Pnt4::new(1.0, 2.0, 3.0, 1.0));
+
+    for _ in range(0u, 10000) {
+        let p: Pnt3<f64> = random();
+
+        assert!(na::approx_eq(&Pnt3::from_homogeneous(&p.to_homogeneous()).unwrap(), &p));
+    }
+}
+
+#[test]
+fn test_spherical_cylindrical() {
+    use std::num::Float;
+
+    let v = Vec3::from_spherical(2.0f64, Float::frac_pi_2(), Float::frac_pi_2());
+    assert!(na::approx_eq(&v, &Vec3::new(0.0, 2.0, 0.0)));
+
+    let v = Vec3::from_cylindrical(2.0f64, Float::pi(), 3.0);
+    assert!(na::approx_eq(&v, &Vec3::new(-2.0, 0.0, 3.0)));
+
+    assert!(na::zero::<Vec3<f64>>().to_spherical() == (0.0, 0.0, 0.0));
+
+    for _ in range(0u, 10000) {
+        let v: Vec3<f64> = random::<Vec3<f64>>() - 0.5f64;
+        let p            = *v.as_pnt();
+
+        let (r, theta, phi) = v.to_spherical();
+        assert!(na::approx_eq(&r, &na::norm(&v)));
+        assert!(theta >= 0.0 && theta <= Float::pi());
+        assert!(na::approx_eq(&Vec3::from_spherical(r, theta, phi), &v));
+        assert!(na::approx_eq(&Pnt3::from_spherical(r, theta, phi), &p));
+
+        let (rho, phi, z) = p.to_cylindrical();
+        assert!(na::approx_eq(&z, &v.z));
+        assert!(na::approx_eq(&Vec3::from_cylindrical(rho, phi, z), &v));
+        assert!(na::approx_eq(&Pnt3::from_cylindrical(rho, phi, z), &p));
+    }
+}
+
+#[test]
+fn test_polar() {
+    use std::num::Float;
+
+    assert!(na::approx_eq(&Vec2::from_polar(2.0f64, Float::frac_pi_2()), &Vec2::new(0.0, 2.0)));
+
+    let (r, theta) = Vec2::new(-1.0f64, 0.0).to_polar();
+    assert!(na::approx_eq(&r, &1.0));
+    assert!(na::approx_eq(&theta, &Float::pi()));
+
+    for _ in range(0u, 10000) {
+        let v: Vec2<f64> = random::<Vec2<f64>>() - 0.5f64;
+
+        let (r, theta) = v.to_polar();
+        assert!(na::approx_eq(&r, &na::norm(&v)));
+        assert!(na::approx_eq(&Vec2::from_polar(r, theta), &v));
+    }
+}
+
+#[test]
+fn test_bytes() {
+    let v: Vec3<f32> = random();
+    let p: Pnt4<f64> = random();
+    let m: Mat3<f64> = random();
+
+    assert!(v.to_bytes().len() == 12);
+    assert!(Vec3::from_bytes(v.to_bytes().as_slice()) == Some(v));
+    assert!(Pnt4::from_bytes(p.to_bytes().as_slice()) == Some(p));
+    assert!(Mat3::from_bytes(m.to_bytes().as_slice()) == Some(m));
+    assert!(Vec3::<f32>::from_bytes(v.to_bytes().slice_to(11)).is_none());
+
+    // explicit byte order, with matrices written in column-major order.
+    let m = Mat2::new(1u16, 2,
+                      3,    4);
+    let mut w = MemWriter::new();
+    m.write_be(&mut w).unwrap();
+    assert!(w.unwrap() == vec!(0u8, 1, 0, 3, 0, 2, 0, 4));
+
+    let mut w = MemWriter::new();
+    m.write_le(&mut w).unwrap();
+    let bytes = w.unwrap();
+    assert!(bytes == vec!(1u8, 0, 3, 0, 2, 0, 4, 0));
+    assert!(Mat2::read_le(&mut BufReader::new(bytes.as_slice())).unwrap() == m);
+}
+
+#[test]
+fn test_random_with_distributions() {
+    let mut rng = task_rng();
+
+    let m: DMat<f64> = DMat::new_uniform(4, 5, &mut rng, -2.0, 3.0);
+    assert!(m.nrows() == 4 && m.ncols() == 5);
+    assert!(m.as_vec().iter().all(|e| *e >= -2.0 && *e < 3.0));
+
+    let m: DMat<int> = DMat::new_random_with(3, 3, &mut rng, &Range::new(10i, 20));
+    assert!(m.as_vec().iter().all(|e| *e >= 10 && *e < 20));
+
+    let g: DVec<f32> = DVec::new_gaussian(10000, &mut rng, 5.0, 0.1);
+    let mean = g.at.iter().fold(0.0, |a, b| a + *b) / 10000.0;
+    assert!(abs(mean - 5.0) < 0.01);
+
+    for _ in range(0u, 100) {
+        let v: Vec3<f64> = Vec3::new_random_unit(&mut rng);
+        assert!(na::approx_eq(&na::norm(&v), &1.0));
+
+        let v: DVec<f64> = DVec::new_random_unit(7, &mut rng);
+        assert!(na::approx_eq(&na::norm(&v), &1.0));
+    }
+}
+
+#[test]
+fn test_ordered() {
+    let nan: f64 = Float::nan();
+    let mut vs = vec!(
+        Ordered(Vec2::new(1.0, nan)),
+        Ordered(Vec2::new(1.0, 2.0)),
+        Ordered(Vec2::new(0.0, 5.0)),
+        Ordered(Vec2::new(1.0, -1.0)),
+        Ordered(Vec2::new(nan, 0.0))
+    );
+
+    vs.sort();
+
+    let ys: Vec<f64> = vs.iter().map(|v| v.clone().unwrap().y).collect();
+    assert!(ys.slice_to(3) == [5.0, -1.0, 2.0].as_slice());
+    assert!(vs[3].clone().unwrap().y.is_nan());
+    assert!(vs[4].clone().unwrap().x.is_nan());
+
+    assert!(Ordered(Vec2::new(nan, 1.0)) == Ordered(Vec2::new(nan, 1.0)));
+    assert!(Ordered(Pnt3::new(0.0f32, -0.0, 1.0)) == Ordered(Pnt3::new(-0.0, 0.0, 1.0)));
+    assert!(Ordered(DVec::from_elem(2, 1i)) < Ordered(DVec::from_elem(3, 1i)));
+
+    // incomparable components which are not NaN.
+    let a = Ordered(Vec2::new(Divisor(2), Divisor(1)));
+    let b = Ordered(Vec2::new(Divisor(3), Divisor(5)));
+    let c = Ordered(Vec2::new(Divisor(4), Divisor(5)));
+
+    assert!(a.partial_cmp(&b).is_none());
+    assert!(a != b);
+    assert!(a < c);
+}
+
+// Integers partially ordered by divisibility.
+#[deriving(PartialEq, Clone, Show)]
+struct Divisor(uint);
+
+impl PartialOrd for Divisor {
+    fn partial_cmp(&self, other: &Divisor) -> Option<Ordering> {
+        let (&Divisor(a), &Divisor(b)) = (self, other);
+
+        if a == b {
+            Some(Equal)
+        }
+        else if b % a == 0 {
+            Some(Less)
+        }
+        else if a % b == 0 {
+            Some(Greater)
+        }
+        else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_inplace() {
+    let mut v = Vec3::new(1.0f64, 2.0, 3.0);
+    v.add_inplace(&Vec3::new(1.0, 1.0, 1.0));
+    v.sub_inplace(&Vec3::new(0.0, 0.0, 2.0));
+    v.scale_inplace(&2.0);
+    assert!(v == Vec3::new(4.0, 6.0, 4.0));
+
+    let mut p = Pnt3::new(1.0f64, 2.0, 3.0);
+    p.add_inplace(&Vec3::new(1.0, 1.0, 1.0));
+    assert!(p == Pnt3::new(2.0, 3.0, 4.0));
+
+    let mut m = Mat2::new(1i, 2, 3, 4);
+    let n     = Mat2::new(0i, 1, 1, 0);
+    m.mul_inplace(&n);
+    assert!(m == Mat2::new(2, 1, 4, 3));
+
+    let mut dv = DVec::from_elem(4, 1i);
+    dv.add_inplace(&DVec::from_elem(4, 2i));
+    dv.scale_inplace(&3);
+    assert!(dv == DVec::from_elem(4, 9i));
+
+    let a: DMat<int> = DMat::from_fn(3, 2, |i, j| (i * 2 + j) as int);
+    let b: DMat<int> = DMat::from_fn(2, 2, |i, j| (i + 3 * j) as int - 1);
+    let mut c = a.clone();
+    c.mul_inplace(&b);
+    assert!(c == a * b);
+    c.sub_inplace(&(a * b));
+    assert!(c == DMat::new_zeros(3, 2));
+}
+
+#[test]
+fn test_approx_eq_ulps() {
+    // 0.1 + 0.2 and 0.3 are consecutive floats.
+    assert!(na::approx_eq_ulps(&(0.1f64 + 0.2), &0.3, 1));
+    assert!(!na::approx_eq_ulps(&(0.1f64 + 0.2), &0.3, 0));
+    assert!(na::approx_eq_ulps(&-0.0f32, &0.0, 0));
+
+    let nan: f64 = Float::nan();
+    assert!(!na::approx_eq_ulps(&nan, &nan, 8));
+
+    // the spacing of floats around 1.0e20 is 16384, way above the default epsilon.
+    let a = Vec3::new(1.0e20f64, 1.0, 0.0);
+    let b = Vec3::new(1.0e20f64 + 32768.0, 1.0, 0.0);
+    assert!(!na::approx_eq(&a, &b));
+    assert!(na::approx_eq_ulps(&a, &b, 2));
+    assert!(!na::approx_eq_ulps(&a, &b, 1));
+}
+
+#[test]
+fn test_approx_eq_eps_rel() {
+    // relative tolerance for large values.
+    let a = Vec2::new(1.0e12f64, 1.0);
+    let b = Vec2::new(1.0e12f64 + 1.0, 1.0);
+    assert!(!na::approx_eq(&a, &b));
+    assert!(na::approx_eq_eps_rel(&a, &b, &1.0e-6, &1.0e-9));
+
+    // absolute tolerance for values close to zero.
+    assert!(na::approx_eq_eps_rel(&1.0e-12f64, &-1.0e-12, &1.0e-9, &1.0e-9));
+
+    // small but distinct magnitudes are not equal when the absolute tolerance is small enough.
+    assert!(na::approx_eq(&1.0e-8f64, &2.0e-8));
+    assert!(!na::approx_eq_eps_rel(&1.0e-8f64, &2.0e-8, &1.0e-12, &1.0e-6));
+}
+
+#[test]
+fn test_assert_approx_eq() {
+    assert_approx_eq!(Vec3::new(1.0f64, 2.0, 3.0), Vec3::new(1.0, 2.0, 3.0 + 1.0e-9));
+    assert_approx_eq_eps!(DVec::from_slice(2, &[1.0f64, 2.0]), DVec::from_slice(2, &[1.1, 2.0]), 0.2);
+}
+
+#[test]
+#[should_fail]
+fn test_assert_approx_eq_fail() {
+    assert_approx_eq!(Vec3::new(1.0f64, 2.0, 3.0), Vec3::new(1.0, 2.0, 4.0));
+}
+
+#[test]
+fn test_collect() {
+    let v: Vec3<f64> = range(0u, 3).map(|i| i as f64).collect();
+    assert!(v == Vec3::new(0.0, 1.0, 2.0));
+
+    let d: DVec<f64> = v.iter().map(|e| *e * 2.0).collect();
+    assert!(d == DVec::from_slice(3, &[0.0, 2.0, 4.0]));
+}
+
+#[test]
+#[should_fail]
+fn test_collect_too_few() {
+    let _: Vec3<f64> = range(0u, 2).map(|i| i as f64).collect();
+}
+
+#[test]
+#[should_fail]
+fn test_collect_too_many() {
+    let _: Vec3<f64> = range(0u, 4).map(|i| i as f64).collect();
+}
+
+#[test]
+fn test_left_scalar_mul() {
+    let v: Vec3<f64> = random();
+    let p: Pnt4<f32> = random();
+    let d: DVec<f64> = DVec::new_random(5);
+
+    assert!(2.0f64 * v == v * 2.0f64);
+    assert!(3.0f32 * p == p * 3.0f32);
+    assert!(2.0f64 * d == d * 2.0f64);
+}
+
+#[test]
+fn test_try_cast() {
+    let v = Vec3::new(1.0f64, -2.5, 3.0e9);
+    let w: Vec3<f32> = na::cast(v);
+    assert!(w == Vec3::new(1.0f32, -2.5, 3.0e9));
+
+    let r: Option<Vec3<f32>> = na::try_cast(v);
+    assert!(r == Some(w));
+
+    let r: Option<Vec3<f32>> = na::try_cast(Vec3::new(1.0f64, 0.1, 0.0));
+    assert!(r.is_none());
+
+    let r: Option<Vec3<u8>> = na::try_cast(Vec3::new(1i, 2, 300));
+    assert!(r.is_none());
+
+    let r: Option<Vec3<u32>> = na::try_cast(Vec3::new(1i, -2, 3));
+    assert!(r.is_none());
+
+    let r: Option<Vec3<i32>> = na::try_cast(Vec3::new(1.0f64, 2.0, 3.0));
+    assert!(r == Some(Vec3::new(1, 2, 3)));
+
+    let m: Option<Mat2<f32>> = na::try_cast(Mat2::new(1.0f64, 2.0, 3.0, 4.0));
+    assert!(m == Some(Mat2::new(1.0, 2.0, 3.0, 4.0)));
+}
+
+#[test]
+fn test_try_cast_out_of_range() {
+    let nan: f64 = Float::nan();
+    let inf: f64 = Float::infinity();
+
+    assert!(na::try_cast::<f64, i32>(3.0e9).is_none());
+    assert!(na::try_cast::<f64, i32>(-3.0e9).is_none());
+    assert!(na::try_cast::<f64, i32>(2147483647.0) == Some(2147483647));
+    assert!(na::try_cast::<f64, i32>(2147483648.0).is_none());
+    assert!(na::try_cast::<f64, i32>(-2147483648.0) == Some(-2147483648));
+    assert!(na::try_cast::<f64, u8>(-1.0).is_none());
+    assert!(na::try_cast::<f64, u8>(256.0).is_none());
+    assert!(na::try_cast::<f32, u64>(1.8446744e19).is_none());
+
+    assert!(na::try_cast::<f64, int>(nan).is_none());
+    assert!(na::try_cast::<f64, u64>(inf).is_none());
+    assert!(na::try_cast::<f64, i64>(-inf).is_none());
+    assert!(na::try_cast::<f64, f32>(nan).unwrap().is_nan());
+    assert!(na::try_cast::<f64, f32>(inf) == Some(Float::infinity()));
+    assert!(na::try_cast::<f64, f32>(1.0e300).is_none());
+    assert!(na::try_cast::<f64, f32>(-1.0e300).is_none());
+
+    assert!(na::try_cast::<u64, f32>(std::u64::MAX).is_none());
+    assert!(na::try_cast::<u64, f64>(std::u64::MAX).is_none());
+    assert!(na::try_cast::<i64, f64>(std::i64::MAX).is_none());
+    assert!(na::try_cast::<i64, f64>(std::i64::MIN) == Some(-9223372036854775808.0));
+    assert!(na::try_cast::<u64, f64>(1 << 53) == Some(9007199254740992.0));
+    assert!(na::try_cast::<u64, f64>((1 << 53) + 1).is_none());
+
+    let r: Option<Vec3<i32>> = na::try_cast(Vec3::new(1.0f64, nan, 3.0));
+    assert!(r.is_none());
+}
+
+#[test]
+fn test_convolve_dvec() {
+    let signal = DVec::from_slice(4, [1i, 2, 3, 4]);
+    let kernel = DVec::from_slice(3, [1i, 0, -1]);
+
+    assert!(signal.convolve(&kernel, na::FullConvolution).as_slice() == [1, 2, 2, 2, -3, -4].as_slice());
+    assert!(signal.convolve(&kernel, na::SameConvolution).as_slice() == [2, 2, 2, -3].as_slice());
+    assert!(signal.convolve(&kernel, na::ValidConvolution).as_slice() == [2, 2].as_slice());
+    assert!(kernel.convolve(&signal, na::ValidConvolution).as_slice() == [2, 2].as_slice());
+    assert!(signal.correlate(&kernel, na::ValidConvolution).as_slice() == [-2, -2].as_slice());
+    assert!(signal.convolve(&DVec::from_vec(Vec::new()), na::FullConvolution).len() == 0);
+}
+
+#[test]
+fn test_zeros_ones_linspace() {
+    assert!(DVec::zeros(3) == DVec::from_slice(3, [0i, 0, 0]));
+    assert!(DVec::ones(2) == DVec::from_slice(2, [1.0f64, 1.0]));
+    assert!(DMat::zeros(2, 3) == DMat::from_elem(2, 3, 0i));
+    assert!(DMat::ones(3, 1) == DMat::from_elem(3, 1, 1u));
+
+    let l = DVec::linspace(1.0f64, 2.0, 5);
+    assert!(l == DVec::from_slice(5, [1.0, 1.25, 1.5, 1.75, 2.0]));
+    assert!(DVec::linspace(3.0f64, 7.0, 1) == DVec::from_slice(1, [3.0]));
+
+    let empty: DVec<f64> = DVec::linspace(0.0, 1.0, 0);
+    assert!(empty.len() == 0);
+}
+
+#[cfg(feature = "dual")]
+#[test]
+fn test_dual() {
+    use na::{Dual, Rot3};
+
+    let x = Dual::new_variable(3.0f64);
+    let c = Dual::new_constant(2.0f64);
+
+    // f(x) = (x + 2) * x / 2 - x, f'(x) = x
+    let f = (x + c) * x / c - x;
+    assert!(f == Dual::new(4.5, 3.0));
+    assert!(-x == Dual::new(-3.0, -1.0));
+
+    assert!(x.powi(3) == Dual::new(27.0, 27.0));
+    assert!(x.powi(0) == Dual::new(1.0, 0.0));
+    assert!(Dual::new_variable(0.0f64).powi(0) == Dual::new(1.0, 0.0));
+    assert!(Dual::new_variable(0.0f64).powi(1) == Dual::new(0.0, 1.0));
+
+    let s = Dual::new_variable(4.0f64).sqrt();
+    assert!(na::approx_eq(&s, &Dual::new(2.0, 0.25)));
+
+    let s = Dual::new_variable(0.5f64).sin();
+    assert!(na::approx_eq(&s, &Dual::new(0.5f64.sin(), 0.5f64.cos())));
+
+    // gradient of the dot product with respect to the first component.
+    let v = Vec3::new(Dual::new_variable(1.0f64), Dual::new_constant(2.0), Dual::new_constant(3.0));
+    assert!(na::dot(&v, &v) == Dual::new(14.0, 2.0));
+
+    // derivative of the norm with respect to the first component.
+    let v = Vec3::new(Dual::new_variable(3.0f64), Dual::new_constant(4.0), Dual::new_constant(0.0));
+    assert!(na::approx_eq(&na::norm(&v), &Dual::new(5.0, 0.6)));
+
+    // derivative of a rotated vector with respect to the rotation angle.
+    let angle = Dual::new_variable(0.5f64);
+    let _0    = Dual::new_constant(0.0f64);
+    let _1    = Dual::new_constant(1.0f64);
+    let rot   = Rot3::new(Vec3::new(_0, _0, angle));
+    let expected = Vec3::new(Dual::new(0.5f64.cos(), -0.5f64.sin()),
+                             Dual::new(0.5f64.sin(), 0.5f64.cos()),
+                             _0);
+
+    assert!(na::approx_eq(&(rot * Vec3::new(_1, _0, _0)), &expected));
+
+    // lexicographic order, consistent with the equality.
+    let a = Dual::new(1.0f64, 2.0);
+    let b = Dual::new(1.0f64, 3.0);
+
+    assert!(a < b && b > a && a != b);
+    assert!(a.partial_cmp(&b) == Some(Less));
+    assert!(a.partial_cmp(&a) == Some(Equal));
+    assert!(Dual::new(0.0f64, 10.0) < Dual::new(1.0, 0.0));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_vec_pnt() {
+    fn prop_vec_add_sub(a: Vec3<int>, b: Vec3<int>) -> bool {
+        a + b - b == a && a + b == b + a
+    }
+
+    fn prop_pnt_vec(p: Pnt4<int>, v: Vec4<int>) -> bool {
+        (p + v) - p == v
+    }
+
+    fn prop_dvec_neg(v: DVec<int>) -> bool {
+        -(-v.clone()) == v
+    }
+
+    quickcheck::quickcheck(prop_vec_add_sub as fn(Vec3<int>, Vec3<int>) -> bool);
+    quickcheck::quickcheck(prop_pnt_vec as fn(Pnt4<int>, Vec4<int>) -> bool);
+    quickcheck::quickcheck(prop_dvec_neg as fn(DVec<int>) -> bool);
+}